@@ -0,0 +1,234 @@
+//! Protobuf envelope handling shared by the streaming transports (WebSocket and gRPC).
+
+use crate::AppState;
+use crate::application::services::achievement::AchievementUnlock;
+use crate::application::services::leaderboard::{LeaderboardQuery, LeaderboardView};
+use crate::application::services::matchmaking::MatchmakingUpdate;
+use crate::domain::game::{DomainError, DomainEvent, GameCommand, GameSessionID, GameType, PlayerID, Role};
+use crate::domain::leaderboard::{LeaderboardPeriod, RankedEntry};
+use crate::pb::runecraftstudios::pastello::game::types::v1::{GameSessionId, GameType as GameTypePb, PlayerId};
+use crate::pb::runecraftstudios::pastello::web::game::v1::{
+    ClientEnvelope, client_envelope,
+    GameCommandEnvelope, game_command_envelope,
+    GameEventEnvelope, game_event_envelope,
+    server_envelope, AchievementUnlocked, GetLeaderboardQuery, Leaderboard, LeaderboardEntry, MatchFound, QueueStatus,
+};
+use crate::domain::room::PlayerKicked;
+use anyhow::{anyhow, Result};
+use std::any::Any;
+use tracing::{error, info, warn};
+
+pub(crate) fn to_event_envelope(game_type: &GameType, event: &dyn DomainEvent) -> GameEventEnvelope {
+    let json = serde_json::json!({
+        "type": event.event_type(),
+        "payload": event.to_json(),
+    })
+    .to_string();
+
+    let event = match game_type {
+        GameType::Puzzle => game_event_envelope::Event::PuzzleEvent(json),
+        GameType::Trivia => game_event_envelope::Event::TriviaEvent(json),
+    };
+    GameEventEnvelope { event: Some(event) }
+}
+
+/// Whether `event` removes `player_id` from its session; their stream closes after forwarding it.
+pub(crate) fn kicks(event: &dyn DomainEvent, player_id: &PlayerID) -> bool {
+    (event as &dyn Any)
+        .downcast_ref::<PlayerKicked>()
+        .is_some_and(|kicked| kicked.player_id == *player_id)
+}
+
+/// Matchmaking update for the connected player, as a `QueueStatus` or `MatchFound` message.
+pub(crate) fn to_matchmaking_message(update: &MatchmakingUpdate) -> server_envelope::Message {
+    match update {
+        MatchmakingUpdate::Queued { game_type, position, queued, waited_seconds, .. } => {
+            server_envelope::Message::QueueStatus(QueueStatus {
+                game_type: game_type_pb(game_type) as i32,
+                position: *position,
+                queued: *queued,
+                waited_seconds: *waited_seconds,
+            })
+        }
+        MatchmakingUpdate::MatchFound { session_id, game_type, player_ids, .. } => {
+            server_envelope::Message::MatchFound(MatchFound {
+                session_id: Some(GameSessionId { value: session_id.clone() }),
+                game_type: game_type_pb(game_type) as i32,
+                player_ids: player_ids.iter().map(|id| PlayerId { value: id.clone() }).collect(),
+            })
+        }
+    }
+}
+
+/// Achievement the connected player just earned, as an `AchievementUnlocked` message.
+pub(crate) fn to_achievement_message(unlock: &AchievementUnlock) -> server_envelope::Message {
+    server_envelope::Message::AchievementUnlocked(AchievementUnlocked {
+        achievement_id: unlock.achievement.id.clone(),
+        name: unlock.achievement.name.clone(),
+        description: unlock.achievement.description.clone(),
+        badge_url: unlock.achievement.badge_url.clone().unwrap_or_default(),
+        unlocked_at: Some(prost_types::Timestamp {
+            seconds: unlock.unlocked_at.timestamp(),
+            nanos: unlock.unlocked_at.timestamp_subsec_nanos() as i32,
+        }),
+        session_id: Some(GameSessionId { value: unlock.session_id.clone() }),
+    })
+}
+
+fn game_type_pb(game_type: &GameType) -> GameTypePb {
+    match game_type {
+        GameType::Puzzle => GameTypePb::Puzzle,
+        GameType::Trivia => GameTypePb::Trivia,
+    }
+}
+
+fn game_type_from_pb(game_type: GameTypePb) -> Result<GameType, DomainError> {
+    match game_type {
+        GameTypePb::Puzzle => Ok(GameType::Puzzle),
+        GameTypePb::Trivia => Ok(GameType::Trivia),
+        GameTypePb::Unspecified => Err(DomainError::InvalidPayload("game_type is required".to_string())),
+    }
+}
+
+pub(crate) fn leaderboard_query_from_pb(query: &GetLeaderboardQuery) -> Result<LeaderboardQuery, DomainError> {
+    Ok(LeaderboardQuery {
+        game_type: game_type_from_pb(query.game_type())?,
+        ruleset_id: Some(query.ruleset_id.clone()).filter(|id| !id.is_empty()),
+        period: LeaderboardPeriod::from_pb(query.period()),
+        limit: Some(query.limit).filter(|limit| *limit > 0),
+    })
+}
+
+pub(crate) fn to_leaderboard_pb(view: &LeaderboardView) -> Leaderboard {
+    let entry = |ranked: &RankedEntry| LeaderboardEntry {
+        rank: ranked.rank,
+        player_id: Some(PlayerId { value: ranked.entry.player_id.clone() }),
+        value: ranked.entry.value,
+        achieved_at: Some(prost_types::Timestamp {
+            seconds: ranked.entry.achieved_at.timestamp(),
+            nanos: ranked.entry.achieved_at.timestamp_subsec_nanos() as i32,
+        }),
+        session_id: Some(GameSessionId { value: ranked.entry.session_id.clone() }),
+    };
+    Leaderboard {
+        game_type: game_type_pb(&view.board.game_type) as i32,
+        ruleset_id: view.board.ruleset_id.clone().unwrap_or_default(),
+        period: view.board.period.to_pb() as i32,
+        window: view.board.window.clone(),
+        total: view.total,
+        entries: view.entries.iter().map(entry).collect(),
+        me: view.me.as_ref().map(entry),
+    }
+}
+
+/// Snapshot of the session for a client that just attached, as a `GameState` message.
+pub(crate) async fn game_state(
+    state: &AppState,
+    session_id: &GameSessionID,
+    player_id: &PlayerID,
+    role: Role,
+) -> Result<server_envelope::Message> {
+    let snapshot = state.game_service.snapshot(session_id, player_id, role).await?;
+    Ok(server_envelope::Message::GameState(snapshot.to_string()))
+}
+
+/// Handles one client envelope. Queries return the message to answer with; commands return
+/// `None` and report their effects through events.
+pub(crate) async fn handle_client_message(
+    state: &AppState,
+    session_id: &GameSessionID,
+    player_id: &PlayerID,
+    role: Role,
+    env: ClientEnvelope,
+) -> Result<Option<server_envelope::Message>> {
+    match env.message {
+        Some(client_envelope::Message::StartGame(_)) => {
+            // The host starts the private room this connection is attached to.
+            state.game_service.start_room(session_id, player_id).await?;
+            Ok(None)
+        },
+        Some(client_envelope::Message::JoinQueue(cmd)) => {
+            let game_type = game_type_from_pb(cmd.game_type())?;
+            // Players are matched on their stored rating, not on one the client claims.
+            let rating = state.ratings.rating(player_id, &game_type).await?;
+            state.matchmaker.join(player_id, game_type, Some(rating.rating))?;
+            Ok(None)
+        },
+        Some(client_envelope::Message::LeaveQueue(_)) => {
+            if !state.matchmaker.leave(player_id) {
+                warn!("{} left the queue without being in it", player_id);
+            }
+            Ok(None)
+        },
+        Some(client_envelope::Message::GetLeaderboard(query)) => {
+            let view = state.leaderboards.query(leaderboard_query_from_pb(&query)?, player_id).await?;
+            Ok(Some(server_envelope::Message::Leaderboard(to_leaderboard_pb(&view))))
+        },
+        Some(client_envelope::Message::GameCommand(_)) if role == Role::Spectator => {
+            Err(DomainError::Spectating(player_id.clone()).into())
+        },
+        Some(client_envelope::Message::GameCommand(wrapper)) => {
+            dispatch_game_command(state, session_id, player_id, wrapper).await?;
+            Ok(None)
+        },
+        None => {
+            warn!("Received empty envelope");
+            Ok(None)
+        }
+    }
+}
+
+async fn dispatch_game_command(
+    state: &AppState,
+    session_id: &GameSessionID,
+    player_id: &PlayerID,
+    wrapper: GameCommandEnvelope,
+) -> Result<()> {
+    // Extract the inner command and convert to Box<dyn GameCommand>
+    // Ideally this mapping logic lives in a mapper, but for the fix we put it here 
+    // to bridge the gap immediately.
+
+    // Commands act on behalf of the authenticated user in the attached session, whatever ids
+    // the client sent; engines stamp the session id on the events they raise.
+    let acting_player = Some(PlayerId { value: player_id.clone() });
+    let acting_session = Some(GameSessionId { value: session_id.clone() });
+
+    let command: Option<Box<dyn GameCommand>> = match wrapper.command {
+        Some(game_command_envelope::Command::PuzzleMove(mut cmd)) => {
+            cmd.session_id = acting_session;
+            cmd.player_id = acting_player;
+            Some(Box::new(cmd))
+        }
+        Some(game_command_envelope::Command::PuzzleUndo(mut cmd)) => {
+            cmd.session_id = acting_session;
+            cmd.player_id = acting_player;
+            Some(Box::new(cmd))
+        }
+        Some(game_command_envelope::Command::TriviaSubmit(mut cmd)) => {
+            cmd.session_id = acting_session;
+            cmd.player_id = acting_player;
+            Some(Box::new(cmd))
+        }
+        Some(game_command_envelope::Command::TriviaHint(mut cmd)) => {
+            cmd.session_id = acting_session;
+            Some(Box::new(cmd))
+        }
+        None => None,
+    };
+
+    if let Some(cmd) = command {
+        match state.game_service.handle_domain_command(session_id, player_id, cmd).await {
+            Ok(_) => {
+                info!("Command handled successfully");
+                Ok(())
+            }
+            Err(e) => {
+                error!("Failed to handle command: {:?}", e);
+                Err(e)
+            }
+        }
+    } else {
+        warn!("Unknown or empty game command received");
+        Err(anyhow!("Unknown or empty game command"))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,532 @@
+use crate::adapters::inbound::envelope::{
+    game_state, handle_client_message, kicks, to_achievement_message, to_event_envelope, to_matchmaking_message,
+};
+use crate::adapters::inbound::ws::auth::{AuthError, Authenticator};
+use crate::application::commands::StartGameSessionCommand;
+use crate::domain::game::{DomainError, ErrorKind, GameType, PlayerID, Role, Session, SessionStatus as DomainSessionStatus};
+use crate::pb::runecraftstudios::pastello::game::session::v1::{
+    CreateGameSessionRequest, CreateGameSessionResponse, GameSession, GetGameSessionRequest,
+    GetGameSessionResponse,
+};
+use crate::pb::runecraftstudios::pastello::game::types::v1::{
+    GameSessionId, GameType as GameTypePb, PlayerId, SessionStatus,
+};
+use crate::pb::runecraftstudios::pastello::web::game::v1::game_service_server::{
+    GameService as GameServiceRpc, GameServiceServer,
+};
+use crate::pb::runecraftstudios::pastello::web::game::v1::{
+    server_envelope, ClientEnvelope, ServerEnvelope,
+};
+use crate::AppState;
+use std::pin::Pin;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::{Stream, StreamExt};
+use tonic::{Request, Response, Status, Streaming};
+use tracing::{info, warn};
+
+/// Metadata entry naming the session a `PlayGame` stream attaches to.
+pub const SESSION_ID_METADATA: &str = "x-game-session-id";
+/// Optional metadata entry: `player` (default) or `spectator`.
+pub const ROLE_METADATA: &str = "x-game-role";
+
+// Outbound messages buffered per stream before a slow client starts holding up its own events.
+const STREAM_BUFFER: usize = 64;
+
+pub fn server(state: AppState) -> GameServiceServer<GrpcGameService> {
+    GameServiceServer::new(GrpcGameService { state })
+}
+
+pub struct GrpcGameService {
+    state: AppState,
+}
+
+#[tonic::async_trait]
+impl GameServiceRpc for GrpcGameService {
+    async fn create_game_session(
+        &self,
+        request: Request<CreateGameSessionRequest>,
+    ) -> Result<Response<CreateGameSessionResponse>, Status> {
+        let player_id = authenticate(self.state.authenticator.as_ref(), &request).await?;
+        let command = StartGameSessionCommand::from_pb(player_id, request.get_ref()).map_err(domain_status)?;
+
+        let session_id = self.state.game_service.start_game_session(command).await.map_err(to_status)?;
+        let session = self
+            .state
+            .game_service
+            .get_session(&session_id)
+            .await
+            .map_err(to_status)?
+            .ok_or_else(|| domain_status(DomainError::SessionNotFound(session_id)))?;
+
+        Ok(Response::new(CreateGameSessionResponse { session: Some(to_pb(&session, &session.host_id)) }))
+    }
+
+    async fn get_game_session(
+        &self,
+        request: Request<GetGameSessionRequest>,
+    ) -> Result<Response<GetGameSessionResponse>, Status> {
+        let player_id = authenticate(self.state.authenticator.as_ref(), &request).await?;
+        let session_id = request
+            .get_ref()
+            .id
+            .as_ref()
+            .map(|id| id.value.clone())
+            .ok_or_else(|| Status::invalid_argument("id is required"))?;
+
+        let session = self
+            .state
+            .game_service
+            .session_for(&session_id, &player_id)
+            .await
+            .map_err(to_status)?;
+
+        Ok(Response::new(GetGameSessionResponse { session: Some(to_pb(&session, &player_id)) }))
+    }
+
+    type PlayGameStream = Pin<Box<dyn Stream<Item = Result<ServerEnvelope, Status>> + Send>>;
+
+    async fn play_game(
+        &self,
+        request: Request<Streaming<ClientEnvelope>>,
+    ) -> Result<Response<Self::PlayGameStream>, Status> {
+        let player_id = authenticate(self.state.authenticator.as_ref(), &request).await?;
+        let session_id = request
+            .metadata()
+            .get(SESSION_ID_METADATA)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+            .ok_or_else(|| Status::invalid_argument(format!("{} metadata is required", SESSION_ID_METADATA)))?;
+
+        let role = match request.metadata().get(ROLE_METADATA) {
+            Some(value) => value
+                .to_str()
+                .ok()
+                .and_then(Role::parse)
+                .ok_or_else(|| Status::invalid_argument(format!("{} must be player or spectator", ROLE_METADATA)))?,
+            None => Role::Player,
+        };
+
+        // Subscribe before opening the session so the engine's opening events are not missed.
+        let mut events = self.state.game_service.subscribe_events();
+        let mut matchmaking = self.state.matchmaker.subscribe();
+        let mut unlocks = self.state.achievements.subscribe();
+        let session = self.state.game_service.attach(&session_id, &player_id, role).await.map_err(to_status)?;
+        let snapshot = game_state(&self.state, &session.id, &player_id, role).await.map_err(to_status)?;
+
+        info!("gRPC PlayGame stream opened by {} for {} as {:?}", player_id, session.id, role);
+        let mut inbound = request.into_inner();
+        let (tx, rx) = mpsc::channel(STREAM_BUFFER);
+        let state = self.state.clone();
+
+        tokio::spawn(async move {
+            if tx.send(Ok(ServerEnvelope { message: Some(snapshot) })).await.is_err() {
+                return;
+            }
+            loop {
+                tokio::select! {
+                    message = inbound.next() => {
+                        let envelope = match message {
+                            Some(Ok(envelope)) => envelope,
+                            Some(Err(status)) => {
+                                warn!("PlayGame stream from {} failed: {}", player_id, status);
+                                break;
+                            }
+                            None => break,
+                        };
+                        let reply = match handle_client_message(&state, &session.id, &player_id, role, envelope).await {
+                            Ok(reply) => reply,
+                            Err(e) => Some(server_envelope::Message::Error(e.to_string())),
+                        };
+                        if let Some(message) = reply {
+                            if tx.send(Ok(ServerEnvelope { message: Some(message) })).await.is_err() {
+                                break;
+                            }
+                        }
+                    }
+                    event = events.recv() => {
+                        match event {
+                            Ok(event) if event.session_id() == &session.id && event.visible_to(&player_id, role) => {
+                                let envelope = to_event_envelope(&session.game_type, event.as_ref());
+                                let reply = ServerEnvelope { message: Some(server_envelope::Message::GameEvent(envelope)) };
+                                if tx.send(Ok(reply)).await.is_err() || kicks(event.as_ref(), &player_id) {
+                                    break;
+                                }
+                            }
+                            Ok(_) => {} // Event for another session, or one this stream may not see
+                            Err(RecvError::Lagged(skipped)) => warn!("{} missed {} events", player_id, skipped),
+                            Err(RecvError::Closed) => break,
+                        }
+                    }
+                    update = matchmaking.recv() => {
+                        match update {
+                            Ok(update) if update.player_id() == &player_id => {
+                                let reply = ServerEnvelope { message: Some(to_matchmaking_message(&update)) };
+                                if tx.send(Ok(reply)).await.is_err() {
+                                    break;
+                                }
+                            }
+                            Ok(_) => {} // Update for another player
+                            Err(RecvError::Lagged(skipped)) => warn!("{} missed {} matchmaking updates", player_id, skipped),
+                            Err(RecvError::Closed) => break,
+                        }
+                    }
+                    unlock = unlocks.recv() => {
+                        match unlock {
+                            Ok(unlock) if unlock.player_id == player_id => {
+                                let reply = ServerEnvelope { message: Some(to_achievement_message(&unlock)) };
+                                if tx.send(Ok(reply)).await.is_err() {
+                                    break;
+                                }
+                            }
+                            Ok(_) => {} // Unlock for another player
+                            Err(RecvError::Lagged(skipped)) => warn!("{} missed {} achievement unlocks", player_id, skipped),
+                            Err(RecvError::Closed) => break,
+                        }
+                    }
+                }
+            }
+            state.matchmaker.leave(&player_id);
+            state.game_service.detach(&session.id, &player_id, role).await;
+            info!("gRPC PlayGame stream finished for {}", player_id);
+        });
+
+        Ok(Response::new(Box::pin(ReceiverStream::new(rx))))
+    }
+}
+
+/// Resolves the `authorization: Bearer <token>` metadata entry to the calling player's id.
+async fn authenticate<T>(authenticator: &dyn Authenticator, request: &Request<T>) -> Result<PlayerID, Status> {
+    let token = request
+        .metadata()
+        .get("authorization")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or_else(|| Status::unauthenticated(AuthError::NoToken.to_string()))?;
+    let session = authenticator
+        .authenticate(token)
+        .await
+        .map_err(|e| Status::unauthenticated(e.to_string()))?;
+    Ok(session.host_id)
+}
+
+/// The session as `viewer` sees it; only the host gets the room's join code.
+fn to_pb(session: &Session, viewer: &PlayerID) -> GameSession {
+    let room = session.room.as_ref().filter(|_| session.host_id == *viewer);
+    let game_type = match session.game_type {
+        GameType::Puzzle => GameTypePb::Puzzle,
+        GameType::Trivia => GameTypePb::Trivia,
+    };
+    GameSession {
+        id: Some(GameSessionId { value: session.id.clone() }),
+        game_type: game_type as i32,
+        player_ids: session.players.iter().map(|p| PlayerId { value: p.id.clone() }).collect(),
+        status: match session.status {
+            DomainSessionStatus::Lobby => SessionStatus::Created,
+            DomainSessionStatus::Active => SessionStatus::Active,
+            DomainSessionStatus::Ended => SessionStatus::Ended,
+            DomainSessionStatus::Cancelled => SessionStatus::Cancelled,
+        } as i32,
+        created_at: None,
+        ruleset_id: session.ruleset_id.clone().unwrap_or_default(),
+        spectator_ids: session.spectators.iter().map(|p| PlayerId { value: p.id.clone() }).collect(),
+        join_code: room.map(|room| room.join_code.clone()).unwrap_or_default(),
+        join_code_expires_at: room.map(|room| prost_types::Timestamp {
+            seconds: room.expires_at.timestamp(),
+            nanos: room.expires_at.timestamp_subsec_nanos() as i32,
+        }),
+        has_password: session.room.as_ref().is_some_and(|room| room.has_password()),
+    }
+}
+
+fn domain_status(error: DomainError) -> Status {
+    let message = error.to_string();
+    match error.kind() {
+        ErrorKind::NotFound => Status::not_found(message),
+        ErrorKind::Invalid => Status::invalid_argument(message),
+        ErrorKind::Forbidden => Status::permission_denied(message),
+        ErrorKind::Expired | ErrorKind::Conflict => Status::failed_precondition(message),
+        ErrorKind::RateLimited => Status::resource_exhausted(message),
+        ErrorKind::Internal => Status::internal(message),
+    }
+}
+
+fn to_status(error: anyhow::Error) -> Status {
+    match error.downcast::<DomainError>() {
+        Ok(domain) => domain_status(domain),
+        Err(other) => {
+            warn!("gRPC call failed: {:?}", other);
+            Status::internal(other.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::inbound::ws::auth::TokenAuthenticator;
+    use crate::application::commands::{RoomOptions, SessionConfig};
+    use crate::pb::runecraftstudios::pastello::game::session::v1::{
+        create_game_session_request::Config, PrivateRoomOptions,
+    };
+    use crate::pb::runecraftstudios::pastello::game::trivia::v1::{RevealHintCommand, TriviaRules};
+    use crate::pb::runecraftstudios::pastello::web::game::v1::{
+        client_envelope, game_command_envelope, game_event_envelope, GameCommandEnvelope, GameEventEnvelope,
+    };
+    use std::sync::Arc;
+    use tonic::codegen::http::uri::PathAndQuery;
+    use tonic::transport::server::TcpIncoming;
+    use tonic::{Code, Streaming};
+
+    const PLAY_GAME: &str = "/runecraftstudios.pastello.web.game.v1.GameService/PlayGame";
+
+    fn service() -> GrpcGameService {
+        GrpcGameService { state: AppState::for_tests(Arc::new(TokenAuthenticator)) }
+    }
+
+    fn request<T>(message: T, token: Option<&str>) -> Request<T> {
+        let mut request = Request::new(message);
+        if let Some(token) = token {
+            request
+                .metadata_mut()
+                .insert("authorization", format!("Bearer {}", token).parse().unwrap());
+        }
+        request
+    }
+
+    fn trivia(private_room: bool) -> CreateGameSessionRequest {
+        CreateGameSessionRequest {
+            game_type: GameTypePb::Trivia as i32,
+            player_ids: Vec::new(),
+            private_room: private_room.then(PrivateRoomOptions::default),
+            config: Some(Config::Trivia(TriviaRules {
+                num_questions: 3,
+                seconds_per_question: 20,
+                max_players: 4,
+                ..TriviaRules::default()
+            })),
+        }
+    }
+
+    async fn get(service: &GrpcGameService, session_id: &str, token: &str) -> Result<GameSession, Status> {
+        let message = GetGameSessionRequest { id: Some(GameSessionId { value: session_id.to_string() }) };
+        let response = service.get_game_session(request(message, Some(token))).await?;
+        Ok(response.into_inner().session.unwrap())
+    }
+
+    #[tokio::test]
+    async fn unary_calls_need_a_valid_token() {
+        let service = service();
+        for token in [None, Some("expired")] {
+            let status = service.create_game_session(request(trivia(false), token)).await.unwrap_err();
+            assert_eq!(status.code(), Code::Unauthenticated);
+            let message = GetGameSessionRequest { id: Some(GameSessionId { value: "any".to_string() }) };
+            let status = service.get_game_session(request(message, token)).await.unwrap_err();
+            assert_eq!(status.code(), Code::Unauthenticated);
+        }
+    }
+
+    #[tokio::test]
+    async fn creating_a_session_makes_the_caller_its_host() {
+        let service = service();
+        let session = service
+            .create_game_session(request(trivia(true), Some("alice")))
+            .await
+            .unwrap()
+            .into_inner()
+            .session
+            .unwrap();
+        assert_eq!(session.game_type(), GameTypePb::Trivia);
+        assert_eq!(session.status(), SessionStatus::Created);
+        assert_eq!(session.player_ids, [PlayerId { value: "alice".to_string() }]);
+        assert!(!session.join_code.is_empty());
+
+        let unspecified = CreateGameSessionRequest { game_type: GameTypePb::Unspecified as i32, ..trivia(false) };
+        let status = service.create_game_session(request(unspecified, Some("alice"))).await.unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+        let invalid = CreateGameSessionRequest { config: Some(Config::Trivia(TriviaRules::default())), ..trivia(false) };
+        let status = service.create_game_session(request(invalid, Some("alice"))).await.unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn sessions_are_only_visible_to_the_people_in_them() {
+        let service = service();
+        let created = service
+            .create_game_session(request(trivia(true), Some("alice")))
+            .await
+            .unwrap()
+            .into_inner()
+            .session
+            .unwrap();
+        let id = created.id.unwrap().value;
+        service
+            .state
+            .game_service
+            .join_by_code(&created.join_code, &"bob".to_string(), None)
+            .await
+            .unwrap();
+
+        let host_view = get(&service, &id, "alice").await.unwrap();
+        assert_eq!(host_view.join_code, created.join_code);
+        assert!(host_view.join_code_expires_at.is_some());
+        let player_view = get(&service, &id, "bob").await.unwrap();
+        assert_eq!(player_view.player_ids.len(), 2);
+        assert!(player_view.join_code.is_empty());
+        assert!(player_view.join_code_expires_at.is_none());
+
+        assert_eq!(get(&service, &id, "mallory").await.unwrap_err().code(), Code::NotFound);
+        assert_eq!(get(&service, "missing", "alice").await.unwrap_err().code(), Code::NotFound);
+        let status = service
+            .get_game_session(request(GetGameSessionRequest { id: None }, Some("alice")))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+    }
+
+    /// Serves `state` over gRPC on a free local port.
+    async fn serve(state: AppState) -> tonic::client::Grpc<tonic::transport::Channel> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(server(state))
+                .serve_with_incoming(TcpIncoming::from(listener)),
+        );
+        let channel = tonic::transport::Endpoint::from_shared(format!("http://{}", addr))
+            .unwrap()
+            .connect()
+            .await
+            .unwrap();
+        tonic::client::Grpc::new(channel)
+    }
+
+    /// Opens a `PlayGame` stream; `metadata` holds extra entries such as the session and role.
+    async fn play(
+        client: &mut tonic::client::Grpc<tonic::transport::Channel>,
+        token: Option<&str>,
+        metadata: &[(&'static str, &str)],
+    ) -> Result<(mpsc::Sender<ClientEnvelope>, Streaming<ServerEnvelope>), Status> {
+        let (tx, rx) = mpsc::channel(8);
+        let mut request = request(ReceiverStream::new(rx), token);
+        for (key, value) in metadata {
+            request.metadata_mut().insert(*key, value.parse().unwrap());
+        }
+        client.ready().await.unwrap();
+        let response = client
+            .streaming(request, PathAndQuery::from_static(PLAY_GAME), tonic_prost::ProstCodec::default())
+            .await?;
+        Ok((tx, response.into_inner()))
+    }
+
+    async fn next(stream: &mut Streaming<ServerEnvelope>) -> server_envelope::Message {
+        let envelope = tokio::time::timeout(std::time::Duration::from_secs(5), stream.message())
+            .await
+            .expect("no message from the server")
+            .unwrap()
+            .expect("stream ended");
+        envelope.message.unwrap()
+    }
+
+    fn event_type(message: server_envelope::Message) -> String {
+        let server_envelope::Message::GameEvent(GameEventEnvelope {
+            event: Some(game_event_envelope::Event::TriviaEvent(event)),
+        }) = message
+        else {
+            panic!("expected a trivia event, got {:?}", message);
+        };
+        let event: serde_json::Value = serde_json::from_str(&event).unwrap();
+        event["type"].as_str().unwrap_or_default().to_string()
+    }
+
+    fn hint() -> ClientEnvelope {
+        ClientEnvelope {
+            message: Some(client_envelope::Message::GameCommand(GameCommandEnvelope {
+                command: Some(game_command_envelope::Command::TriviaHint(RevealHintCommand { session_id: None })),
+            })),
+        }
+    }
+
+    #[tokio::test]
+    async fn play_game_streams_the_state_and_the_events_of_a_session() {
+        let state = AppState::for_tests(Arc::new(TokenAuthenticator));
+        let session_id = state
+            .game_service
+            .start_game_session(StartGameSessionCommand {
+                player_id: "alice".to_string(),
+                game_type: GameType::Trivia,
+                player_ids: Vec::new(),
+                config: SessionConfig::Default,
+                room: None,
+                seed: None,
+                daily_challenge: None,
+                question_ids: Vec::new(),
+            })
+            .await
+            .unwrap();
+        let mut client = serve(state).await;
+
+        let (player, mut player_stream) =
+            play(&mut client, Some("alice"), &[(SESSION_ID_METADATA, &session_id)]).await.unwrap();
+        let server_envelope::Message::GameState(snapshot) = next(&mut player_stream).await else {
+            panic!("the stream should open with the game state");
+        };
+        let snapshot: serde_json::Value = serde_json::from_str(&snapshot).unwrap();
+        assert_eq!(snapshot["session_id"], session_id.as_str());
+        assert!(event_type(next(&mut player_stream).await).contains("question_revealed"));
+
+        let (spectator, mut spectator_stream) = play(
+            &mut client,
+            Some("sam"),
+            &[(SESSION_ID_METADATA, &session_id), (ROLE_METADATA, "spectator")],
+        )
+        .await
+        .unwrap();
+        assert!(matches!(next(&mut spectator_stream).await, server_envelope::Message::GameState(_)));
+
+        player.send(hint()).await.unwrap();
+        for stream in [&mut player_stream, &mut spectator_stream] {
+            assert!(event_type(next(stream).await).contains("hint_revealed"));
+        }
+
+        spectator.send(hint()).await.unwrap();
+        let server_envelope::Message::Error(error) = next(&mut spectator_stream).await else {
+            panic!("spectators may not send commands");
+        };
+        assert!(error.contains("sam"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn play_game_checks_the_caller_before_attaching() {
+        let state = AppState::for_tests(Arc::new(TokenAuthenticator));
+        let session_id = state
+            .game_service
+            .start_game_session(StartGameSessionCommand {
+                player_id: "alice".to_string(),
+                game_type: GameType::Trivia,
+                player_ids: Vec::new(),
+                config: SessionConfig::Default,
+                room: Some(RoomOptions::default()),
+                seed: None,
+                daily_challenge: None,
+                question_ids: Vec::new(),
+            })
+            .await
+            .unwrap();
+        let mut client = serve(state).await;
+
+        let session = (SESSION_ID_METADATA, session_id.as_str());
+        let cases = [
+            (None, vec![session], Code::Unauthenticated),
+            (Some("alice"), vec![], Code::InvalidArgument),
+            (Some("alice"), vec![session, (ROLE_METADATA, "referee")], Code::InvalidArgument),
+            (Some("mallory"), vec![session], Code::PermissionDenied),
+            (Some("alice"), vec![(SESSION_ID_METADATA, "missing")], Code::NotFound),
+        ];
+        for (token, metadata, code) in cases {
+            let status = play(&mut client, token, &metadata).await.unwrap_err();
+            assert_eq!(status.code(), code, "{:?} {:?}: {}", token, metadata, status.message());
+        }
+    }
+}
@@ -0,0 +1,685 @@
+//! JSON shapes for the REST API. They follow the proto3 JSON mapping of the matching
+//! protobuf messages (lowerCamelCase fields, enums by name), so a client can switch between
+//! REST and protobuf transports without remapping fields.
+
+use crate::application::services::achievement::AchievementView;
+use crate::application::services::leaderboard::{LeaderboardQuery, LeaderboardView};
+use crate::domain::anti_cheat::CheatFlag;
+use crate::domain::daily::{DailyAttempt, DailyChallenge};
+use crate::domain::game::{DomainError, GameType, PlayerID, Session, SessionStatus};
+use crate::domain::leaderboard::{LeaderboardPeriod, RankedEntry};
+use crate::domain::profile::{PlayerProfile, PlayerStats, Preferences};
+use crate::domain::rating::{PlayerRatings, RatingChange};
+use crate::domain::replay::ReplayReport;
+use crate::domain::rules::TriviaCategory;
+use crate::pb::runecraftstudios::pastello::game::puzzle::v1 as puzzle_pb;
+use crate::pb::runecraftstudios::pastello::game::session::v1::{
+    create_game_session_request, CreateGameSessionRequest, PrivateRoomOptions,
+};
+use crate::pb::runecraftstudios::pastello::game::trivia::v1 as trivia_pb;
+use crate::pb::runecraftstudios::pastello::game::types::v1::{GameType as GameTypePb, PlayerId, SessionStatus as SessionStatusPb};
+use crate::pb::runecraftstudios::pastello::web::game::v1::LeaderboardPeriod as LeaderboardPeriodPb;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdDto {
+    pub value: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateGameSessionRequestDto {
+    pub game_type: String,
+    #[serde(default)]
+    pub player_ids: Vec<IdDto>,
+    pub private_room: Option<PrivateRoomOptionsDto>,
+    // Members of the `config` oneof; at most one may be set.
+    pub trivia: Option<TriviaRulesDto>,
+    pub puzzle: Option<PuzzleRulesDto>,
+    pub ruleset_id: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct PrivateRoomOptionsDto {
+    pub password: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TriviaRulesDto {
+    pub num_questions: u32,
+    pub seconds_per_question: u32,
+    pub negative_marking: bool,
+    pub categories: Vec<String>,
+    pub max_players: u32,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PuzzleRulesDto {
+    pub difficulty: Option<String>,
+    pub allow_hints: bool,
+    pub time_limit_seconds: u32,
+    pub max_players: u32,
+    pub mode: Option<String>,
+}
+
+impl TryFrom<CreateGameSessionRequestDto> for CreateGameSessionRequest {
+    type Error = DomainError;
+
+    fn try_from(dto: CreateGameSessionRequestDto) -> Result<Self, Self::Error> {
+        let game_type = enum_by_name("gameType", &dto.game_type, GameTypePb::from_str_name)?;
+
+        let config = match (dto.trivia, dto.puzzle, dto.ruleset_id) {
+            (None, None, None) => None,
+            (Some(rules), None, None) => Some(create_game_session_request::Config::Trivia(rules.try_into()?)),
+            (None, Some(rules), None) => Some(create_game_session_request::Config::Puzzle(rules.try_into()?)),
+            (None, None, Some(id)) => Some(create_game_session_request::Config::RulesetId(id)),
+            _ => {
+                return Err(DomainError::InvalidPayload(
+                    "only one of trivia, puzzle or rulesetId may be set".to_string(),
+                ))
+            }
+        };
+
+        Ok(Self {
+            game_type: game_type as i32,
+            player_ids: dto.player_ids.into_iter().map(|p| PlayerId { value: p.value }).collect(),
+            private_room: dto.private_room.map(|room| PrivateRoomOptions { password: room.password }),
+            config,
+        })
+    }
+}
+
+impl TryFrom<TriviaRulesDto> for trivia_pb::TriviaRules {
+    type Error = DomainError;
+
+    fn try_from(dto: TriviaRulesDto) -> Result<Self, Self::Error> {
+        let categories = dto
+            .categories
+            .iter()
+            .map(|c| enum_by_name("categories", c, trivia_pb::TriviaCategory::from_str_name).map(|c| c as i32))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            num_questions: dto.num_questions,
+            seconds_per_question: dto.seconds_per_question,
+            negative_marking: dto.negative_marking,
+            categories,
+            max_players: dto.max_players,
+        })
+    }
+}
+
+impl TryFrom<PuzzleRulesDto> for puzzle_pb::PuzzleRules {
+    type Error = DomainError;
+
+    fn try_from(dto: PuzzleRulesDto) -> Result<Self, Self::Error> {
+        let difficulty = match &dto.difficulty {
+            Some(name) => enum_by_name("difficulty", name, puzzle_pb::PuzzleDifficulty::from_str_name)?,
+            None => puzzle_pb::PuzzleDifficulty::Unspecified,
+        };
+        let mode = match &dto.mode {
+            Some(name) => enum_by_name("mode", name, puzzle_pb::PuzzleMode::from_str_name)?,
+            None => puzzle_pb::PuzzleMode::Unspecified,
+        };
+        Ok(Self {
+            difficulty: difficulty as i32,
+            allow_hints: dto.allow_hints,
+            time_limit_seconds: dto.time_limit_seconds,
+            max_players: dto.max_players,
+            mode: mode as i32,
+        })
+    }
+}
+
+fn enum_by_name<T>(field: &str, name: &str, parse: fn(&str) -> Option<T>) -> Result<T, DomainError> {
+    parse(name).ok_or_else(|| DomainError::InvalidPayload(format!("unknown {} '{}'", field, name)))
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameSessionDto {
+    pub id: IdDto,
+    pub game_type: &'static str,
+    pub player_ids: Vec<IdDto>,
+    pub status: &'static str,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub spectator_ids: Vec<IdDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ruleset_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub join_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub join_code_expires_at: Option<String>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub has_password: bool,
+}
+
+impl GameSessionDto {
+    /// The session as `viewer` sees it; only the host gets the room's join code.
+    pub fn new(session: &Session, viewer: &PlayerID) -> Self {
+        let room = session.room.as_ref().filter(|_| session.host_id == *viewer);
+        Self {
+            id: IdDto { value: session.id.clone() },
+            game_type: game_type_name(&session.game_type),
+            player_ids: session.players.iter().map(|p| IdDto { value: p.id.clone() }).collect(),
+            status: session_status_name(session.status),
+            spectator_ids: session.spectators.iter().map(|p| IdDto { value: p.id.clone() }).collect(),
+            ruleset_id: session.ruleset_id.clone(),
+            join_code: room.map(|room| room.join_code.clone()),
+            join_code_expires_at: room.map(|room| room.expires_at.to_rfc3339_opts(chrono::SecondsFormat::Millis, true)),
+            has_password: session.room.as_ref().is_some_and(|room| room.has_password()),
+        }
+    }
+}
+
+fn session_status_name(status: SessionStatus) -> &'static str {
+    match status {
+        SessionStatus::Lobby => SessionStatusPb::Created,
+        SessionStatus::Active => SessionStatusPb::Active,
+        SessionStatus::Ended => SessionStatusPb::Ended,
+        SessionStatus::Cancelled => SessionStatusPb::Cancelled,
+    }
+    .as_str_name()
+}
+
+fn game_type_name(game_type: &GameType) -> &'static str {
+    match game_type {
+        GameType::Puzzle => GameTypePb::Puzzle,
+        GameType::Trivia => GameTypePb::Trivia,
+    }
+    .as_str_name()
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateGameSessionResponseDto {
+    pub session: GameSessionDto,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ListGameSessionsResponseDto {
+    pub sessions: Vec<GameSessionDto>,
+}
+
+/// Body of `POST /rooms/{code}/join`; the password only matters for rooms that have one.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct JoinRoomRequestDto {
+    pub password: Option<String>,
+}
+
+/// A command for the JSON `CommandRegistry` path, e.g. `{"type": "PuzzleMove", "payload": {...}}`.
+#[derive(Debug, Deserialize)]
+pub struct GameCommandDto {
+    #[serde(rename = "type")]
+    pub command_type: String,
+    #[serde(default)]
+    pub payload: serde_json::Value,
+}
+
+// --- PROFILES ---
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerProfileDto {
+    pub player_id: IdDto,
+    pub display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    /// Only included when players read their own profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferences: Option<PreferencesDto>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl PlayerProfileDto {
+    pub fn new(profile: &PlayerProfile, include_preferences: bool) -> Self {
+        let timestamp = |at: &chrono::DateTime<chrono::Utc>| at.to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
+        Self {
+            player_id: IdDto { value: profile.player_id.clone() },
+            display_name: profile.display_name.clone(),
+            avatar_url: profile.avatar_url.clone(),
+            preferences: include_preferences.then(|| PreferencesDto::from(&profile.preferences)),
+            created_at: timestamp(&profile.created_at),
+            updated_at: timestamp(&profile.updated_at),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PreferencesDto {
+    pub sound: bool,
+    pub reduced_motion: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+}
+
+impl From<&Preferences> for PreferencesDto {
+    fn from(preferences: &Preferences) -> Self {
+        Self {
+            sound: preferences.sound,
+            reduced_motion: preferences.reduced_motion,
+            theme: preferences.theme.clone(),
+            locale: preferences.locale.clone(),
+        }
+    }
+}
+
+impl From<PreferencesDto> for Preferences {
+    fn from(dto: PreferencesDto) -> Self {
+        Self {
+            sound: dto.sound,
+            reduced_motion: dto.reduced_motion,
+            theme: dto.theme,
+            locale: dto.locale,
+        }
+    }
+}
+
+/// Body of `PUT /players/me/profile`. Omitted fields keep their value; an empty `avatarUrl`
+/// removes the avatar.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct UpdateProfileRequestDto {
+    pub display_name: Option<String>,
+    pub avatar_url: Option<String>,
+    pub preferences: Option<PreferencesDto>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerStatsDto {
+    pub player_id: IdDto,
+    pub games: Vec<GameStatsDto>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameStatsDto {
+    pub game_type: &'static str,
+    pub games_played: u32,
+    pub wins: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub best_puzzle_time_ms: Option<i64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub trivia_accuracy: Vec<CategoryAccuracyDto>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryAccuracyDto {
+    pub category: &'static str,
+    pub answered: u32,
+    pub correct: u32,
+    pub accuracy: f64,
+}
+
+impl From<&PlayerStats> for PlayerStatsDto {
+    fn from(stats: &PlayerStats) -> Self {
+        let games = stats
+            .games
+            .iter()
+            .map(|(game_type, game)| GameStatsDto {
+                game_type: game_type_name(game_type),
+                games_played: game.games_played,
+                wins: game.wins,
+                best_puzzle_time_ms: game.best_puzzle_time_ms,
+                trivia_accuracy: game
+                    .trivia_accuracy
+                    .iter()
+                    .map(|(category, accuracy)| CategoryAccuracyDto {
+                        category: category_name(category),
+                        answered: accuracy.answered,
+                        correct: accuracy.correct,
+                        accuracy: accuracy.accuracy(),
+                    })
+                    .collect(),
+            })
+            .collect();
+        Self {
+            player_id: IdDto { value: stats.player_id.clone() },
+            games,
+        }
+    }
+}
+
+fn category_name(category: &TriviaCategory) -> &'static str {
+    match category {
+        TriviaCategory::GeneralKnowledge => trivia_pb::TriviaCategory::GeneralKnowledge,
+        TriviaCategory::Science => trivia_pb::TriviaCategory::Science,
+        TriviaCategory::History => trivia_pb::TriviaCategory::History,
+    }
+    .as_str_name()
+}
+
+// --- RATINGS ---
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerRatingsDto {
+    pub player_id: IdDto,
+    pub games: Vec<SkillRatingDto>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillRatingDto {
+    pub game_type: &'static str,
+    pub rating: f64,
+    pub deviation: f64,
+    pub volatility: f64,
+    pub games_rated: u32,
+    /// Most recent game first.
+    pub history: Vec<RatingChangeDto>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RatingChangeDto {
+    pub session_id: IdDto,
+    pub at: String,
+    pub rank: u32,
+    pub players: u32,
+    pub rating_before: f64,
+    pub rating_after: f64,
+    pub deviation_after: f64,
+}
+
+impl From<&RatingChange> for RatingChangeDto {
+    fn from(change: &RatingChange) -> Self {
+        Self {
+            session_id: IdDto { value: change.session_id.clone() },
+            at: change.at.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+            rank: change.rank,
+            players: change.players,
+            rating_before: change.rating_before,
+            rating_after: change.rating_after,
+            deviation_after: change.deviation_after,
+        }
+    }
+}
+
+impl From<&PlayerRatings> for PlayerRatingsDto {
+    fn from(ratings: &PlayerRatings) -> Self {
+        let games = ratings
+            .games
+            .iter()
+            .map(|(game_type, rating)| SkillRatingDto {
+                game_type: game_type_name(game_type),
+                rating: rating.rating,
+                deviation: rating.deviation,
+                volatility: rating.volatility,
+                games_rated: rating.games_rated,
+                history: rating.history.iter().rev().map(RatingChangeDto::from).collect(),
+            })
+            .collect();
+        Self {
+            player_id: IdDto { value: ratings.player_id.clone() },
+            games,
+        }
+    }
+}
+
+// --- ACHIEVEMENTS ---
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerAchievementsDto {
+    pub player_id: IdDto,
+    pub achievements: Vec<AchievementDto>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AchievementDto {
+    pub achievement_id: String,
+    pub name: String,
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub badge_url: Option<String>,
+    pub unlocked: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unlocked_at: Option<String>,
+}
+
+impl From<&AchievementView> for AchievementDto {
+    fn from(view: &AchievementView) -> Self {
+        Self {
+            achievement_id: view.achievement.id.clone(),
+            name: view.achievement.name.clone(),
+            description: view.achievement.description.clone(),
+            badge_url: view.achievement.badge_url.clone(),
+            unlocked: view.unlocked_at.is_some(),
+            unlocked_at: view
+                .unlocked_at
+                .map(|at| at.to_rfc3339_opts(chrono::SecondsFormat::Millis, true)),
+        }
+    }
+}
+
+// --- DAILY CHALLENGE ---
+
+/// Query string of `GET /daily-challenge` and body of `POST /daily-challenge/attempts`,
+/// e.g. `{"gameType": "GAME_TYPE_PUZZLE"}`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DailyChallengeParamsDto {
+    pub game_type: String,
+}
+
+impl TryFrom<&DailyChallengeParamsDto> for GameType {
+    type Error = DomainError;
+
+    fn try_from(params: &DailyChallengeParamsDto) -> Result<Self, Self::Error> {
+        match enum_by_name("gameType", &params.game_type, GameTypePb::from_str_name)? {
+            GameTypePb::Puzzle => Ok(GameType::Puzzle),
+            GameTypePb::Trivia => Ok(GameType::Trivia),
+            GameTypePb::Unspecified => Err(DomainError::InvalidPayload("gameType is required".to_string())),
+        }
+    }
+}
+
+/// Today's challenge. The seed is left out so the game can't be previewed before it is played.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyChallengeDto {
+    /// `YYYY-MM-DD`, in UTC.
+    pub date: String,
+    pub game_type: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ruleset_id: Option<String>,
+    /// The caller's attempt, once started.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attempt: Option<DailyAttemptDto>,
+}
+
+impl DailyChallengeDto {
+    pub fn new(challenge: &DailyChallenge, attempt: Option<&DailyAttempt>) -> Self {
+        Self {
+            date: challenge.date.format("%Y-%m-%d").to_string(),
+            game_type: game_type_name(&challenge.game_type),
+            ruleset_id: challenge.ruleset_id.clone(),
+            attempt: attempt.map(|attempt| DailyAttemptDto {
+                session_id: IdDto { value: attempt.session_id.clone() },
+                started_at: attempt.started_at.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyAttemptDto {
+    pub session_id: IdDto,
+    pub started_at: String,
+}
+
+// --- REPLAYS ---
+
+/// Result of `POST /replays/verify`. Replay files themselves are exchanged as written by the
+/// server, not remapped, so they round-trip unchanged.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayReportDto {
+    pub session_id: IdDto,
+    pub steps: u32,
+    pub matched: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mismatch: Option<ReplayMismatchDto>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayMismatchDto {
+    /// Index of the first differing step; unset when only the final state differs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub step: Option<u32>,
+    pub expected: serde_json::Value,
+    pub actual: serde_json::Value,
+}
+
+impl From<&ReplayReport> for ReplayReportDto {
+    fn from(report: &ReplayReport) -> Self {
+        Self {
+            session_id: IdDto { value: report.session_id.clone() },
+            steps: report.steps as u32,
+            matched: report.matched(),
+            mismatch: report.mismatch.as_ref().map(|mismatch| ReplayMismatchDto {
+                step: mismatch.step.map(|step| step as u32),
+                expected: mismatch.expected.clone(),
+                actual: mismatch.actual.clone(),
+            }),
+        }
+    }
+}
+
+// --- CHEAT FLAGS ---
+
+/// Query string of `GET /cheat-flags`; both filters are optional.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CheatFlagsParamsDto {
+    pub player_id: Option<String>,
+    pub session_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheatFlagsDto {
+    pub flags: Vec<CheatFlagDto>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheatFlagDto {
+    pub session_id: IdDto,
+    pub player_id: IdDto,
+    pub game_type: &'static str,
+    /// e.g. `fast_solve` or `shared_answers`.
+    pub kind: &'static str,
+    pub detail: String,
+    pub flagged_at: String,
+}
+
+impl From<&CheatFlag> for CheatFlagDto {
+    fn from(flag: &CheatFlag) -> Self {
+        Self {
+            session_id: IdDto { value: flag.session_id.clone() },
+            player_id: IdDto { value: flag.player_id.clone() },
+            game_type: game_type_name(&flag.game_type),
+            kind: flag.kind.as_str(),
+            detail: flag.detail.clone(),
+            flagged_at: flag.flagged_at.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+        }
+    }
+}
+
+// --- LEADERBOARDS ---
+
+/// Query string of `GET /leaderboards`, e.g. `?gameType=GAME_TYPE_TRIVIA&period=LEADERBOARD_PERIOD_WEEKLY`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LeaderboardParamsDto {
+    pub game_type: String,
+    pub ruleset_id: Option<String>,
+    pub period: Option<String>,
+    pub limit: Option<u32>,
+}
+
+impl TryFrom<LeaderboardParamsDto> for LeaderboardQuery {
+    type Error = DomainError;
+
+    fn try_from(params: LeaderboardParamsDto) -> Result<Self, Self::Error> {
+        let game_type = match enum_by_name("gameType", &params.game_type, GameTypePb::from_str_name)? {
+            GameTypePb::Puzzle => GameType::Puzzle,
+            GameTypePb::Trivia => GameType::Trivia,
+            GameTypePb::Unspecified => return Err(DomainError::InvalidPayload("gameType is required".to_string())),
+        };
+        let period = match params.period {
+            Some(name) => enum_by_name("period", &name, LeaderboardPeriodPb::from_str_name)?,
+            None => LeaderboardPeriodPb::Unspecified,
+        };
+        Ok(LeaderboardQuery {
+            game_type,
+            ruleset_id: params.ruleset_id.filter(|id| !id.is_empty()),
+            period: LeaderboardPeriod::from_pb(period),
+            limit: params.limit,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaderboardDto {
+    pub game_type: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ruleset_id: Option<String>,
+    pub period: &'static str,
+    pub window: String,
+    pub total: u32,
+    pub entries: Vec<LeaderboardEntryDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub me: Option<LeaderboardEntryDto>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaderboardEntryDto {
+    pub rank: u32,
+    pub player_id: IdDto,
+    /// int64, so a string in proto3 JSON.
+    pub value: String,
+    pub achieved_at: String,
+    pub session_id: IdDto,
+}
+
+impl From<&RankedEntry> for LeaderboardEntryDto {
+    fn from(ranked: &RankedEntry) -> Self {
+        Self {
+            rank: ranked.rank,
+            player_id: IdDto { value: ranked.entry.player_id.clone() },
+            value: ranked.entry.value.to_string(),
+            achieved_at: ranked.entry.achieved_at.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+            session_id: IdDto { value: ranked.entry.session_id.clone() },
+        }
+    }
+}
+
+impl From<&LeaderboardView> for LeaderboardDto {
+    fn from(view: &LeaderboardView) -> Self {
+        Self {
+            game_type: game_type_name(&view.board.game_type),
+            ruleset_id: view.board.ruleset_id.clone(),
+            period: view.board.period.to_pb().as_str_name(),
+            window: view.board.window.clone(),
+            total: view.total,
+            entries: view.entries.iter().map(LeaderboardEntryDto::from).collect(),
+            me: view.me.as_ref().map(LeaderboardEntryDto::from),
+        }
+    }
+}
@@ -0,0 +1,510 @@
+pub mod dto;
+
+use crate::adapters::inbound::http::dto::{
+    AchievementDto, CheatFlagDto, CheatFlagsDto, CheatFlagsParamsDto, CreateGameSessionRequestDto, CreateGameSessionResponseDto, DailyChallengeDto,
+    DailyChallengeParamsDto, GameCommandDto,
+    GameSessionDto, IdDto, JoinRoomRequestDto, LeaderboardDto, LeaderboardParamsDto,
+    ListGameSessionsResponseDto, PlayerAchievementsDto, PlayerProfileDto, PlayerRatingsDto,
+    PlayerStatsDto, ReplayReportDto, UpdateProfileRequestDto,
+};
+use crate::adapters::inbound::ws::auth::{AuthError, Authenticator};
+use crate::application::commands::{GameCommandMessage, StartGameSessionCommand};
+use crate::application::services::leaderboard::LeaderboardQuery;
+use crate::application::services::profile::ProfileUpdate;
+use crate::application::services::replay;
+use crate::domain::replay::Replay;
+use crate::domain::game::{DomainError, ErrorKind, GameType, PlayerID};
+use crate::pb::runecraftstudios::pastello::game::session::v1::CreateGameSessionRequest;
+use crate::AppState;
+use axum::extract::{Path, Query, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{delete, get, post, put};
+use axum::Json;
+use std::sync::Arc;
+use tracing::warn;
+
+pub fn router() -> axum::Router<AppState> {
+    axum::Router::new()
+        .route("/sessions", post(create_session))
+        .route("/sessions/{session_id}", get(get_session))
+        .route("/sessions/{session_id}/start", post(start_room))
+        .route("/sessions/{session_id}/commands", post(submit_command))
+        .route("/sessions/{session_id}/players/{player_id}", delete(kick_player))
+        .route("/sessions/{session_id}/replay", get(get_replay))
+        .route("/replays/verify", post(verify_replay))
+        .route("/rooms/{code}/join", post(join_room))
+        .route("/players/{player_id}/sessions", get(list_player_sessions))
+        .route("/players/me/profile", put(update_profile))
+        .route("/players/{player_id}/profile", get(get_profile))
+        .route("/players/{player_id}/stats", get(get_stats))
+        .route("/players/{player_id}/ratings", get(get_ratings))
+        .route("/players/{player_id}/achievements", get(get_achievements))
+        .route("/leaderboards", get(get_leaderboard))
+        .route("/daily-challenge", get(get_daily_challenge))
+        .route("/daily-challenge/attempts", post(start_daily_challenge))
+        .route("/cheat-flags", get(list_cheat_flags))
+}
+
+async fn create_session(
+    State(state): State<AppState>,
+    State(authenticator): State<Arc<dyn Authenticator>>,
+    headers: HeaderMap,
+    Json(body): Json<CreateGameSessionRequestDto>,
+) -> Result<(StatusCode, Json<CreateGameSessionResponseDto>), ApiError> {
+    let player_id = authenticate(authenticator.as_ref(), &headers).await?;
+    let request = CreateGameSessionRequest::try_from(body)?;
+    let command = StartGameSessionCommand::from_pb(player_id, &request)?;
+
+    let session_id = state.game_service.start_game_session(command).await?;
+    let session = state
+        .game_service
+        .get_session(&session_id)
+        .await?
+        .ok_or(DomainError::SessionNotFound(session_id))?;
+
+    let response = CreateGameSessionResponseDto { session: GameSessionDto::new(&session, &session.host_id) };
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+async fn get_session(
+    State(state): State<AppState>,
+    State(authenticator): State<Arc<dyn Authenticator>>,
+    headers: HeaderMap,
+    Path(session_id): Path<String>,
+) -> Result<Json<GameSessionDto>, ApiError> {
+    let player_id = authenticate(authenticator.as_ref(), &headers).await?;
+    let session = state.game_service.session_for(&session_id, &player_id).await?;
+    Ok(Json(GameSessionDto::new(&session, &player_id)))
+}
+
+/// Downloads a replay file of the session so far, for support and regression fixtures.
+async fn get_replay(
+    State(state): State<AppState>,
+    State(authenticator): State<Arc<dyn Authenticator>>,
+    headers: HeaderMap,
+    Path(session_id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let player_id = authenticate(authenticator.as_ref(), &headers).await?;
+    let replay = state.replays.export(&session_id, &player_id).await?;
+    let disposition = format!("attachment; filename=\"replay-{}.json\"", session_id);
+    Ok(([(header::CONTENT_DISPOSITION, disposition)], Json(replay)))
+}
+
+/// Plays an uploaded replay file back and reports whether it still ends the same way.
+async fn verify_replay(
+    State(authenticator): State<Arc<dyn Authenticator>>,
+    headers: HeaderMap,
+    Json(replay): Json<Replay>,
+) -> Result<Json<ReplayReportDto>, ApiError> {
+    authenticate(authenticator.as_ref(), &headers).await?;
+    let report = replay::verify(&replay).await?;
+    Ok(Json(ReplayReportDto::from(&report)))
+}
+
+async fn join_room(
+    State(state): State<AppState>,
+    State(authenticator): State<Arc<dyn Authenticator>>,
+    headers: HeaderMap,
+    Path(code): Path<String>,
+    body: Option<Json<JoinRoomRequestDto>>,
+) -> Result<Json<GameSessionDto>, ApiError> {
+    let player_id = authenticate(authenticator.as_ref(), &headers).await?;
+    let password = body.and_then(|Json(body)| body.password);
+    let session = state.game_service.join_by_code(&code, &player_id, password.as_deref()).await?;
+    Ok(Json(GameSessionDto::new(&session, &player_id)))
+}
+
+/// Starts the game of a private room waiting in the lobby; only its host may.
+async fn start_room(
+    State(state): State<AppState>,
+    State(authenticator): State<Arc<dyn Authenticator>>,
+    headers: HeaderMap,
+    Path(session_id): Path<String>,
+) -> Result<Json<GameSessionDto>, ApiError> {
+    let host_id = authenticate(authenticator.as_ref(), &headers).await?;
+    let session = state.game_service.start_room(&session_id, &host_id).await?;
+    Ok(Json(GameSessionDto::new(&session, &host_id)))
+}
+
+async fn kick_player(
+    State(state): State<AppState>,
+    State(authenticator): State<Arc<dyn Authenticator>>,
+    headers: HeaderMap,
+    Path((session_id, player_id)): Path<(String, String)>,
+) -> Result<StatusCode, ApiError> {
+    let host_id = authenticate(authenticator.as_ref(), &headers).await?;
+    state.game_service.kick(&session_id, &host_id, &player_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn list_player_sessions(
+    State(state): State<AppState>,
+    State(authenticator): State<Arc<dyn Authenticator>>,
+    headers: HeaderMap,
+    Path(player_id): Path<String>,
+) -> Result<Json<ListGameSessionsResponseDto>, ApiError> {
+    let caller = authenticate(authenticator.as_ref(), &headers).await?;
+    let player_id = if player_id == "me" { caller.clone() } else { player_id };
+    let sessions = state.game_service.list_player_sessions(&caller, &player_id).await?;
+    Ok(Json(ListGameSessionsResponseDto {
+        sessions: sessions.iter().map(|session| GameSessionDto::new(session, &caller)).collect(),
+    }))
+}
+
+async fn get_profile(
+    State(state): State<AppState>,
+    State(authenticator): State<Arc<dyn Authenticator>>,
+    headers: HeaderMap,
+    Path(player_id): Path<String>,
+) -> Result<Json<PlayerProfileDto>, ApiError> {
+    let caller = authenticate(authenticator.as_ref(), &headers).await?;
+    let player_id = if player_id == "me" { caller.clone() } else { player_id };
+    let profile = state.profiles.profile(&player_id).await?;
+    Ok(Json(PlayerProfileDto::new(&profile, player_id == caller)))
+}
+
+async fn update_profile(
+    State(state): State<AppState>,
+    State(authenticator): State<Arc<dyn Authenticator>>,
+    headers: HeaderMap,
+    Json(body): Json<UpdateProfileRequestDto>,
+) -> Result<Json<PlayerProfileDto>, ApiError> {
+    let player_id = authenticate(authenticator.as_ref(), &headers).await?;
+    let update = ProfileUpdate {
+        display_name: body.display_name,
+        avatar_url: body.avatar_url.map(|url| Some(url).filter(|url| !url.is_empty())),
+        preferences: body.preferences.map(Into::into),
+    };
+    let profile = state.profiles.update_profile(&player_id, update).await?;
+    Ok(Json(PlayerProfileDto::new(&profile, true)))
+}
+
+async fn get_stats(
+    State(state): State<AppState>,
+    State(authenticator): State<Arc<dyn Authenticator>>,
+    headers: HeaderMap,
+    Path(player_id): Path<String>,
+) -> Result<Json<PlayerStatsDto>, ApiError> {
+    let caller = authenticate(authenticator.as_ref(), &headers).await?;
+    let player_id = if player_id == "me" { caller } else { player_id };
+    let stats = state.profiles.stats(&player_id).await?;
+    Ok(Json(PlayerStatsDto::from(&stats)))
+}
+
+async fn get_ratings(
+    State(state): State<AppState>,
+    State(authenticator): State<Arc<dyn Authenticator>>,
+    headers: HeaderMap,
+    Path(player_id): Path<String>,
+) -> Result<Json<PlayerRatingsDto>, ApiError> {
+    let caller = authenticate(authenticator.as_ref(), &headers).await?;
+    let player_id = if player_id == "me" { caller } else { player_id };
+    let ratings = state.ratings.ratings(&player_id).await?;
+    Ok(Json(PlayerRatingsDto::from(&ratings)))
+}
+
+async fn get_achievements(
+    State(state): State<AppState>,
+    State(authenticator): State<Arc<dyn Authenticator>>,
+    headers: HeaderMap,
+    Path(player_id): Path<String>,
+) -> Result<Json<PlayerAchievementsDto>, ApiError> {
+    let caller = authenticate(authenticator.as_ref(), &headers).await?;
+    let player_id = if player_id == "me" { caller } else { player_id };
+    let achievements = state.achievements.achievements(&player_id).await?;
+    Ok(Json(PlayerAchievementsDto {
+        player_id: IdDto { value: player_id },
+        achievements: achievements.iter().map(AchievementDto::from).collect(),
+    }))
+}
+
+async fn get_leaderboard(
+    State(state): State<AppState>,
+    State(authenticator): State<Arc<dyn Authenticator>>,
+    headers: HeaderMap,
+    Query(params): Query<LeaderboardParamsDto>,
+) -> Result<Json<LeaderboardDto>, ApiError> {
+    let player_id = authenticate(authenticator.as_ref(), &headers).await?;
+    let query = LeaderboardQuery::try_from(params)?;
+    let view = state.leaderboards.query(query, &player_id).await?;
+    Ok(Json(LeaderboardDto::from(&view)))
+}
+
+/// Flags raised by the anti-cheat checks, for the reviewers named in the config.
+async fn list_cheat_flags(
+    State(state): State<AppState>,
+    State(authenticator): State<Arc<dyn Authenticator>>,
+    headers: HeaderMap,
+    Query(params): Query<CheatFlagsParamsDto>,
+) -> Result<Json<CheatFlagsDto>, ApiError> {
+    let reviewer = authenticate(authenticator.as_ref(), &headers).await?;
+    let flags = state
+        .anti_cheat
+        .flags(&reviewer, params.player_id.as_deref(), params.session_id.as_deref())
+        .await?;
+    Ok(Json(CheatFlagsDto {
+        flags: flags.iter().map(CheatFlagDto::from).collect(),
+    }))
+}
+
+async fn get_daily_challenge(
+    State(state): State<AppState>,
+    State(authenticator): State<Arc<dyn Authenticator>>,
+    headers: HeaderMap,
+    Query(params): Query<DailyChallengeParamsDto>,
+) -> Result<Json<DailyChallengeDto>, ApiError> {
+    let player_id = authenticate(authenticator.as_ref(), &headers).await?;
+    let game_type = GameType::try_from(&params)?;
+    let attempt = state.daily.attempt(&player_id, &game_type).await?;
+    let challenge = state.daily.challenge(game_type);
+    Ok(Json(DailyChallengeDto::new(&challenge, attempt.as_ref())))
+}
+
+/// Starts the caller's one attempt at today's challenge. The returned session is played like
+/// any other, over the WebSocket or gRPC stream.
+async fn start_daily_challenge(
+    State(state): State<AppState>,
+    State(authenticator): State<Arc<dyn Authenticator>>,
+    headers: HeaderMap,
+    Json(body): Json<DailyChallengeParamsDto>,
+) -> Result<(StatusCode, Json<DailyChallengeDto>), ApiError> {
+    let player_id = authenticate(authenticator.as_ref(), &headers).await?;
+    let game_type = GameType::try_from(&body)?;
+    let (challenge, attempt) = state.daily.start(&player_id, game_type).await?;
+    Ok((StatusCode::CREATED, Json(DailyChallengeDto::new(&challenge, Some(&attempt)))))
+}
+
+async fn submit_command(
+    State(state): State<AppState>,
+    State(authenticator): State<Arc<dyn Authenticator>>,
+    headers: HeaderMap,
+    Path(session_id): Path<String>,
+    Json(body): Json<GameCommandDto>,
+) -> Result<StatusCode, ApiError> {
+    let player_id = authenticate(authenticator.as_ref(), &headers).await?;
+
+    // As on the WebSocket, commands act for the authenticated player in the addressed session,
+    // whatever ids the payload carries.
+    let mut payload = body.payload;
+    if let Some(fields) = payload.as_object_mut() {
+        fields.insert("player_id".to_string(), player_id.clone().into());
+        fields.insert("session_id".to_string(), session_id.clone().into());
+    }
+
+    let command = GameCommandMessage {
+        session_id: session_id.clone(),
+        r#type: body.command_type,
+        payload,
+        timestamp: chrono::Utc::now(),
+        player_id,
+    };
+    state.game_service.handle_game_command(&session_id, command).await?;
+    Ok(StatusCode::ACCEPTED)
+}
+
+/// Resolves the `Authorization: Bearer <token>` header to the calling player's id.
+async fn authenticate(authenticator: &dyn Authenticator, headers: &HeaderMap) -> Result<PlayerID, ApiError> {
+    let token = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or(AuthError::NoToken)?;
+    let session = authenticator.authenticate(token).await?;
+    Ok(session.host_id)
+}
+
+// --- ERRORS ---
+
+#[derive(Debug)]
+pub struct ApiError {
+    status: StatusCode,
+    message: String,
+}
+
+impl From<AuthError> for ApiError {
+    fn from(error: AuthError) -> Self {
+        Self { status: StatusCode::UNAUTHORIZED, message: error.to_string() }
+    }
+}
+
+impl From<DomainError> for ApiError {
+    fn from(error: DomainError) -> Self {
+        let status = match error.kind() {
+            ErrorKind::NotFound => StatusCode::NOT_FOUND,
+            ErrorKind::Invalid => StatusCode::BAD_REQUEST,
+            ErrorKind::Forbidden => StatusCode::FORBIDDEN,
+            ErrorKind::Expired => StatusCode::GONE,
+            ErrorKind::Conflict => StatusCode::CONFLICT,
+            ErrorKind::RateLimited => StatusCode::TOO_MANY_REQUESTS,
+            ErrorKind::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        Self { status, message: error.to_string() }
+    }
+}
+
+impl From<anyhow::Error> for ApiError {
+    fn from(error: anyhow::Error) -> Self {
+        match error.downcast::<DomainError>() {
+            Ok(domain) => domain.into(),
+            Err(other) => {
+                warn!("Request failed: {:?}", other);
+                Self { status: StatusCode::INTERNAL_SERVER_ERROR, message: other.to_string() }
+            }
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.status, Json(serde_json::json!({ "error": self.message }))).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::inbound::ws::auth::TokenAuthenticator;
+    use crate::domain::game::Role;
+    use axum::body::Body;
+    use axum::http::{Method, Request};
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    fn app() -> (axum::Router, AppState) {
+        let state = AppState::for_tests(Arc::new(TokenAuthenticator));
+        (router().with_state(state.clone()), state)
+    }
+
+    async fn call(app: &axum::Router, method: Method, uri: &str, token: Option<&str>, body: Option<Value>) -> (StatusCode, Value) {
+        let mut request = Request::builder().method(method).uri(uri);
+        if let Some(token) = token {
+            request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+        }
+        let request = match body {
+            Some(body) => request
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string())),
+            None => request.body(Body::empty()),
+        }
+        .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+    }
+
+    async fn create(app: &axum::Router, host: &str, body: Value) -> Value {
+        let (status, body) = call(app, Method::POST, "/sessions", Some(host), Some(body)).await;
+        assert_eq!(status, StatusCode::CREATED, "{}", body);
+        body["session"].clone()
+    }
+
+    fn room() -> Value {
+        json!({
+            "gameType": "GAME_TYPE_TRIVIA",
+            "trivia": { "numQuestions": 3, "secondsPerQuestion": 20, "maxPlayers": 4 },
+            "privateRoom": {},
+        })
+    }
+
+    #[tokio::test]
+    async fn requests_without_a_valid_token_are_unauthorized() {
+        let (app, _) = app();
+        let requests = [
+            (Method::POST, "/sessions", Some(json!({ "gameType": "GAME_TYPE_TRIVIA" }))),
+            (Method::GET, "/sessions/any", None),
+            (Method::GET, "/players/me/sessions", None),
+            (Method::POST, "/sessions/any/commands", Some(json!({ "type": "TriviaHint" }))),
+        ];
+        for (method, uri, body) in requests {
+            for token in [None, Some("expired")] {
+                let (status, _) = call(&app, method.clone(), uri, token, body.clone()).await;
+                assert_eq!(status, StatusCode::UNAUTHORIZED, "{} {} with {:?}", method, uri, token);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn creating_a_session_makes_the_caller_its_host() {
+        let (app, _) = app();
+        let session = create(&app, "alice", room()).await;
+        assert_eq!(session["gameType"], "GAME_TYPE_TRIVIA");
+        assert_eq!(session["status"], "SESSION_STATUS_CREATED");
+        assert_eq!(session["playerIds"], json!([{ "value": "alice" }]));
+        assert!(session["joinCode"].is_string());
+
+        let (status, body) = call(&app, Method::POST, "/sessions", Some("alice"), Some(json!({ "gameType": "chess" }))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{}", body);
+    }
+
+    #[tokio::test]
+    async fn sessions_are_only_visible_to_the_people_in_them() {
+        let (app, state) = app();
+        let session = create(&app, "alice", room()).await;
+        let id = session["id"]["value"].as_str().unwrap();
+        let code = session["joinCode"].as_str().unwrap();
+        let uri = format!("/sessions/{}", id);
+
+        let (status, _) = call(&app, Method::POST, &format!("/rooms/{}/join", code), Some("bob"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        state.game_service.attach(id, &"sam".to_string(), Role::Spectator).await.unwrap();
+
+        let (status, host_view) = call(&app, Method::GET, &uri, Some("alice"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(host_view["joinCode"], code);
+        for viewer in ["bob", "sam"] {
+            let (status, view) = call(&app, Method::GET, &uri, Some(viewer), None).await;
+            assert_eq!(status, StatusCode::OK, "{}", viewer);
+            assert_eq!(view["playerIds"], json!([{ "value": "alice" }, { "value": "bob" }]));
+            assert!(view.get("joinCode").is_none(), "{} saw the join code", viewer);
+            assert!(view.get("joinCodeExpiresAt").is_none());
+        }
+
+        // Outsiders can't tell the session from one that doesn't exist.
+        let (status, _) = call(&app, Method::GET, &uri, Some("mallory"), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = call(&app, Method::GET, "/sessions/missing", Some("alice"), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn players_list_only_their_own_sessions() {
+        let (app, _) = app();
+        let session = create(&app, "alice", room()).await;
+
+        let (status, body) = call(&app, Method::GET, "/players/me/sessions", Some("alice"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["sessions"][0]["id"], session["id"]);
+        assert_eq!(body["sessions"][0]["joinCode"], session["joinCode"]);
+
+        let (status, _) = call(&app, Method::GET, "/players/alice/sessions", Some("bob"), None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, body) = call(&app, Method::GET, "/players/me/sessions", Some("bob"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["sessions"], json!([]));
+    }
+
+    #[tokio::test]
+    async fn only_seated_players_may_submit_commands() {
+        let (app, state) = app();
+        let session = create(&app, "alice", json!({ "gameType": "GAME_TYPE_TRIVIA" })).await;
+        let id = session["id"]["value"].as_str().unwrap();
+        let uri = format!("/sessions/{}/commands", id);
+        let hint = json!({ "type": "TriviaHint", "payload": {} });
+
+        let (status, body) = call(&app, Method::POST, &uri, Some("alice"), Some(hint.clone())).await;
+        assert_eq!(status, StatusCode::ACCEPTED, "{}", body);
+
+        state.game_service.attach(id, &"sam".to_string(), Role::Spectator).await.unwrap();
+        for outsider in ["sam", "mallory"] {
+            let (status, _) = call(&app, Method::POST, &uri, Some(outsider), Some(hint.clone())).await;
+            assert_eq!(status, StatusCode::FORBIDDEN, "{}", outsider);
+        }
+
+        let (status, _) = call(&app, Method::POST, &uri, Some("alice"), Some(json!({ "type": "Checkmate" }))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (status, _) = call(&app, Method::POST, "/sessions/missing/commands", Some("alice"), Some(hint)).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
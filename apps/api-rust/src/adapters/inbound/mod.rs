@@ -1 +1,4 @@
+pub mod envelope;
+pub mod grpc;
+pub mod http;
 pub mod ws;
\ No newline at end of file
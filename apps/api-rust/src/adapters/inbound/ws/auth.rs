@@ -1,4 +1,5 @@
-use crate::domain::game::Session;
+use crate::domain::game::{GameType, Session, SessionStatus};
+use crate::domain::rules::GameRules;
 use anyhow::Result;
 use async_trait::async_trait;
 use jsonwebtoken::jwk::JwkSet;
@@ -40,21 +41,13 @@ pub trait Authenticator: Send + Sync {
 }
 
 // --- The Stub Implementation ---
-pub struct StubAuthenticator {
-    jwks_url: String,
-    project_id: String,
-    keys: RwLock<HashMap<String, DecodingKey>>,
-    client: Client,
-}
+// Takes the same arguments as FirebaseAuthenticator so the two can be swapped in main, but
+// verifies nothing.
+pub struct StubAuthenticator;
 
 impl StubAuthenticator {
-    pub fn new(project_id: &str) -> Self {
-        Self {
-            jwks_url: "https://www.googleapis.com/robot/v1/metadata/x509/securetoken@system.gserviceaccount.com".to_string(),
-            project_id: project_id.to_string(),
-            keys: RwLock::new(HashMap::new()),
-            client: Client::new(),
-        }
+    pub fn new(_project_id: &str) -> Self {
+        Self
     }
 }
 
@@ -71,10 +64,35 @@ impl Authenticator for StubAuthenticator {
                 id: "stub-user-123".to_string(),
                 name: "Stubby The Tester".to_string(),
             }],
+            spectators: Vec::new(),
+            ruleset_id: None,
+            rules: GameRules::default_for(&GameType::Puzzle),
+            room: None,
+            seed: None,
+            daily_challenge: None,
+            question_ids: Vec::new(),
+            status: SessionStatus::Active,
         })
     }
 }
 
+/// Test double that treats the token as the caller's player id; the token "expired" is turned away.
+#[cfg(test)]
+pub(crate) struct TokenAuthenticator;
+
+#[cfg(test)]
+#[async_trait]
+impl Authenticator for TokenAuthenticator {
+    async fn authenticate(&self, token: &str) -> Result<Session, AuthError> {
+        if token == "expired" {
+            return Err(AuthError::Jwt("token expired".to_string()));
+        }
+        let mut session = StubAuthenticator.authenticate(token).await?;
+        session.host_id = token.to_string();
+        Ok(session)
+    }
+}
+
 pub struct FirebaseAuthenticator { 
     jwks_url: String,
     project_id: String,
@@ -133,7 +151,7 @@ impl FirebaseAuthenticator {
         // FIX: Use DecodingKey::from_jwk which correctly handles RS256 components (E0599 fix)
         if jwk.common.key_algorithm.as_ref().map(|a| a.to_string()) == Some("RS256".to_string()) {
             let decoding_key = DecodingKey::from_jwk(jwk)
-                .map_err(|e| AuthError::Jwk(format!("JWK conversion error: {}", e.to_string())))?;
+                .map_err(|e| AuthError::Jwk(format!("JWK conversion error: {}", e)))?;
 
             // Cache the key
             self.keys
@@ -177,6 +195,14 @@ impl Authenticator for FirebaseAuthenticator {
             id: "temp-session".to_string(),
             game_type: crate::domain::game::GameType::Puzzle, // Placeholder
             players: Vec::new(), // Placeholder
+            spectators: Vec::new(),
+            ruleset_id: None,
+            rules: GameRules::default_for(&GameType::Puzzle), // Placeholder
+            room: None,
+            seed: None,
+            daily_challenge: None,
+            question_ids: Vec::new(),
+            status: SessionStatus::Active,
         })
     }
 }
@@ -194,6 +220,14 @@ impl Authenticator for NoOpAuthenticator {
             id: "no-op-session".to_string(),
             game_type: crate::domain::game::GameType::Puzzle,
             players: Vec::new(),
+            spectators: Vec::new(),
+            ruleset_id: None,
+            rules: GameRules::default_for(&GameType::Puzzle),
+            room: None,
+            seed: None,
+            daily_challenge: None,
+            question_ids: Vec::new(),
+            status: SessionStatus::Active,
         })
     }
 }
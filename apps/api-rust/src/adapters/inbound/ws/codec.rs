@@ -0,0 +1,197 @@
+//! Wire formats for the game WebSocket. Binary frames carry protobuf envelopes; clients that
+//! negotiate the `pastello.json.v1` subprotocol exchange the same envelopes as proto3 JSON text
+//! frames instead, which is easier to read in browser devtools or `websocat`.
+//!
+//! The JSON mapping is driven by the descriptors generated alongside the protobuf types, so it
+//! follows the contracts without a hand-kept copy: fields left at their default are omitted,
+//! 64-bit integers are strings, enums are names and timestamps are RFC 3339. The one departure
+//! is `GameEventEnvelope`, whose `puzzleEvent`/`triviaEvent` payloads are JSON documents in a
+//! string field; they are sent as JSON objects rather than as strings of escaped JSON.
+
+use crate::pb::runecraftstudios::pastello::game::{puzzle, session, trivia, types};
+use crate::pb::runecraftstudios::pastello::web;
+use crate::pb::runecraftstudios::pastello::web::game::v1::{ClientEnvelope, ServerEnvelope};
+use anyhow::{anyhow, Context, Result};
+use axum::extract::ws::Message;
+use prost::Message as ProstMessage;
+use prost_reflect::{DescriptorPool, DynamicMessage, MessageDescriptor};
+use serde_json::Value;
+use std::sync::LazyLock;
+
+pub const JSON_SUBPROTOCOL: &str = "pastello.json.v1";
+
+pub const CLIENT_ENVELOPE: &str = "runecraftstudios.pastello.web.game.v1.ClientEnvelope";
+pub const SERVER_ENVELOPE: &str = "runecraftstudios.pastello.web.game.v1.ServerEnvelope";
+pub const AUTH_ENVELOPE: &str = "runecraftstudios.pastello.web.auth.v1.Envelope";
+
+/// Every contract package, dependencies first; the global pool brings the well-known types.
+static DESCRIPTORS: LazyLock<DescriptorPool> = LazyLock::new(|| {
+    let mut pool = DescriptorPool::global();
+    for set in [
+        types::v1::FILE_DESCRIPTOR_SET,
+        puzzle::v1::FILE_DESCRIPTOR_SET,
+        trivia::v1::FILE_DESCRIPTOR_SET,
+        session::v1::FILE_DESCRIPTOR_SET,
+        web::auth::v1::FILE_DESCRIPTOR_SET,
+        web::game::v1::FILE_DESCRIPTOR_SET,
+    ] {
+        pool.decode_file_descriptor_set(set).expect("generated descriptor sets are valid");
+    }
+    pool
+});
+
+fn descriptor(name: &str) -> MessageDescriptor {
+    DESCRIPTORS.get_message_by_name(name).unwrap_or_else(|| panic!("{} is not in the contracts", name))
+}
+
+/// `message`, a `name` message, in proto3 JSON.
+pub fn to_json<M: ProstMessage>(message: &M, name: &str) -> Value {
+    let dynamic = DynamicMessage::decode(descriptor(name), message.encode_to_vec().as_slice())
+        .expect("a message re-decodes with its own descriptor");
+    serde_json::to_value(&dynamic).expect("proto3 JSON serialization does not fail")
+}
+
+/// Parses `text` as a `name` message in proto3 JSON. Field names may be lowerCamelCase or the
+/// original proto names; unknown fields are rejected.
+pub fn from_json<M: ProstMessage + Default>(text: &str, name: &str) -> Result<M> {
+    let mut deserializer = serde_json::Deserializer::from_str(text);
+    let dynamic = DynamicMessage::deserialize(descriptor(name), &mut deserializer)
+        .and_then(|dynamic| deserializer.end().map(|_| dynamic))
+        .context("invalid JSON envelope")?;
+    dynamic.transcode_to().context("invalid JSON envelope")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WireFormat {
+    Protobuf,
+    Json,
+}
+
+impl WireFormat {
+    /// Format for the subprotocol the server accepted, if any.
+    pub fn from_subprotocol(protocol: Option<&str>) -> Self {
+        match protocol {
+            Some(JSON_SUBPROTOCOL) => WireFormat::Json,
+            _ => WireFormat::Protobuf,
+        }
+    }
+
+    /// Decodes a client frame. Returns `None` for control frames, which carry no envelope;
+    /// data frames of the other format are errors.
+    pub fn decode(&self, message: &Message) -> Option<Result<ClientEnvelope>> {
+        match (self, message) {
+            (WireFormat::Protobuf, Message::Binary(bin)) => {
+                Some(ClientEnvelope::decode(&bin[..]).map_err(|e| anyhow!("invalid protobuf envelope: {}", e)))
+            }
+            (WireFormat::Json, Message::Text(text)) => Some(from_json(text.as_str(), CLIENT_ENVELOPE)),
+            (WireFormat::Json, Message::Binary(_)) => {
+                Some(Err(anyhow!("binary frames are not accepted on {}", JSON_SUBPROTOCOL)))
+            }
+            (WireFormat::Protobuf, Message::Text(_)) => Some(Err(anyhow!("text frames are not accepted without {}", JSON_SUBPROTOCOL))),
+            _ => None,
+        }
+    }
+
+    pub fn encode(&self, envelope: &ServerEnvelope) -> Message {
+        match self {
+            WireFormat::Protobuf => Message::Binary(envelope.encode_to_vec().into()),
+            WireFormat::Json => {
+                let mut json = to_json(envelope, SERVER_ENVELOPE);
+                if let Some(event) = json.get_mut("gameEvent").and_then(Value::as_object_mut) {
+                    for payload in event.values_mut() {
+                        if let Some(parsed) = payload.as_str().and_then(|text| serde_json::from_str(text).ok()) {
+                            *payload = parsed;
+                        }
+                    }
+                }
+                Message::Text(json.to_string().into())
+            }
+        }
+    }
+
+    /// Frame for any other contract message, e.g. the handshake replies.
+    pub fn frame<M: ProstMessage>(&self, message: &M, name: &str) -> Message {
+        match self {
+            WireFormat::Protobuf => Message::Binary(message.encode_to_vec().into()),
+            WireFormat::Json => Message::Text(to_json(message, name).to_string().into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pb::runecraftstudios::pastello::web::game::v1::{
+        client_envelope, game_command_envelope, game_event_envelope, server_envelope, GameEventEnvelope,
+        QueueStatus,
+    };
+    use serde_json::json;
+
+    fn text(message: Message) -> Value {
+        match message {
+            Message::Text(text) => serde_json::from_str(text.as_str()).unwrap(),
+            other => panic!("expected a text frame, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn server_envelopes_omit_default_fields() {
+        let envelope = ServerEnvelope {
+            message: Some(server_envelope::Message::QueueStatus(QueueStatus {
+                game_type: types::v1::GameType::Trivia as i32,
+                position: 0,
+                queued: 3,
+                waited_seconds: 0,
+            })),
+        };
+        assert_eq!(
+            text(WireFormat::Json.encode(&envelope)),
+            json!({ "queueStatus": { "gameType": "GAME_TYPE_TRIVIA", "queued": 3 } })
+        );
+    }
+
+    #[test]
+    fn game_event_payloads_are_objects() {
+        let envelope = ServerEnvelope {
+            message: Some(server_envelope::Message::GameEvent(GameEventEnvelope {
+                event: Some(game_event_envelope::Event::TriviaEvent(r#"{"type":"trivia.hint_revealed"}"#.into())),
+            })),
+        };
+        assert_eq!(
+            text(WireFormat::Json.encode(&envelope)),
+            json!({ "gameEvent": { "triviaEvent": { "type": "trivia.hint_revealed" } } })
+        );
+    }
+
+    #[test]
+    fn client_envelopes_accept_camel_case_and_proto_names() {
+        for text in [
+            r#"{"gameCommand":{"triviaSubmit":{"answer":"Paris"}}}"#,
+            r#"{"game_command":{"trivia_submit":{"answer":"Paris"}}}"#,
+        ] {
+            let envelope = WireFormat::Json.decode(&Message::Text(text.into())).unwrap().unwrap();
+            let Some(client_envelope::Message::GameCommand(command)) = envelope.message else {
+                panic!("expected a game command");
+            };
+            let Some(game_command_envelope::Command::TriviaSubmit(submit)) = command.command else {
+                panic!("expected a trivia answer");
+            };
+            assert_eq!(submit.answer, "Paris");
+        }
+    }
+
+    #[test]
+    fn client_envelopes_reject_unknown_fields_and_two_messages() {
+        for text in [r#"{"startGame":{},"leaveQueue":{}}"#, r#"{"startGame":{"speed":2}}"#] {
+            assert!(WireFormat::Json.decode(&Message::Text(text.into())).unwrap().is_err(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn data_frames_of_the_other_format_are_errors() {
+        assert!(WireFormat::Protobuf.decode(&Message::Text("{}".into())).unwrap().is_err());
+        assert!(WireFormat::Json.decode(&Message::Binary(vec![0u8].into())).unwrap().is_err());
+        assert!(WireFormat::Protobuf.decode(&Message::Ping(vec![].into())).is_none());
+        assert!(WireFormat::Json.decode(&Message::Pong(vec![].into())).is_none());
+    }
+}
@@ -0,0 +1,592 @@
+//! Flood protection for the game WebSocket.
+//!
+//! Every connection has a frame size limit, enforced by the transport, a budget of frames that
+//! fail to decode, and a token bucket per message type. Each user also has a bucket per message
+//! type shared by all of their connections, so opening more sockets doesn't buy more
+//! throughput, and a cap on how many connections they may hold. Connections still waiting to
+//! authenticate are capped server-wide. Violations are counted and served at `/metrics` on the
+//! internal port.
+
+use crate::domain::game::PlayerID;
+use crate::pb::runecraftstudios::pastello::web::game::v1::{client_envelope, ClientEnvelope};
+use axum::extract::ws::{close_code, CloseFrame, Message};
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::time::Instant;
+use tungstenite::error::CapacityError;
+
+/// Client message types, each rate limited on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageKind {
+    StartGame,
+    GameCommand,
+    JoinQueue,
+    LeaveQueue,
+    GetLeaderboard,
+}
+
+impl MessageKind {
+    pub const ALL: [MessageKind; 5] = [
+        MessageKind::StartGame,
+        MessageKind::GameCommand,
+        MessageKind::JoinQueue,
+        MessageKind::LeaveQueue,
+        MessageKind::GetLeaderboard,
+    ];
+
+    pub fn of(envelope: &ClientEnvelope) -> Option<Self> {
+        match envelope.message.as_ref()? {
+            client_envelope::Message::StartGame(_) => Some(MessageKind::StartGame),
+            client_envelope::Message::GameCommand(_) => Some(MessageKind::GameCommand),
+            client_envelope::Message::JoinQueue(_) => Some(MessageKind::JoinQueue),
+            client_envelope::Message::LeaveQueue(_) => Some(MessageKind::LeaveQueue),
+            client_envelope::Message::GetLeaderboard(_) => Some(MessageKind::GetLeaderboard),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MessageKind::StartGame => "start_game",
+            MessageKind::GameCommand => "game_command",
+            MessageKind::JoinQueue => "join_queue",
+            MessageKind::LeaveQueue => "leave_queue",
+            MessageKind::GetLeaderboard => "get_leaderboard",
+        }
+    }
+}
+
+impl fmt::Display for MessageKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Whose bucket ran dry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LimitScope {
+    Connection,
+    User,
+}
+
+impl LimitScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LimitScope::Connection => "connection",
+            LimitScope::User => "user",
+        }
+    }
+}
+
+impl fmt::Display for LimitScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A token bucket: up to `burst` messages at once, refilled at `per_second`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RateLimit {
+    pub burst: u32,
+    pub per_second: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MessageLimits {
+    pub connection: RateLimit,
+    /// Shared by all of a user's connections.
+    pub user: RateLimit,
+}
+
+/// WebSocket limits, read from the `flood_protection` section of `config.yaml`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FloodPolicy {
+    /// Larger frames close the connection.
+    pub max_frame_bytes: usize,
+    /// Frames that fail to decode, per connection, before it is closed.
+    pub max_decode_failures: u32,
+    /// Further connections of a user are refused after authentication.
+    pub max_connections_per_user: usize,
+    /// Connections that haven't authenticated yet, across all users. Upgrades past it are
+    /// refused.
+    pub max_pending_connections: usize,
+    /// Message types without an entry are not rate limited.
+    pub rate_limits: HashMap<MessageKind, MessageLimits>,
+}
+
+impl Default for FloodPolicy {
+    fn default() -> Self {
+        let limits = |burst: u32, per_second: f64| MessageLimits {
+            connection: RateLimit { burst, per_second },
+            user: RateLimit { burst: burst * 2, per_second: per_second * 2.0 },
+        };
+        Self {
+            max_frame_bytes: 64 * 1024,
+            max_decode_failures: 5,
+            max_connections_per_user: 4,
+            max_pending_connections: 256,
+            rate_limits: HashMap::from([
+                (MessageKind::StartGame, limits(2, 0.2)),
+                (MessageKind::GameCommand, limits(20, 10.0)),
+                (MessageKind::JoinQueue, limits(3, 0.5)),
+                (MessageKind::LeaveQueue, limits(3, 0.5)),
+                (MessageKind::GetLeaderboard, limits(5, 1.0)),
+            ]),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum FloodError {
+    #[error("Frame of {size} bytes is over the {max} byte limit")]
+    FrameTooLarge { size: usize, max: usize },
+    #[error("Too many {kind} messages on this {scope}; slow down")]
+    RateLimited { kind: MessageKind, scope: LimitScope },
+    #[error("Too many frames that could not be decoded ({0})")]
+    TooManyDecodeFailures(u32),
+    #[error("{player_id} already has {max} connections open")]
+    TooManyConnections { player_id: PlayerID, max: usize },
+    #[error("{0} connections are already waiting to authenticate")]
+    TooManyPendingConnections(usize),
+}
+
+impl FloodError {
+    /// Close frame for errors that end the connection; rate limited messages are only dropped.
+    pub fn close_frame(&self) -> Option<CloseFrame> {
+        let code = match self {
+            FloodError::RateLimited { .. } => return None,
+            FloodError::FrameTooLarge { .. } => close_code::SIZE,
+            FloodError::TooManyDecodeFailures(_)
+            | FloodError::TooManyConnections { .. }
+            | FloodError::TooManyPendingConnections(_) => close_code::POLICY,
+        };
+        Some(CloseFrame {
+            code,
+            reason: self.to_string().into(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct TokenBucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    fn full(limit: &RateLimit, now: Instant) -> Self {
+        Self {
+            tokens: limit.burst as f64,
+            updated: now,
+        }
+    }
+
+    fn refill(&mut self, limit: &RateLimit, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * limit.per_second).min(limit.burst as f64);
+        self.updated = now;
+    }
+
+    fn is_full(&self, limit: &RateLimit, now: Instant) -> bool {
+        let mut bucket = self.clone();
+        bucket.refill(limit, now);
+        bucket.tokens >= limit.burst as f64
+    }
+}
+
+#[derive(Debug, Default)]
+struct UserState {
+    connections: usize,
+    buckets: HashMap<MessageKind, TokenBucket>,
+}
+
+#[derive(Debug, Default)]
+struct FloodMetrics {
+    open_connections: AtomicU64,
+    pending_connections: AtomicU64,
+    rejected_connections: AtomicU64,
+    rejected_pending_connections: AtomicU64,
+    oversized_frames: AtomicU64,
+    decode_failures: AtomicU64,
+    decode_failure_disconnects: AtomicU64,
+    rate_limited: DashMap<(MessageKind, LimitScope), AtomicU64>,
+}
+
+/// Limits shared by every WebSocket connection. One per server, in `AppState`.
+#[derive(Debug)]
+pub struct FloodGuard {
+    policy: FloodPolicy,
+    users: DashMap<PlayerID, UserState>,
+    metrics: FloodMetrics,
+}
+
+impl FloodGuard {
+    pub fn new(policy: FloodPolicy) -> Self {
+        Self {
+            policy,
+            users: DashMap::new(),
+            metrics: FloodMetrics::default(),
+        }
+    }
+
+    /// Largest frame or message the transport accepts.
+    pub fn max_frame_bytes(&self) -> usize {
+        self.policy.max_frame_bytes
+    }
+
+    /// Admits a connection that has yet to authenticate, unless too many already are. Holding
+    /// the returned slot counts the connection as pending.
+    pub fn admit(self: &Arc<Self>) -> Result<PendingConnection, FloodError> {
+        let max = self.policy.max_pending_connections;
+        let pending = self.metrics.pending_connections.fetch_add(1, Ordering::Relaxed);
+        if pending >= max as u64 {
+            self.metrics.pending_connections.fetch_sub(1, Ordering::Relaxed);
+            self.metrics.rejected_pending_connections.fetch_add(1, Ordering::Relaxed);
+            return Err(FloodError::TooManyPendingConnections(max));
+        }
+        Ok(PendingConnection { guard: self.clone() })
+    }
+
+    /// Counts a frame the transport refused for being over `max_frame_bytes`. Other receive
+    /// errors pass through.
+    pub fn check_frame(&self, frame: &Result<Message, axum::Error>) -> Result<(), FloodError> {
+        let Err(e) = frame else {
+            return Ok(());
+        };
+        let error = std::error::Error::source(e).and_then(|inner| inner.downcast_ref::<tungstenite::Error>());
+        let Some(tungstenite::Error::Capacity(CapacityError::MessageTooLong { size, .. })) = error else {
+            return Ok(());
+        };
+        self.metrics.oversized_frames.fetch_add(1, Ordering::Relaxed);
+        Err(FloodError::FrameTooLarge {
+            size: *size,
+            max: self.policy.max_frame_bytes,
+        })
+    }
+
+    /// Admits a connection of `player_id`, unless they already hold as many as allowed.
+    pub fn connect(self: &Arc<Self>, player_id: &PlayerID) -> Result<ConnectionLimiter, FloodError> {
+        let mut user = self.users.entry(player_id.clone()).or_default();
+        if user.connections >= self.policy.max_connections_per_user {
+            self.metrics.rejected_connections.fetch_add(1, Ordering::Relaxed);
+            return Err(FloodError::TooManyConnections {
+                player_id: player_id.clone(),
+                max: self.policy.max_connections_per_user,
+            });
+        }
+        user.connections += 1;
+        self.metrics.open_connections.fetch_add(1, Ordering::Relaxed);
+        Ok(ConnectionLimiter {
+            guard: self.clone(),
+            player_id: player_id.clone(),
+            buckets: HashMap::new(),
+            decode_failures: 0,
+            released: false,
+        })
+    }
+
+    /// Counters in the Prometheus text format.
+    pub fn render_metrics(&self) -> String {
+        let metrics = &self.metrics;
+        let mut out = String::new();
+        let mut metric = |name: &str, kind: &str, help: &str, value: &AtomicU64| {
+            let _ = writeln!(out, "# HELP {} {}\n# TYPE {} {}", name, help, name, kind);
+            let _ = writeln!(out, "{} {}", name, value.load(Ordering::Relaxed));
+        };
+        metric("pastello_ws_connections", "gauge", "Open game WebSocket connections.", &metrics.open_connections);
+        metric(
+            "pastello_ws_pending_connections",
+            "gauge",
+            "Game WebSocket connections waiting to authenticate.",
+            &metrics.pending_connections,
+        );
+        metric(
+            "pastello_ws_rejected_connections_total",
+            "counter",
+            "Connections refused for going over the per-user cap.",
+            &metrics.rejected_connections,
+        );
+        metric(
+            "pastello_ws_rejected_pending_connections_total",
+            "counter",
+            "Upgrades refused for going over the cap on connections waiting to authenticate.",
+            &metrics.rejected_pending_connections,
+        );
+        metric(
+            "pastello_ws_oversized_frames_total",
+            "counter",
+            "Connections closed for sending a frame over the size limit.",
+            &metrics.oversized_frames,
+        );
+        metric(
+            "pastello_ws_decode_failures_total",
+            "counter",
+            "Frames that could not be decoded.",
+            &metrics.decode_failures,
+        );
+        metric(
+            "pastello_ws_decode_failure_disconnects_total",
+            "counter",
+            "Connections closed for sending too many frames that could not be decoded.",
+            &metrics.decode_failure_disconnects,
+        );
+
+        let name = "pastello_ws_rate_limited_total";
+        let _ = writeln!(out, "# HELP {} Messages dropped by a rate limit.\n# TYPE {} counter", name, name);
+        for kind in MessageKind::ALL {
+            for scope in [LimitScope::Connection, LimitScope::User] {
+                let count = metrics
+                    .rate_limited
+                    .get(&(kind, scope))
+                    .map(|count| count.load(Ordering::Relaxed))
+                    .unwrap_or(0);
+                let _ = writeln!(out, "{}{{kind=\"{}\",scope=\"{}\"}} {}", name, kind, scope, count);
+            }
+        }
+        out
+    }
+}
+
+/// A connection's place among those waiting to authenticate, released on drop.
+#[derive(Debug)]
+pub struct PendingConnection {
+    guard: Arc<FloodGuard>,
+}
+
+impl Drop for PendingConnection {
+    fn drop(&mut self) {
+        self.guard.metrics.pending_connections.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// One connection's view of the limits. Closing or dropping it releases the user's connection
+/// slot.
+#[derive(Debug)]
+pub struct ConnectionLimiter {
+    guard: Arc<FloodGuard>,
+    player_id: PlayerID,
+    buckets: HashMap<MessageKind, TokenBucket>,
+    decode_failures: u32,
+    released: bool,
+}
+
+impl ConnectionLimiter {
+    /// Takes a token for `envelope` from this connection's bucket and the user's, or from
+    /// neither when either is empty.
+    pub fn check_message(&mut self, envelope: &ClientEnvelope) -> Result<(), FloodError> {
+        let Some(kind) = MessageKind::of(envelope) else {
+            return Ok(());
+        };
+        let Some(limits) = self.guard.policy.rate_limits.get(&kind) else {
+            return Ok(());
+        };
+        let now = Instant::now();
+        let mut user = self.guard.users.entry(self.player_id.clone()).or_default();
+        let connection_bucket = self
+            .buckets
+            .entry(kind)
+            .or_insert_with(|| TokenBucket::full(&limits.connection, now));
+        let user_bucket = user
+            .buckets
+            .entry(kind)
+            .or_insert_with(|| TokenBucket::full(&limits.user, now));
+        connection_bucket.refill(&limits.connection, now);
+        user_bucket.refill(&limits.user, now);
+
+        let scope = if connection_bucket.tokens < 1.0 {
+            LimitScope::Connection
+        } else if user_bucket.tokens < 1.0 {
+            LimitScope::User
+        } else {
+            connection_bucket.tokens -= 1.0;
+            user_bucket.tokens -= 1.0;
+            return Ok(());
+        };
+        self.guard
+            .metrics
+            .rate_limited
+            .entry((kind, scope))
+            .or_default()
+            .fetch_add(1, Ordering::Relaxed);
+        Err(FloodError::RateLimited { kind, scope })
+    }
+
+    /// Counts a frame that failed to decode; errs once the connection has sent too many.
+    pub fn decode_failed(&mut self) -> Result<(), FloodError> {
+        self.decode_failures += 1;
+        self.guard.metrics.decode_failures.fetch_add(1, Ordering::Relaxed);
+        if self.decode_failures >= self.guard.policy.max_decode_failures {
+            self.guard.metrics.decode_failure_disconnects.fetch_add(1, Ordering::Relaxed);
+            return Err(FloodError::TooManyDecodeFailures(self.decode_failures));
+        }
+        Ok(())
+    }
+
+    /// Releases the user's connection slot. Returns whether this was their last connection.
+    pub fn close(mut self) -> bool {
+        self.release()
+    }
+
+    fn release(&mut self) -> bool {
+        if std::mem::replace(&mut self.released, true) {
+            return false;
+        }
+        self.guard.metrics.open_connections.fetch_sub(1, Ordering::Relaxed);
+        let policy = &self.guard.policy;
+        let now = Instant::now();
+        let mut last = true;
+        // Forget users once they are gone and their buckets have refilled; a user who
+        // reconnects sooner finds their buckets as they left them.
+        self.guard.users.remove_if_mut(&self.player_id, |_, user| {
+            user.connections = user.connections.saturating_sub(1);
+            last = user.connections == 0;
+            last && user.buckets.iter().all(|(kind, bucket)| {
+                policy
+                    .rate_limits
+                    .get(kind)
+                    .is_none_or(|limits| bucket.is_full(&limits.user, now))
+            })
+        });
+        last
+    }
+}
+
+impl Drop for ConnectionLimiter {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pb::runecraftstudios::pastello::web::game::v1::LeaveQueueCommand;
+    use std::time::Duration;
+
+    fn leave_queue() -> ClientEnvelope {
+        ClientEnvelope {
+            message: Some(client_envelope::Message::LeaveQueue(LeaveQueueCommand {})),
+        }
+    }
+
+    /// Leaving the queue allowed twice per connection and three times per user, never refilled.
+    fn guard() -> Arc<FloodGuard> {
+        let limits = MessageLimits {
+            connection: RateLimit { burst: 2, per_second: 0.0 },
+            user: RateLimit { burst: 3, per_second: 0.0 },
+        };
+        Arc::new(FloodGuard::new(FloodPolicy {
+            max_connections_per_user: 2,
+            max_pending_connections: 2,
+            rate_limits: HashMap::from([(MessageKind::LeaveQueue, limits)]),
+            ..FloodPolicy::default()
+        }))
+    }
+
+    #[test]
+    fn buckets_refill_at_their_rate_up_to_the_burst() {
+        let limit = RateLimit { burst: 4, per_second: 2.0 };
+        let start = Instant::now();
+        let mut bucket = TokenBucket::full(&limit, start);
+        bucket.tokens = 0.0;
+
+        bucket.refill(&limit, start + Duration::from_millis(500));
+        assert!((bucket.tokens - 1.0).abs() < 1e-9);
+        assert!(!bucket.is_full(&limit, start + Duration::from_millis(1999)));
+        assert!(bucket.is_full(&limit, start + Duration::from_millis(2000)));
+        bucket.refill(&limit, start + Duration::from_secs(60));
+        assert_eq!(bucket.tokens, 4.0);
+    }
+
+    #[test]
+    fn connections_run_dry_before_their_user() {
+        let guard = guard();
+        let player = "alice".to_string();
+        let mut first = guard.connect(&player).unwrap();
+        assert!(first.check_message(&leave_queue()).is_ok());
+        assert!(first.check_message(&leave_queue()).is_ok());
+        assert_eq!(
+            first.check_message(&leave_queue()),
+            Err(FloodError::RateLimited { kind: MessageKind::LeaveQueue, scope: LimitScope::Connection })
+        );
+
+        // A second connection has its own bucket, but shares the user's, which has one left.
+        let mut second = guard.connect(&player).unwrap();
+        assert!(second.check_message(&leave_queue()).is_ok());
+        assert_eq!(
+            second.check_message(&leave_queue()),
+            Err(FloodError::RateLimited { kind: MessageKind::LeaveQueue, scope: LimitScope::User })
+        );
+        // Unlimited kinds always pass.
+        let join = ClientEnvelope {
+            message: Some(client_envelope::Message::JoinQueue(Default::default())),
+        };
+        assert!(second.check_message(&join).is_ok());
+        assert!(guard.render_metrics().contains("kind=\"leave_queue\",scope=\"user\"} 1"));
+    }
+
+    #[test]
+    fn users_keep_their_buckets_while_they_have_not_refilled() {
+        let guard = guard();
+        let player = "alice".to_string();
+        let mut limiter = guard.connect(&player).unwrap();
+        limiter.check_message(&leave_queue()).unwrap();
+        limiter.check_message(&leave_queue()).unwrap();
+        drop(limiter);
+
+        let mut limiter = guard.connect(&player).unwrap();
+        limiter.check_message(&leave_queue()).unwrap();
+        assert!(limiter.check_message(&leave_queue()).is_err());
+    }
+
+    #[test]
+    fn connections_are_capped_per_user_and_before_authentication() {
+        let guard = guard();
+        let player = "alice".to_string();
+        let _first = guard.connect(&player).unwrap();
+        let second = guard.connect(&player).unwrap();
+        assert!(matches!(guard.connect(&player), Err(FloodError::TooManyConnections { .. })));
+        drop(second);
+        assert!(guard.connect(&player).is_ok());
+
+        let pending = guard.admit().unwrap();
+        let _other = guard.admit().unwrap();
+        assert_eq!(guard.admit().unwrap_err(), FloodError::TooManyPendingConnections(2));
+        drop(pending);
+        assert!(guard.admit().is_ok());
+        assert!(guard.render_metrics().contains("pastello_ws_rejected_pending_connections_total 1"));
+    }
+
+    #[test]
+    fn closing_reports_the_users_last_connection() {
+        let guard = guard();
+        let player = "alice".to_string();
+        let first = guard.connect(&player).unwrap();
+        let second = guard.connect(&player).unwrap();
+        assert!(!second.close());
+        assert!(guard.render_metrics().contains("\npastello_ws_connections 1\n"));
+        let third = guard.connect(&player).unwrap();
+        drop(first);
+        assert!(third.close());
+        assert!(guard.render_metrics().contains("\npastello_ws_connections 0\n"));
+    }
+
+    #[test]
+    fn oversized_frames_refused_by_the_transport_are_counted() {
+        let guard = guard();
+        let too_long = tungstenite::Error::Capacity(CapacityError::MessageTooLong { size: 70000, max_size: 65536 });
+        assert_eq!(
+            guard.check_frame(&Err(axum::Error::new(too_long))),
+            Err(FloodError::FrameTooLarge { size: 70000, max: 65536 })
+        );
+        assert!(guard.check_frame(&Err(axum::Error::new(tungstenite::Error::ConnectionClosed))).is_ok());
+        assert!(guard.check_frame(&Ok(Message::Text("hello".into()))).is_ok());
+        assert!(guard.render_metrics().contains("pastello_ws_oversized_frames_total 1"));
+    }
+}
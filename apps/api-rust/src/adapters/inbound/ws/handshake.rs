@@ -0,0 +1,398 @@
+//! Opening exchange of the game WebSocket.
+//!
+//! Versioned clients send a `web.auth.v1.Envelope` with a `StartUserSessionCommand` carrying
+//! their token and `client_version`, and get a `SessionStartedEvent` advertising the server's
+//! protocol version and capabilities, or `UpgradeRequired` when their version is unsupported.
+//! Older clients send the bare token as a text frame and get the `AUTH_SUCCESS` text reply.
+
+use crate::adapters::inbound::ws::codec::{self, WireFormat, AUTH_ENVELOPE, JSON_SUBPROTOCOL};
+use crate::config::ProtocolConfig;
+use crate::pb::runecraftstudios::pastello::web::auth::v1::{
+    envelope, Envelope, SessionStartedEvent, UpgradeRequired,
+};
+use anyhow::{Context, Result};
+use axum::extract::ws::{CloseFrame, Message};
+use prost::Message as ProstMessage;
+use semver::Version;
+
+/// Version of the envelope protocol this server speaks. Bump the minor version for
+/// additive changes and the major version when old clients can no longer be served.
+pub const PROTOCOL_VERSION: &str = "1.1.0";
+
+/// Optional features advertised in `SessionStartedEvent.capabilities`.
+pub const CAPABILITIES: &[&str] = &["protobuf", JSON_SUBPROTOCOL];
+
+/// WebSocket close code sent after `UpgradeRequired`, mirroring HTTP 426.
+pub const CLOSE_UPGRADE_REQUIRED: u16 = 4426;
+
+/// Range of client versions the server accepts, from the `protocol` config section.
+#[derive(Debug, Clone)]
+pub struct ProtocolPolicy {
+    min_client_version: Option<Version>,
+    max_client_version: Option<Version>,
+    allow_unversioned: bool,
+}
+
+impl ProtocolPolicy {
+    pub fn from_config(config: &ProtocolConfig) -> Result<Self> {
+        let parse = |field: &str, value: &Option<String>| {
+            value
+                .as_deref()
+                .map(Version::parse)
+                .transpose()
+                .with_context(|| format!("protocol.{} is not a semantic version", field))
+        };
+        Ok(Self {
+            min_client_version: parse("min_client_version", &config.min_client_version)?,
+            max_client_version: parse("max_client_version", &config.max_client_version)?,
+            allow_unversioned: config.allow_unversioned,
+        })
+    }
+
+    /// Checks a client's version against the supported range (both ends inclusive).
+    /// `None` is a legacy client that did not send a version.
+    pub fn check(&self, client_version: Option<&str>) -> Result<(), UpgradeRequired> {
+        let reject = |message: String| UpgradeRequired {
+            client_version: client_version.unwrap_or_default().to_string(),
+            min_client_version: self.min_client_version.as_ref().map(Version::to_string).unwrap_or_default(),
+            max_client_version: self.max_client_version.as_ref().map(Version::to_string).unwrap_or_default(),
+            protocol_version: PROTOCOL_VERSION.to_string(),
+            message,
+        };
+
+        let Some(raw) = client_version else {
+            return match self.allow_unversioned {
+                true => Ok(()),
+                false => Err(reject("this server requires a client version; please upgrade".to_string())),
+            };
+        };
+        let version = Version::parse(raw.trim())
+            .map_err(|_| reject(format!("client version '{}' is not a semantic version", raw)))?;
+
+        if self.min_client_version.as_ref().is_some_and(|min| version < *min) {
+            return Err(reject(format!("client version {} is too old; please upgrade", version)));
+        }
+        if self.max_client_version.as_ref().is_some_and(|max| version > *max) {
+            return Err(reject(format!("client version {} is newer than this server supports", version)));
+        }
+        Ok(())
+    }
+}
+
+impl Default for ProtocolPolicy {
+    fn default() -> Self {
+        Self {
+            min_client_version: None,
+            max_client_version: None,
+            allow_unversioned: true,
+        }
+    }
+}
+
+/// The client's first frame.
+#[derive(Debug, Clone)]
+pub enum ClientHello {
+    Legacy { token: String },
+    Versioned { token: String, client_version: String, correlation_id: String },
+}
+
+impl ClientHello {
+    /// Parses the first frame. A text frame that is not a JSON envelope is a legacy bare token.
+    pub fn parse(format: WireFormat, frame: &Message) -> Option<Self> {
+        match (format, frame) {
+            (WireFormat::Protobuf, Message::Binary(bin)) => Envelope::decode(&bin[..]).ok().and_then(Self::from_envelope),
+            (WireFormat::Json, Message::Text(text)) if text.trim_start().starts_with('{') => {
+                codec::from_json(text.as_str(), AUTH_ENVELOPE).ok().and_then(Self::from_envelope)
+            }
+            (_, Message::Text(token)) => Some(ClientHello::Legacy { token: token.to_string() }),
+            _ => None,
+        }
+    }
+
+    pub fn token(&self) -> &str {
+        match self {
+            ClientHello::Legacy { token } | ClientHello::Versioned { token, .. } => token,
+        }
+    }
+
+    pub fn client_version(&self) -> Option<&str> {
+        match self {
+            ClientHello::Legacy { .. } => None,
+            ClientHello::Versioned { client_version, .. } => Some(client_version),
+        }
+    }
+
+    /// Reply for a successful handshake.
+    pub fn accepted(&self, format: WireFormat, user_id: &str, session_id: &str) -> Message {
+        let ClientHello::Versioned { correlation_id, .. } = self else {
+            return Message::Text("AUTH_SUCCESS".into());
+        };
+        let event = SessionStartedEvent {
+            session_id: session_id.to_string(),
+            user_id: user_id.to_string(),
+            created_at: Some(now()),
+            protocol_version: PROTOCOL_VERSION.to_string(),
+            capabilities: CAPABILITIES.iter().map(|c| c.to_string()).collect(),
+        };
+        reply(format, correlation_id, envelope::Msg::SessionStartedEvent(event))
+    }
+
+    /// Frames to send before closing the connection of an unsupported client.
+    pub fn rejected(&self, format: WireFormat, upgrade: UpgradeRequired) -> Vec<Message> {
+        let close = Message::Close(Some(CloseFrame {
+            code: CLOSE_UPGRADE_REQUIRED,
+            reason: "upgrade required".into(),
+        }));
+        let reply = match self {
+            ClientHello::Legacy { .. } => Message::Text(format!("UPGRADE_REQUIRED: {}", upgrade.message).into()),
+            ClientHello::Versioned { correlation_id, .. } => {
+                reply(format, correlation_id, envelope::Msg::UpgradeRequired(upgrade))
+            }
+        };
+        vec![reply, close]
+    }
+
+    fn from_envelope(envelope: Envelope) -> Option<Self> {
+        match envelope.msg {
+            Some(envelope::Msg::StartUserSessionCommand(cmd)) => Some(ClientHello::Versioned {
+                token: cmd.token,
+                client_version: cmd.client_version,
+                correlation_id: envelope.correlation_id,
+            }),
+            _ => None,
+        }
+    }
+}
+
+fn reply(format: WireFormat, correlation_id: &str, msg: envelope::Msg) -> Message {
+    let envelope = Envelope {
+        correlation_id: correlation_id.to_string(),
+        msg: Some(msg),
+    };
+    format.frame(&envelope, AUTH_ENVELOPE)
+}
+
+fn now() -> prost_types::Timestamp {
+    let now = chrono::Utc::now();
+    prost_types::Timestamp {
+        seconds: now.timestamp(),
+        nanos: now.timestamp_subsec_nanos() as i32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pb::runecraftstudios::pastello::web::auth::v1::StartUserSessionCommand;
+    use serde_json::{json, Value};
+
+    fn text(frame: &str) -> Message {
+        Message::Text(frame.into())
+    }
+
+    fn json_frame(message: &Message) -> Value {
+        let Message::Text(text) = message else {
+            panic!("expected a text frame, got {:?}", message);
+        };
+        serde_json::from_str(text.as_str()).unwrap()
+    }
+
+    fn versioned(token: &str, client_version: &str, correlation_id: &str) -> ClientHello {
+        ClientHello::Versioned {
+            token: token.to_string(),
+            client_version: client_version.to_string(),
+            correlation_id: correlation_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn json_connections_open_with_an_auth_envelope() {
+        for frame in [
+            r#"{"correlationId":"c1","startUserSessionCommand":{"token":"t0k","clientVersion":"1.2.0"}}"#,
+            r#"  {"correlation_id":"c1","start_user_session_command":{"token":"t0k","client_version":"1.2.0"}}"#,
+        ] {
+            let Some(ClientHello::Versioned { token, client_version, correlation_id }) =
+                ClientHello::parse(WireFormat::Json, &text(frame))
+            else {
+                panic!("expected a versioned hello from {}", frame);
+            };
+            assert_eq!((token.as_str(), client_version.as_str(), correlation_id.as_str()), ("t0k", "1.2.0", "c1"));
+        }
+    }
+
+    #[test]
+    fn json_envelopes_that_do_not_start_a_session_are_refused() {
+        for frame in [
+            r#"{"sessionStartedEvent":{"sessionId":"s"}}"#,
+            r#"{"correlationId":"c1"}"#,
+            r#"{"startUserSessionCommand":{"token":"t0k","speed":2}}"#,
+            r#"{"startUserSessionCommand":"#,
+        ] {
+            assert!(ClientHello::parse(WireFormat::Json, &text(frame)).is_none(), "{}", frame);
+        }
+        assert!(ClientHello::parse(WireFormat::Json, &Message::Binary(vec![1, 2, 3].into())).is_none());
+    }
+
+    #[test]
+    fn json_connections_may_still_send_a_bare_token() {
+        let hello = ClientHello::parse(WireFormat::Json, &text("t0k")).unwrap();
+        assert!(matches!(&hello, ClientHello::Legacy { token } if token == "t0k"));
+        assert_eq!(hello.client_version(), None);
+    }
+
+    #[test]
+    fn json_replies_are_auth_envelopes() {
+        let hello = versioned("t0k", "1.2.0", "c1");
+        let accepted = json_frame(&hello.accepted(WireFormat::Json, "alice", "s1"));
+        assert_eq!(accepted["correlationId"], "c1");
+        let started = &accepted["sessionStartedEvent"];
+        assert_eq!(started["userId"], "alice");
+        assert_eq!(started["sessionId"], "s1");
+        assert_eq!(started["protocolVersion"], PROTOCOL_VERSION);
+        assert_eq!(started["capabilities"], json!(CAPABILITIES));
+
+        let upgrade = UpgradeRequired {
+            client_version: "0.9.0".to_string(),
+            message: "too old".to_string(),
+            ..UpgradeRequired::default()
+        };
+        let [reply, close] = hello.rejected(WireFormat::Json, upgrade).try_into().unwrap();
+        let reply = json_frame(&reply);
+        assert_eq!(reply["correlationId"], "c1");
+        assert_eq!(reply["upgradeRequired"]["clientVersion"], "0.9.0");
+        assert_eq!(reply["upgradeRequired"]["message"], "too old");
+        assert!(matches!(close, Message::Close(Some(frame)) if frame.code == CLOSE_UPGRADE_REQUIRED));
+    }
+
+    fn policy(min: Option<&str>, max: Option<&str>, allow_unversioned: bool) -> ProtocolPolicy {
+        ProtocolPolicy::from_config(&ProtocolConfig {
+            min_client_version: min.map(str::to_string),
+            max_client_version: max.map(str::to_string),
+            allow_unversioned,
+        })
+        .unwrap()
+    }
+
+    fn auth_frame(msg: envelope::Msg) -> Message {
+        let envelope = Envelope {
+            correlation_id: "c1".to_string(),
+            msg: Some(msg),
+        };
+        Message::Binary(envelope.encode_to_vec().into())
+    }
+
+    #[test]
+    fn versions_inside_the_range_are_accepted() {
+        let bounded = policy(Some("1.2.0"), Some("2.0.0"), false);
+        for version in ["1.2.0", "1.10.3", "2.0.0", " 1.4.0 "] {
+            assert!(bounded.check(Some(version)).is_ok(), "{}", version);
+        }
+        let open = policy(None, None, false);
+        for version in ["0.0.1", "99.0.0"] {
+            assert!(open.check(Some(version)).is_ok(), "{}", version);
+        }
+    }
+
+    #[test]
+    fn versions_outside_the_range_must_upgrade() {
+        let bounded = policy(Some("1.2.0"), Some("2.0.0"), false);
+        let cases = [
+            ("1.1.9", "too old"),
+            ("1.2.0-beta.1", "too old"),
+            ("2.0.1", "newer than this server supports"),
+            ("3.0.0", "newer than this server supports"),
+            ("1.2", "not a semantic version"),
+            ("latest", "not a semantic version"),
+            ("", "not a semantic version"),
+        ];
+        for (version, reason) in cases {
+            let upgrade = bounded.check(Some(version)).unwrap_err();
+            assert!(upgrade.message.contains(reason), "{}: {}", version, upgrade.message);
+            assert_eq!(upgrade.client_version, version);
+            assert_eq!(upgrade.min_client_version, "1.2.0");
+            assert_eq!(upgrade.max_client_version, "2.0.0");
+            assert_eq!(upgrade.protocol_version, PROTOCOL_VERSION);
+        }
+        let open_ended = policy(Some("1.2.0"), None, true).check(Some("1.0.0")).unwrap_err();
+        assert_eq!(open_ended.max_client_version, "");
+    }
+
+    #[test]
+    fn unversioned_clients_connect_only_when_allowed() {
+        assert!(policy(Some("1.2.0"), None, true).check(None).is_ok());
+        assert!(ProtocolPolicy::default().check(None).is_ok());
+        let upgrade = policy(Some("1.2.0"), None, false).check(None).unwrap_err();
+        assert!(upgrade.message.contains("requires a client version"), "{}", upgrade.message);
+        assert_eq!(upgrade.client_version, "");
+    }
+
+    #[test]
+    fn bounds_must_be_semantic_versions() {
+        for (min, max, field) in [(Some("1.2"), None, "min_client_version"), (None, Some("two"), "max_client_version")] {
+            let config = ProtocolConfig {
+                min_client_version: min.map(str::to_string),
+                max_client_version: max.map(str::to_string),
+                allow_unversioned: true,
+            };
+            let error = ProtocolPolicy::from_config(&config).unwrap_err();
+            assert!(error.to_string().contains(field), "{}", error);
+        }
+    }
+
+    #[test]
+    fn protobuf_connections_open_with_an_auth_envelope() {
+        let frame = auth_frame(envelope::Msg::StartUserSessionCommand(StartUserSessionCommand {
+            client_version: "1.2.0".to_string(),
+            token: "t0k".to_string(),
+        }));
+        let hello = ClientHello::parse(WireFormat::Protobuf, &frame).unwrap();
+        assert!(matches!(
+            &hello,
+            ClientHello::Versioned { token, client_version, correlation_id }
+                if token == "t0k" && client_version == "1.2.0" && correlation_id == "c1"
+        ));
+        assert_eq!(hello.token(), "t0k");
+        assert_eq!(hello.client_version(), Some("1.2.0"));
+
+        let Message::Binary(reply) = hello.accepted(WireFormat::Protobuf, "alice", "s1") else {
+            panic!("protobuf connections get a binary reply");
+        };
+        let reply = Envelope::decode(&reply[..]).unwrap();
+        assert_eq!(reply.correlation_id, "c1");
+        let Some(envelope::Msg::SessionStartedEvent(started)) = reply.msg else {
+            panic!("expected SessionStartedEvent");
+        };
+        assert_eq!(started.protocol_version, PROTOCOL_VERSION);
+        assert_eq!(started.user_id, "alice");
+    }
+
+    #[test]
+    fn binary_frames_that_do_not_start_a_session_are_refused() {
+        let frames = [
+            auth_frame(envelope::Msg::SessionStartedEvent(SessionStartedEvent::default())),
+            Message::Binary(Envelope::default().encode_to_vec().into()),
+            Message::Binary(vec![0xff, 0xff, 0xff].into()),
+            Message::Ping(Vec::new().into()),
+            Message::Close(None),
+        ];
+        for frame in frames {
+            assert!(ClientHello::parse(WireFormat::Protobuf, &frame).is_none(), "{:?}", frame);
+        }
+    }
+
+    #[test]
+    fn legacy_clients_send_a_bare_token_and_get_text_replies() {
+        let hello = ClientHello::parse(WireFormat::Protobuf, &text("t0k")).unwrap();
+        assert!(matches!(&hello, ClientHello::Legacy { token } if token == "t0k"));
+        assert_eq!(hello.token(), "t0k");
+        assert_eq!(hello.client_version(), None);
+        // On a protobuf connection even a brace-led text frame is a token, not an envelope.
+        assert!(matches!(ClientHello::parse(WireFormat::Protobuf, &text("{x}")), Some(ClientHello::Legacy { .. })));
+
+        assert!(matches!(hello.accepted(WireFormat::Protobuf, "alice", "s1"), Message::Text(t) if t == "AUTH_SUCCESS"));
+        let upgrade = policy(None, None, false).check(None).unwrap_err();
+        let [reply, close] = hello.rejected(WireFormat::Protobuf, upgrade).try_into().unwrap();
+        assert!(matches!(reply, Message::Text(t) if t.starts_with("UPGRADE_REQUIRED: ")));
+        assert!(matches!(close, Message::Close(Some(frame)) if frame.code == CLOSE_UPGRADE_REQUIRED));
+    }
+}
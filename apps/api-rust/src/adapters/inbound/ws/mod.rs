@@ -1,63 +1,139 @@
 pub mod auth;
+pub mod codec;
+pub mod flood;
+pub mod handshake;
 
+use crate::adapters::inbound::envelope::{
+    game_state, handle_client_message, kicks, to_achievement_message, to_event_envelope, to_matchmaking_message,
+};
 use crate::adapters::inbound::ws::auth::{AuthError, Authenticator};
+use crate::adapters::inbound::ws::codec::{WireFormat, JSON_SUBPROTOCOL};
+use crate::adapters::inbound::ws::flood::{FloodError, PendingConnection};
+use crate::adapters::inbound::ws::handshake::ClientHello;
 use crate::AppState;
-use crate::domain::game::{Session, GameCommand};
+use crate::domain::game::{Role, Session};
 use crate::pb::runecraftstudios::pastello::web::game::v1::{
-    ClientEnvelope, client_envelope, 
-    GameCommandEnvelope, game_command_envelope
+    ServerEnvelope, server_envelope,
 };
 use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
-use axum::extract::{State};
-use axum::response::IntoResponse;
+use axum::extract::{Query, State};
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
 use futures_util::{StreamExt, SinkExt};
-use prost::Message as ProstMessage;
 use std::sync::Arc;
+use tokio::sync::broadcast::error::RecvError;
 use tracing::{error, info, warn};
 
 pub fn router() -> axum::Router<AppState> {
     axum::Router::new().route("/ws/game", axum::routing::get(ws_handler))
 }
 
+/// Routes for operators only, served on the internal port.
+pub fn internal_router() -> axum::Router<AppState> {
+    axum::Router::new().route("/metrics", axum::routing::get(ws_metrics))
+}
+
+/// Flood protection counters, in the Prometheus text format.
+async fn ws_metrics(State(state): State<AppState>) -> impl IntoResponse {
+    ([(header::CONTENT_TYPE, "text/plain; version=0.0.4")], state.flood.render_metrics())
+}
+
+/// Optional query parameters. Without `session_id` the connection plays the session its
+/// token resolves to; with it, the connection attaches to that stored session in `role`.
+#[derive(Debug, Default, Deserialize)]
+struct AttachParams {
+    session_id: Option<String>,
+    role: Option<String>,
+}
+
 async fn ws_handler(
     ws: WebSocketUpgrade,
     State(state): State<AppState>, 
     State(authenticator): State<Arc<dyn Authenticator>>, 
-) -> impl IntoResponse {
+    Query(params): Query<AttachParams>,
+) -> Response {
+    let role = match params.role.as_deref().map(Role::parse) {
+        Some(Some(role)) => role,
+        Some(None) => return (StatusCode::BAD_REQUEST, "role must be player or spectator").into_response(),
+        None => Role::Player,
+    };
+    let attach = params.session_id.map(|session_id| (session_id, role));
+    let pending = match state.flood.admit() {
+        Ok(pending) => pending,
+        Err(e) => {
+            warn!("Refused upgrade: {}", e);
+            return (StatusCode::SERVICE_UNAVAILABLE, e.to_string()).into_response();
+        }
+    };
+
     info!("Upgrading WebSocket connection");
-    ws.on_upgrade(move |socket| handle_socket(socket, state, authenticator))
+    // Clients opt into JSON frames via Sec-WebSocket-Protocol; everyone else speaks protobuf.
+    // Larger frames are refused by the transport before they are buffered, the hello included.
+    let max_frame_bytes = state.flood.max_frame_bytes();
+    let ws = ws
+        .protocols([JSON_SUBPROTOCOL])
+        .max_frame_size(max_frame_bytes)
+        .max_message_size(max_frame_bytes);
+    let format = WireFormat::from_subprotocol(ws.selected_protocol().and_then(|p| p.to_str().ok()));
+    ws.on_upgrade(move |socket| handle_socket(socket, state, authenticator, format, attach, pending))
 }
 
-async fn handle_socket(stream: WebSocket, state: AppState, authenticator: Arc<dyn Authenticator>) {
-    info!("New WebSocket connection");
+async fn handle_socket(
+    stream: WebSocket,
+    state: AppState,
+    authenticator: Arc<dyn Authenticator>,
+    format: WireFormat,
+    attach: Option<(String, Role)>,
+    pending: PendingConnection,
+) {
+    info!("New WebSocket connection ({:?})", format);
     let (mut tx, mut rx) = stream.split();
 
     // 1. Authentication Handshake
-    let auth_result =
-        tokio::time::timeout(std::time::Duration::from_secs(5), async {
-            if let Some(Ok(Message::Text(token))) = rx.next().await {
-                authenticator.authenticate(&token).await
-            } else {
-                Err(AuthError::NoToken)
+    // The first frame is either a versioned StartUserSessionCommand or, from older clients, the bare token.
+    let hello = match tokio::time::timeout(std::time::Duration::from_secs(5), rx.next()).await {
+        Ok(Some(frame)) => {
+            if let Err(e) = state.flood.check_frame(&frame) {
+                warn!("Closing unauthenticated connection: {}", e);
+                close_with(&mut tx, format, &e).await;
+                return;
+            }
+            match frame {
+                Ok(frame) => ClientHello::parse(format, &frame).ok_or(AuthError::NoToken),
+                Err(_) => Err(AuthError::NoToken),
             }
-        })
-        .await;
-
-    let session: Option<Session> = match auth_result {
-        Ok(Ok(session)) => {
-            info!("Auth successful for user: {}", session.host_id);
-            // Notify client of success
-            // Note: Real impl would send a ServerEnvelope::AuthStatus
-            tx.send(Message::Text("AUTH_SUCCESS".to_string().into())).await.ok();
-            Some(session)
         }
-        Ok(Err(e)) => {
+        Ok(_) => Err(AuthError::NoToken),
+        Err(_) => Err(AuthError::Timeout),
+    };
+
+    let hello = match hello {
+        Ok(hello) => hello,
+        Err(e) => {
             warn!("Auth failed: {:?}", e);
             tx.send(Message::Text("AUTH_FAILED".to_string().into())).await.ok();
-            None
+            return;
         }
-        Err(_) => {
-            let e = AuthError::Timeout;
+    };
+
+    if let Err(upgrade) = state.protocol.check(hello.client_version()) {
+        warn!("Rejected client version {:?}: {}", hello.client_version(), upgrade.message);
+        for frame in hello.rejected(format, upgrade) {
+            if tx.send(frame).await.is_err() {
+                break;
+            }
+        }
+        return;
+    }
+
+    let session: Option<Session> = match authenticator.authenticate(hello.token()).await {
+        Ok(session) => {
+            info!("Auth successful for user: {} (client {:?})", session.host_id, hello.client_version());
+            tx.send(hello.accepted(format, &session.host_id, &session.id)).await.ok();
+            Some(session)
+        }
+        Err(e) => {
             warn!("Auth failed: {:?}", e);
             tx.send(Message::Text("AUTH_FAILED".to_string().into())).await.ok();
             None
@@ -68,82 +144,171 @@ async fn handle_socket(stream: WebSocket, state: AppState, authenticator: Arc<dy
         return; // Close connection
     }
 
-    let mut session = session.unwrap();
+    let session = session.unwrap();
     let player_id = session.host_id.clone();
+    drop(pending);
 
-    // 2. CRITICAL: Persist the session so the Service can find it later
-    // In a real app, StartGame would create it, but for this test flow we ensure it exists.
-    if let Err(e) = state.game_service.force_save_session(session.clone()).await {
-        error!("Failed to initialize session in repo: {}", e);
-        return;
+    let mut limiter = match state.flood.connect(&player_id) {
+        Ok(limiter) => limiter,
+        Err(e) => {
+            warn!("Refused connection: {}", e);
+            close_with(&mut tx, format, &e).await;
+            return;
+        }
+    };
+
+    // Subscribe before the session is saved so the engine's opening events are not missed.
+    let mut events = state.game_service.subscribe_events();
+    let mut matchmaking = state.matchmaker.subscribe();
+    let mut unlocks = state.achievements.subscribe();
+
+    let (session, role) = match attach {
+        Some((session_id, role)) => match state.game_service.attach(&session_id, &player_id, role).await {
+            Ok(session) => (session, role),
+            Err(e) => {
+                warn!("{} could not attach to {}: {}", player_id, session_id, e);
+                send_envelope(&mut tx, format, server_envelope::Message::Error(e.to_string())).await.ok();
+                return;
+            }
+        },
+        None => {
+            // 2. CRITICAL: Persist the session so the Service can find it later
+            // In a real app, StartGame would create it, but for this test flow we ensure it exists.
+            if let Err(e) = state.game_service.force_save_session(session.clone()).await {
+                error!("Failed to initialize session in repo: {}", e);
+                return;
+            }
+            (session, Role::Player)
+        }
+    };
+
+    match game_state(&state, &session.id, &player_id, role).await {
+        Ok(snapshot) => {
+            if send_envelope(&mut tx, format, snapshot).await.is_err() {
+                return;
+            }
+        }
+        Err(e) => warn!("No snapshot for {}: {}", session.id, e),
     }
 
     // 3. Main Game Loop
     loop {
         tokio::select! {
             Some(msg) = rx.next() => {
+                if let Err(e) = state.flood.check_frame(&msg) {
+                    warn!("Closing connection of {}: {}", player_id, e);
+                    close_with(&mut tx, format, &e).await;
+                    break;
+                }
                 match msg {
-                    Ok(Message::Binary(bin)) => {
-                        // Decode Protobuf
-                        match ClientEnvelope::decode(&bin[..]) {
-                            Ok(envelope) => {
-                                handle_client_message(&state, &mut session, envelope).await;
-                            }
-                            Err(e) => error!("Failed to decode Protobuf: {}", e),
-                        }
-                    }
                     Ok(Message::Close(_)) => {
                         info!("Connection closed by {}", player_id);
                         break;
                     }
+                    Ok(frame) => {
+                        let result = match format.decode(&frame) {
+                            Some(Ok(envelope)) => match limiter.check_message(&envelope) {
+                                Ok(()) => handle_client_message(&state, &session.id, &player_id, role, envelope).await,
+                                Err(e) => Err(e.into()),
+                            },
+                            Some(Err(e)) => {
+                                error!("Failed to decode envelope: {}", e);
+                                if let Err(e) = limiter.decode_failed() {
+                                    warn!("Closing connection of {}: {}", player_id, e);
+                                    close_with(&mut tx, format, &e).await;
+                                    break;
+                                }
+                                Err(e)
+                            }
+                            None => Ok(None), // Ping/Pong
+                        };
+                        let reply = match result {
+                            Ok(reply) => reply,
+                            Err(e) => Some(server_envelope::Message::Error(e.to_string())),
+                        };
+                        if let Some(reply) = reply {
+                            if send_envelope(&mut tx, format, reply).await.is_err() {
+                                break;
+                            }
+                        }
+                    }
                     Err(e) => {
                         error!("Error receiving message from {}: {:?}", player_id, e);
                         break;
                     }
-                    _ => {} // Ignore Text/Ping/Pong for game logic
+                }
+            }
+            event = events.recv() => {
+                match event {
+                    Ok(event) if event.session_id() == &session.id && event.visible_to(&player_id, role) => {
+                        let reply = server_envelope::Message::GameEvent(to_event_envelope(&session.game_type, event.as_ref()));
+                        if send_envelope(&mut tx, format, reply).await.is_err() {
+                            break;
+                        }
+                        if kicks(event.as_ref(), &player_id) {
+                            info!("Closing connection of kicked player {}", player_id);
+                            break;
+                        }
+                    }
+                    Ok(_) => {} // Event for another session, or one this connection may not see
+                    Err(RecvError::Lagged(skipped)) => warn!("{} missed {} events", player_id, skipped),
+                    Err(RecvError::Closed) => break,
+                }
+            }
+            update = matchmaking.recv() => {
+                match update {
+                    Ok(update) if update.player_id() == &player_id => {
+                        if send_envelope(&mut tx, format, to_matchmaking_message(&update)).await.is_err() {
+                            break;
+                        }
+                    }
+                    Ok(_) => {} // Update for another player
+                    Err(RecvError::Lagged(skipped)) => warn!("{} missed {} matchmaking updates", player_id, skipped),
+                    Err(RecvError::Closed) => break,
+                }
+            }
+            unlock = unlocks.recv() => {
+                match unlock {
+                    Ok(unlock) if unlock.player_id == player_id => {
+                        if send_envelope(&mut tx, format, to_achievement_message(&unlock)).await.is_err() {
+                            break;
+                        }
+                    }
+                    Ok(_) => {} // Unlock for another player
+                    Err(RecvError::Lagged(skipped)) => warn!("{} missed {} achievement unlocks", player_id, skipped),
+                    Err(RecvError::Closed) => break,
                 }
             }
             else => break,
         }
     }
 
+    // A player with no connection left can't be told about a match, so take them out of the queue.
+    if limiter.close() {
+        state.matchmaker.leave(&player_id);
+    }
+    state.game_service.detach(&session.id, &player_id, role).await;
+
     info!("WebSocket connection handler finished for {}", player_id);
 }
 
-async fn handle_client_message(state: &AppState, session: &mut Session, env: ClientEnvelope) {
-    match env.message {
-        Some(client_envelope::Message::StartGame(cmd)) => {
-            info!("Received StartGame command for type: {:?}", cmd.game_type);
-            // Logic to switch session game type could go here
-        },
-        Some(client_envelope::Message::GameCommand(wrapper)) => {
-            dispatch_game_command(state, session, wrapper).await;
-        },
-        None => warn!("Received empty envelope"),
+/// Tells the client why it is being limited and closes the connection if the error calls for it.
+async fn close_with<S>(tx: &mut S, format: WireFormat, error: &FloodError)
+where
+    S: SinkExt<Message, Error = axum::Error> + Unpin,
+{
+    if send_envelope(tx, format, server_envelope::Message::Error(error.to_string())).await.is_err() {
+        return;
+    }
+    if let Some(frame) = error.close_frame() {
+        tx.send(Message::Close(Some(frame))).await.ok();
     }
 }
 
-async fn dispatch_game_command(state: &AppState, session: &Session, wrapper: GameCommandEnvelope) {
-    // Extract the inner command and convert to Box<dyn GameCommand>
-    // Ideally this mapping logic lives in a mapper, but for the fix we put it here 
-    // to bridge the gap immediately.
-    
-    let command: Option<Box<dyn GameCommand>> = match wrapper.command {
-        Some(game_command_envelope::Command::PuzzleMove(cmd)) => Some(Box::new(cmd)),
-        Some(game_command_envelope::Command::PuzzleUndo(cmd)) => Some(Box::new(cmd)),
-        Some(game_command_envelope::Command::TriviaSubmit(cmd)) => Some(Box::new(cmd)),
-        Some(game_command_envelope::Command::TriviaHint(cmd)) => Some(Box::new(cmd)),
-        None => None,
-    };
-
-    if let Some(cmd) = command {
-        // Use the session ID from the active socket session, 
-        // effectively ignoring the one in the message if it differs (security)
-        match state.game_service.handle_domain_command(&session.id, cmd).await {
-            Ok(_) => info!("Command handled successfully"),
-            Err(e) => error!("Failed to handle command: {:?}", e),
-        }
-    } else {
-        warn!("Unknown or empty game command received");
-    }
-}
\ No newline at end of file
+async fn send_envelope<S>(tx: &mut S, format: WireFormat, message: server_envelope::Message) -> Result<(), axum::Error>
+where
+    S: SinkExt<Message, Error = axum::Error> + Unpin,
+{
+    let envelope = ServerEnvelope { message: Some(message) };
+    tx.send(format.encode(&envelope)).await
+}
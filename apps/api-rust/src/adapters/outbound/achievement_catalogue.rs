@@ -0,0 +1,57 @@
+use crate::domain::achievement::AchievementDefinition;
+use crate::ports::AchievementCatalogue;
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::path::Path;
+
+#[derive(Debug, Deserialize)]
+struct AchievementFile {
+    achievements: Vec<AchievementDefinition>,
+}
+
+/// Achievement catalogue loaded once at startup. Like rulesets, every entry is validated on
+/// load so a bad definition stops the server instead of silently never unlocking.
+#[derive(Debug, Default)]
+pub struct YamlAchievementCatalogue {
+    achievements: Vec<AchievementDefinition>,
+}
+
+impl YamlAchievementCatalogue {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents =
+            std::fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+        Self::from_yaml(&contents).with_context(|| format!("invalid achievement catalogue {}", path.display()))
+    }
+
+    pub fn from_yaml(contents: &str) -> Result<Self> {
+        let file: AchievementFile = serde_yaml::from_str(contents)?;
+        let mut seen = HashSet::new();
+        for achievement in &file.achievements {
+            achievement
+                .validate()
+                .with_context(|| format!("achievement '{}'", achievement.id))?;
+            if !seen.insert(achievement.id.as_str()) {
+                bail!("achievement {} is defined twice", achievement.id);
+            }
+        }
+        Ok(Self { achievements: file.achievements })
+    }
+
+    pub fn len(&self) -> usize {
+        self.achievements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.achievements.is_empty()
+    }
+}
+
+#[async_trait]
+impl AchievementCatalogue for YamlAchievementCatalogue {
+    async fn list(&self) -> Result<Vec<AchievementDefinition>> {
+        Ok(self.achievements.clone())
+    }
+}
@@ -0,0 +1,86 @@
+use crate::domain::anti_cheat::{AnswerSheet, CheatFlag};
+use crate::domain::question::QuestionID;
+use crate::ports::CheatFlagRepository;
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use std::sync::RwLock;
+
+/// In-process cheat flags, kept since startup, and the trivia answer sheets of the last window.
+#[derive(Debug, Default)]
+pub struct MemoryCheatFlagRepo {
+    flags: RwLock<Vec<CheatFlag>>,
+    sheets: DashMap<Vec<QuestionID>, Vec<AnswerSheet>>,
+}
+
+impl MemoryCheatFlagRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CheatFlagRepository for MemoryCheatFlagRepo {
+    async fn record(&self, flags: Vec<CheatFlag>) -> Result<()> {
+        self.flags
+            .write()
+            .map_err(|_| anyhow::anyhow!("cheat flag store poisoned"))?
+            .extend(flags);
+        Ok(())
+    }
+
+    async fn list(&self, player_id: Option<&str>, session_id: Option<&str>) -> Result<Vec<CheatFlag>> {
+        let flags = self.flags.read().map_err(|_| anyhow::anyhow!("cheat flag store poisoned"))?;
+        Ok(flags
+            .iter()
+            .filter(|flag| player_id.is_none_or(|id| flag.player_id == id))
+            .filter(|flag| session_id.is_none_or(|id| flag.session_id == id))
+            .cloned()
+            .collect())
+    }
+
+    async fn add_sheet(&self, sheet: AnswerSheet, since: DateTime<Utc>) -> Result<Vec<AnswerSheet>> {
+        self.sheets.retain(|_, sheets| {
+            sheets.retain(|kept| kept.finished_at >= since);
+            !sheets.is_empty()
+        });
+        let mut sheets = self.sheets.entry(sheet.questions.clone()).or_default();
+        let earlier = sheets.clone();
+        sheets.push(sheet);
+        Ok(earlier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use std::collections::BTreeMap;
+
+    fn sheet(player_id: &str, questions: &[&str], minute: u32) -> AnswerSheet {
+        AnswerSheet {
+            session_id: format!("session-{}", player_id),
+            player_id: player_id.to_string(),
+            questions: questions.iter().map(|q| q.to_string()).collect(),
+            answers: BTreeMap::new(),
+            finished_at: Utc.with_ymd_and_hms(2024, 1, 1, 12, minute, 0).unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn sheets_are_returned_for_the_same_questions_within_the_window() {
+        let repo = MemoryCheatFlagRepo::new();
+        let since = |minute| Utc.with_ymd_and_hms(2024, 1, 1, 12, minute, 0).unwrap();
+
+        assert!(repo.add_sheet(sheet("a", &["q1", "q2"], 0), since(0)).await.unwrap().is_empty());
+        repo.add_sheet(sheet("b", &["q3"], 1), since(0)).await.unwrap();
+        let earlier = repo.add_sheet(sheet("c", &["q1", "q2"], 2), since(0)).await.unwrap();
+        assert_eq!(earlier.iter().map(|s| s.player_id.as_str()).collect::<Vec<_>>(), ["a"]);
+
+        // Moving the window past the first two sheets drops them, other questions included.
+        let earlier = repo.add_sheet(sheet("d", &["q1", "q2"], 3), since(2)).await.unwrap();
+        assert_eq!(earlier.iter().map(|s| s.player_id.as_str()).collect::<Vec<_>>(), ["c"]);
+        assert!(!repo.sheets.contains_key(&vec!["q3".to_string()]));
+    }
+}
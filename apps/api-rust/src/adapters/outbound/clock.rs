@@ -1,6 +1,7 @@
 use crate::ports::Clock;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use std::sync::Mutex;
 
 #[derive(Debug, Clone, Copy)]
 pub struct SystemClock;
@@ -23,4 +24,26 @@ impl Clock for SystemClock {
     fn now_utc(&self) -> DateTime<Utc> {
         Utc::now()
     }
-}
\ No newline at end of file
+}
+/// Clock that stands still until it is set. Each engine runs on one, set once per start, tick
+/// and command, so every reading within a step agrees and a replay can reproduce it exactly.
+#[derive(Debug)]
+pub struct StepClock {
+    now: Mutex<DateTime<Utc>>,
+}
+
+impl StepClock {
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self { now: Mutex::new(now) }
+    }
+
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner()) = now;
+    }
+}
+
+impl Clock for StepClock {
+    fn now_utc(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
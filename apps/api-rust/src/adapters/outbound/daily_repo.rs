@@ -0,0 +1,137 @@
+use crate::adapters::outbound::postgres::{game_type_key, parse_game_type};
+use crate::domain::daily::DailyAttempt;
+use crate::domain::game::{GameType, PlayerID};
+use crate::domain::question::QuestionID;
+use crate::ports::DailyChallengeRepository;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use dashmap::mapref::entry::Entry;
+use dashmap::DashMap;
+use deadpool_postgres::{Object, Pool};
+
+/// In-process daily challenge attempts, kept for every day since startup. Used when no database
+/// is configured; a restart lets everyone play the day again.
+#[derive(Debug, Default)]
+pub struct MemoryDailyChallengeRepo {
+    attempts: DashMap<(PlayerID, NaiveDate, GameType), DailyAttempt>,
+    questions: DashMap<NaiveDate, Vec<QuestionID>>,
+}
+
+impl MemoryDailyChallengeRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl DailyChallengeRepository for MemoryDailyChallengeRepo {
+    async fn get_attempt(&self, player_id: &str, date: NaiveDate, game_type: &GameType) -> Result<Option<DailyAttempt>> {
+        let key = (player_id.to_string(), date, game_type.clone());
+        Ok(self.attempts.get(&key).map(|a| a.clone()))
+    }
+
+    async fn record_attempt(&self, attempt: DailyAttempt) -> Result<bool> {
+        let key = (attempt.player_id.clone(), attempt.date, attempt.game_type.clone());
+        match self.attempts.entry(key) {
+            Entry::Occupied(_) => Ok(false),
+            Entry::Vacant(entry) => {
+                entry.insert(attempt);
+                Ok(true)
+            }
+        }
+    }
+
+    async fn get_questions(&self, date: NaiveDate) -> Result<Option<Vec<QuestionID>>> {
+        Ok(self.questions.get(&date).map(|ids| ids.clone()))
+    }
+
+    async fn record_questions(&self, date: NaiveDate, question_ids: Vec<QuestionID>) -> Result<Vec<QuestionID>> {
+        Ok(self.questions.entry(date).or_insert(question_ids).clone())
+    }
+}
+
+/// Daily attempts and each day's trivia questions in the `game` schema. See
+/// `database_setup/setup_schemas.sql` for the tables.
+pub struct PostgresDailyChallengeRepo {
+    pool: Pool,
+}
+
+impl PostgresDailyChallengeRepo {
+    /// Uses `pool`, from `postgres::connect`.
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn client(&self) -> Result<Object> {
+        self.pool.get().await.context("checking out a daily challenge database connection")
+    }
+}
+
+#[async_trait]
+impl DailyChallengeRepository for PostgresDailyChallengeRepo {
+    async fn get_attempt(&self, player_id: &str, date: NaiveDate, game_type: &GameType) -> Result<Option<DailyAttempt>> {
+        let row = self
+            .client()
+            .await?
+            .query_opt(
+                "SELECT game_type, session_id, started_at FROM game.daily_attempts \
+                 WHERE player_id = $1 AND date = $2 AND game_type = $3",
+                &[&player_id, &date, &game_type_key(game_type)],
+            )
+            .await?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let key: String = row.get("game_type");
+        Ok(Some(DailyAttempt {
+            player_id: player_id.to_string(),
+            date,
+            game_type: parse_game_type(&key).with_context(|| format!("unknown game type {}", key))?,
+            session_id: row.get("session_id"),
+            started_at: row.get("started_at"),
+        }))
+    }
+
+    async fn record_attempt(&self, attempt: DailyAttempt) -> Result<bool> {
+        let inserted = self
+            .client()
+            .await?
+            .execute(
+                "INSERT INTO game.daily_attempts (player_id, date, game_type, session_id, started_at) \
+                 VALUES ($1, $2, $3, $4, $5) ON CONFLICT DO NOTHING",
+                &[
+                    &attempt.player_id,
+                    &attempt.date,
+                    &game_type_key(&attempt.game_type),
+                    &attempt.session_id,
+                    &attempt.started_at,
+                ],
+            )
+            .await?;
+        Ok(inserted == 1)
+    }
+
+    async fn get_questions(&self, date: NaiveDate) -> Result<Option<Vec<QuestionID>>> {
+        let row = self
+            .client()
+            .await?
+            .query_opt("SELECT question_ids FROM game.daily_questions WHERE date = $1", &[&date])
+            .await?;
+        Ok(row.map(|row| row.get("question_ids")))
+    }
+
+    async fn record_questions(&self, date: NaiveDate, question_ids: Vec<QuestionID>) -> Result<Vec<QuestionID>> {
+        let client = self.client().await?;
+        client
+            .execute(
+                "INSERT INTO game.daily_questions (date, question_ids) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+                &[&date, &question_ids],
+            )
+            .await?;
+        let row = client
+            .query_one("SELECT question_ids FROM game.daily_questions WHERE date = $1", &[&date])
+            .await?;
+        Ok(row.get("question_ids"))
+    }
+}
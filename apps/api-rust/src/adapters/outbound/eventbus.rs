@@ -1,6 +1,7 @@
+use crate::domain::game::DomainEvent;
 use crate::ports::EventBus;
 use anyhow::Result;
-use std::any::Any;
+use tokio::sync::broadcast;
 // Removed unused imports (async_trait, tokio_stream)
 
 #[derive(Debug, Clone)]
@@ -20,7 +21,46 @@ impl Default for NopEventBus {
 
 impl EventBus for NopEventBus {
     // FIX: Corrected method signature to match trait (E0050)
-    fn publish(&self, _event: Box<dyn Any + Send>) -> Result<()> {
+    fn publish(&self, _event: Box<dyn DomainEvent>) -> Result<()> {
         Ok(())
     }
-}
\ No newline at end of file
+
+    fn subscribe(&self) -> broadcast::Receiver<Box<dyn DomainEvent>> {
+        // Nothing is ever published, so hand out a receiver whose sender is already gone.
+        broadcast::channel(1).1
+    }
+}
+
+// Slow subscribers that fall this far behind skip ahead and miss the oldest events.
+const BROADCAST_CAPACITY: usize = 1024;
+
+/// In-process fan-out of domain events to every subscriber (e.g. WebSocket connections).
+#[derive(Debug, Clone)]
+pub struct BroadcastEventBus {
+    sender: broadcast::Sender<Box<dyn DomainEvent>>,
+}
+
+impl BroadcastEventBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(BROADCAST_CAPACITY);
+        Self { sender }
+    }
+}
+
+impl Default for BroadcastEventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventBus for BroadcastEventBus {
+    fn publish(&self, event: Box<dyn DomainEvent>) -> Result<()> {
+        // A send error only means nobody is listening right now, which is fine.
+        let _ = self.sender.send(event);
+        Ok(())
+    }
+
+    fn subscribe(&self) -> broadcast::Receiver<Box<dyn DomainEvent>> {
+        self.sender.subscribe()
+    }
+}
@@ -1,5 +1,7 @@
 // FIX: Corrected trait import name from IDGen to IdGenerator (E0432)
+use crate::domain::room::{JOIN_CODE_ALPHABET, JOIN_CODE_LENGTH};
 use crate::ports::IdGenerator; 
+use rand::Rng;
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Copy)]
@@ -21,4 +23,11 @@ impl IdGenerator for UuidGenerator {
     fn new_id(&self) -> String {
         Uuid::new_v4().to_string()
     }
+
+    fn new_join_code(&self) -> String {
+        let mut rng = rand::rng();
+        (0..JOIN_CODE_LENGTH)
+            .map(|_| JOIN_CODE_ALPHABET[rng.random_range(0..JOIN_CODE_ALPHABET.len())] as char)
+            .collect()
+    }
 }
\ No newline at end of file
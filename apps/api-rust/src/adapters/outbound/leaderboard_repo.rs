@@ -0,0 +1,49 @@
+use crate::domain::leaderboard::{BoardId, Leaderboard, LeaderboardEntry, LeaderboardPeriod, RankOrder, RankedEntry};
+use crate::ports::LeaderboardRepository;
+use anyhow::Result;
+use async_trait::async_trait;
+use dashmap::DashMap;
+
+/// In-process leaderboards. Only the current weekly and daily windows are kept: opening a
+/// new window drops the previous one.
+#[derive(Debug, Default)]
+pub struct MemoryLeaderboardRepo {
+    boards: DashMap<BoardId, Leaderboard>,
+}
+
+impl MemoryLeaderboardRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl LeaderboardRepository for MemoryLeaderboardRepo {
+    async fn submit(&self, board: &BoardId, entry: LeaderboardEntry) -> Result<bool> {
+        if board.period != LeaderboardPeriod::AllTime && !self.boards.contains_key(board) {
+            self.boards.retain(|id, _| {
+                id.window == board.window
+                    || id.period != board.period
+                    || id.game_type != board.game_type
+                    || id.ruleset_id != board.ruleset_id
+            });
+        }
+        let mut leaderboard = self
+            .boards
+            .entry(board.clone())
+            .or_insert_with(|| Leaderboard::new(RankOrder::for_game(&board.game_type)));
+        Ok(leaderboard.submit(entry))
+    }
+
+    async fn top(&self, board: &BoardId, limit: usize) -> Result<Vec<RankedEntry>> {
+        Ok(self.boards.get(board).map(|b| b.top(limit)).unwrap_or_default())
+    }
+
+    async fn rank_of(&self, board: &BoardId, player_id: &str) -> Result<Option<RankedEntry>> {
+        Ok(self.boards.get(board).and_then(|b| b.rank_of(&player_id.to_string())))
+    }
+
+    async fn count(&self, board: &BoardId) -> Result<usize> {
+        Ok(self.boards.get(board).map(|b| b.len()).unwrap_or_default())
+    }
+}
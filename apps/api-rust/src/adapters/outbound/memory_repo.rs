@@ -5,15 +5,17 @@ use anyhow::Result;
 use async_trait::async_trait;
 use dashmap::DashMap;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct MemoryRepo {
     sessions: DashMap<GameSessionID, Session>,
+    join_codes: DashMap<String, GameSessionID>,
 }
 
 impl MemoryRepo {
     pub fn new() -> Self {
         Self {
             sessions: DashMap::new(),
+            join_codes: DashMap::new(),
         }
     }
 }
@@ -27,7 +29,26 @@ impl GameRepository for MemoryRepo {
 
     async fn save(&self, id: &str, session: Session) -> Result<()> {
         let id = id.to_string();
+        if let Some(room) = &session.room {
+            self.join_codes.insert(room.join_code.clone(), id.clone());
+        }
         self.sessions.insert(id, session);
         Ok(())
     }
+
+    async fn list_by_player(&self, player_id: &str) -> Result<Vec<Session>> {
+        Ok(self
+            .sessions
+            .iter()
+            .filter(|entry| entry.host_id == player_id || entry.players.iter().any(|p| p.id == player_id))
+            .map(|entry| entry.clone())
+            .collect())
+    }
+
+    async fn get_by_join_code(&self, code: &str) -> Result<Option<Session>> {
+        let Some(id) = self.join_codes.get(code).map(|entry| entry.clone()) else {
+            return Ok(None);
+        };
+        self.get(&id).await
+    }
 }
\ No newline at end of file
@@ -1,5 +1,14 @@
+pub mod achievement_catalogue;
+pub mod cheat_flag_repo;
 pub mod clock;
+pub mod daily_repo;
 pub mod eventbus;
 pub mod id_gen;
+pub mod leaderboard_repo;
 pub mod memory_repo;
-pub mod rng;
\ No newline at end of file
+pub mod postgres;
+pub mod profile_repo;
+pub mod question_repo;
+pub mod rng;
+pub mod ruleset_repo;
+pub mod session_log;
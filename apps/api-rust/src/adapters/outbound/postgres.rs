@@ -0,0 +1,45 @@
+use crate::domain::game::GameType;
+use anyhow::{Context, Result};
+use deadpool_postgres::{Manager, ManagerConfig, Pool, RecyclingMethod};
+use std::sync::Arc;
+use tokio_postgres_rustls::MakeRustlsConnect;
+
+/// Opens a pool of up to `pool_size` connections and checks one out, so an unreachable database
+/// is reported here rather than on the first request. Connections use TLS as the URL's `sslmode`
+/// asks (`prefer` when unset), verified against the Mozilla root store.
+pub async fn connect(url: &str, pool_size: usize) -> Result<Pool> {
+    let config: tokio_postgres::Config = url.parse().context("parsing the database URL")?;
+    let manager = Manager::from_config(
+        config,
+        tls_connector()?,
+        ManagerConfig {
+            recycling_method: RecyclingMethod::Fast,
+        },
+    );
+    let pool = Pool::builder(manager)
+        .max_size(pool_size)
+        .build()
+        .context("building the database pool")?;
+    drop(pool.get().await.context("connecting to the database")?);
+    Ok(pool)
+}
+
+fn tls_connector() -> Result<MakeRustlsConnect> {
+    let mut roots = rustls::RootCertStore::empty();
+    roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    let config = rustls::ClientConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+        .with_safe_default_protocol_versions()
+        .context("setting up TLS for the database")?
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    Ok(MakeRustlsConnect::new(config))
+}
+
+// Rows are keyed by the game type's serialized name ("Trivia", "Puzzle").
+pub fn game_type_key(game_type: &GameType) -> String {
+    format!("{:?}", game_type)
+}
+
+pub fn parse_game_type(key: &str) -> Option<GameType> {
+    serde_json::from_value(serde_json::Value::String(key.to_string())).ok()
+}
@@ -0,0 +1,252 @@
+use crate::domain::achievement::PlayerAchievements;
+use crate::adapters::outbound::postgres::{game_type_key, parse_game_type};
+use crate::domain::game::PlayerID;
+use crate::domain::profile::{GameStats, PlayerProfile, PlayerStats};
+use crate::domain::rating::{PlayerRatings, SkillRating};
+use crate::ports::ProfileRepository;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use dashmap::DashMap;
+use deadpool_postgres::{Object, Pool};
+
+/// Profiles kept for the lifetime of the process. Used when no database is configured.
+#[derive(Debug, Default)]
+pub struct MemoryProfileRepo {
+    profiles: DashMap<PlayerID, PlayerProfile>,
+    stats: DashMap<PlayerID, PlayerStats>,
+    ratings: DashMap<PlayerID, PlayerRatings>,
+    achievements: DashMap<PlayerID, PlayerAchievements>,
+}
+
+impl MemoryProfileRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ProfileRepository for MemoryProfileRepo {
+    async fn get_profile(&self, player_id: &str) -> Result<Option<PlayerProfile>> {
+        Ok(self.profiles.get(player_id).map(|entry| entry.clone()))
+    }
+
+    async fn save_profile(&self, profile: PlayerProfile) -> Result<()> {
+        self.profiles.insert(profile.player_id.clone(), profile);
+        Ok(())
+    }
+
+    async fn get_stats(&self, player_id: &str) -> Result<Option<PlayerStats>> {
+        Ok(self.stats.get(player_id).map(|entry| entry.clone()))
+    }
+
+    async fn save_stats(&self, stats: PlayerStats) -> Result<()> {
+        self.stats.insert(stats.player_id.clone(), stats);
+        Ok(())
+    }
+
+    async fn get_ratings(&self, player_id: &str) -> Result<Option<PlayerRatings>> {
+        Ok(self.ratings.get(player_id).map(|entry| entry.clone()))
+    }
+
+    async fn save_ratings(&self, ratings: PlayerRatings) -> Result<()> {
+        self.ratings.insert(ratings.player_id.clone(), ratings);
+        Ok(())
+    }
+
+    async fn get_achievements(&self, player_id: &str) -> Result<Option<PlayerAchievements>> {
+        Ok(self.achievements.get(player_id).map(|entry| entry.clone()))
+    }
+
+    async fn save_achievements(&self, achievements: PlayerAchievements) -> Result<()> {
+        self.achievements.insert(achievements.player_id.clone(), achievements);
+        Ok(())
+    }
+}
+
+/// Profiles, stats, ratings and achievements in the `auth` schema, next to the user accounts. See
+/// `database_setup/setup_schemas.sql` for the tables.
+pub struct PostgresProfileRepo {
+    pool: Pool,
+}
+
+impl PostgresProfileRepo {
+    /// Uses `pool`, from `postgres::connect`.
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+
+    async fn client(&self) -> Result<Object> {
+        self.pool.get().await.context("checking out a profile database connection")
+    }
+}
+
+#[async_trait]
+impl ProfileRepository for PostgresProfileRepo {
+    async fn get_profile(&self, player_id: &str) -> Result<Option<PlayerProfile>> {
+        let row = self
+            .client()
+            .await?
+            .query_opt(
+                "SELECT player_id, display_name, avatar_url, preferences, created_at, updated_at \
+                 FROM auth.player_profiles WHERE player_id = $1",
+                &[&player_id],
+            )
+            .await?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let preferences: serde_json::Value = row.get("preferences");
+        Ok(Some(PlayerProfile {
+            player_id: row.get("player_id"),
+            display_name: row.get("display_name"),
+            avatar_url: row.get("avatar_url"),
+            preferences: serde_json::from_value(preferences)
+                .with_context(|| format!("decoding preferences of {}", player_id))?,
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        }))
+    }
+
+    async fn save_profile(&self, profile: PlayerProfile) -> Result<()> {
+        let preferences = serde_json::to_value(&profile.preferences)?;
+        self.client()
+            .await?
+            .execute(
+                "INSERT INTO auth.player_profiles \
+                 (player_id, display_name, avatar_url, preferences, created_at, updated_at) \
+                 VALUES ($1, $2, $3, $4, $5, $6) \
+                 ON CONFLICT (player_id) DO UPDATE SET display_name = EXCLUDED.display_name, \
+                 avatar_url = EXCLUDED.avatar_url, preferences = EXCLUDED.preferences, \
+                 updated_at = EXCLUDED.updated_at",
+                &[
+                    &profile.player_id,
+                    &profile.display_name,
+                    &profile.avatar_url,
+                    &preferences,
+                    &profile.created_at,
+                    &profile.updated_at,
+                ],
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn get_stats(&self, player_id: &str) -> Result<Option<PlayerStats>> {
+        let rows = self
+            .client()
+            .await?
+            .query(
+                "SELECT game_type, stats FROM auth.player_stats WHERE player_id = $1",
+                &[&player_id],
+            )
+            .await?;
+        if rows.is_empty() {
+            return Ok(None);
+        }
+        let mut stats = PlayerStats::new(&player_id.to_string());
+        for row in rows {
+            let key: String = row.get("game_type");
+            let Some(game_type) = parse_game_type(&key) else {
+                continue;
+            };
+            let value: serde_json::Value = row.get("stats");
+            let game_stats: GameStats = serde_json::from_value(value)
+                .with_context(|| format!("decoding {} stats of {}", key, player_id))?;
+            stats.games.insert(game_type, game_stats);
+        }
+        Ok(Some(stats))
+    }
+
+    async fn save_stats(&self, stats: PlayerStats) -> Result<()> {
+        let client = self.client().await?;
+        for (game_type, game_stats) in &stats.games {
+            let value = serde_json::to_value(game_stats)?;
+            client
+                .execute(
+                    "INSERT INTO auth.player_stats (player_id, game_type, stats, updated_at) \
+                     VALUES ($1, $2, $3, now()) \
+                     ON CONFLICT (player_id, game_type) DO UPDATE SET stats = EXCLUDED.stats, \
+                     updated_at = EXCLUDED.updated_at",
+                    &[&stats.player_id, &game_type_key(game_type), &value],
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn get_ratings(&self, player_id: &str) -> Result<Option<PlayerRatings>> {
+        let rows = self
+            .client()
+            .await?
+            .query(
+                "SELECT game_type, rating FROM auth.player_ratings WHERE player_id = $1",
+                &[&player_id],
+            )
+            .await?;
+        if rows.is_empty() {
+            return Ok(None);
+        }
+        let mut ratings = PlayerRatings::new(&player_id.to_string());
+        for row in rows {
+            let key: String = row.get("game_type");
+            let Some(game_type) = parse_game_type(&key) else {
+                continue;
+            };
+            let value: serde_json::Value = row.get("rating");
+            let rating: SkillRating = serde_json::from_value(value)
+                .with_context(|| format!("decoding {} rating of {}", key, player_id))?;
+            ratings.games.insert(game_type, rating);
+        }
+        Ok(Some(ratings))
+    }
+
+    async fn save_ratings(&self, ratings: PlayerRatings) -> Result<()> {
+        let client = self.client().await?;
+        for (game_type, rating) in &ratings.games {
+            let value = serde_json::to_value(rating)?;
+            client
+                .execute(
+                    "INSERT INTO auth.player_ratings (player_id, game_type, rating, updated_at) \
+                     VALUES ($1, $2, $3, now()) \
+                     ON CONFLICT (player_id, game_type) DO UPDATE SET rating = EXCLUDED.rating, \
+                     updated_at = EXCLUDED.updated_at",
+                    &[&ratings.player_id, &game_type_key(game_type), &value],
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn get_achievements(&self, player_id: &str) -> Result<Option<PlayerAchievements>> {
+        let row = self
+            .client()
+            .await?
+            .query_opt(
+                "SELECT achievements FROM auth.player_achievements WHERE player_id = $1",
+                &[&player_id],
+            )
+            .await?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let value: serde_json::Value = row.get("achievements");
+        let achievements = serde_json::from_value(value)
+            .with_context(|| format!("decoding achievements of {}", player_id))?;
+        Ok(Some(achievements))
+    }
+
+    async fn save_achievements(&self, achievements: PlayerAchievements) -> Result<()> {
+        let value = serde_json::to_value(&achievements)?;
+        self.client()
+            .await?
+            .execute(
+                "INSERT INTO auth.player_achievements (player_id, achievements, updated_at) \
+                 VALUES ($1, $2, now()) \
+                 ON CONFLICT (player_id) DO UPDATE SET achievements = EXCLUDED.achievements, \
+                 updated_at = EXCLUDED.updated_at",
+                &[&achievements.player_id, &value],
+            )
+            .await?;
+        Ok(())
+    }
+}
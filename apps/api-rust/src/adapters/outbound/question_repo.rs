@@ -0,0 +1,191 @@
+use crate::domain::question::{Question, QuestionFormat, QuestionID};
+use crate::domain::rules::TriviaCategory;
+use crate::ports::QuestionRepository;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+#[derive(Debug)]
+pub struct MemoryQuestionRepo {
+    questions: RwLock<Vec<Question>>,
+}
+
+impl MemoryQuestionRepo {
+    pub fn new(questions: Vec<Question>) -> Self {
+        Self {
+            questions: RwLock::new(questions),
+        }
+    }
+
+    /// A handful of built-in questions so trivia is playable without any imported content.
+    pub fn with_samples() -> Self {
+        let sample = |id: &str, category, prompt: &str, format, hint: &str| Question {
+            id: id.to_string(),
+            category,
+            prompt: prompt.to_string(),
+            format,
+            hint: Some(hint.to_string()),
+        };
+        let options = |options: &[&str]| options.iter().map(|o| o.to_string()).collect();
+
+        Self::new(vec![
+            sample(
+                "gk-1",
+                TriviaCategory::GeneralKnowledge,
+                "How many days are in a leap year?",
+                QuestionFormat::Numeric { value: 366.0, tolerance: 0.0 },
+                "One more than usual",
+            ),
+            sample(
+                "gk-2",
+                TriviaCategory::GeneralKnowledge,
+                "What colour do you get by mixing blue and yellow?",
+                QuestionFormat::MultipleChoice { options: options(&["Green", "Purple", "Orange", "Brown"]), correct: 0 },
+                "Think of grass",
+            ),
+            sample(
+                "sci-1",
+                TriviaCategory::Science,
+                "What is the chemical symbol for gold?",
+                QuestionFormat::FreeText { answer: "Au".to_string(), aliases: Vec::new() },
+                "From the Latin aurum",
+            ),
+            sample(
+                "sci-2",
+                TriviaCategory::Science,
+                "Mars is known as the Red Planet.",
+                QuestionFormat::TrueFalse { answer: true },
+                "Named after a god of war",
+            ),
+            sample(
+                "hist-1",
+                TriviaCategory::History,
+                "In which year did the Berlin Wall fall?",
+                QuestionFormat::Numeric { value: 1989.0, tolerance: 0.0 },
+                "Late 1980s",
+            ),
+            sample(
+                "hist-2",
+                TriviaCategory::History,
+                "Who was the first emperor of Rome?",
+                QuestionFormat::FreeText { answer: "Augustus".to_string(), aliases: vec!["Octavian".to_string()] },
+                "Born Octavian",
+            ),
+        ])
+    }
+}
+
+impl Default for MemoryQuestionRepo {
+    fn default() -> Self {
+        Self::with_samples()
+    }
+}
+
+#[async_trait]
+impl QuestionRepository for MemoryQuestionRepo {
+    async fn list(&self, categories: &[TriviaCategory]) -> Result<Vec<Question>> {
+        let questions = self.questions.read().map_err(|_| anyhow::anyhow!("question store poisoned"))?;
+        Ok(filter_by_category(&questions, categories))
+    }
+
+    async fn get(&self, ids: &[QuestionID]) -> Result<Vec<Question>> {
+        let questions = self.questions.read().map_err(|_| anyhow::anyhow!("question store poisoned"))?;
+        Ok(find_by_id(&questions, ids))
+    }
+
+    async fn upsert(&self, questions: Vec<Question>) -> Result<()> {
+        let mut stored = self.questions.write().map_err(|_| anyhow::anyhow!("question store poisoned"))?;
+        merge(&mut stored, questions);
+        Ok(())
+    }
+}
+
+/// Question store persisted as a JSON array, written by `pastello-admin import-questions`
+/// and read by the server at startup.
+#[derive(Debug)]
+pub struct FileQuestionRepo {
+    path: PathBuf,
+    questions: RwLock<Vec<Question>>,
+}
+
+impl FileQuestionRepo {
+    /// Loads the store at `path`. A missing file is an empty store; it is created on first write.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let questions = match std::fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .with_context(|| format!("invalid question store {}", path.display()))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e).with_context(|| format!("failed to read {}", path.display())),
+        };
+        Ok(Self {
+            path,
+            questions: RwLock::new(questions),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.questions.read().map(|q| q.len()).unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Write to a sibling file and rename so a crash never leaves a half-written store.
+    fn persist(&self, questions: &[Question]) -> Result<()> {
+        if let Some(dir) = self.path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        let tmp = self.path.with_extension("json.tmp");
+        std::fs::write(&tmp, serde_json::to_vec_pretty(questions)?)?;
+        std::fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl QuestionRepository for FileQuestionRepo {
+    async fn list(&self, categories: &[TriviaCategory]) -> Result<Vec<Question>> {
+        let questions = self.questions.read().map_err(|_| anyhow::anyhow!("question store poisoned"))?;
+        Ok(filter_by_category(&questions, categories))
+    }
+
+    async fn get(&self, ids: &[QuestionID]) -> Result<Vec<Question>> {
+        let questions = self.questions.read().map_err(|_| anyhow::anyhow!("question store poisoned"))?;
+        Ok(find_by_id(&questions, ids))
+    }
+
+    async fn upsert(&self, questions: Vec<Question>) -> Result<()> {
+        let mut stored = self.questions.write().map_err(|_| anyhow::anyhow!("question store poisoned"))?;
+        let mut updated = stored.clone();
+        merge(&mut updated, questions);
+        self.persist(&updated)?;
+        *stored = updated;
+        Ok(())
+    }
+}
+
+fn filter_by_category(questions: &[Question], categories: &[TriviaCategory]) -> Vec<Question> {
+    questions
+        .iter()
+        .filter(|q| categories.is_empty() || categories.contains(&q.category))
+        .cloned()
+        .collect()
+}
+
+fn find_by_id(questions: &[Question], ids: &[QuestionID]) -> Vec<Question> {
+    ids.iter()
+        .filter_map(|id| questions.iter().find(|q| q.id == *id).cloned())
+        .collect()
+}
+
+fn merge(stored: &mut Vec<Question>, questions: Vec<Question>) {
+    for question in questions {
+        match stored.iter_mut().find(|q| q.id == question.id) {
+            Some(existing) => *existing = question,
+            None => stored.push(question),
+        }
+    }
+}
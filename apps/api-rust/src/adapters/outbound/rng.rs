@@ -1,6 +1,7 @@
 use crate::ports::Rng;
-use async_trait::async_trait;
-use rand::{rng, Rng as _};
+use rand::{rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::sync::Mutex;
 
 #[derive(Debug, Clone, Copy)]
 pub struct SystemRng;
@@ -17,19 +18,56 @@ impl Default for SystemRng {
     }
 }
 
-#[async_trait]
+// Thread-local generator: cheap enough to draw from inline, so no blocking task is needed.
 impl Rng for SystemRng {
-    async fn rand_int(&self, min: i32, max: i32) -> i32 {
-        tokio::task::spawn_blocking(move || {
-            // Use random_range to fix deprecation in newer rand versions,
-            // or fallback to gen_range on the thread_rng().
-            let mut rng = rng();
-            rng.random_range(min..max)
-        })
-        .await
-        .unwrap_or_else(|e| {
-            eprintln!("Blocking task for rand_int panicked: {:?}", e);
-            min
-        })
+    fn next_u64(&self) -> u64 {
+        rng().next_u64()
+    }
+}
+
+/// Deterministic generator for seeded sessions: ChaCha8 keyed by the session's seed, so a
+/// game replays exactly from the seed recorded on its `Session`. Numbered streams of one seed
+/// are independent of each other, letting separate uses draw without shifting one another.
+#[derive(Debug)]
+pub struct SeededRng {
+    rng: Mutex<ChaCha8Rng>,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        Self::with_stream(seed, 0)
+    }
+
+    /// Stream `stream` of `seed`. `engine_factory` numbers the streams an engine draws from.
+    pub fn with_stream(seed: u64, stream: u64) -> Self {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        rng.set_stream(stream);
+        Self { rng: Mutex::new(rng) }
+    }
+}
+
+impl Rng for SeededRng {
+    fn next_u64(&self) -> u64 {
+        self.rng.lock().unwrap_or_else(|e| e.into_inner()).next_u64()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draws(rng: &SeededRng) -> Vec<u64> {
+        (0..8).map(|_| rng.next_u64()).collect()
+    }
+
+    #[test]
+    fn a_seed_and_stream_always_draw_the_same_numbers() {
+        assert_eq!(draws(&SeededRng::new(7)), draws(&SeededRng::with_stream(7, 0)));
+        assert_ne!(draws(&SeededRng::new(7)), draws(&SeededRng::new(8)));
+    }
+
+    #[test]
+    fn streams_of_one_seed_draw_different_numbers() {
+        assert_ne!(draws(&SeededRng::with_stream(7, 0)), draws(&SeededRng::with_stream(7, 1)));
     }
 }
@@ -0,0 +1,152 @@
+use crate::domain::rules::Ruleset;
+use crate::ports::RulesetRepository;
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::path::Path;
+
+#[derive(Debug, Deserialize)]
+struct RulesetFile {
+    rulesets: Vec<Ruleset>,
+}
+
+/// Ruleset catalogue loaded once at startup. Every preset is validated on load, so a bad
+/// entry stops the server instead of surfacing when a player picks it.
+#[derive(Debug, Default)]
+pub struct YamlRulesetRepo {
+    rulesets: Vec<Ruleset>,
+}
+
+impl YamlRulesetRepo {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents =
+            std::fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+        Self::from_yaml(&contents).with_context(|| format!("invalid ruleset catalogue {}", path.display()))
+    }
+
+    pub fn from_yaml(contents: &str) -> Result<Self> {
+        let file: RulesetFile = serde_yaml::from_str(contents)?;
+        let mut seen = HashSet::new();
+        for ruleset in &file.rulesets {
+            if ruleset.id.is_empty() || ruleset.id.contains('@') {
+                bail!("ruleset id '{}' must be non-empty and must not contain '@'", ruleset.id);
+            }
+            if !seen.insert(ruleset.versioned_id()) {
+                bail!("ruleset {} is defined twice", ruleset.versioned_id());
+            }
+            ruleset
+                .rules
+                .validate()
+                .with_context(|| format!("ruleset {}", ruleset.versioned_id()))?;
+        }
+        Ok(Self { rulesets: file.rulesets })
+    }
+
+    pub fn len(&self) -> usize {
+        self.rulesets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rulesets.is_empty()
+    }
+}
+
+#[async_trait]
+impl RulesetRepository for YamlRulesetRepo {
+    async fn get(&self, id: &str) -> Result<Option<Ruleset>> {
+        let found = match id.split_once('@') {
+            Some((id, version)) => {
+                let Ok(version) = version.parse::<u32>() else {
+                    return Ok(None);
+                };
+                self.rulesets.iter().find(|r| r.id == id && r.version == version)
+            }
+            None => self.rulesets.iter().filter(|r| r.id == id).max_by_key(|r| r.version),
+        };
+        Ok(found.cloned())
+    }
+
+    async fn list(&self) -> Result<Vec<Ruleset>> {
+        Ok(self.rulesets.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::rules::GameRules;
+
+    const CATALOGUE: &str = "
+rulesets:
+  - id: quick
+    version: 1
+    rules: { game: trivia, num_questions: 5 }
+  - id: quick
+    version: 3
+    rules: { game: trivia, num_questions: 7 }
+  - id: quick
+    version: 2
+    rules: { game: trivia, num_questions: 6 }
+  - id: race
+    version: 1
+    rules: { game: puzzle, mode: Race, max_players: 4 }
+";
+
+    fn repo() -> YamlRulesetRepo {
+        YamlRulesetRepo::from_yaml(CATALOGUE).unwrap()
+    }
+
+    fn num_questions(ruleset: Option<Ruleset>) -> u32 {
+        match ruleset.expect("ruleset should resolve").rules {
+            GameRules::Trivia(rules) => rules.num_questions,
+            other => panic!("expected trivia rules, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn bare_ids_resolve_to_the_latest_version() {
+        let ruleset = repo().get("quick").await.unwrap();
+        assert_eq!(ruleset.as_ref().map(Ruleset::versioned_id).as_deref(), Some("quick@3"));
+        assert_eq!(num_questions(ruleset), 7);
+    }
+
+    #[tokio::test]
+    async fn versioned_ids_pin_that_version() {
+        let repo = repo();
+        assert_eq!(num_questions(repo.get("quick@1").await.unwrap()), 5);
+        assert_eq!(num_questions(repo.get("quick@2").await.unwrap()), 6);
+    }
+
+    #[tokio::test]
+    async fn unknown_ids_and_versions_resolve_to_nothing() {
+        let repo = repo();
+        for id in ["slow", "quick@4", "quick@latest", "quick@", "@1", ""] {
+            assert!(repo.get(id).await.unwrap().is_none(), "{}", id);
+        }
+    }
+
+    #[test]
+    fn bad_catalogues_are_refused_on_load() {
+        let cases = [
+            ("rulesets: [{ id: 'a@1', version: 1, rules: { game: trivia } }]", "must not contain '@'"),
+            ("rulesets: [{ id: '', version: 1, rules: { game: trivia } }]", "must be non-empty"),
+            (
+                "rulesets: [{ id: a, version: 1, rules: { game: trivia } }, { id: a, version: 1, rules: { game: puzzle } }]",
+                "a@1 is defined twice",
+            ),
+            ("rulesets: [{ id: a, version: 1, rules: { game: trivia, num_questions: 0 } }]", "ruleset a@1"),
+        ];
+        for (yaml, expected) in cases {
+            let error = format!("{:#}", YamlRulesetRepo::from_yaml(yaml).unwrap_err());
+            assert!(error.contains(expected), "{}: {}", yaml, error);
+        }
+    }
+
+    #[test]
+    fn the_shipped_catalogue_loads() {
+        let repo = YamlRulesetRepo::load(Path::new(env!("CARGO_MANIFEST_DIR")).join("rulesets.yaml")).unwrap();
+        assert!(!repo.is_empty());
+    }
+}
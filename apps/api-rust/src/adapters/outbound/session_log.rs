@@ -0,0 +1,128 @@
+use crate::domain::game::GameSessionID;
+use crate::domain::replay::{Recording, ReplayStep};
+use crate::ports::SessionLogRepository;
+use anyhow::Result;
+use async_trait::async_trait;
+use dashmap::DashMap;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+/// In-process session logs. Running sessions keep theirs until they finish; after that only the
+/// `kept` most recent recordings stay.
+#[derive(Debug)]
+pub struct MemorySessionLog {
+    kept: usize,
+    running: DashMap<GameSessionID, Vec<ReplayStep>>,
+    finished: Mutex<Finished>,
+}
+
+#[derive(Debug, Default)]
+struct Finished {
+    recordings: HashMap<GameSessionID, Recording>,
+    // Oldest first.
+    order: VecDeque<GameSessionID>,
+}
+
+impl MemorySessionLog {
+    pub fn new(kept: usize) -> Self {
+        Self {
+            kept,
+            running: DashMap::new(),
+            finished: Mutex::new(Finished::default()),
+        }
+    }
+}
+
+#[async_trait]
+impl SessionLogRepository for MemorySessionLog {
+    async fn append(&self, session_id: &str, step: ReplayStep) -> Result<()> {
+        if self.finished.lock().unwrap().recordings.contains_key(session_id) {
+            return Ok(());
+        }
+        self.running.entry(session_id.to_string()).or_default().push(step);
+        Ok(())
+    }
+
+    async fn steps(&self, session_id: &str) -> Result<Vec<ReplayStep>> {
+        if let Some(steps) = self.running.get(session_id) {
+            return Ok(steps.clone());
+        }
+        let finished = self.finished.lock().unwrap();
+        Ok(finished
+            .recordings
+            .get(session_id)
+            .map(|recording| recording.steps.clone())
+            .unwrap_or_default())
+    }
+
+    async fn finish(&self, session_id: &str, final_state: serde_json::Value) -> Result<()> {
+        let Some((session_id, steps)) = self.running.remove(session_id) else {
+            return Ok(());
+        };
+        let mut finished = self.finished.lock().unwrap();
+        finished.order.push_back(session_id.clone());
+        finished.recordings.insert(session_id, Recording { steps, final_state });
+        while finished.order.len() > self.kept {
+            if let Some(oldest) = finished.order.pop_front() {
+                finished.recordings.remove(&oldest);
+            }
+        }
+        Ok(())
+    }
+
+    async fn recording(&self, session_id: &str) -> Result<Option<Recording>> {
+        Ok(self.finished.lock().unwrap().recordings.get(session_id).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::replay::StepInput;
+    use chrono::Utc;
+
+    fn tick() -> ReplayStep {
+        ReplayStep {
+            at: Utc::now(),
+            input: StepInput::Tick,
+            events: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn finishing_closes_the_log_and_keeps_it_for_export() {
+        let log = MemorySessionLog::new(10);
+        log.append("a", tick()).await.unwrap();
+        log.finish("a", serde_json::json!({ "phase": "finished" })).await.unwrap();
+        log.append("a", tick()).await.unwrap();
+
+        let recording = log.recording("a").await.unwrap().unwrap();
+        assert_eq!(recording.steps.len(), 1);
+        assert_eq!(recording.final_state, serde_json::json!({ "phase": "finished" }));
+        assert_eq!(log.steps("a").await.unwrap().len(), 1);
+        assert!(log.recording("unknown").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn only_the_most_recent_recordings_are_kept() {
+        let log = MemorySessionLog::new(2);
+        for id in ["a", "b", "c"] {
+            log.append(id, tick()).await.unwrap();
+            log.finish(id, serde_json::Value::Null).await.unwrap();
+        }
+        assert!(log.recording("a").await.unwrap().is_none());
+        assert!(log.steps("a").await.unwrap().is_empty());
+        assert!(log.recording("b").await.unwrap().is_some());
+        assert!(log.recording("c").await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn running_logs_are_not_evicted() {
+        let log = MemorySessionLog::new(0);
+        log.append("running", tick()).await.unwrap();
+        log.append("done", tick()).await.unwrap();
+        log.finish("done", serde_json::Value::Null).await.unwrap();
+        assert_eq!(log.steps("running").await.unwrap().len(), 1);
+        assert!(log.recording("done").await.unwrap().is_none());
+    }
+}
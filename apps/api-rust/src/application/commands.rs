@@ -1,11 +1,80 @@
-use crate::domain::game::{GameSessionID, GameType};
+use crate::domain::game::{DomainError, GameSessionID, GameType, PlayerID};
+use crate::domain::question::QuestionID;
+use crate::domain::rules::{GameRules, PuzzleRules, TriviaRules};
+use crate::pb::runecraftstudios::pastello::game::session::v1::{
+    create_game_session_request::Config as ConfigPb, CreateGameSessionRequest,
+};
+use crate::pb::runecraftstudios::pastello::game::types::v1::GameType as GameTypePb;
 use serde::{Deserialize, Serialize};
 
+/// Where a new session gets its rules from; mirrors the `config` oneof of `CreateGameSessionRequest`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionConfig {
+    /// The game's default rules.
+    #[default]
+    Default,
+    /// Rules given inline with the request.
+    Rules(GameRules),
+    /// A preset from the ruleset catalogue, as `id` or `id@version`.
+    Ruleset(String),
+}
+
+/// Makes a new session a private room that players join by code.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RoomOptions {
+    pub password: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StartGameSessionCommand {
     // FIX: Use String instead of the non-serializable Protobuf struct.
     pub player_id: String, 
     pub game_type: GameType,
+    /// Players to seat besides the host.
+    #[serde(default)]
+    pub player_ids: Vec<PlayerID>,
+    #[serde(default)]
+    pub config: SessionConfig,
+    #[serde(default)]
+    pub room: Option<RoomOptions>,
+    /// Fixed seed for the session's engine, e.g. a daily challenge's; a fresh one is drawn when unset.
+    #[serde(default)]
+    pub seed: Option<u64>,
+    #[serde(default)]
+    pub daily_challenge: Option<chrono::NaiveDate>,
+    /// Trivia only: fixed questions to ask, in order, instead of drawing them.
+    #[serde(default)]
+    pub question_ids: Vec<QuestionID>,
+}
+
+impl StartGameSessionCommand {
+    /// Builds the command from a protobuf request made by `player_id`, who becomes the host.
+    pub fn from_pb(player_id: String, request: &CreateGameSessionRequest) -> Result<Self, DomainError> {
+        let game_type = match request.game_type() {
+            GameTypePb::Puzzle => GameType::Puzzle,
+            GameTypePb::Trivia => GameType::Trivia,
+            GameTypePb::Unspecified => return Err(DomainError::InvalidPayload("game_type is required".to_string())),
+        };
+        let config = match &request.config {
+            None => SessionConfig::Default,
+            Some(ConfigPb::Puzzle(rules)) => SessionConfig::Rules(GameRules::Puzzle(PuzzleRules::from(rules))),
+            Some(ConfigPb::Trivia(rules)) => SessionConfig::Rules(GameRules::Trivia(TriviaRules::from(rules))),
+            Some(ConfigPb::RulesetId(id)) => SessionConfig::Ruleset(id.clone()),
+        };
+        Ok(Self {
+            player_id,
+            game_type,
+            player_ids: request.player_ids.iter().map(|p| p.value.clone()).collect(),
+            config,
+            room: request.private_room.as_ref().map(|room| RoomOptions {
+                password: Some(room.password.clone()).filter(|p| !p.is_empty()),
+            }),
+            seed: None,
+            daily_challenge: None,
+            question_ids: Vec::new(),
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,181 @@
+//! Achievements. `run` follows the event bus and the games counted by the profile service, turns
+//! them into triggers for the players they concern, checks the catalogue's conditions and pushes
+//! an `AchievementUnlock` to the transports for every badge earned. Game counts come from the
+//! lifetime stats, so they can't drift from them and include games from before a badge existed.
+
+use crate::application::services::game_service::GameService;
+use crate::application::services::profile::GameCounted;
+use crate::domain::achievement::{AchievementDefinition, PlayerAchievements, Trigger};
+use crate::domain::game::{DomainEvent, GameSessionID, PlayerID};
+use crate::domain::puzzle::PuzzleSolved;
+use crate::domain::rules::GameRules;
+use crate::domain::trivia::AnswerAccepted;
+use crate::ports::{AchievementCatalogue, ProfileRepository};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::any::Any;
+use std::sync::Arc;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tracing::{info, warn};
+
+// Unlocks buffered per subscriber before a slow connection starts missing them.
+const UNLOCK_BUFFER: usize = 256;
+
+/// Pushed to the transports for the player it names.
+#[derive(Debug, Clone)]
+pub struct AchievementUnlock {
+    pub player_id: PlayerID,
+    pub achievement: AchievementDefinition,
+    pub session_id: GameSessionID,
+    pub unlocked_at: DateTime<Utc>,
+}
+
+/// An achievement as shown on a player's profile.
+#[derive(Debug, Clone)]
+pub struct AchievementView {
+    pub achievement: AchievementDefinition,
+    /// `None` while the achievement is still locked.
+    pub unlocked_at: Option<DateTime<Utc>>,
+}
+
+pub struct AchievementService {
+    profiles: Arc<dyn ProfileRepository>,
+    game_service: Arc<GameService>,
+    catalogue: Arc<dyn AchievementCatalogue>,
+    unlocks: broadcast::Sender<AchievementUnlock>,
+}
+
+impl AchievementService {
+    pub fn new(
+        profiles: Arc<dyn ProfileRepository>,
+        game_service: Arc<GameService>,
+        catalogue: Arc<dyn AchievementCatalogue>,
+    ) -> Self {
+        let (unlocks, _) = broadcast::channel(UNLOCK_BUFFER);
+        Self {
+            profiles,
+            game_service,
+            catalogue,
+            unlocks,
+        }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<AchievementUnlock> {
+        self.unlocks.subscribe()
+    }
+
+    /// Every achievement in the catalogue, marked with whether and when `player_id` unlocked it.
+    pub async fn achievements(&self, player_id: &PlayerID) -> Result<Vec<AchievementView>> {
+        let player = self.player_achievements(player_id).await?;
+        Ok(self
+            .catalogue
+            .list()
+            .await?
+            .into_iter()
+            .map(|achievement| AchievementView {
+                unlocked_at: player.unlocked.get(&achievement.id).map(|u| u.unlocked_at),
+                achievement,
+            })
+            .collect())
+    }
+
+    /// Checks achievements against `events` and `games` until either closes. Spawned once from
+    /// `main`, with `games` from `ProfileService::subscribe_games`.
+    pub async fn run(
+        &self,
+        mut events: broadcast::Receiver<Box<dyn DomainEvent>>,
+        mut games: broadcast::Receiver<GameCounted>,
+    ) {
+        loop {
+            tokio::select! {
+                event = events.recv() => match event {
+                    Ok(event) => {
+                        if let Err(e) = self.record(event.as_ref()).await {
+                            warn!("Could not check achievements for {}: {}", event.event_type(), e);
+                        }
+                    }
+                    Err(RecvError::Lagged(skipped)) => warn!("Achievements missed {} events", skipped),
+                    Err(RecvError::Closed) => break,
+                },
+                game = games.recv() => match game {
+                    Ok(game) => {
+                        let trigger = Trigger::GameCounted { stats: game.stats };
+                        if let Err(e) = self.unlock(&game.player_id, &trigger, &game.session_id, game.finished_at).await {
+                            warn!("Could not check achievements for {} in {}: {}", game.player_id, game.session_id, e);
+                        }
+                    }
+                    Err(RecvError::Lagged(skipped)) => warn!("Achievements missed {} counted games", skipped),
+                    Err(RecvError::Closed) => break,
+                },
+            }
+        }
+    }
+
+    async fn record(&self, event: &dyn DomainEvent) -> Result<()> {
+        let Some((player_id, trigger, at)) = self.trigger(event).await? else {
+            return Ok(());
+        };
+        self.unlock(&player_id, &trigger, event.session_id(), at).await
+    }
+
+    /// Applies `trigger` to `player_id` and announces whatever it unlocks.
+    async fn unlock(
+        &self,
+        player_id: &PlayerID,
+        trigger: &Trigger,
+        session_id: &GameSessionID,
+        at: DateTime<Utc>,
+    ) -> Result<()> {
+        let catalogue = self.catalogue.list().await?;
+        let mut player = self.player_achievements(player_id).await?;
+        let unlocked: Vec<AchievementDefinition> = player
+            .record(&catalogue, trigger, session_id, at)
+            .into_iter()
+            .cloned()
+            .collect();
+        self.profiles.save_achievements(player).await?;
+        for achievement in unlocked {
+            info!("{} unlocked achievement {}", player_id, achievement.id);
+            // No subscribers just means the player isn't connected right now.
+            let _ = self.unlocks.send(AchievementUnlock {
+                player_id: player_id.clone(),
+                achievement,
+                session_id: session_id.clone(),
+                unlocked_at: at,
+            });
+        }
+        Ok(())
+    }
+
+    /// What `event` means for the player it concerns. Finished games arrive from the profile
+    /// service instead, once they are in the player's stats.
+    async fn trigger(&self, event: &dyn DomainEvent) -> Result<Option<(PlayerID, Trigger, DateTime<Utc>)>> {
+        let any = event as &(dyn Any + Send + Sync);
+        if let Some(e) = any.downcast_ref::<AnswerAccepted>() {
+            return Ok(Some((e.player_id.clone(), Trigger::AnswerGiven { correct: e.correct }, e.meta.at)));
+        }
+        let Some(e) = any.downcast_ref::<PuzzleSolved>() else {
+            return Ok(None);
+        };
+        let Some(session) = self.game_service.get_session(&e.session_id).await? else {
+            return Ok(None);
+        };
+        let GameRules::Puzzle(rules) = &session.rules else {
+            return Ok(None);
+        };
+        let trigger = Trigger::PuzzleSolved {
+            difficulty: rules.difficulty,
+            elapsed_ms: e.elapsed_ms,
+            moves: e.moves,
+        };
+        Ok(Some((e.player_id.clone(), trigger, e.meta.at)))
+    }
+
+    async fn player_achievements(&self, player_id: &PlayerID) -> Result<PlayerAchievements> {
+        Ok(self
+            .profiles
+            .get_achievements(player_id)
+            .await?
+            .unwrap_or_else(|| PlayerAchievements::new(player_id)))
+    }
+}
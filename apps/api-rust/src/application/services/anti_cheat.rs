@@ -0,0 +1,137 @@
+//! Reviews results for cheating before they are ranked. Reviews read the session log, which
+//! is written before a step's events are published, so a result is always reviewed against
+//! everything that led up to it. Commands are not checked as they arrive; a review only decides
+//! whether a finished result is ranked.
+
+use crate::domain::anti_cheat::{AnswerSheet, AntiCheatPolicy, CheatFlag, CheatKind};
+use crate::domain::game::{DomainError, GameType, PlayerID, Session};
+use crate::domain::replay::{ReplayStep, StepInput};
+use crate::ports::{CheatFlagRepository, Clock, SessionLogRepository};
+use anyhow::Result;
+use std::sync::Arc;
+use tracing::warn;
+
+pub struct AntiCheatService {
+    flags: Arc<dyn CheatFlagRepository>,
+    session_log: Arc<dyn SessionLogRepository>,
+    clock: Arc<dyn Clock>,
+    policy: AntiCheatPolicy,
+}
+
+impl AntiCheatService {
+    pub fn new(
+        flags: Arc<dyn CheatFlagRepository>,
+        session_log: Arc<dyn SessionLogRepository>,
+        clock: Arc<dyn Clock>,
+        policy: AntiCheatPolicy,
+    ) -> Self {
+        Self {
+            flags,
+            session_log,
+            clock,
+            policy,
+        }
+    }
+
+    /// Checks `player_id`'s result in `session` and records whatever it trips. Returns the
+    /// player's new flags; accounts found sharing answers with them are flagged as well.
+    pub async fn review(&self, session: &Session, player_id: &PlayerID) -> Result<Vec<CheatFlag>> {
+        let steps = self.session_log.steps(&session.id).await?;
+        let flag = |player_id: &PlayerID, session_id: &str, kind: CheatKind, detail: String| CheatFlag {
+            session_id: session_id.to_string(),
+            player_id: player_id.clone(),
+            game_type: session.game_type.clone(),
+            kind,
+            detail,
+            flagged_at: self.clock.now_utc(),
+        };
+
+        let mut flags = Vec::new();
+        let mut others = Vec::new();
+        match session.game_type {
+            GameType::Puzzle => {
+                if let Some(detail) = solve_of(player_id, &steps)
+                    .and_then(|(elapsed_ms, moves)| self.policy.check_solve(elapsed_ms, moves))
+                {
+                    flags.push(flag(player_id, &session.id, CheatKind::FastSolve, detail));
+                }
+                if let Some(detail) = self.policy.check_moves(&move_times(player_id, &steps)) {
+                    flags.push(flag(player_id, &session.id, CheatKind::MoveBurst, detail));
+                }
+            }
+            GameType::Trivia => {
+                let now = self.clock.now_utc();
+                let sheet = AnswerSheet::from_steps(&session.id, player_id, &steps, now);
+                if let Some(detail) = self.policy.check_answers(&sheet) {
+                    flags.push(flag(player_id, &session.id, CheatKind::EarlyAnswer, detail));
+                }
+                if !sheet.answers.is_empty() {
+                    let earlier = self.flags.add_sheet(sheet.clone(), self.policy.shared_answers_since(now)).await?;
+                    for other in earlier.iter().filter(|other| self.policy.shared_answers(&sheet, other)) {
+                        let detail = format!("same answers as {} in {}", other.player_id, other.session_id);
+                        flags.push(flag(player_id, &session.id, CheatKind::SharedAnswers, detail));
+                        let detail = format!("same answers as {} in {}", player_id, session.id);
+                        others.push(flag(&other.player_id, &other.session_id, CheatKind::SharedAnswers, detail));
+                    }
+                }
+            }
+        }
+
+        for flag in flags.iter().chain(&others) {
+            warn!(
+                "Flagged {} in {} for {}: {}",
+                flag.player_id,
+                flag.session_id,
+                flag.kind.as_str(),
+                flag.detail
+            );
+        }
+        let mut recorded = flags.clone();
+        recorded.extend(others);
+        if !recorded.is_empty() {
+            self.flags.record(recorded).await?;
+        }
+        Ok(flags)
+    }
+
+    /// Whether `flags` keep the result they were raised on off the leaderboards.
+    pub fn rejects(&self, flags: &[CheatFlag]) -> bool {
+        self.policy.rejects(flags)
+    }
+
+    /// Recorded flags, for a reviewer.
+    pub async fn flags(
+        &self,
+        reviewer: &PlayerID,
+        player_id: Option<&str>,
+        session_id: Option<&str>,
+    ) -> Result<Vec<CheatFlag>> {
+        if !self.policy.is_reviewer(reviewer) {
+            return Err(DomainError::NotAReviewer(reviewer.clone()).into());
+        }
+        self.flags.list(player_id, session_id).await
+    }
+}
+
+/// Time and move count of `player_id`'s solve, from its `puzzle.solved` event.
+fn solve_of(player_id: &PlayerID, steps: &[ReplayStep]) -> Option<(i64, u32)> {
+    steps
+        .iter()
+        .flat_map(|step| &step.events)
+        .filter(|event| event.event_type == "puzzle.solved")
+        .map(|event| &event.payload)
+        .find(|payload| payload["player_id"].as_str() == Some(player_id.as_str()))
+        .and_then(|payload| Some((payload["elapsed_ms"].as_i64()?, payload["moves"].as_u64()? as u32)))
+}
+
+/// When each of `player_id`'s accepted moves was made, in order.
+fn move_times(player_id: &PlayerID, steps: &[ReplayStep]) -> Vec<chrono::DateTime<chrono::Utc>> {
+    steps
+        .iter()
+        .filter(|step| {
+            matches!(&step.input, StepInput::Command { player_id: by, command_type, .. }
+                if by == player_id && command_type == "PuzzleMove")
+        })
+        .map(|step| step.at)
+        .collect()
+}
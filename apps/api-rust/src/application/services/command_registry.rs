@@ -1,5 +1,5 @@
 use crate::domain::{
-    game::{GameCommand, GameType, DomainError, GameEngineFactory},
+    game::{GameCommand, GameType, DomainError, GameEngineFactory, RemovePlayerCommand, SeatPlayerCommand},
 };
 use crate::pb::runecraftstudios::pastello::game::{
     puzzle::v1::{MovePieceCommand, UndoMoveCommand},
@@ -50,6 +50,7 @@ struct UndoMoveDto {
 struct SubmitAnswerDto {
     pub player_id: String,
     pub session_id: String,
+    pub answer: String,
 }
 
 #[derive(Deserialize)]
@@ -62,6 +63,9 @@ struct RevealHintDto {
 pub struct CommandRegistry {
     engine_factory: Arc<dyn GameEngineFactory>,
     commands: HashMap<GameType, CommandMap>,
+    // Commands the server sends engines of any game type. Logged like client commands, so
+    // replays can parse them, but never accepted from clients.
+    server_commands: CommandMap,
 }
 
 impl fmt::Debug for CommandRegistry {
@@ -78,18 +82,40 @@ impl CommandRegistry {
         Self {
             engine_factory,
             commands: Self::init_command_map(),
+            server_commands: Self::init_server_command_map(),
         }
     }
 
+    fn init_server_command_map() -> CommandMap {
+        let seat_player: CommandDeserializer = Box::new(|payload: &Value| {
+            let cmd: SeatPlayerCommand = serde_json::from_value(payload.clone())
+                .map_err(|e| DomainError::InvalidPayload(e.to_string()))?;
+            Ok(Box::new(cmd) as Box<dyn GameCommand>)
+        });
+
+        let remove_player: CommandDeserializer = Box::new(|payload: &Value| {
+            let cmd: RemovePlayerCommand = serde_json::from_value(payload.clone())
+                .map_err(|e| DomainError::InvalidPayload(e.to_string()))?;
+            Ok(Box::new(cmd) as Box<dyn GameCommand>)
+        });
+
+        let mut map: CommandMap = HashMap::new();
+        map.insert("SeatPlayer", seat_player);
+        map.insert("RemovePlayer", remove_player);
+        map
+    }
+
     fn init_command_map() -> HashMap<GameType, CommandMap> {
         let move_piece: CommandDeserializer = Box::new(|payload: &Value| {
-            let dto: MovePieceDto = serde_json::from_value(payload.clone())?;
+            let dto: MovePieceDto = serde_json::from_value(payload.clone())
+                .map_err(|e| DomainError::InvalidPayload(e.to_string()))?;
             let cmd = build_move_piece_command(dto);
             Ok(Box::new(cmd) as Box<dyn GameCommand>)
         });
 
         let undo_move: CommandDeserializer = Box::new(|payload: &Value| {
-            let dto: UndoMoveDto = serde_json::from_value(payload.clone())?;
+            let dto: UndoMoveDto = serde_json::from_value(payload.clone())
+                .map_err(|e| DomainError::InvalidPayload(e.to_string()))?;
             let cmd = UndoMoveCommand {
                 session_id: Some(GameSessionId { value: dto.session_id }),
                 player_id: Some(PlayerId { value: dto.player_id }),
@@ -98,17 +124,19 @@ impl CommandRegistry {
         });
 
         let submit_answer: CommandDeserializer = Box::new(|payload: &Value| {
-            let dto: SubmitAnswerDto = serde_json::from_value(payload.clone())?;
+            let dto: SubmitAnswerDto = serde_json::from_value(payload.clone())
+                .map_err(|e| DomainError::InvalidPayload(e.to_string()))?;
             let cmd = SubmitAnswerCommand {
                 session_id: Some(GameSessionId { value: dto.session_id }),
                 player_id: Some(PlayerId { value: dto.player_id }),
-                answer: "placeholder".to_string(),
+                answer: dto.answer,
             };
             Ok(Box::new(cmd) as Box<dyn GameCommand>)
         });
 
         let reveal_hint: CommandDeserializer = Box::new(|payload: &Value| {
-            let dto: RevealHintDto = serde_json::from_value(payload.clone())?;
+            let dto: RevealHintDto = serde_json::from_value(payload.clone())
+                .map_err(|e| DomainError::InvalidPayload(e.to_string()))?;
             let cmd = RevealHintCommand {
                 session_id: Some(GameSessionId { value: dto.session_id }),
             };
@@ -142,8 +170,21 @@ impl CommandRegistry {
 
         let deserializer = map
             .get(command_type)
-            .ok_or_else(|| DomainError::InvalidCommand)?;
+            .ok_or(DomainError::InvalidCommand)?;
 
         deserializer(payload)
     }
+
+    /// Parses a command read back from a session log, which may also be one the server sent.
+    pub fn deserialize_recorded(
+        &self,
+        game_type: GameType,
+        command_type: &str,
+        payload: &serde_json::Value,
+    ) -> Result<Box<dyn GameCommand>> {
+        match self.server_commands.get(command_type) {
+            Some(deserializer) => deserializer(payload),
+            None => self.deserialize(game_type, command_type, payload),
+        }
+    }
 }
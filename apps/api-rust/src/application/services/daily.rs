@@ -0,0 +1,254 @@
+//! Daily challenges. Every player plays the same seeded game for the UTC date the clock reports,
+//! once per game type; results are ranked on that day's `DailyChallenge` leaderboard. Trivia
+//! questions are drawn from the seed the first time a day is played and kept, so imports into the
+//! question store later on don't change a past day.
+
+use crate::adapters::outbound::rng::SeededRng;
+use crate::application::commands::{SessionConfig, StartGameSessionCommand};
+use crate::application::services::engine_factory::QUESTION_STREAM;
+use crate::application::services::game_service::GameService;
+use crate::domain::daily::{DailyAttempt, DailyChallenge, DailyChallengePolicy};
+use crate::domain::game::{DomainError, GameType, PlayerID};
+use crate::domain::question::{Question, QuestionID};
+use crate::domain::rules::{GameRules, TriviaRules};
+use crate::domain::trivia::draw_questions;
+use crate::ports::{Clock, DailyChallengeRepository, QuestionRepository};
+use anyhow::Result;
+use chrono::NaiveDate;
+use dashmap::DashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::info;
+
+pub struct DailyChallengeService {
+    repo: Arc<dyn DailyChallengeRepository>,
+    questions: Arc<dyn QuestionRepository>,
+    game_service: Arc<GameService>,
+    clock: Arc<dyn Clock>,
+    policy: DailyChallengePolicy,
+    // Held per player from the attempt check until it is recorded, so a double click can't
+    // start two games. Dropped once nobody is waiting on it.
+    starting: DashMap<PlayerID, Arc<Mutex<()>>>,
+}
+
+impl DailyChallengeService {
+    pub fn new(
+        repo: Arc<dyn DailyChallengeRepository>,
+        questions: Arc<dyn QuestionRepository>,
+        game_service: Arc<GameService>,
+        clock: Arc<dyn Clock>,
+        policy: DailyChallengePolicy,
+    ) -> Self {
+        Self {
+            repo,
+            questions,
+            game_service,
+            clock,
+            policy,
+            starting: DashMap::new(),
+        }
+    }
+
+    pub fn today(&self) -> NaiveDate {
+        self.clock.now_utc().date_naive()
+    }
+
+    /// Today's challenge for `game_type`.
+    pub fn challenge(&self, game_type: GameType) -> DailyChallenge {
+        self.policy.challenge(self.today(), game_type)
+    }
+
+    /// `player_id`'s attempt at today's `game_type` challenge, if they have made it.
+    pub async fn attempt(&self, player_id: &PlayerID, game_type: &GameType) -> Result<Option<DailyAttempt>> {
+        self.repo.get_attempt(player_id, self.today(), game_type).await
+    }
+
+    /// Starts `player_id`'s one attempt at today's `game_type` challenge as a solo session.
+    pub async fn start(&self, player_id: &PlayerID, game_type: GameType) -> Result<(DailyChallenge, DailyAttempt)> {
+        let lock = self.starting.entry(player_id.clone()).or_default().clone();
+        let guard = lock.lock_owned().await;
+        let started = self.start_locked(player_id, game_type).await;
+        drop(guard);
+        self.starting.remove_if(player_id, |_, lock| Arc::strong_count(lock) == 1);
+        started
+    }
+
+    async fn start_locked(&self, player_id: &PlayerID, game_type: GameType) -> Result<(DailyChallenge, DailyAttempt)> {
+        let challenge = self.challenge(game_type);
+        if self
+            .repo
+            .get_attempt(player_id, challenge.date, &challenge.game_type)
+            .await?
+            .is_some()
+        {
+            return Err(DomainError::AlreadyAttempted(player_id.clone()).into());
+        }
+
+        let config = match &challenge.ruleset_id {
+            Some(id) => SessionConfig::Ruleset(id.clone()),
+            None => SessionConfig::Default,
+        };
+        let question_ids = match self.game_service.rules(&challenge.game_type, config.clone()).await? {
+            GameRules::Trivia(rules) => self.question_ids(&challenge, &rules).await?,
+            GameRules::Puzzle(_) => Vec::new(),
+        };
+        let session_id = self
+            .game_service
+            .start_game_session(StartGameSessionCommand {
+                player_id: player_id.clone(),
+                game_type: challenge.game_type.clone(),
+                player_ids: Vec::new(),
+                config,
+                room: None,
+                seed: Some(challenge.seed),
+                daily_challenge: Some(challenge.date),
+                question_ids,
+            })
+            .await?;
+        let attempt = DailyAttempt {
+            player_id: player_id.clone(),
+            date: challenge.date,
+            game_type: challenge.game_type.clone(),
+            session_id,
+            started_at: self.clock.now_utc(),
+        };
+        if !self.repo.record_attempt(attempt.clone()).await? {
+            return Err(DomainError::AlreadyAttempted(player_id.clone()).into());
+        }
+        info!(
+            "{} started the {:?} daily challenge of {} in {}",
+            player_id, challenge.game_type, challenge.date, attempt.session_id
+        );
+        Ok((challenge, attempt))
+    }
+
+    /// The questions of `challenge`'s day, drawn and kept on the day's first attempt.
+    async fn question_ids(&self, challenge: &DailyChallenge, rules: &TriviaRules) -> Result<Vec<QuestionID>> {
+        if let Some(ids) = self.repo.get_questions(challenge.date).await? {
+            return Ok(ids);
+        }
+        let stored = self.questions.list(&rules.categories).await?;
+        let ids = draw_daily_questions(stored, rules, challenge.seed);
+        self.repo.record_questions(challenge.date, ids).await
+    }
+}
+
+/// Ids of the questions a daily trivia challenge with `seed` draws from `stored`, the ones a
+/// trivia engine seeded with it would draw.
+pub fn draw_daily_questions(stored: Vec<Question>, rules: &TriviaRules, seed: u64) -> Vec<QuestionID> {
+    let rng = SeededRng::with_stream(seed, QUESTION_STREAM);
+    draw_questions(stored, rules, &rng).into_iter().map(|q| q.id).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::{
+        clock::StepClock, daily_repo::MemoryDailyChallengeRepo, eventbus::BroadcastEventBus, id_gen::UuidGenerator,
+        memory_repo::MemoryRepo, profile_repo::MemoryProfileRepo, question_repo::MemoryQuestionRepo, rng::SystemRng,
+        ruleset_repo::YamlRulesetRepo, session_log::MemorySessionLog,
+    };
+    use crate::application::services::command_registry::CommandRegistry;
+    use crate::application::services::engine_factory::DefaultEngineFactory;
+    use crate::domain::question::QuestionFormat;
+    use crate::domain::room::RoomPolicy;
+    use crate::domain::rules::TriviaCategory;
+    use crate::domain::scoring::ScoringPolicy;
+    use crate::application::services::game_service::SessionPolicy;
+    use chrono::{TimeZone, Utc};
+
+    fn question(id: &str) -> Question {
+        Question {
+            id: id.to_string(),
+            category: TriviaCategory::Science,
+            prompt: format!("Question {}", id),
+            format: QuestionFormat::TrueFalse { answer: true },
+            hint: None,
+        }
+    }
+
+    struct Fixture {
+        daily: Arc<DailyChallengeService>,
+        repo: Arc<MemoryDailyChallengeRepo>,
+        questions: Arc<MemoryQuestionRepo>,
+        game_service: Arc<GameService>,
+    }
+
+    fn fixture() -> Fixture {
+        let clock = Arc::new(StepClock::new(Utc.with_ymd_and_hms(2024, 2, 14, 9, 0, 0).unwrap()));
+        let rng = Arc::new(SystemRng::new());
+        let questions = Arc::new(MemoryQuestionRepo::new((0..20).map(|i| question(&format!("q{:02}", i))).collect()));
+        let engine_factory = Arc::new(DefaultEngineFactory::new(rng.clone(), questions.clone(), ScoringPolicy::default()));
+        let game_service = Arc::new(GameService::new(
+            Arc::new(MemoryRepo::new()),
+            Arc::new(BroadcastEventBus::new()),
+            clock.clone(),
+            Arc::new(UuidGenerator::new()),
+            rng,
+            engine_factory.clone(),
+            Arc::new(CommandRegistry::new(engine_factory)),
+            Arc::new(YamlRulesetRepo::default()),
+            Arc::new(MemoryProfileRepo::new()),
+            Arc::new(MemorySessionLog::new(SessionPolicy::default().recordings_kept)),
+            RoomPolicy::default(),
+            SessionPolicy::default(),
+        ));
+        let repo = Arc::new(MemoryDailyChallengeRepo::new());
+        let daily = Arc::new(DailyChallengeService::new(
+            repo.clone(),
+            questions.clone(),
+            game_service.clone(),
+            clock,
+            DailyChallengePolicy::default(),
+        ));
+        Fixture { daily, repo, questions, game_service }
+    }
+
+    async fn session_questions(fixture: &Fixture, attempt: &DailyAttempt) -> Vec<QuestionID> {
+        let session = fixture.game_service.get_session(&attempt.session_id).await.unwrap().unwrap();
+        session.question_ids
+    }
+
+    #[tokio::test]
+    async fn one_attempt_per_player_and_day_even_when_racing() {
+        let fixture = fixture();
+        let player = "p1".to_string();
+        let (first, second) = tokio::join!(
+            fixture.daily.start(&player, GameType::Puzzle),
+            fixture.daily.start(&player, GameType::Puzzle),
+        );
+        assert!(first.is_ok() != second.is_ok());
+        assert!(fixture.daily.attempt(&player, &GameType::Puzzle).await.unwrap().is_some());
+        // Other players and other game types are not held up by it.
+        assert!(fixture.daily.start(&"p2".to_string(), GameType::Puzzle).await.is_ok());
+        assert!(fixture.daily.start(&player, GameType::Trivia).await.is_ok());
+        assert!(fixture.daily.starting.is_empty());
+    }
+
+    #[tokio::test]
+    async fn a_days_questions_survive_later_imports() {
+        let fixture = fixture();
+        let (challenge, first) = fixture.daily.start(&"p1".to_string(), GameType::Trivia).await.unwrap();
+        let drawn = session_questions(&fixture, &first).await;
+        assert_eq!(drawn.len(), TriviaRules::default().num_questions as usize);
+        assert_eq!(fixture.repo.get_questions(challenge.date).await.unwrap(), Some(drawn.clone()));
+
+        fixture
+            .questions
+            .upsert((0..20).map(|i| question(&format!("new{:02}", i))).collect())
+            .await
+            .unwrap();
+        let (_, second) = fixture.daily.start(&"p2".to_string(), GameType::Trivia).await.unwrap();
+        assert_eq!(session_questions(&fixture, &second).await, drawn);
+    }
+
+    #[test]
+    fn daily_questions_depend_only_on_the_seed() {
+        let stored: Vec<Question> = (0..20).map(|i| question(&format!("q{:02}", i))).collect();
+        let mut reversed = stored.clone();
+        reversed.reverse();
+        let rules = TriviaRules::default();
+        assert_eq!(draw_daily_questions(stored.clone(), &rules, 7), draw_daily_questions(reversed, &rules, 7));
+        assert_ne!(draw_daily_questions(stored.clone(), &rules, 7), draw_daily_questions(stored, &rules, 8));
+    }
+}
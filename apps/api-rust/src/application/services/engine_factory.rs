@@ -1,47 +1,70 @@
-use crate::ports::{Clock, Rng};
-use crate::domain::game::{Engine, GameType, GameEngineFactory};
+use crate::ports::{Clock, QuestionRepository, Rng};
+use crate::domain::game::{Engine, GameEngineFactory};
+use crate::domain::rules::GameRules;
+use crate::domain::scoring::ScoringPolicy;
+use crate::adapters::outbound::rng::SeededRng;
 use std::sync::Arc;
 use std::fmt;
 
+// Streams of a session's seed, one per use, so drawing more for one use never shifts another.
+// Changing a number changes what every recorded seed replays to.
+pub const BOARD_STREAM: u64 = 0;
+pub const QUESTION_STREAM: u64 = 0;
+pub const OPTION_STREAM: u64 = 1;
+
 // DefaultEngineFactory now implements Debug manually to satisfy trait bounds
 #[derive(Clone)]
 pub struct DefaultEngineFactory {
-    clock: Arc<dyn Clock + Send + Sync>,
     rng: Arc<dyn Rng + Send + Sync>,
+    questions: Arc<dyn QuestionRepository>,
+    scoring: ScoringPolicy,
 }
 
 impl fmt::Debug for DefaultEngineFactory {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("DefaultEngineFactory")
-            .field("clock", &"Arc<dyn Clock>")
             .field("rng", &"Arc<dyn Rng>")
+            .field("questions", &"Arc<dyn QuestionRepository>")
+            .field("scoring", &self.scoring)
             .finish()
     }
 }
 
 impl DefaultEngineFactory {
-    pub fn new(clock: Arc<dyn Clock + Send + Sync>, rng: Arc<dyn Rng + Send + Sync>) -> Self {
-        Self { clock, rng }
+    pub fn new(rng: Arc<dyn Rng + Send + Sync>, questions: Arc<dyn QuestionRepository>, scoring: ScoringPolicy) -> Self {
+        Self { rng, questions, scoring }
     }
 }
 
 impl GameEngineFactory for DefaultEngineFactory { 
-    fn create_engine(&self, game_type: GameType) -> Box<dyn Engine> {
-        match game_type {
-            GameType::Puzzle => {
+    fn create_engine(&self, rules: &GameRules, seed: Option<u64>, clock: Arc<dyn Clock>) -> Box<dyn Engine> {
+        // Seeded sessions get generators of their own so no other game can advance them.
+        let stream = |stream: u64| -> Arc<dyn Rng> {
+            match seed {
+                Some(seed) => Arc::new(SeededRng::with_stream(seed, stream)),
+                None => self.rng.clone(),
+            }
+        };
+        match rules {
+            GameRules::Puzzle(rules) => {
                 Box::new(crate::domain::puzzle::PuzzleEngine::new(
-                    self.clock.clone() as Arc<dyn crate::ports::Clock>, 
-                    self.rng.clone() as Arc<dyn crate::ports::Rng>,
+                    clock.clone(), 
+                    stream(BOARD_STREAM),
                     Arc::new(crate::adapters::outbound::id_gen::UuidGenerator::new()) as Arc<dyn crate::ports::IdGenerator>,
+                    rules.clone(),
                 ))
             }
-            GameType::Trivia => {
+            GameRules::Trivia(rules) => {
                  Box::new(crate::domain::trivia::TriviaEngine::new(
-                    self.clock.clone() as Arc<dyn crate::ports::Clock>,
-                    self.rng.clone() as Arc<dyn crate::ports::Rng>,
+                    clock.clone(),
+                    stream(QUESTION_STREAM),
+                    stream(OPTION_STREAM),
                     Arc::new(crate::adapters::outbound::id_gen::UuidGenerator::new()) as Arc<dyn crate::ports::IdGenerator>,
+                    self.questions.clone(),
+                    rules.clone(),
+                    self.scoring.clone(),
                 ))
             }
         }
     }
-}
\ No newline at end of file
+}
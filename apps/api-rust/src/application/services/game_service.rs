@@ -1,21 +1,90 @@
-use crate::ports::{Clock, GameRepository, IdGenerator, EventBus};
-use crate::domain::game::{Session, Player, GameEngineFactory, Engine, GameCommand};
-use crate::application::usecase::handle_game_command::HandleGameCommandUseCase;
-use crate::application::commands::{StartGameSessionCommand, GameCommandMessage};
+use crate::ports::{Clock, GameRepository, IdGenerator, EventBus, ProfileRepository, Rng, RulesetRepository, SessionLogRepository};
+use crate::adapters::outbound::clock::StepClock;
+use crate::domain::replay::{RecordedEvent, Recording, ReplayStep, StepInput};
+use crate::domain::game::{Session, SessionStatus, Player, PlayerID, Role, GameEngineFactory, Engine, GameCommand, GameSessionID, GameType, DomainEvent, DomainError, RemovePlayerCommand, SeatPlayerCommand};
+use crate::domain::room::{normalize_join_code, PlayerJoined, PlayerKicked, PrivateRoom, RoomPolicy};
+use crate::domain::rules::GameRules;
+use crate::application::usecase::handle_game_command::{publish_events, HandleGameCommandUseCase};
+use crate::application::commands::{StartGameSessionCommand, GameCommandMessage, RoomOptions, SessionConfig};
 use crate::application::services::command_registry::CommandRegistry;
+use dashmap::mapref::entry::Entry;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use tokio::sync::Mutex;
-use anyhow::{Result, bail};
-use tracing::info;
+use tokio::sync::{broadcast, Mutex};
+use anyhow::Result;
+use tracing::{info, warn};
+
+// Attempts at drawing a join code that no live room is using before giving up.
+const JOIN_CODE_ATTEMPTS: usize = 16;
+
+/// Session lifecycle settings, read from the `sessions` section of `config.yaml`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionPolicy {
+    /// A running game that goes this long without a command is abandoned and its engine dropped.
+    pub abandon_after_secs: u32,
+    /// Recordings of finished games kept for replay export and review; the oldest go first.
+    pub recordings_kept: usize,
+}
+
+impl Default for SessionPolicy {
+    fn default() -> Self {
+        Self {
+            abandon_after_secs: 1800,
+            recordings_kept: 1000,
+        }
+    }
+}
+
+/// A session's live engine and the clock it runs on, which moves only between steps.
+pub struct RunningEngine {
+    pub engine: Box<dyn Engine + Send>,
+    pub clock: Arc<StepClock>,
+    /// When the engine started or last took a command.
+    pub last_active: chrono::DateTime<chrono::Utc>,
+}
+
+pub type SharedEngine = Arc<Mutex<RunningEngine>>;
+
+/// Held while a stored session is read, changed and saved back.
+type SessionLocks = DashMap<GameSessionID, Arc<Mutex<()>>>;
+
+/// Holds a session's write lock. Dropping the last handle on the lock removes it from the map,
+/// so sessions don't leave entries behind.
+struct SessionGuard {
+    session_id: GameSessionID,
+    locks: Arc<SessionLocks>,
+    guard: Option<tokio::sync::OwnedMutexGuard<()>>,
+}
+
+impl Drop for SessionGuard {
+    fn drop(&mut self) {
+        self.guard.take();
+        // Callers waiting for the lock hold a handle too, so the entry stays until they are done.
+        self.locks.remove_if(&self.session_id, |_, lock| Arc::strong_count(lock) == 1);
+    }
+}
 
 #[derive(Clone)]
 pub struct GameService {
     repo: Arc<dyn GameRepository>,
-    _event_bus: Arc<dyn EventBus>,
+    event_bus: Arc<dyn EventBus>,
     clock: Arc<dyn Clock>,
     id_gen: Arc<dyn IdGenerator>,
+    rng: Arc<dyn Rng>,
     engine_factory: Arc<dyn GameEngineFactory>,
     command_registry: Arc<CommandRegistry>,
+    rulesets: Arc<dyn RulesetRepository>,
+    profiles: Arc<dyn ProfileRepository>,
+    session_log: Arc<dyn SessionLogRepository>,
+    room_policy: RoomPolicy,
+    session_policy: SessionPolicy,
+    // Engines hold the live game state. They are dropped once their game ends or is abandoned.
+    engines: Arc<DashMap<GameSessionID, SharedEngine>>,
+    // One write lock per stored session, so concurrent joins, kicks, spectators and status
+    // changes can't overwrite each other's saves.
+    session_locks: Arc<SessionLocks>,
 }
 
 impl GameService {
@@ -25,70 +94,932 @@ impl GameService {
         event_bus: Arc<dyn EventBus>,
         clock: Arc<dyn Clock>,
         id_gen: Arc<dyn IdGenerator>,
+        rng: Arc<dyn Rng>,
         engine_factory: Arc<dyn GameEngineFactory>,
         command_registry: Arc<CommandRegistry>,
+        rulesets: Arc<dyn RulesetRepository>,
+        profiles: Arc<dyn ProfileRepository>,
+        session_log: Arc<dyn SessionLogRepository>,
+        room_policy: RoomPolicy,
+        session_policy: SessionPolicy,
     ) -> Self {
         Self {
             repo,
-            _event_bus: event_bus,
+            event_bus,
             clock,
             id_gen,
+            rng,
             engine_factory,
             command_registry,
+            rulesets,
+            profiles,
+            session_log,
+            room_policy,
+            session_policy,
+            engines: Arc::new(DashMap::new()),
+            session_locks: Arc::new(DashMap::new()),
+        }
+    }
+
+    /// Takes the write lock of `session_id`. Read the session after taking it and save before
+    /// letting it go.
+    async fn lock_session(&self, session_id: &str) -> SessionGuard {
+        let lock = self.session_locks.entry(session_id.to_string()).or_default().clone();
+        SessionGuard {
+            session_id: session_id.to_string(),
+            locks: self.session_locks.clone(),
+            guard: Some(lock.lock_owned().await),
         }
     }
 
+    async fn load(&self, game_id: &str) -> Result<Session> {
+        self.repo
+            .get(game_id)
+            .await?
+            .ok_or_else(|| DomainError::SessionNotFound(game_id.to_string()).into())
+    }
+
     // Helper for WebSocket auth flow to ensure session exists
     pub async fn force_save_session(&self, session: Session) -> Result<()> {
-        self.repo.save(&session.id.clone(), session).await
+        self.repo.save(&session.id.clone(), session.clone()).await?;
+        // Start the engine right away so connected players receive its opening events.
+        self.engine_for(&session).await?;
+        Ok(())
     }
 
     pub async fn start_game_session(&self, cmd: StartGameSessionCommand) -> Result<String> {
-        let session_id = self.id_gen.new_id();
+        let (ruleset_id, rules) = self.resolve_rules(&cmd.game_type, cmd.config).await?;
 
-        let host = Player {
+        let mut players = vec![Player {
             id: cmd.player_id.clone(),
-            name: "Host".to_string(),
+            name: self.display_name(&cmd.player_id, "Host".to_string()).await,
+        }];
+        for player_id in cmd.player_ids {
+            if !players.iter().any(|p| p.id == player_id) {
+                players.push(Player {
+                    name: self.display_name(&player_id, format!("Player {}", players.len() + 1)).await,
+                    id: player_id,
+                });
+            }
+        }
+        if players.len() as u32 > rules.max_players() {
+            return Err(DomainError::InvalidRules(format!(
+                "{} players exceed max_players of {}",
+                players.len(),
+                rules.max_players()
+            ))
+            .into());
+        }
+
+        let room = match cmd.room {
+            Some(options) => Some(self.open_room(options).await?),
+            None => None,
+        };
+        // Rooms wait in the lobby for players to join until the host starts them.
+        let status = match room {
+            Some(_) => SessionStatus::Lobby,
+            None => SessionStatus::Active,
         };
 
+        let session_id = self.id_gen.new_id();
+        // Every session gets a seed of its own, so any game can be replayed for a bug report.
+        let seed = match cmd.seed {
+            Some(seed) => seed,
+            None => self.rng.next_u64(),
+        };
         let session = Session {
             id: session_id.clone(),
             host_id: cmd.player_id.clone(),
             game_type: cmd.game_type,
-            players: vec![host],
+            players,
+            spectators: Vec::new(),
+            ruleset_id,
+            rules,
+            room,
+            seed: Some(seed),
+            daily_challenge: cmd.daily_challenge,
+            question_ids: cmd.question_ids,
+            status,
         };
 
         self.repo.save(&session.id.clone(), session).await?;
-        info!("Game started: {}", session_id);
+        info!("Game started: {} (seed {})", session_id, seed);
         Ok(session_id)
     }
 
-    // Legacy JSON handler (kept for compatibility)
+    /// The name `player_id` chose on their profile, or `fallback` if they haven't saved one.
+    async fn display_name(&self, player_id: &PlayerID, fallback: String) -> String {
+        match self.profiles.get_profile(player_id).await {
+            Ok(Some(profile)) => profile.display_name,
+            Ok(None) => fallback,
+            Err(e) => {
+                warn!("Could not load the profile of {}: {}", player_id, e);
+                fallback
+            }
+        }
+    }
+
+    /// Draws a join code no live room is using and sets up the room around it.
+    async fn open_room(&self, options: RoomOptions) -> Result<PrivateRoom> {
+        let now = self.clock.now_utc();
+        for _ in 0..JOIN_CODE_ATTEMPTS {
+            let code = self.id_gen.new_join_code();
+            let taken = self
+                .repo
+                .get_by_join_code(&code)
+                .await?
+                .and_then(|session| session.room)
+                .is_some_and(|room| !room.is_expired(now));
+            if !taken {
+                let expires_at = now + chrono::Duration::seconds(self.room_policy.code_ttl_secs as i64);
+                let salt = (u128::from(self.rng.next_u64()) << 64 | u128::from(self.rng.next_u64())).to_le_bytes();
+                let room = tokio::task::spawn_blocking(move || {
+                    PrivateRoom::new(code, expires_at, options.password.as_deref(), &salt)
+                })
+                .await??;
+                return Ok(room);
+            }
+        }
+        Err(DomainError::Internal("no free join code".to_string()).into())
+    }
+
+    /// Seats `player_id` in the private room with join code `code`, in the lobby or in a game
+    /// already under way. Joining a room you are already in returns it unchanged.
+    pub async fn join_by_code(&self, code: &str, player_id: &PlayerID, password: Option<&str>) -> Result<Session> {
+        let code = normalize_join_code(code);
+        let session_id = self
+            .repo
+            .get_by_join_code(&code)
+            .await?
+            .ok_or_else(|| DomainError::UnknownJoinCode(code.clone()))?
+            .id;
+        let guard = self.lock_session(&session_id).await;
+        let mut session = self.load(&session_id).await?;
+        let room = session.room.clone().ok_or_else(|| DomainError::UnknownJoinCode(code.clone()))?;
+
+        let now = self.clock.now_utc();
+        if room.is_expired(now) {
+            return Err(DomainError::JoinCodeExpired(code).into());
+        }
+        if !matches!(session.status, SessionStatus::Lobby | SessionStatus::Active) {
+            return Err(DomainError::GameFinished.into());
+        }
+        if room.kicked.contains(player_id) {
+            return Err(DomainError::Kicked(player_id.clone()).into());
+        }
+        if session.role_of(player_id) == Some(Role::Player) {
+            return Ok(session);
+        }
+        if room.has_password() {
+            room.ensure_accepts_attempts(now)?;
+            let password = password.map(str::to_string);
+            let checked = tokio::task::spawn_blocking(move || room.check_password(password.as_deref())).await?;
+            if let Some(room) = session.room.as_mut() {
+                room.record_attempt(checked.is_ok(), now, &self.room_policy);
+            }
+            if let Err(e) = checked {
+                self.repo.save(&session.id.clone(), session).await?;
+                return Err(e.into());
+            }
+        }
+        if session.players.len() as u32 >= session.rules.max_players() {
+            return Err(DomainError::RoomFull.into());
+        }
+
+        session.spectators.retain(|p| p.id != *player_id);
+        let name = self.display_name(player_id, format!("Player {}", session.players.len() + 1)).await;
+        session.players.push(Player {
+            id: player_id.clone(),
+            name,
+        });
+        self.repo.save(&session.id.clone(), session.clone()).await?;
+        // Engines seat everyone in the session when they start; a game already under way
+        // seats the new player itself.
+        if let Some(engine) = self.engines.get(&session.id).map(|e| e.value().clone()) {
+            let seat = SeatPlayerCommand { player_id: player_id.clone() };
+            self.use_case(engine)
+                .execute_direct(session.clone(), Box::new(seat), player_id)
+                .await?;
+        }
+        drop(guard);
+        info!("{} joined room {} ({})", player_id, code, session.id);
+
+        publish_events(
+            self.event_bus.as_ref(),
+            vec![Box::new(PlayerJoined {
+                meta: crate::domain::game::new_meta(self.clock.as_ref()),
+                session_id: session.id.clone(),
+                player_id: player_id.clone(),
+                players: session.players.len() as u32,
+            })],
+        );
+        Ok(session)
+    }
+
+    /// Starts the game of a private room waiting in the lobby, on behalf of its host. Players
+    /// connected to the room receive the engine's opening events.
+    pub async fn start_room(&self, game_id: &str, host_id: &PlayerID) -> Result<Session> {
+        let guard = self.lock_session(game_id).await;
+        let mut session = self.load(game_id).await?;
+        if session.host_id != *host_id {
+            return Err(DomainError::NotTheHost.into());
+        }
+        match session.status {
+            SessionStatus::Lobby => {}
+            SessionStatus::Active => return Err(DomainError::AlreadyStarted.into()),
+            SessionStatus::Ended | SessionStatus::Cancelled => return Err(DomainError::GameFinished.into()),
+        }
+        session.status = SessionStatus::Active;
+        self.repo.save(&session.id.clone(), session.clone()).await?;
+        drop(guard);
+        info!("{} started room {} with {} players", host_id, session.id, session.players.len());
+        self.engine_for(&session).await?;
+        Ok(session)
+    }
+
+    /// Removes `player_id` from the session on behalf of its host, and from its game if one is
+    /// running. In a private room the player can't rejoin with the code afterwards.
+    pub async fn kick(&self, game_id: &str, host_id: &PlayerID, player_id: &PlayerID) -> Result<()> {
+        let guard = self.lock_session(game_id).await;
+        let mut session = self.load(game_id).await?;
+        if session.host_id != *host_id {
+            return Err(DomainError::NotTheHost.into());
+        }
+        if session.host_id == *player_id {
+            return Err(DomainError::InvalidPayload("the host cannot be kicked".to_string()).into());
+        }
+        if session.role_of(player_id).is_none() {
+            return Err(DomainError::NotAPlayer(player_id.clone()).into());
+        }
+
+        session.players.retain(|p| p.id != *player_id);
+        session.spectators.retain(|p| p.id != *player_id);
+        if let Some(room) = session.room.as_mut() {
+            room.kicked.push(player_id.clone());
+        }
+        self.repo.save(&session.id.clone(), session.clone()).await?;
+        let engine = self.engines.get(&session.id).map(|e| e.value().clone());
+        if let Some(engine) = &engine {
+            let remove = RemovePlayerCommand { player_id: player_id.clone() };
+            self.use_case(engine.clone())
+                .execute_direct(session.clone(), Box::new(remove), host_id)
+                .await?;
+        }
+        drop(guard);
+        if let Some(engine) = &engine {
+            // Removing the last player still racing or answering can end the game.
+            self.retire_if_finished(&session.id, engine).await;
+        }
+        info!("{} was kicked from {} by the host", player_id, session.id);
+
+        publish_events(
+            self.event_bus.as_ref(),
+            vec![Box::new(PlayerKicked {
+                meta: crate::domain::game::new_meta(self.clock.as_ref()),
+                session_id: session.id.clone(),
+                player_id: player_id.clone(),
+            })],
+        );
+        Ok(())
+    }
+
+    /// Seats available in a new `game_type` session created with `config`.
+    pub async fn max_players(&self, game_type: &GameType, config: SessionConfig) -> Result<u32> {
+        Ok(self.rules(game_type, config).await?.max_players())
+    }
+
+    /// Rules a new `game_type` session created with `config` would run with.
+    pub async fn rules(&self, game_type: &GameType, config: SessionConfig) -> Result<GameRules> {
+        let (_, rules) = self.resolve_rules(game_type, config).await?;
+        Ok(rules)
+    }
+
+    /// Resolves the rules a new session runs with and checks they fit `game_type`. Sessions
+    /// created from a preset record the pinned `id@version`, so later catalogue edits don't
+    /// change running games.
+    async fn resolve_rules(&self, game_type: &GameType, config: SessionConfig) -> Result<(Option<String>, GameRules)> {
+        let (ruleset_id, rules) = match config {
+            SessionConfig::Default => (None, GameRules::default_for(game_type)),
+            SessionConfig::Rules(rules) => (None, rules),
+            SessionConfig::Ruleset(id) => {
+                let ruleset = self
+                    .rulesets
+                    .get(&id)
+                    .await?
+                    .ok_or(DomainError::UnknownRuleset(id))?;
+                (Some(ruleset.versioned_id()), ruleset.rules)
+            }
+        };
+        if rules.game_type() != *game_type {
+            let source = match &ruleset_id {
+                Some(id) => format!("rules from ruleset {}", id),
+                None => "rules".to_string(),
+            };
+            return Err(DomainError::InvalidRules(format!(
+                "{} are for {:?}, not {:?}",
+                source,
+                rules.game_type(),
+                game_type
+            ))
+            .into());
+        }
+        rules.validate()?;
+        Ok((ruleset_id, rules))
+    }
+
+    /// Loads a stored session and makes sure its engine is running, for transports that attach
+    /// to existing sessions. Rooms still in the lobby have no engine until the host starts
+    /// them. Subscribe to events first to receive the engine's opening events.
+    pub async fn open_session(&self, game_id: &str) -> Result<Session> {
+        let session = self.load(game_id).await?;
+        if session.status != SessionStatus::Lobby {
+            self.engine_for(&session).await?;
+        }
+        Ok(session)
+    }
+
+    /// Attaches a connection to a stored session. Players must already be part of it;
+    /// spectators are recorded on the session the first time they watch. The engine is only
+    /// started once the attach is allowed, as with `open_session`.
+    pub async fn attach(&self, game_id: &str, player_id: &PlayerID, role: Role) -> Result<Session> {
+        let _guard = self.lock_session(game_id).await;
+        let mut session = self.load(game_id).await?;
+        let known = session.role_of(player_id);
+        if role == Role::Player && known != Some(Role::Player) {
+            return Err(DomainError::NotAPlayer(player_id.clone()).into());
+        }
+        if session.status != SessionStatus::Lobby {
+            self.engine_for(&session).await?;
+        }
+        match (role, known) {
+            (Role::Player, _) | (Role::Spectator, Some(_)) => {}
+            (Role::Spectator, None) => {
+                let name = self
+                    .display_name(player_id, format!("Spectator {}", session.spectators.len() + 1))
+                    .await;
+                session.spectators.push(Player {
+                    id: player_id.clone(),
+                    name,
+                });
+                self.repo.save(&session.id.clone(), session.clone()).await?;
+                info!("{} is spectating {}", player_id, session.id);
+            }
+        }
+        Ok(session)
+    }
+
+    /// Called when a connection attached with `attach` closes. Spectators stop being listed on
+    /// the session; players keep their seat.
+    pub async fn detach(&self, game_id: &str, player_id: &PlayerID, role: Role) {
+        if role != Role::Spectator {
+            return;
+        }
+        let _guard = self.lock_session(game_id).await;
+        let result = match self.load(game_id).await {
+            Ok(mut session) if session.role_of(player_id) == Some(Role::Spectator) => {
+                session.spectators.retain(|p| p.id != *player_id);
+                self.repo.save(game_id, session).await
+            }
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(()) => info!("{} stopped spectating {}", player_id, game_id),
+            Err(e) => warn!("Could not remove spectator {} from {}: {}", player_id, game_id, e),
+        }
+    }
+
+    /// Live state of a session's engine as `viewer` may see it in `role`.
+    pub async fn snapshot(&self, game_id: &str, viewer: &PlayerID, role: Role) -> Result<serde_json::Value> {
+        let engine = self
+            .engines
+            .get(game_id)
+            .map(|e| e.value().clone())
+            .ok_or_else(|| DomainError::SessionNotFound(game_id.to_string()))?;
+        let snapshot = engine.lock().await.engine.snapshot(viewer, role);
+        Ok(snapshot)
+    }
+
+    pub async fn get_session(&self, game_id: &str) -> Result<Option<Session>> {
+        self.repo.get(game_id).await
+    }
+
+    /// The session as `viewer` may look it up: only its host, players and spectators can. Anyone
+    /// else gets `SessionNotFound`, so private rooms don't give away that they exist.
+    pub async fn session_for(&self, game_id: &str, viewer: &PlayerID) -> Result<Session> {
+        self.repo
+            .get(game_id)
+            .await?
+            .filter(|session| session.role_of(viewer).is_some())
+            .ok_or_else(|| DomainError::SessionNotFound(game_id.to_string()).into())
+    }
+
+    /// Sessions `player_id` plays in. Players can only list their own.
+    pub async fn list_player_sessions(&self, caller: &PlayerID, player_id: &str) -> Result<Vec<Session>> {
+        if caller != player_id {
+            return Err(DomainError::NotYourSessions(caller.clone()).into());
+        }
+        self.repo.list_by_player(player_id).await
+    }
+
+    // JSON handler: the command type and payload are resolved through the CommandRegistry.
     pub async fn handle_game_command(&self, game_id: &str, command: GameCommandMessage) -> Result<()> {
-         // This logic is now largely superseded by handle_domain_command for WS
-         // But keeping the structure for reference
-         bail!("Use handle_domain_command for Protobuf messages");
+        let session = self
+            .repo
+            .get(game_id)
+            .await?
+            .ok_or_else(|| DomainError::SessionNotFound(game_id.to_string()))?;
+        ensure_can_act(&session, &command.player_id)?;
+
+        let engine_mutex = self.engine_for(&session).await?;
+
+        let result = self.use_case(engine_mutex.clone()).execute(session, command).await;
+        self.retire_if_finished(game_id, &engine_mutex).await;
+        result
     }
 
     // NEW: Direct handler for Typed Domain Commands (from Protobuf)
-    pub async fn handle_domain_command(&self, game_id: &str, command: Box<dyn GameCommand>) -> Result<()> {
-        let session = self.repo.get(game_id).await?;
-        let session = match session {
-            Some(s) => s,
-            None => bail!("Game session not found for id: {}", game_id),
-        };
+    pub async fn handle_domain_command(
+        &self,
+        game_id: &str,
+        player_id: &PlayerID,
+        command: Box<dyn GameCommand>,
+    ) -> Result<()> {
+        let session = self
+            .repo
+            .get(game_id)
+            .await?
+            .ok_or_else(|| DomainError::SessionNotFound(game_id.to_string()))?;
+        ensure_can_act(&session, player_id)?;
 
-        let engine = self.engine_factory.create_engine(session.game_type.clone());
-        let engine_mutex: Arc<Mutex<Box<dyn Engine + Send>>> = Arc::new(Mutex::new(engine as Box<dyn Engine + Send>));
+        let engine_mutex = self.engine_for(&session).await?;
 
-        let use_case = HandleGameCommandUseCase::new(
-            self.repo.clone(),
+        // We pass the command directly, bypassing the JSON registry
+        let result = self.use_case(engine_mutex.clone()).execute_direct(session, command, player_id).await;
+        self.retire_if_finished(game_id, &engine_mutex).await;
+        result
+    }
+
+    fn use_case(&self, engine: SharedEngine) -> HandleGameCommandUseCase {
+        HandleGameCommandUseCase::new(
+            self.event_bus.clone(),
             self.clock.clone(),
             self.command_registry.clone(),
-            engine_mutex,
+            engine,
+            self.session_log.clone(),
+        )
+    }
+
+    pub fn subscribe_events(&self) -> broadcast::Receiver<Box<dyn DomainEvent>> {
+        self.event_bus.subscribe()
+    }
+
+    /// Runs time-based rules on every live engine and drops the engines of games that have
+    /// ended or been abandoned. Driven by a background interval in `main`.
+    pub async fn tick_engines(&self) {
+        let engines: Vec<(GameSessionID, SharedEngine)> =
+            self.engines.iter().map(|e| (e.key().clone(), e.value().clone())).collect();
+        let abandon_after = chrono::Duration::seconds(self.session_policy.abandon_after_secs as i64);
+        for (session_id, engine) in engines {
+            let mut running = engine.lock().await;
+            let at = self.clock.now_utc();
+            running.clock.set(at);
+            let events = running.engine.tick();
+            if !events.is_empty() {
+                self.log_step(&session_id, at, StepInput::Tick, &events).await;
+            }
+            publish_events(self.event_bus.as_ref(), events);
+
+            let status = if running.engine.is_finished() {
+                SessionStatus::Ended
+            } else if at - running.last_active >= abandon_after {
+                SessionStatus::Cancelled
+            } else {
+                continue;
+            };
+            drop(running);
+            self.retire(&session_id, status).await;
+        }
+    }
+
+    async fn retire_if_finished(&self, session_id: &str, engine: &SharedEngine) {
+        let finished = engine.lock().await.engine.is_finished();
+        if finished {
+            self.retire(session_id, SessionStatus::Ended).await;
+        }
+    }
+
+    /// Records how the session's game came to an end, drops its engine and closes its log with
+    /// the engine's last state. The session is updated first so the engine can't be started
+    /// again in between.
+    async fn retire(&self, session_id: &str, status: SessionStatus) {
+        let _guard = self.lock_session(session_id).await;
+        match self.repo.get(session_id).await {
+            Ok(Some(mut session)) => {
+                session.status = status;
+                if let Err(e) = self.repo.save(session_id, session).await {
+                    warn!("Could not mark {} as {:?}: {}", session_id, status, e);
+                }
+            }
+            Ok(None) => {}
+            Err(e) => warn!("Could not load {} to mark it as {:?}: {}", session_id, status, e),
+        }
+        if let Some((_, engine)) = self.engines.remove(session_id) {
+            info!("Engine of {} dropped ({:?})", session_id, status);
+            let final_state = engine.lock().await.engine.snapshot(&PlayerID::new(), Role::Spectator);
+            if let Err(e) = self.session_log.finish(session_id, final_state).await {
+                warn!("Could not close the log of {}: {}", session_id, e);
+            }
+        }
+    }
+
+    async fn engine_for(&self, session: &Session) -> Result<SharedEngine> {
+        match session.status {
+            SessionStatus::Active => {}
+            SessionStatus::Lobby => return Err(DomainError::NotStarted.into()),
+            SessionStatus::Ended | SessionStatus::Cancelled => return Err(DomainError::GameFinished.into()),
+        }
+        if let Some(engine) = self.engines.get(&session.id) {
+            return Ok(engine.clone());
+        }
+
+        let at = self.clock.now_utc();
+        let clock = Arc::new(StepClock::new(at));
+        let mut engine = self.engine_factory.create_engine(&session.rules, session.seed, clock.clone());
+        let events = engine.start(session).await?;
+
+        let shared = Arc::new(Mutex::new(RunningEngine {
+            engine: engine as Box<dyn Engine + Send>,
+            clock,
+            last_active: at,
+        }));
+        // Held until the start is logged, so no command can be logged ahead of it.
+        let started = shared.lock().await;
+        match self.engines.entry(session.id.clone()) {
+            // Another connection started it first; its opening events are the ones that count.
+            Entry::Occupied(running) => return Ok(running.get().clone()),
+            Entry::Vacant(slot) => slot.insert(shared.clone()),
+        };
+        let input = StepInput::Start {
+            players: session.players.clone(),
+            questions: started.engine.drawn_questions(),
+        };
+        self.log_step(&session.id, at, input, &events).await;
+        publish_events(self.event_bus.as_ref(), events);
+        drop(started);
+        Ok(shared)
+    }
+
+    async fn log_step(
+        &self,
+        session_id: &GameSessionID,
+        at: chrono::DateTime<chrono::Utc>,
+        input: StepInput,
+        events: &[Box<dyn DomainEvent>],
+    ) {
+        let step = ReplayStep {
+            at,
+            input,
+            events: RecordedEvent::from_events(events),
+        };
+        if let Err(e) = self.session_log.append(session_id, step).await {
+            warn!("Could not log a step of {}: {}", session_id, e);
+        }
+    }
+
+    /// The recording of `game_id`, once its game has ended and while the recording is kept.
+    pub async fn recording(&self, game_id: &str) -> Result<Option<Recording>> {
+        self.session_log.recording(game_id).await
+    }
+}
+
+/// Only seated players may send commands; spectators and removed players are turned away.
+fn ensure_can_act(session: &Session, player_id: &PlayerID) -> Result<(), DomainError> {
+    match session.role_of(player_id) {
+        Some(Role::Player) => Ok(()),
+        Some(Role::Spectator) => Err(DomainError::Spectating(player_id.clone())),
+        None if session.room.as_ref().is_some_and(|room| room.kicked.contains(player_id)) => {
+            Err(DomainError::Kicked(player_id.clone()))
+        }
+        None => Err(DomainError::NotAPlayer(player_id.clone())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::{
+        eventbus::BroadcastEventBus, id_gen::UuidGenerator, memory_repo::MemoryRepo, profile_repo::MemoryProfileRepo,
+        question_repo::MemoryQuestionRepo, rng::SystemRng, ruleset_repo::YamlRulesetRepo, session_log::MemorySessionLog,
+    };
+    use crate::application::services::engine_factory::DefaultEngineFactory;
+    use crate::domain::rules::{PuzzleMode, PuzzleRules, TriviaRules};
+    use crate::domain::scoring::ScoringPolicy;
+    use chrono::TimeZone;
+
+    fn service(room_policy: RoomPolicy) -> (GameService, Arc<StepClock>) {
+        with_rulesets(room_policy, YamlRulesetRepo::default())
+    }
+
+    fn with_rulesets(room_policy: RoomPolicy, rulesets: YamlRulesetRepo) -> (GameService, Arc<StepClock>) {
+        let clock = Arc::new(StepClock::new(chrono::Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap()));
+        let rng = Arc::new(SystemRng::new());
+        let engine_factory = Arc::new(DefaultEngineFactory::new(
+            rng.clone(),
+            Arc::new(MemoryQuestionRepo::default()),
+            ScoringPolicy::default(),
+        ));
+        let service = GameService::new(
+            Arc::new(MemoryRepo::new()),
+            Arc::new(BroadcastEventBus::new()),
+            clock.clone(),
+            Arc::new(UuidGenerator::new()),
+            rng,
+            engine_factory.clone(),
+            Arc::new(CommandRegistry::new(engine_factory)),
+            Arc::new(rulesets),
+            Arc::new(MemoryProfileRepo::new()),
+            Arc::new(MemorySessionLog::new(SessionPolicy::default().recordings_kept)),
+            room_policy,
+            SessionPolicy::default(),
         );
+        (service, clock)
+    }
 
-        // We pass the command directly, bypassing the JSON registry
-        use_case.execute_direct(session, command).await
+    /// A race room for up to three players, hosted by `host`.
+    async fn open(service: &GameService, password: Option<&str>) -> Session {
+        let rules = PuzzleRules {
+            max_players: 3,
+            mode: PuzzleMode::Race,
+            ..PuzzleRules::default()
+        };
+        let session_id = service
+            .start_game_session(StartGameSessionCommand {
+                player_id: "host".to_string(),
+                game_type: GameType::Puzzle,
+                player_ids: Vec::new(),
+                config: SessionConfig::Rules(GameRules::Puzzle(rules)),
+                room: Some(RoomOptions { password: password.map(str::to_string) }),
+                seed: None,
+                daily_challenge: None,
+                question_ids: Vec::new(),
+            })
+            .await
+            .unwrap();
+        service.load(&session_id).await.unwrap()
+    }
+
+    fn code(session: &Session) -> String {
+        session.room.as_ref().unwrap().join_code.clone()
+    }
+
+    fn domain_error(result: Result<impl std::fmt::Debug>) -> DomainError {
+        result.unwrap_err().downcast::<DomainError>().unwrap()
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn rooms_wait_in_the_lobby_until_the_host_starts_them() {
+        let (service, _) = service(RoomPolicy::default());
+        let room = open(&service, None).await;
+        assert_eq!(room.status, SessionStatus::Lobby);
+
+        service.join_by_code(&code(&room), &"bob".to_string(), None).await.unwrap();
+        assert!(!service.engines.contains_key(&room.id));
+        let command = GameCommandMessage {
+            session_id: room.id.clone(),
+            r#type: "PuzzleUndo".to_string(),
+            payload: serde_json::json!({}),
+            timestamp: chrono::Utc::now(),
+            player_id: "bob".to_string(),
+        };
+        assert!(matches!(
+            domain_error(service.handle_game_command(&room.id, command).await),
+            DomainError::NotStarted
+        ));
+
+        let bob = "bob".to_string();
+        assert!(matches!(domain_error(service.start_room(&room.id, &bob).await), DomainError::NotTheHost));
+        let started = service.start_room(&room.id, &"host".to_string()).await.unwrap();
+        assert_eq!(started.status, SessionStatus::Active);
+        assert!(service.engines.contains_key(&room.id));
+        assert!(matches!(
+            domain_error(service.start_room(&room.id, &"host".to_string()).await),
+            DomainError::AlreadyStarted
+        ));
+    }
+
+    #[tokio::test]
+    async fn joins_check_the_password_and_the_seats_left() {
+        let (service, _) = service(RoomPolicy::default());
+        let room = open(&service, Some("hunter2")).await;
+        let code = code(&room);
+
+        assert!(matches!(
+            domain_error(service.join_by_code(&code, &"bob".to_string(), Some("wrong")).await),
+            DomainError::WrongPassword
+        ));
+        let joined = service.join_by_code(&code.to_lowercase(), &"bob".to_string(), Some("hunter2")).await.unwrap();
+        assert_eq!(joined.players.len(), 2);
+        // Joining again changes nothing, and needs no password.
+        let again = service.join_by_code(&code, &"bob".to_string(), None).await.unwrap();
+        assert_eq!(again.players.len(), 2);
+
+        service.join_by_code(&code, &"carol".to_string(), Some("hunter2")).await.unwrap();
+        assert!(matches!(
+            domain_error(service.join_by_code(&code, &"dave".to_string(), Some("hunter2")).await),
+            DomainError::RoomFull
+        ));
+    }
+
+    #[tokio::test]
+    async fn kicked_players_cannot_rejoin_and_codes_expire() {
+        let (service, clock) = service(RoomPolicy::default());
+        let room = open(&service, None).await;
+        let code = code(&room);
+        let bob = "bob".to_string();
+
+        service.join_by_code(&code, &bob, None).await.unwrap();
+        service.kick(&room.id, &"host".to_string(), &bob).await.unwrap();
+        assert!(matches!(domain_error(service.join_by_code(&code, &bob, None).await), DomainError::Kicked(_)));
+
+        clock.set(room.room.as_ref().unwrap().expires_at);
+        assert!(matches!(
+            domain_error(service.join_by_code(&code, &"carol".to_string(), None).await),
+            DomainError::JoinCodeExpired(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn wrong_passwords_lock_the_room_out_for_a_while() {
+        let policy = RoomPolicy {
+            max_password_attempts: 2,
+            ..RoomPolicy::default()
+        };
+        let (service, clock) = service(policy.clone());
+        let room = open(&service, Some("hunter2")).await;
+        let code = code(&room);
+        let bob = "bob".to_string();
+
+        for _ in 0..policy.max_password_attempts {
+            assert!(matches!(
+                domain_error(service.join_by_code(&code, &bob, Some("guess")).await),
+                DomainError::WrongPassword
+            ));
+        }
+        // Even the right password is turned away during the lockout.
+        assert!(matches!(
+            domain_error(service.join_by_code(&code, &bob, Some("hunter2")).await),
+            DomainError::TooManyAttempts(_)
+        ));
+
+        clock.set(clock.now_utc() + chrono::Duration::seconds(policy.password_lockout_secs as i64));
+        service.join_by_code(&code, &bob, Some("hunter2")).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn kicking_takes_the_player_out_of_the_running_game() {
+        let (service, _) = service(RoomPolicy::default());
+        let room = open(&service, None).await;
+        let (host, bob) = ("host".to_string(), "bob".to_string());
+        service.join_by_code(&code(&room), &bob, None).await.unwrap();
+        service.start_room(&room.id, &host).await.unwrap();
+
+        let racers = |snapshot: serde_json::Value| -> Vec<String> {
+            snapshot["standings"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|s| s["player_id"].as_str().unwrap().to_string())
+                .collect()
+        };
+        let mut before = racers(service.snapshot(&room.id, &host, Role::Player).await.unwrap());
+        before.sort();
+        assert_eq!(before, ["bob", "host"]);
+
+        service.kick(&room.id, &host, &bob).await.unwrap();
+        assert_eq!(racers(service.snapshot(&room.id, &host, Role::Player).await.unwrap()), ["host"]);
+    }
+
+    #[tokio::test]
+    async fn presets_must_match_the_game_type() {
+        let catalogue = "
+rulesets:
+  - id: quick
+    version: 1
+    rules: { game: trivia, num_questions: 5 }
+  - id: quick
+    version: 2
+    rules: { game: trivia, num_questions: 7 }
+";
+        let (service, _) = with_rulesets(RoomPolicy::default(), YamlRulesetRepo::from_yaml(catalogue).unwrap());
+        let rules = |id: &str| service.rules(&GameType::Trivia, SessionConfig::Ruleset(id.to_string()));
+
+        assert!(matches!(rules("quick").await.unwrap(), GameRules::Trivia(r) if r.num_questions == 7));
+        assert!(matches!(rules("quick@1").await.unwrap(), GameRules::Trivia(r) if r.num_questions == 5));
+        assert!(matches!(domain_error(rules("slow").await), DomainError::UnknownRuleset(id) if id == "slow"));
+
+        let mismatch = domain_error(service.rules(&GameType::Puzzle, SessionConfig::Ruleset("quick".to_string())).await);
+        assert!(
+            matches!(&mismatch, DomainError::InvalidRules(reason) if reason.contains("ruleset quick@2")),
+            "{:?}",
+            mismatch
+        );
+    }
+
+    #[tokio::test]
+    async fn inline_rules_reach_the_engine() {
+        use crate::pb::runecraftstudios::pastello::game::session::v1::{
+            create_game_session_request::Config, CreateGameSessionRequest,
+        };
+        use crate::pb::runecraftstudios::pastello::game::trivia::v1::TriviaRules as TriviaRulesPb;
+        use crate::pb::runecraftstudios::pastello::game::types::v1::{GameType as GameTypePb, PlayerId};
+
+        let (service, _) = service(RoomPolicy::default());
+        let request = |rules: TriviaRulesPb| CreateGameSessionRequest {
+            game_type: GameTypePb::Trivia as i32,
+            player_ids: vec![PlayerId { value: "bob".to_string() }],
+            private_room: None,
+            config: Some(Config::Trivia(rules)),
+        };
+        let rules = TriviaRulesPb {
+            num_questions: 3,
+            seconds_per_question: 15,
+            negative_marking: true,
+            categories: Vec::new(),
+            max_players: 2,
+        };
+
+        let command = StartGameSessionCommand::from_pb("host".to_string(), &request(rules.clone())).unwrap();
+        let session_id = service.start_game_session(command).await.unwrap();
+        let session = service.open_session(&session_id).await.unwrap();
+        assert_eq!(session.ruleset_id, None);
+        assert_eq!(session.rules, GameRules::Trivia(TriviaRules::from(&rules)));
+
+        let snapshot = service.snapshot(&session_id, &"bob".to_string(), Role::Player).await.unwrap();
+        assert_eq!(snapshot["questions_total"], 3);
+        assert_eq!(snapshot["question"]["seconds_to_answer"], 15);
+
+        let invalid = TriviaRulesPb { num_questions: 0, ..rules };
+        let command = StartGameSessionCommand::from_pb("host".to_string(), &request(invalid)).unwrap();
+        assert!(matches!(
+            domain_error(service.start_game_session(command).await),
+            DomainError::InvalidRules(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn session_locks_are_dropped_once_nobody_holds_or_waits_for_them() {
+        let (service, _) = service(RoomPolicy::default());
+        let room = open(&service, None).await;
+        service.join_by_code(&code(&room), &"bob".to_string(), None).await.unwrap();
+        service.start_room(&room.id, &"host".to_string()).await.unwrap();
+        assert!(service.session_locks.is_empty());
+
+        let held = service.lock_session(&room.id).await;
+        let waiter = tokio::spawn({
+            let service = service.clone();
+            let id = room.id.clone();
+            async move {
+                let _guard = service.lock_session(&id).await;
+            }
+        });
+        while Arc::strong_count(service.session_locks.get(&room.id).unwrap().value()) < 3 {
+            tokio::task::yield_now().await;
+        }
+        drop(held);
+        // The waiter still needs this lock; a new caller must queue behind it, not get a fresh one.
+        assert!(service.session_locks.contains_key(&room.id));
+        waiter.await.unwrap();
+        assert!(service.session_locks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn outsiders_are_turned_away_before_the_engine_starts() {
+        let (service, _) = service(RoomPolicy::default());
+        let session_id = service
+            .start_game_session(StartGameSessionCommand {
+                player_id: "host".to_string(),
+                game_type: GameType::Puzzle,
+                player_ids: Vec::new(),
+                config: SessionConfig::Default,
+                room: None,
+                seed: None,
+                daily_challenge: None,
+                question_ids: Vec::new(),
+            })
+            .await
+            .unwrap();
+
+        let mallory = "mallory".to_string();
+        assert!(matches!(
+            domain_error(service.attach(&session_id, &mallory, Role::Player).await),
+            DomainError::NotAPlayer(_)
+        ));
+        assert!(!service.engines.contains_key(&session_id));
+
+        let watching = service.attach(&session_id, &mallory, Role::Spectator).await.unwrap();
+        assert_eq!(watching.spectators.len(), 1);
+        assert!(service.engines.contains_key(&session_id));
+        service.attach(&session_id, &"host".to_string(), Role::Player).await.unwrap();
+    }
+}
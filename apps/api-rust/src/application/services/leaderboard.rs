@@ -0,0 +1,163 @@
+//! Feeds the leaderboards from engine events and answers top-N and "my rank" queries.
+
+use crate::application::services::anti_cheat::AntiCheatService;
+use crate::application::services::game_service::GameService;
+use crate::domain::game::{DomainEvent, GameType, PlayerID, Session};
+use crate::domain::leaderboard::{BoardId, LeaderboardEntry, LeaderboardPeriod, LeaderboardPolicy, RankedEntry};
+use crate::domain::puzzle::PuzzleSolved;
+use crate::domain::rules::GameRules;
+use crate::domain::scoring::ScoringPolicy;
+use crate::domain::trivia::TriviaFinished;
+use crate::ports::{Clock, LeaderboardRepository};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::any::Any;
+use std::sync::Arc;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tracing::{info, warn};
+
+#[derive(Debug, Clone)]
+pub struct LeaderboardQuery {
+    pub game_type: GameType,
+    /// Narrows the board to sessions played with this ruleset; `None` ranks every session.
+    pub ruleset_id: Option<String>,
+    pub period: LeaderboardPeriod,
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LeaderboardView {
+    pub board: BoardId,
+    /// Players ranked on the board, not just those in `entries`.
+    pub total: u32,
+    pub entries: Vec<RankedEntry>,
+    /// The viewer's own standing, whether or not it made the top entries.
+    pub me: Option<RankedEntry>,
+}
+
+pub struct LeaderboardService {
+    boards: Arc<dyn LeaderboardRepository>,
+    game_service: Arc<GameService>,
+    anti_cheat: Arc<AntiCheatService>,
+    clock: Arc<dyn Clock>,
+    policy: LeaderboardPolicy,
+    scoring: ScoringPolicy,
+}
+
+impl LeaderboardService {
+    pub fn new(
+        boards: Arc<dyn LeaderboardRepository>,
+        game_service: Arc<GameService>,
+        anti_cheat: Arc<AntiCheatService>,
+        clock: Arc<dyn Clock>,
+        policy: LeaderboardPolicy,
+        scoring: ScoringPolicy,
+    ) -> Self {
+        Self {
+            boards,
+            game_service,
+            anti_cheat,
+            clock,
+            policy,
+            scoring,
+        }
+    }
+
+    pub async fn query(&self, query: LeaderboardQuery, viewer: &PlayerID) -> Result<LeaderboardView> {
+        let limit = self.policy.page_size(query.limit)?;
+        let board = BoardId::new(query.game_type, query.ruleset_id.as_deref(), query.period, self.clock.now_utc());
+        Ok(LeaderboardView {
+            total: self.boards.count(&board).await? as u32,
+            entries: self.boards.top(&board, limit).await?,
+            me: self.boards.rank_of(&board, viewer).await?,
+            board,
+        })
+    }
+
+    /// Ranks results from `events` until the bus closes. Spawned once from `main`.
+    pub async fn run(&self, mut events: broadcast::Receiver<Box<dyn DomainEvent>>) {
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    if let Err(e) = self.record(event.as_ref()).await {
+                        warn!("Could not rank {}: {}", event.event_type(), e);
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => warn!("Leaderboards missed {} events", skipped),
+                Err(RecvError::Closed) => break,
+            }
+        }
+    }
+
+    async fn record(&self, event: &dyn DomainEvent) -> Result<()> {
+        let event = event as &dyn Any;
+        if let Some(e) = event.downcast_ref::<TriviaFinished>() {
+            let Some(session) = self.game_service.get_session(&e.session_id).await? else {
+                return Ok(());
+            };
+            let max_score = match &session.rules {
+                GameRules::Trivia(rules) => rules.num_questions as i64 * self.scoring.max_question_score() as i64,
+                _ => return Ok(()),
+            };
+            for score in &e.scores {
+                let value = score.score as i64;
+                if !(0..=max_score).contains(&value) {
+                    warn!("Not ranking impossible trivia score {} of {} in {}", value, score.player_id, session.id);
+                    continue;
+                }
+                if self.passes_review(&session, &score.player_id).await? {
+                    self.submit(&session, &score.player_id, value, e.meta.at).await?;
+                }
+            }
+        } else if let Some(e) = event.downcast_ref::<PuzzleSolved>() {
+            let Some(session) = self.game_service.get_session(&e.session_id).await? else {
+                return Ok(());
+            };
+            if self.passes_review(&session, &e.player_id).await? {
+                self.submit(&session, &e.player_id, e.elapsed_ms, e.meta.at).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Has the result reviewed for cheating; false when its flags keep it off the boards. The
+    /// review runs once the result is in, so flags are raised after the game was played and
+    /// only hold back its ranking.
+    async fn passes_review(&self, session: &Session, player_id: &PlayerID) -> Result<bool> {
+        let flags = self.anti_cheat.review(session, player_id).await?;
+        if self.anti_cheat.rejects(&flags) {
+            warn!("Not ranking the result of {} in {}: flagged for review", player_id, session.id);
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    /// Enters one result on the game type's boards and, for preset rules, the ruleset's boards.
+    async fn submit(&self, session: &Session, player_id: &PlayerID, value: i64, at: DateTime<Utc>) -> Result<()> {
+        let entry = LeaderboardEntry {
+            player_id: player_id.clone(),
+            value,
+            achieved_at: at,
+            session_id: session.id.clone(),
+        };
+        let mut ruleset_ids = vec![None];
+        if let Some(id) = session.ruleset_id.as_deref() {
+            ruleset_ids.push(Some(id));
+        }
+        for ruleset_id in ruleset_ids {
+            for period in LeaderboardPeriod::ALL {
+                let board = BoardId::new(session.game_type.clone(), ruleset_id, period, at);
+                if self.boards.submit(&board, entry.clone()).await? && period == LeaderboardPeriod::AllTime {
+                    info!("New all-time best of {} on {:?}/{:?}: {}", player_id, board.game_type, ruleset_id, value);
+                }
+            }
+        }
+        if let Some(date) = session.daily_challenge {
+            // Ranked on the challenge's own day even when the attempt ends after midnight.
+            let day = date.and_time(chrono::NaiveTime::MIN).and_utc();
+            let board = BoardId::new(session.game_type.clone(), None, LeaderboardPeriod::DailyChallenge, day);
+            self.boards.submit(&board, entry).await?;
+        }
+        Ok(())
+    }
+}
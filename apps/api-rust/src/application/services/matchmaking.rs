@@ -0,0 +1,354 @@
+//! Quick-play matchmaking. Players queue per game type, optionally with a skill rating, and
+//! `tick` groups compatible players into new sessions. A full match starts as soon as enough
+//! players are available; once the longest-waiting player has used up the wait budget, the game
+//! starts with whoever is compatible, down to a solo game. Matches are never filled with bots.
+
+use crate::application::commands::{SessionConfig, StartGameSessionCommand};
+use crate::application::services::game_service::GameService;
+use crate::domain::game::{DomainError, GameSessionID, GameType, PlayerID};
+use crate::ports::Clock;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::broadcast;
+use tracing::{info, warn};
+
+// Updates buffered per subscriber before a slow connection starts missing them.
+const UPDATE_BUFFER: usize = 256;
+
+/// Tuning for the matchmaker, read from the `matchmaking` section of `config.yaml`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MatchmakingPolicy {
+    /// Players per match. Capped by the `max_players` of the rules matched sessions use.
+    pub match_size: u32,
+    /// Seconds the longest-waiting player waits for a full match before a smaller game starts.
+    pub wait_budget_secs: u32,
+    /// Largest rating difference allowed between players who have just queued.
+    pub rating_window: f64,
+    /// How much the rating window widens for every second spent waiting.
+    pub rating_window_growth: f64,
+    /// Ruleset id used for matched sessions, per game type; default rules when absent.
+    pub rulesets: HashMap<GameType, String>,
+}
+
+impl Default for MatchmakingPolicy {
+    fn default() -> Self {
+        Self {
+            match_size: 4,
+            wait_budget_secs: 30,
+            rating_window: 100.0,
+            rating_window_growth: 10.0,
+            rulesets: HashMap::new(),
+        }
+    }
+}
+
+/// Pushed to the transports for the player it names.
+#[derive(Debug, Clone)]
+pub enum MatchmakingUpdate {
+    /// Current place in the queue; `position` is 1-based and 0 once the player has left.
+    Queued {
+        player_id: PlayerID,
+        game_type: GameType,
+        position: u32,
+        queued: u32,
+        waited_seconds: u32,
+    },
+    MatchFound {
+        player_id: PlayerID,
+        session_id: GameSessionID,
+        game_type: GameType,
+        player_ids: Vec<PlayerID>,
+    },
+}
+
+impl MatchmakingUpdate {
+    pub fn player_id(&self) -> &PlayerID {
+        match self {
+            MatchmakingUpdate::Queued { player_id, .. } | MatchmakingUpdate::MatchFound { player_id, .. } => player_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Ticket {
+    player_id: PlayerID,
+    rating: Option<f64>,
+    joined_at: DateTime<Utc>,
+    // Last position reported to the player, so unchanged positions aren't pushed every tick.
+    reported: u32,
+}
+
+pub struct MatchmakingService {
+    game_service: Arc<GameService>,
+    clock: Arc<dyn Clock>,
+    policy: MatchmakingPolicy,
+    // Each queue is kept in arrival order.
+    queues: Mutex<HashMap<GameType, Vec<Ticket>>>,
+    updates: broadcast::Sender<MatchmakingUpdate>,
+}
+
+impl MatchmakingService {
+    pub fn new(game_service: Arc<GameService>, clock: Arc<dyn Clock>, policy: MatchmakingPolicy) -> Self {
+        let (updates, _) = broadcast::channel(UPDATE_BUFFER);
+        Self {
+            game_service,
+            clock,
+            policy,
+            queues: Mutex::new(HashMap::new()),
+            updates,
+        }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<MatchmakingUpdate> {
+        self.updates.subscribe()
+    }
+
+    /// Queues `player_id` for `game_type`, replacing any earlier entry in any queue.
+    pub fn join(&self, player_id: &PlayerID, game_type: GameType, rating: Option<f64>) -> Result<()> {
+        if rating.is_some_and(|r| !r.is_finite()) {
+            return Err(DomainError::InvalidPayload("rating must be a finite number".to_string()).into());
+        }
+        let mut queues = self.queues.lock().unwrap();
+        for queue in queues.values_mut() {
+            queue.retain(|t| t.player_id != *player_id);
+        }
+        let queue = queues.entry(game_type.clone()).or_default();
+        queue.push(Ticket {
+            player_id: player_id.clone(),
+            rating,
+            joined_at: self.clock.now_utc(),
+            reported: 0,
+        });
+        info!("{} joined the {:?} queue ({} waiting)", player_id, game_type, queue.len());
+        self.report_positions(&game_type, queue);
+        Ok(())
+    }
+
+    /// Removes `player_id` from whichever queue they are in. Returns false if they weren't queued.
+    pub fn leave(&self, player_id: &PlayerID) -> bool {
+        let mut queues = self.queues.lock().unwrap();
+        let Some((game_type, queue)) = queues
+            .iter_mut()
+            .find(|(_, queue)| queue.iter().any(|t| t.player_id == *player_id))
+        else {
+            return false;
+        };
+        queue.retain(|t| t.player_id != *player_id);
+        self.publish(MatchmakingUpdate::Queued {
+            player_id: player_id.clone(),
+            game_type: game_type.clone(),
+            position: 0,
+            queued: queue.len() as u32,
+            waited_seconds: 0,
+        });
+        let game_type = game_type.clone();
+        self.report_positions(&game_type, queue);
+        true
+    }
+
+    /// Forms whatever matches are ready and starts their sessions. Driven by the ticker in `main`.
+    pub async fn tick(&self) {
+        let game_types: Vec<GameType> = self.queues.lock().unwrap().keys().cloned().collect();
+        let mut sizes = HashMap::new();
+        for game_type in game_types {
+            match self.game_service.max_players(&game_type, self.session_config(&game_type)).await {
+                Ok(max_players) => {
+                    sizes.insert(game_type, self.policy.match_size.clamp(1, max_players) as usize);
+                }
+                Err(e) => warn!("Could not resolve the {:?} match size: {}", game_type, e),
+            }
+        }
+
+        let now = self.clock.now_utc();
+        let matches = {
+            let mut queues = self.queues.lock().unwrap();
+            let mut matches = Vec::new();
+            for (game_type, queue) in queues.iter_mut() {
+                if let Some(&size) = sizes.get(game_type) {
+                    while let Some(group) = take_match(&self.policy, queue, size, now) {
+                        matches.push((game_type.clone(), group));
+                    }
+                }
+                self.report_positions(game_type, queue);
+            }
+            matches
+        };
+
+        for (game_type, group) in matches {
+            if let Err(e) = self.start_match(&game_type, &group).await {
+                warn!("Could not start {:?} match for {} players: {}", game_type, group.len(), e);
+                // Put the players back at the front so they keep their place.
+                let mut queues = self.queues.lock().unwrap();
+                let queue = queues.entry(game_type).or_default();
+                queue.splice(0..0, group);
+            }
+        }
+    }
+
+    fn session_config(&self, game_type: &GameType) -> SessionConfig {
+        match self.policy.rulesets.get(game_type) {
+            Some(id) => SessionConfig::Ruleset(id.clone()),
+            None => SessionConfig::Default,
+        }
+    }
+
+    async fn start_match(&self, game_type: &GameType, group: &[Ticket]) -> Result<()> {
+        let (host, others) = group.split_first().ok_or(DomainError::InvalidCommand)?;
+        let command = StartGameSessionCommand {
+            player_id: host.player_id.clone(),
+            game_type: game_type.clone(),
+            player_ids: others.iter().map(|t| t.player_id.clone()).collect(),
+            config: self.session_config(game_type),
+            room: None,
+            seed: None,
+            daily_challenge: None,
+            question_ids: Vec::new(),
+        };
+        let session_id = self.game_service.start_game_session(command).await?;
+        info!("Matched {} players into {:?} session {}", group.len(), game_type, session_id);
+
+        let player_ids: Vec<PlayerID> = group.iter().map(|t| t.player_id.clone()).collect();
+        for player_id in &player_ids {
+            self.publish(MatchmakingUpdate::MatchFound {
+                player_id: player_id.clone(),
+                session_id: session_id.clone(),
+                game_type: game_type.clone(),
+                player_ids: player_ids.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    fn report_positions(&self, game_type: &GameType, queue: &mut [Ticket]) {
+        let now = self.clock.now_utc();
+        let queued = queue.len() as u32;
+        for (i, ticket) in queue.iter_mut().enumerate() {
+            let position = i as u32 + 1;
+            if ticket.reported == position {
+                continue;
+            }
+            ticket.reported = position;
+            self.publish(MatchmakingUpdate::Queued {
+                player_id: ticket.player_id.clone(),
+                game_type: game_type.clone(),
+                position,
+                queued,
+                waited_seconds: (now - ticket.joined_at).num_seconds().max(0) as u32,
+            });
+        }
+    }
+
+    fn publish(&self, update: MatchmakingUpdate) {
+        // No subscribers just means no one is connected right now.
+        let _ = self.updates.send(update);
+    }
+}
+
+/// Takes the next match of up to `size` players out of `queue`, anchored on the longest-waiting
+/// player. A short match is only taken once the anchor has used up the wait budget.
+fn take_match(policy: &MatchmakingPolicy, queue: &mut Vec<Ticket>, size: usize, now: DateTime<Utc>) -> Option<Vec<Ticket>> {
+    let anchor = queue.first()?;
+    let waited = (now - anchor.joined_at).num_seconds().max(0) as f64;
+    let window = policy.rating_window + policy.rating_window_growth * waited;
+
+    let picked: Vec<usize> = queue
+        .iter()
+        .enumerate()
+        .filter(|(_, t)| match (anchor.rating, t.rating) {
+            (Some(a), Some(b)) => (a - b).abs() <= window,
+            _ => true,
+        })
+        .map(|(i, _)| i)
+        .take(size)
+        .collect();
+
+    if picked.len() < size && waited < policy.wait_budget_secs as f64 {
+        return None;
+    }
+    let mut group = Vec::with_capacity(picked.len());
+    for i in picked.into_iter().rev() {
+        group.push(queue.remove(i));
+    }
+    group.reverse();
+    Some(group)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap()
+    }
+
+    fn ticket(player: &str, rating: Option<f64>, joined_secs: i64) -> Ticket {
+        Ticket {
+            player_id: player.to_string(),
+            rating,
+            joined_at: start() + Duration::seconds(joined_secs),
+            reported: 0,
+        }
+    }
+
+    fn players(group: &[Ticket]) -> Vec<&str> {
+        group.iter().map(|t| t.player_id.as_str()).collect()
+    }
+
+    #[test]
+    fn full_match_starts_at_once_with_compatible_players() {
+        let policy = MatchmakingPolicy::default();
+        let mut queue = vec![
+            ticket("a", Some(1500.0), 0),
+            ticket("far", Some(1800.0), 0),
+            ticket("b", Some(1550.0), 0),
+        ];
+        let group = take_match(&policy, &mut queue, 2, start()).unwrap();
+        assert_eq!(players(&group), ["a", "b"]);
+        assert_eq!(players(&queue), ["far"]);
+    }
+
+    #[test]
+    fn short_match_waits_for_the_budget() {
+        let policy = MatchmakingPolicy::default();
+        let mut queue = vec![ticket("a", Some(1500.0), 0), ticket("b", Some(1520.0), 5)];
+        let budget = policy.wait_budget_secs as i64;
+        assert!(take_match(&policy, &mut queue, 4, start() + Duration::seconds(budget - 1)).is_none());
+        let group = take_match(&policy, &mut queue, 4, start() + Duration::seconds(budget)).unwrap();
+        assert_eq!(players(&group), ["a", "b"]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn rating_window_widens_while_waiting() {
+        let policy = MatchmakingPolicy::default();
+        // 150 apart: outside the 100 window at first, inside once it has grown by 10/s for 5s.
+        let mut queue = vec![ticket("a", Some(1500.0), 0), ticket("b", Some(1650.0), 0)];
+        assert!(take_match(&policy, &mut queue, 2, start() + Duration::seconds(4)).is_none());
+        let group = take_match(&policy, &mut queue, 2, start() + Duration::seconds(5)).unwrap();
+        assert_eq!(players(&group), ["a", "b"]);
+    }
+
+    #[test]
+    fn unrated_players_match_anyone() {
+        let policy = MatchmakingPolicy::default();
+        let mut queue = vec![ticket("a", None, 0), ticket("b", Some(2400.0), 0)];
+        assert_eq!(players(&take_match(&policy, &mut queue, 2, start()).unwrap()), ["a", "b"]);
+    }
+
+    #[test]
+    fn anchor_plays_solo_after_the_budget_when_no_one_fits() {
+        let policy = MatchmakingPolicy {
+            rating_window_growth: 0.0,
+            ..MatchmakingPolicy::default()
+        };
+        let mut queue = vec![ticket("a", Some(1000.0), 0), ticket("b", Some(2000.0), 0)];
+        let after_budget = start() + Duration::seconds(policy.wait_budget_secs as i64);
+        assert_eq!(players(&take_match(&policy, &mut queue, 2, after_budget).unwrap()), ["a"]);
+        assert_eq!(players(&queue), ["b"]);
+    }
+}
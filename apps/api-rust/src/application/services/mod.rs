@@ -1,3 +1,12 @@
+pub mod achievement;
+pub mod anti_cheat;
 pub mod command_registry;
+pub mod daily;
 pub mod engine_factory;
-pub mod game_service;
\ No newline at end of file
+pub mod game_service;
+pub mod leaderboard;
+pub mod matchmaking;
+pub mod profile;
+pub mod question_import;
+pub mod rating;
+pub mod replay;
\ No newline at end of file
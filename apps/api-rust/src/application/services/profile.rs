@@ -0,0 +1,213 @@
+//! Player profiles and lifetime stats. Stats are not written by the engines: `run` follows the
+//! event bus and folds finished games into each player's totals, then announces each counted game
+//! on `subscribe_games` for anything that keys off those totals.
+
+use crate::application::services::game_service::GameService;
+use crate::domain::game::{DomainEvent, GameSessionID, GameType, PlayerID};
+use crate::domain::profile::{GameStats, PlayerProfile, PlayerStats, Preferences};
+use crate::domain::puzzle::{PuzzleSolved, RaceFinished};
+use crate::domain::rules::{GameRules, PuzzleMode, TriviaCategory};
+use crate::domain::trivia::{AnswerAccepted, QuestionRevealed, TriviaFinished};
+use crate::ports::{Clock, ProfileRepository};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use std::any::Any;
+use std::sync::Arc;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tracing::warn;
+
+/// Fields a player may change on their own profile. Unset fields are left as they are.
+#[derive(Debug, Clone, Default)]
+pub struct ProfileUpdate {
+    pub display_name: Option<String>,
+    /// `Some(None)` removes the avatar.
+    pub avatar_url: Option<Option<String>>,
+    pub preferences: Option<Preferences>,
+}
+
+// Counted games buffered per subscriber before a slow one starts missing them.
+const GAMES_BUFFER: usize = 256;
+
+/// A finished game, once it has been counted in the player's stats.
+#[derive(Debug, Clone)]
+pub struct GameCounted {
+    pub player_id: PlayerID,
+    pub session_id: GameSessionID,
+    pub finished_at: DateTime<Utc>,
+    /// The player's stats with the game included.
+    pub stats: PlayerStats,
+}
+
+pub struct ProfileService {
+    profiles: Arc<dyn ProfileRepository>,
+    game_service: Arc<GameService>,
+    clock: Arc<dyn Clock>,
+    // Category of the question each trivia session is currently asking, so answers can be
+    // counted against it.
+    trivia_categories: DashMap<GameSessionID, TriviaCategory>,
+    games: broadcast::Sender<GameCounted>,
+}
+
+impl ProfileService {
+    pub fn new(profiles: Arc<dyn ProfileRepository>, game_service: Arc<GameService>, clock: Arc<dyn Clock>) -> Self {
+        let (games, _) = broadcast::channel(GAMES_BUFFER);
+        Self {
+            profiles,
+            game_service,
+            clock,
+            trivia_categories: DashMap::new(),
+            games,
+        }
+    }
+
+    pub fn subscribe_games(&self) -> broadcast::Receiver<GameCounted> {
+        self.games.subscribe()
+    }
+
+    /// The stored profile, or the default one for players who never saved theirs.
+    pub async fn profile(&self, player_id: &PlayerID) -> Result<PlayerProfile> {
+        Ok(match self.profiles.get_profile(player_id).await? {
+            Some(profile) => profile,
+            None => PlayerProfile::new(player_id, self.clock.now_utc()),
+        })
+    }
+
+    pub async fn update_profile(&self, player_id: &PlayerID, update: ProfileUpdate) -> Result<PlayerProfile> {
+        let mut profile = self.profile(player_id).await?;
+        if let Some(display_name) = update.display_name {
+            profile.display_name = display_name.trim().to_string();
+        }
+        if let Some(avatar_url) = update.avatar_url {
+            profile.avatar_url = avatar_url;
+        }
+        if let Some(preferences) = update.preferences {
+            profile.preferences = preferences;
+        }
+        profile.validate()?;
+        profile.updated_at = self.clock.now_utc();
+        self.profiles.save_profile(profile.clone()).await?;
+        Ok(profile)
+    }
+
+    pub async fn stats(&self, player_id: &PlayerID) -> Result<PlayerStats> {
+        Ok(self
+            .profiles
+            .get_stats(player_id)
+            .await?
+            .unwrap_or_else(|| PlayerStats::new(player_id)))
+    }
+
+    /// Records stats from `events` until the bus closes. Spawned once from `main`.
+    pub async fn run(&self, mut events: broadcast::Receiver<Box<dyn DomainEvent>>) {
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    if let Err(e) = self.record(event.as_ref()).await {
+                        warn!("Could not record stats for {}: {}", event.event_type(), e);
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => warn!("Stats missed {} events", skipped),
+                Err(RecvError::Closed) => break,
+            }
+        }
+    }
+
+    async fn record(&self, event: &dyn DomainEvent) -> Result<()> {
+        let event = event as &dyn Any;
+        if let Some(e) = event.downcast_ref::<QuestionRevealed>() {
+            self.trivia_categories.insert(e.session_id.clone(), e.category);
+        } else if let Some(e) = event.downcast_ref::<AnswerAccepted>() {
+            let Some(category) = self.trivia_categories.get(&e.session_id).map(|c| *c) else {
+                return Ok(());
+            };
+            self.update_stats(&e.player_id, &GameType::Trivia, |stats| {
+                stats.record_answer(category, e.correct)
+            })
+            .await?;
+        } else if let Some(e) = event.downcast_ref::<TriviaFinished>() {
+            self.trivia_categories.remove(&e.session_id);
+            let contested = e.scores.len() >= 2;
+            for score in &e.scores {
+                let stats = self
+                    .update_stats(&score.player_id, &GameType::Trivia, |stats| {
+                        stats.games_played += 1;
+                        if contested && score.rank == 1 {
+                            stats.wins += 1;
+                        }
+                    })
+                    .await?;
+                self.counted(&e.session_id, e.meta.at, stats);
+            }
+        } else if let Some(e) = event.downcast_ref::<PuzzleSolved>() {
+            self.record_puzzle_solved(e).await?;
+        } else if let Some(e) = event.downcast_ref::<RaceFinished>() {
+            let contested = e.standings.len() >= 2;
+            for standing in &e.standings {
+                let stats = self
+                    .update_stats(&standing.player_id, &GameType::Puzzle, |stats| {
+                        stats.games_played += 1;
+                        if contested && standing.finished && standing.rank == 1 {
+                            stats.wins += 1;
+                        }
+                    })
+                    .await?;
+                self.counted(&e.session_id, e.meta.at, stats);
+            }
+        }
+        Ok(())
+    }
+
+    /// In turn-based games the solve ends the game for everyone at the table; in a race only
+    /// the solver's time is recorded here and the game is counted once `RaceFinished` arrives.
+    async fn record_puzzle_solved(&self, e: &PuzzleSolved) -> Result<()> {
+        let Some(session) = self.game_service.get_session(&e.session_id).await? else {
+            return Ok(());
+        };
+        let race = matches!(&session.rules, GameRules::Puzzle(rules) if rules.mode == PuzzleMode::Race);
+        if race {
+            self.update_stats(&e.player_id, &GameType::Puzzle, |stats| stats.record_puzzle_time(e.elapsed_ms))
+                .await?;
+            return Ok(());
+        }
+        let contested = session.players.len() >= 2;
+        for player in &session.players {
+            let solver = player.id == e.player_id;
+            let stats = self
+                .update_stats(&player.id, &GameType::Puzzle, |stats| {
+                    stats.games_played += 1;
+                    if solver {
+                        stats.record_puzzle_time(e.elapsed_ms);
+                        if contested {
+                            stats.wins += 1;
+                        }
+                    }
+                })
+                .await?;
+            self.counted(&e.session_id, e.meta.at, stats);
+        }
+        Ok(())
+    }
+
+    async fn update_stats(
+        &self,
+        player_id: &PlayerID,
+        game_type: &GameType,
+        apply: impl FnOnce(&mut GameStats),
+    ) -> Result<PlayerStats> {
+        let mut stats = self.stats(player_id).await?;
+        apply(stats.game_mut(game_type));
+        self.profiles.save_stats(stats.clone()).await?;
+        Ok(stats)
+    }
+
+    fn counted(&self, session_id: &GameSessionID, finished_at: DateTime<Utc>, stats: PlayerStats) {
+        // No subscribers just means nothing is listening for counted games.
+        let _ = self.games.send(GameCounted {
+            player_id: stats.player_id.clone(),
+            session_id: session_id.clone(),
+            finished_at,
+            stats,
+        });
+    }
+}
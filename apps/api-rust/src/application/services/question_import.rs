@@ -0,0 +1,504 @@
+use crate::domain::question::{normalize_answer, Question, QuestionFormat};
+use crate::domain::rules::TriviaCategory;
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::fmt;
+use std::path::Path;
+
+// Separator for list-valued CSV columns (options, aliases).
+const CSV_LIST_SEPARATOR: char = '|';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    Csv,
+    Json,
+    /// The `{"response_code": 0, "results": [...]}` shape returned by opentdb.com.
+    OpenTdb,
+}
+
+impl ImportFormat {
+    /// Picks a format from the file extension and, for JSON files, the top-level shape.
+    pub fn detect(path: &Path, contents: &str) -> Result<Self> {
+        match path.extension().and_then(|e| e.to_str()).map(str::to_lowercase).as_deref() {
+            Some("csv") => Ok(ImportFormat::Csv),
+            Some("json") if contents.trim_start().starts_with('{') => Ok(ImportFormat::OpenTdb),
+            Some("json") => Ok(ImportFormat::Json),
+            _ => bail!("cannot tell the format of {}; pass --format", path.display()),
+        }
+    }
+}
+
+/// One question as written by content authors, before validation. CSV rows and JSON
+/// objects share these field names; in CSV, `options` and `aliases` are `|`-separated.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct QuestionRecord {
+    pub id: Option<String>,
+    pub category: String,
+    /// `free_text` (default), `multiple_choice`, `true_false` or `numeric`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub prompt: String,
+    /// For multiple choice, the text of the correct option.
+    pub answer: String,
+    pub options: Vec<String>,
+    pub aliases: Vec<String>,
+    pub tolerance: Option<f64>,
+    pub hint: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct CsvRecord {
+    id: Option<String>,
+    category: String,
+    #[serde(rename = "type")]
+    kind: String,
+    prompt: String,
+    answer: String,
+    options: String,
+    aliases: String,
+    tolerance: Option<f64>,
+    hint: Option<String>,
+}
+
+impl From<CsvRecord> for QuestionRecord {
+    fn from(row: CsvRecord) -> Self {
+        let list = |value: &str| {
+            value
+                .split(CSV_LIST_SEPARATOR)
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+                .collect()
+        };
+        Self {
+            id: row.id.filter(|id| !id.trim().is_empty()),
+            category: row.category,
+            kind: row.kind,
+            prompt: row.prompt,
+            answer: row.answer,
+            options: list(&row.options),
+            aliases: list(&row.aliases),
+            tolerance: row.tolerance,
+            hint: row.hint.filter(|h| !h.trim().is_empty()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenTdbDump {
+    #[serde(default)]
+    response_code: i32,
+    results: Vec<OpenTdbQuestion>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenTdbQuestion {
+    #[serde(rename = "type")]
+    kind: String,
+    category: String,
+    question: String,
+    correct_answer: String,
+    #[serde(default)]
+    incorrect_answers: Vec<String>,
+}
+
+impl From<OpenTdbQuestion> for QuestionRecord {
+    fn from(q: OpenTdbQuestion) -> Self {
+        let (kind, options) = match q.kind.as_str() {
+            "boolean" => ("true_false", Vec::new()),
+            _ => (
+                "multiple_choice",
+                std::iter::once(q.correct_answer.clone()).chain(q.incorrect_answers).collect(),
+            ),
+        };
+        Self {
+            id: None,
+            category: q.category,
+            kind: kind.to_string(),
+            prompt: q.question,
+            answer: q.correct_answer,
+            options,
+            ..Default::default()
+        }
+    }
+}
+
+/// Parses `contents` into records, each paired with where it came from for error reporting.
+pub fn parse(format: ImportFormat, source: &str, contents: &str) -> Result<Vec<(String, QuestionRecord)>> {
+    let records: Vec<QuestionRecord> = match format {
+        ImportFormat::Csv => {
+            let mut reader = csv::ReaderBuilder::new().trim(csv::Trim::All).from_reader(contents.as_bytes());
+            return reader
+                .deserialize::<CsvRecord>()
+                .enumerate()
+                .map(|(i, row)| {
+                    // Line 1 is the header.
+                    let location = format!("{}:{}", source, i + 2);
+                    row.map(|r| (location.clone(), r.into()))
+                        .with_context(|| format!("{}: malformed row", location))
+                })
+                .collect();
+        }
+        ImportFormat::Json => serde_json::from_str(contents).with_context(|| format!("{}: invalid JSON", source))?,
+        ImportFormat::OpenTdb => {
+            let dump: OpenTdbDump =
+                serde_json::from_str(contents).with_context(|| format!("{}: invalid Open Trivia DB dump", source))?;
+            if dump.response_code != 0 {
+                bail!("{}: Open Trivia DB dump has response_code {}", source, dump.response_code);
+            }
+            dump.results.into_iter().map(QuestionRecord::from).collect()
+        }
+    };
+    Ok(records
+        .into_iter()
+        .enumerate()
+        .map(|(i, record)| (format!("{}#{}", source, i + 1), record))
+        .collect())
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportIssue {
+    pub location: String,
+    pub reason: String,
+}
+
+/// Outcome of validating a batch. Only `accepted` questions should reach the store.
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    pub accepted: Vec<Question>,
+    pub rejected: Vec<ImportIssue>,
+    /// Accepted questions whose id already exists in the store and will be overwritten.
+    pub replaced: usize,
+}
+
+impl fmt::Display for ImportReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} accepted ({} replacing stored questions), {} rejected",
+            self.accepted.len(),
+            self.replaced,
+            self.rejected.len()
+        )?;
+        for issue in &self.rejected {
+            writeln!(f, "  {}: {}", issue.location, issue.reason)?;
+        }
+        Ok(())
+    }
+}
+
+/// Validates `records` against each other and against the questions already `stored`.
+///
+/// A record whose prompt matches another question in the same batch, or a stored question
+/// with a different id, is rejected as a duplicate. Reusing a stored id replaces that question.
+pub fn validate(records: Vec<(String, QuestionRecord)>, stored: &[Question]) -> ImportReport {
+    let stored_ids: HashSet<&str> = stored.iter().map(|q| q.id.as_str()).collect();
+    let mut report = ImportReport::default();
+    let mut batch_ids = HashSet::new();
+    let mut batch_prompts = HashSet::new();
+
+    for (location, record) in records {
+        let question = match to_question(record) {
+            Ok(q) => q,
+            Err(reason) => {
+                report.rejected.push(ImportIssue { location, reason });
+                continue;
+            }
+        };
+
+        let prompt_key = normalize_answer(&question.prompt);
+        let duplicate = if !batch_ids.insert(question.id.clone()) {
+            Some(format!("duplicate id '{}' in this import", question.id))
+        } else if !batch_prompts.insert(prompt_key.clone()) {
+            Some("duplicate prompt in this import".to_string())
+        } else {
+            stored
+                .iter()
+                .find(|q| q.id != question.id && normalize_answer(&q.prompt) == prompt_key)
+                .map(|q| format!("same prompt as stored question '{}'", q.id))
+        };
+        if let Some(reason) = duplicate {
+            report.rejected.push(ImportIssue { location, reason });
+            continue;
+        }
+
+        if stored_ids.contains(question.id.as_str()) {
+            report.replaced += 1;
+        }
+        report.accepted.push(question);
+    }
+
+    report
+}
+
+fn to_question(record: QuestionRecord) -> Result<Question, String> {
+    let decode = |text: &str| html_escape::decode_html_entities(text.trim()).into_owned();
+
+    let label = decode(&record.category);
+    let category = TriviaCategory::parse(&label).ok_or_else(|| format!("unknown category '{}'", label))?;
+    let prompt = decode(&record.prompt);
+    if prompt.is_empty() {
+        return Err("missing prompt".to_string());
+    }
+    let answer = decode(&record.answer);
+    if answer.is_empty() {
+        return Err("missing answer".to_string());
+    }
+
+    let format = match normalize_answer(&record.kind).replace([' ', '-'], "_").as_str() {
+        "" | "free_text" | "text" => QuestionFormat::FreeText {
+            answer,
+            aliases: record.aliases.iter().map(|a| decode(a)).filter(|a| !a.is_empty()).collect(),
+        },
+        "multiple_choice" | "multiple" => {
+            let options: Vec<String> = record.options.iter().map(|o| decode(o)).collect();
+            if options.len() < 2 {
+                return Err("multiple choice needs at least two options".to_string());
+            }
+            if options.iter().any(String::is_empty) {
+                return Err("empty option".to_string());
+            }
+            let keys: HashSet<String> = options.iter().map(|o| normalize_answer(o)).collect();
+            if keys.len() != options.len() {
+                return Err("duplicate options".to_string());
+            }
+            let correct = options
+                .iter()
+                .position(|o| normalize_answer(o) == normalize_answer(&answer))
+                .ok_or_else(|| format!("answer '{}' is not one of the options", answer))?;
+            QuestionFormat::MultipleChoice { options, correct }
+        }
+        "true_false" | "boolean" => {
+            let answer = match normalize_answer(&answer).as_str() {
+                "true" => true,
+                "false" => false,
+                other => return Err(format!("'{}' is not true or false", other)),
+            };
+            QuestionFormat::TrueFalse { answer }
+        }
+        "numeric" | "number" => {
+            let value = answer.parse::<f64>().map_err(|_| format!("'{}' is not a number", answer))?;
+            let tolerance = record.tolerance.unwrap_or(0.0);
+            if !value.is_finite() || !tolerance.is_finite() || tolerance < 0.0 {
+                return Err("numeric answers need a finite value and a non-negative tolerance".to_string());
+            }
+            QuestionFormat::Numeric { value, tolerance }
+        }
+        other => return Err(format!("unknown question type '{}'", other)),
+    };
+
+    let id = match record.id.map(|id| id.trim().to_string()).filter(|id| !id.is_empty()) {
+        Some(id) => id,
+        None => derived_id(category, &prompt),
+    };
+
+    Ok(Question {
+        id,
+        category,
+        prompt,
+        format,
+        hint: record.hint.map(|h| decode(&h)).filter(|h| !h.is_empty()),
+    })
+}
+
+// Sources like Open Trivia DB carry no ids. Deriving one from the content keeps re-imports of
+// the same dump idempotent instead of piling up copies.
+fn derived_id(category: TriviaCategory, prompt: &str) -> String {
+    // FNV-1a: stable across builds, unlike std's DefaultHasher.
+    let key = format!("{:?}:{}", category, normalize_answer(prompt));
+    let hash = key
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |h, b| (h ^ b as u64).wrapping_mul(0x0100_0000_01b3));
+    format!("q-{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CSV: &str = "\
+id,category,type,prompt,answer,options,aliases,tolerance,hint
+geo-1,General Knowledge,free_text,What is the capital of France?,Paris,,Paree|Paris France,,
+sci-1,science,multiple_choice,Which planet is largest?,Jupiter,Mars | Venus | Jupiter,,,
+sci-2,Science,numeric,What is g in m/s²?,9.81,,,0.05,Round to two places
+";
+
+    const OPEN_TDB: &str = r#"{
+        "response_code": 0,
+        "results": [
+            {
+                "type": "multiple",
+                "category": "Science: Computers",
+                "question": "What does &quot;HTML&quot; stand for?",
+                "correct_answer": "Hypertext Markup Language",
+                "incorrect_answers": ["Hyperlink &amp; Text Markup Language", "Home Tool Markup Language", "Hightext Machine Language"]
+            },
+            {
+                "type": "boolean",
+                "category": "History",
+                "question": "The Berlin Wall fell in 1989.",
+                "correct_answer": "True",
+                "incorrect_answers": ["False"]
+            }
+        ]
+    }"#;
+
+    fn import(format: ImportFormat, contents: &str, stored: &[Question]) -> ImportReport {
+        validate(parse(format, "fixture", contents).unwrap(), stored)
+    }
+
+    fn record(prompt: &str, answer: &str) -> (String, QuestionRecord) {
+        (
+            format!("fixture:{}", prompt),
+            QuestionRecord {
+                category: "History".to_string(),
+                prompt: prompt.to_string(),
+                answer: answer.to_string(),
+                ..Default::default()
+            },
+        )
+    }
+
+    fn reasons(report: &ImportReport) -> Vec<&str> {
+        report.rejected.iter().map(|issue| issue.reason.as_str()).collect()
+    }
+
+    #[test]
+    fn csv_rows_become_questions() {
+        let report = import(ImportFormat::Csv, CSV, &[]);
+        assert!(report.rejected.is_empty(), "{}", report);
+        let [capital, planet, gravity] = report.accepted.as_slice() else {
+            panic!("expected three questions, got {}", report);
+        };
+        assert_eq!(capital.category, TriviaCategory::GeneralKnowledge);
+        assert!(capital.check("paree").unwrap());
+        assert_eq!(planet.choices(), ["Mars", "Venus", "Jupiter"]);
+        assert!(planet.check("2").unwrap());
+        assert!(gravity.check("9.78").unwrap());
+        assert_eq!(gravity.hint.as_deref(), Some("Round to two places"));
+    }
+
+    #[test]
+    fn open_trivia_db_dumps_are_decoded() {
+        let report = import(ImportFormat::OpenTdb, OPEN_TDB, &[]);
+        assert!(report.rejected.is_empty(), "{}", report);
+        let [html, wall] = report.accepted.as_slice() else {
+            panic!("expected two questions, got {}", report);
+        };
+        assert_eq!(html.category, TriviaCategory::Science);
+        assert_eq!(html.prompt, "What does \"HTML\" stand for?");
+        assert_eq!(html.choices()[1], "Hyperlink & Text Markup Language");
+        assert!(html.check("0").unwrap());
+        assert_eq!(wall.category, TriviaCategory::History);
+        assert!(wall.check("true").unwrap());
+    }
+
+    #[test]
+    fn reimporting_a_dump_derives_the_same_ids() {
+        let first = import(ImportFormat::OpenTdb, OPEN_TDB, &[]);
+        let second = import(ImportFormat::OpenTdb, OPEN_TDB, &first.accepted);
+        assert!(second.rejected.is_empty(), "{}", second);
+        assert_eq!(second.replaced, 2);
+        let ids = |report: &ImportReport| report.accepted.iter().map(|q| q.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&first), ids(&second));
+    }
+
+    #[test]
+    fn failed_dumps_are_refused() {
+        let error = parse(ImportFormat::OpenTdb, "fixture", r#"{"response_code": 1, "results": []}"#).unwrap_err();
+        assert!(error.to_string().contains("response_code 1"), "{}", error);
+    }
+
+    #[test]
+    fn duplicates_are_rejected_within_the_batch_and_against_the_store() {
+        let stored = import(ImportFormat::Csv, CSV, &[]).accepted;
+        let mut same_id = record("Who built the pyramids?", "Egyptians");
+        same_id.1.id = Some("h-1".to_string());
+        let mut id_reused = record("Who crossed the Alps with elephants?", "Hannibal");
+        id_reused.1.id = Some("h-1".to_string());
+        let mut replacement = record("What is the capital of France?", "Paris");
+        replacement.1.id = Some("geo-1".to_string());
+
+        let report = validate(
+            vec![
+                same_id,
+                id_reused,
+                record("Who  built the PYRAMIDS?", "Egyptians"),
+                record("which planet is LARGEST?", "Jupiter"),
+                replacement,
+            ],
+            &stored,
+        );
+        assert_eq!(
+            reasons(&report),
+            [
+                "duplicate id 'h-1' in this import",
+                "duplicate prompt in this import",
+                "same prompt as stored question 'sci-1'",
+            ]
+        );
+        assert_eq!(report.accepted.len(), 2);
+        assert_eq!(report.replaced, 1);
+    }
+
+    #[test]
+    fn records_without_a_usable_answer_are_rejected() {
+        let mut wrong_option = record("Which planet is red?", "Pluto");
+        wrong_option.1.kind = "multiple_choice".to_string();
+        wrong_option.1.options = vec!["Mars".to_string(), "Venus".to_string()];
+        let mut one_option = record("Which planet is blue?", "Neptune");
+        one_option.1.kind = "multiple".to_string();
+        one_option.1.options = vec!["Neptune".to_string()];
+        let mut not_boolean = record("Is the sky green?", "maybe");
+        not_boolean.1.kind = "boolean".to_string();
+        let mut not_numeric = record("How many moons has Mars?", "two");
+        not_numeric.1.kind = "numeric".to_string();
+
+        let report = validate(
+            vec![record("Who was the first emperor of Rome?", "  "), wrong_option, one_option, not_boolean, not_numeric],
+            &[],
+        );
+        assert!(report.accepted.is_empty());
+        assert_eq!(
+            reasons(&report),
+            [
+                "missing answer",
+                "answer 'Pluto' is not one of the options",
+                "multiple choice needs at least two options",
+                "'maybe' is not true or false",
+                "'two' is not a number",
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_categories_are_rejected() {
+        let mut sports = record("Who won the 1966 World Cup?", "England");
+        sports.1.category = "Sports".to_string();
+        let mut blank = record("Who painted the Mona Lisa?", "Leonardo");
+        blank.1.category = String::new();
+        let report = validate(vec![sports, blank], &[]);
+        assert_eq!(reasons(&report), ["unknown category 'Sports'", "unknown category ''"]);
+    }
+
+    #[test]
+    fn category_labels_fold_into_ours() {
+        let cases = [
+            ("GeneralKnowledge", Some(TriviaCategory::GeneralKnowledge)),
+            ("general_knowledge", Some(TriviaCategory::GeneralKnowledge)),
+            ("General Knowledge", Some(TriviaCategory::GeneralKnowledge)),
+            ("Science & Nature", Some(TriviaCategory::Science)),
+            ("Science: Computers", Some(TriviaCategory::Science)),
+            ("HISTORY", Some(TriviaCategory::History)),
+            ("Entertainment: Film", None),
+            ("Sciences", None),
+            ("", None),
+        ];
+        for (label, expected) in cases {
+            assert_eq!(TriviaCategory::parse(label), expected, "{}", label);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! Skill ratings. `run` follows the event bus, turns every finished multiplayer trivia game or
+//! puzzle race into a `GameOutcome` and updates the Glicko-2 rating of each player in it.
+
+use crate::application::services::game_service::GameService;
+use crate::domain::game::{DomainEvent, GameType, PlayerID};
+use crate::domain::outcome::GameOutcome;
+use crate::domain::puzzle::RaceFinished;
+use crate::domain::rating::{PlayerRatings, RatingPolicy, SkillRating};
+use crate::domain::trivia::TriviaFinished;
+use crate::ports::ProfileRepository;
+use anyhow::Result;
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tracing::{info, warn};
+
+pub struct RatingService {
+    profiles: Arc<dyn ProfileRepository>,
+    game_service: Arc<GameService>,
+    policy: RatingPolicy,
+}
+
+impl RatingService {
+    pub fn new(profiles: Arc<dyn ProfileRepository>, game_service: Arc<GameService>, policy: RatingPolicy) -> Self {
+        Self {
+            profiles,
+            game_service,
+            policy,
+        }
+    }
+
+    pub async fn ratings(&self, player_id: &PlayerID) -> Result<PlayerRatings> {
+        Ok(self
+            .profiles
+            .get_ratings(player_id)
+            .await?
+            .unwrap_or_else(|| PlayerRatings::new(player_id)))
+    }
+
+    /// The player's rating in `game_type`, or the starting rating if they have none yet.
+    pub async fn rating(&self, player_id: &PlayerID, game_type: &GameType) -> Result<SkillRating> {
+        Ok(self
+            .ratings(player_id)
+            .await?
+            .games
+            .remove(game_type)
+            .unwrap_or_else(|| self.policy.unrated()))
+    }
+
+    /// Rates games from `events` until the bus closes. Spawned once from `main`.
+    pub async fn run(&self, mut events: broadcast::Receiver<Box<dyn DomainEvent>>) {
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    if let Err(e) = self.record(event.as_ref()).await {
+                        warn!("Could not rate {}: {}", event.event_type(), e);
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => warn!("Ratings missed {} events", skipped),
+                Err(RecvError::Closed) => break,
+            }
+        }
+    }
+
+    async fn record(&self, event: &dyn DomainEvent) -> Result<()> {
+        // Turn-based puzzles are played together against the board, so only these are rated.
+        let finished = event as &dyn Any;
+        if !(finished.is::<TriviaFinished>() || finished.is::<RaceFinished>()) {
+            return Ok(());
+        }
+        let Some(session) = self.game_service.get_session(event.session_id()).await? else {
+            return Ok(());
+        };
+        let Some(outcome) = GameOutcome::from_events(&session, [event]) else {
+            return Ok(());
+        };
+        if !outcome.is_contested() {
+            return Ok(());
+        }
+        self.rate(&outcome).await
+    }
+
+    async fn rate(&self, outcome: &GameOutcome) -> Result<()> {
+        let mut stored = HashMap::with_capacity(outcome.placements.len());
+        let mut before = HashMap::with_capacity(outcome.placements.len());
+        for placement in &outcome.placements {
+            let ratings = self.ratings(&placement.player_id).await?;
+            if let Some(rating) = ratings.games.get(&outcome.game_type) {
+                before.insert(placement.player_id.clone(), rating.clone());
+            }
+            stored.insert(placement.player_id.clone(), ratings);
+        }
+
+        for (player_id, rating) in self.policy.rate(outcome, &before) {
+            let Some(mut ratings) = stored.remove(&player_id) else {
+                continue;
+            };
+            info!(
+                "{} {:?} rating {:.0} -> {:.0} after {}",
+                player_id,
+                outcome.game_type,
+                before.get(&player_id).map_or(self.policy.initial_rating, |r| r.rating),
+                rating.rating,
+                outcome.session_id
+            );
+            ratings.games.insert(outcome.game_type.clone(), rating);
+            self.profiles.save_ratings(ratings).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,330 @@
+//! Replay export and verification. `export` packs a finished session's recording into a
+//! `Replay`; `verify` plays one back through fresh engines, step by step, and reports the first
+//! difference.
+
+use crate::adapters::outbound::clock::StepClock;
+use crate::adapters::outbound::question_repo::MemoryQuestionRepo;
+use crate::adapters::outbound::rng::SystemRng;
+use crate::application::services::command_registry::CommandRegistry;
+use crate::application::services::engine_factory::DefaultEngineFactory;
+use crate::application::services::game_service::GameService;
+use crate::domain::game::{DomainError, Engine, GameEngineFactory, PlayerID, Role, SessionStatus};
+use crate::domain::replay::{
+    RecordedEvent, Recording, Replay, ReplayMismatch, ReplayReport, StepInput, REPLAY_VERSION,
+};
+use crate::domain::scoring::ScoringPolicy;
+use crate::ports::Clock;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct ReplayService {
+    game_service: Arc<GameService>,
+    clock: Arc<dyn Clock>,
+    scoring: ScoringPolicy,
+}
+
+impl ReplayService {
+    pub fn new(
+        game_service: Arc<GameService>,
+        clock: Arc<dyn Clock>,
+        scoring: ScoringPolicy,
+    ) -> Self {
+        Self {
+            game_service,
+            clock,
+            scoring,
+        }
+    }
+
+    /// Replay of `game_id`, for one of its players or spectators. Only finished games can be
+    /// exported, while their recording is kept.
+    pub async fn export(&self, game_id: &str, viewer: &PlayerID) -> Result<Replay> {
+        let mut session = self
+            .game_service
+            .get_session(game_id)
+            .await?
+            .ok_or_else(|| DomainError::SessionNotFound(game_id.to_string()))?;
+        if session.role_of(viewer).is_none() {
+            return Err(DomainError::NotAPlayer(viewer.clone()).into());
+        }
+        if session.seed.is_none() {
+            return Err(DomainError::NotReplayable("the session was created without a seed".to_string()).into());
+        }
+        if !matches!(session.status, SessionStatus::Ended | SessionStatus::Cancelled) {
+            return Err(DomainError::NotReplayable("the game is still running".to_string()).into());
+        }
+        let Recording { mut steps, final_state } = self
+            .game_service
+            .recording(game_id)
+            .await?
+            .ok_or_else(|| DomainError::NotReplayable("the game's recording is no longer kept".to_string()))?;
+
+        // The engine asks exactly the questions it drew at the start, in that order.
+        let questions = match steps.first_mut().map(|step| &mut step.input) {
+            Some(StepInput::Start { questions, .. }) => std::mem::take(questions),
+            _ => Vec::new(),
+        };
+        if !questions.is_empty() {
+            session.question_ids = questions.iter().map(|q| q.id.clone()).collect();
+        }
+        // Nothing in the room is needed to play the game, and its password hash must not leak.
+        session.room = None;
+        session.spectators.clear();
+
+        Ok(Replay {
+            version: REPLAY_VERSION,
+            session,
+            scoring: self.scoring.clone(),
+            questions,
+            steps,
+            final_state,
+            exported_at: self.clock.now_utc(),
+        })
+    }
+}
+
+/// Plays `replay` back and compares every step's events, then the final state, with the
+/// recording. Fails only for replays that can't be played at all.
+pub async fn verify(replay: &Replay) -> Result<ReplayReport> {
+    if replay.version != REPLAY_VERSION {
+        return Err(DomainError::InvalidPayload(format!(
+            "replay version {} is not supported (expected {})",
+            replay.version, REPLAY_VERSION
+        ))
+        .into());
+    }
+    let seed = replay
+        .session
+        .seed
+        .ok_or_else(|| DomainError::NotReplayable("the replay has no seed".to_string()))?;
+    let game_type = replay.session.game_type.clone();
+
+    let factory = Arc::new(DefaultEngineFactory::new(
+        Arc::new(SystemRng::new()),
+        Arc::new(MemoryQuestionRepo::new(replay.questions.clone())),
+        replay.scoring.clone(),
+    ));
+    let registry = CommandRegistry::new(factory.clone());
+    let clock = Arc::new(StepClock::new(replay.exported_at));
+    let mut engine: Option<Box<dyn Engine>> = None;
+
+    for (i, step) in replay.steps.iter().enumerate() {
+        clock.set(step.at);
+        let events = match (&step.input, engine.as_mut()) {
+            (StepInput::Start { players, .. }, None) => {
+                let mut session = replay.session.clone();
+                session.players = players.clone();
+                let mut started = factory.create_engine(&session.rules, Some(seed), clock.clone());
+                let events = started.start(&session).await;
+                engine = Some(started);
+                events
+            }
+            (StepInput::Tick, Some(engine)) => Ok(engine.tick()),
+            (StepInput::Command { command_type, payload, .. }, Some(engine)) => {
+                let mut events = engine.tick();
+                let command = registry.deserialize_recorded(game_type.clone(), command_type, payload)?;
+                engine.execute_command(command).map(|more| {
+                    events.extend(more);
+                    events
+                })
+            }
+            _ => {
+                return Err(DomainError::InvalidPayload(format!(
+                    "step {} is out of order: the game must start exactly once, first",
+                    i
+                ))
+                .into())
+            }
+        };
+        let actual = match events {
+            Ok(events) => serde_json::to_value(RecordedEvent::from_events(&events))?,
+            Err(e) => serde_json::json!({ "error": e.to_string() }),
+        };
+        let expected = serde_json::to_value(&step.events)?;
+        if actual != expected {
+            return Ok(report(replay, Some(ReplayMismatch { step: Some(i), expected, actual })));
+        }
+    }
+
+    let actual = engine
+        .map(|engine| engine.snapshot(&PlayerID::new(), Role::Spectator))
+        .unwrap_or_default();
+    if actual != replay.final_state {
+        let mismatch = ReplayMismatch {
+            step: None,
+            expected: replay.final_state.clone(),
+            actual,
+        };
+        return Ok(report(replay, Some(mismatch)));
+    }
+    Ok(report(replay, None))
+}
+
+fn report(replay: &Replay, mismatch: Option<ReplayMismatch>) -> ReplayReport {
+    ReplayReport {
+        session_id: replay.session.id.clone(),
+        steps: replay.steps.len(),
+        mismatch,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::{
+        eventbus::BroadcastEventBus, id_gen::UuidGenerator, memory_repo::MemoryRepo, profile_repo::MemoryProfileRepo,
+        ruleset_repo::YamlRulesetRepo, session_log::MemorySessionLog,
+    };
+    use crate::application::commands::{SessionConfig, StartGameSessionCommand};
+    use crate::application::services::game_service::SessionPolicy;
+    use crate::domain::game::GameType;
+    use crate::domain::replay::ReplayStep;
+    use crate::domain::room::RoomPolicy;
+    use crate::domain::rules::{GameRules, TriviaRules};
+    use crate::pb::runecraftstudios::pastello::game::trivia::v1::SubmitAnswerCommand;
+    use crate::pb::runecraftstudios::pastello::game::types::v1::{GameSessionId, PlayerId};
+    use crate::ports::QuestionRepository;
+    use chrono::TimeZone;
+
+    struct Fixture {
+        game_service: Arc<GameService>,
+        replays: ReplayService,
+        questions: Arc<MemoryQuestionRepo>,
+        clock: Arc<StepClock>,
+    }
+
+    fn fixture() -> Fixture {
+        let clock = Arc::new(StepClock::new(chrono::Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap()));
+        let rng = Arc::new(SystemRng::new());
+        let questions = Arc::new(MemoryQuestionRepo::with_samples());
+        let engine_factory = Arc::new(DefaultEngineFactory::new(rng.clone(), questions.clone(), ScoringPolicy::default()));
+        let game_service = Arc::new(GameService::new(
+            Arc::new(MemoryRepo::new()),
+            Arc::new(BroadcastEventBus::new()),
+            clock.clone(),
+            Arc::new(UuidGenerator::new()),
+            rng,
+            engine_factory.clone(),
+            Arc::new(CommandRegistry::new(engine_factory)),
+            Arc::new(YamlRulesetRepo::default()),
+            Arc::new(MemoryProfileRepo::new()),
+            Arc::new(MemorySessionLog::new(SessionPolicy::default().recordings_kept)),
+            RoomPolicy::default(),
+            SessionPolicy::default(),
+        ));
+        let replays = ReplayService::new(game_service.clone(), clock.clone(), ScoringPolicy::default());
+        Fixture { game_service, replays, questions, clock }
+    }
+
+    /// Starts a seeded solo trivia game of two questions with one answer in.
+    async fn start(fixture: &Fixture, seed: u64) -> String {
+        let rules = TriviaRules {
+            num_questions: 2,
+            ..TriviaRules::default()
+        };
+        let session_id = fixture
+            .game_service
+            .start_game_session(StartGameSessionCommand {
+                player_id: "alice".to_string(),
+                game_type: GameType::Trivia,
+                player_ids: Vec::new(),
+                config: SessionConfig::Rules(GameRules::Trivia(rules)),
+                room: None,
+                seed: Some(seed),
+                daily_challenge: None,
+                question_ids: Vec::new(),
+            })
+            .await
+            .unwrap();
+        let answer = SubmitAnswerCommand {
+            session_id: Some(GameSessionId { value: session_id.clone() }),
+            player_id: Some(PlayerId { value: "alice".to_string() }),
+            answer: "0".to_string(),
+        };
+        // Whether "0" fits the first question depends on the seed; either way the game starts.
+        let _ = fixture
+            .game_service
+            .handle_domain_command(&session_id, &"alice".to_string(), Box::new(answer))
+            .await;
+        session_id
+    }
+
+    /// Lets every question time out until the game ends and its engine is dropped.
+    async fn finish(fixture: &Fixture) {
+        for _ in 0..10 {
+            fixture.clock.set(fixture.clock.now_utc() + chrono::Duration::seconds(30));
+            fixture.game_service.tick_engines().await;
+        }
+    }
+
+    fn not_replayable(result: Result<Replay>) -> bool {
+        matches!(result.unwrap_err().downcast::<DomainError>().unwrap(), DomainError::NotReplayable(_))
+    }
+
+    #[tokio::test]
+    async fn running_games_cannot_be_exported() {
+        let fixture = fixture();
+        let session_id = start(&fixture, 7).await;
+        assert!(not_replayable(fixture.replays.export(&session_id, &"alice".to_string()).await));
+
+        finish(&fixture).await;
+        let session = fixture.game_service.get_session(&session_id).await.unwrap().unwrap();
+        assert_eq!(session.status, SessionStatus::Ended);
+        assert!(fixture.replays.export(&session_id, &"alice".to_string()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn exported_games_replay_with_the_questions_drawn_at_the_start() {
+        let fixture = fixture();
+        let session_id = start(&fixture, 11).await;
+        // Edits to the store after the start must not reach the replay.
+        let mut edited = fixture.questions.list(&[]).await.unwrap();
+        for question in &mut edited {
+            question.prompt = format!("{} (edited)", question.prompt);
+        }
+        fixture.questions.upsert(edited).await.unwrap();
+        finish(&fixture).await;
+
+        let replay = fixture.replays.export(&session_id, &"alice".to_string()).await.unwrap();
+        assert_eq!(replay.questions.len(), 2);
+        assert!(replay.questions.iter().all(|q| !q.prompt.ends_with("(edited)")));
+        let ids: Vec<_> = replay.questions.iter().map(|q| q.id.clone()).collect();
+        assert_eq!(replay.session.question_ids, ids);
+        assert!(matches!(&replay.steps[0].input, StepInput::Start { questions, .. } if questions.is_empty()));
+
+        let report = verify(&replay).await.unwrap();
+        assert_eq!(report.mismatch, None);
+        assert!(report.steps > 1);
+    }
+
+    #[tokio::test]
+    async fn the_same_seed_replays_the_same_game() {
+        let fixture = fixture();
+        let first = start(&fixture, 3).await;
+        let second = start(&fixture, 3).await;
+        finish(&fixture).await;
+
+        let alice = "alice".to_string();
+        let first = fixture.replays.export(&first, &alice).await.unwrap();
+        let second = fixture.replays.export(&second, &alice).await.unwrap();
+        assert_eq!(first.questions, second.questions);
+        let events = |replay: &Replay| -> Vec<Vec<String>> {
+            let types = |step: &ReplayStep| step.events.iter().map(|e| e.event_type.clone()).collect();
+            replay.steps.iter().map(types).collect()
+        };
+        assert_eq!(events(&first), events(&second));
+        assert_eq!(verify(&second).await.unwrap().mismatch, None);
+    }
+
+    #[tokio::test]
+    async fn tampered_replays_are_caught() {
+        let fixture = fixture();
+        let session_id = start(&fixture, 5).await;
+        finish(&fixture).await;
+
+        let mut replay = fixture.replays.export(&session_id, &"alice".to_string()).await.unwrap();
+        replay.session.question_ids.reverse();
+        let report = verify(&replay).await.unwrap();
+        assert_eq!(report.mismatch.map(|m| m.step), Some(Some(0)));
+    }
+}
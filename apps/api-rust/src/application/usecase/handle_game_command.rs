@@ -1,31 +1,35 @@
 use crate::application::commands::GameCommandMessage;
 use crate::application::services::command_registry::CommandRegistry;
-use crate::domain::game::{Engine, Session, GameCommand};
-use crate::ports::{Clock, GameRepository};
-use anyhow::{bail, Result};
+use crate::application::services::game_service::SharedEngine;
+use crate::domain::game::{DomainEvent, Session, GameCommand};
+use crate::domain::replay::{RecordedEvent, ReplayStep, StepInput};
+use crate::ports::{Clock, EventBus, SessionLogRepository};
+use anyhow::Result;
 use std::sync::Arc;
-use tokio::sync::Mutex;
 use tracing::{warn, info};
 
 pub struct HandleGameCommandUseCase {
-    repo: Arc<dyn GameRepository>,
-    _clock: Arc<dyn Clock>,
+    event_bus: Arc<dyn EventBus>,
+    clock: Arc<dyn Clock>,
     command_registry: Arc<CommandRegistry>,
-    engine: Arc<Mutex<Box<dyn Engine + Send>>>,
+    engine: SharedEngine,
+    session_log: Arc<dyn SessionLogRepository>,
 }
 
 impl HandleGameCommandUseCase {
     pub fn new(
-        repo: Arc<dyn GameRepository>,
+        event_bus: Arc<dyn EventBus>,
         clock: Arc<dyn Clock>,
         command_registry: Arc<CommandRegistry>,
-        engine: Arc<Mutex<Box<dyn Engine + Send>>>,
+        engine: SharedEngine,
+        session_log: Arc<dyn SessionLogRepository>,
     ) -> Self {
         Self {
-            repo,
-            _clock: clock,
+            event_bus,
+            clock,
             command_registry,
             engine,
+            session_log,
         }
     }
 
@@ -38,28 +42,66 @@ impl HandleGameCommandUseCase {
             Ok(c) => c,
             Err(e) => {
                 warn!("Failed to deserialize command {}: {:?}", command_type, e);
-                bail!(e.to_string());
+                return Err(e);
             }
         };
         
-        self.run_engine(session, game_command).await
+        self.run_engine(session, game_command, &command.player_id).await
     }
 
     // NEW: Direct entry point for Pre-parsed Commands
-    pub async fn execute_direct(&self, session: Session, command: Box<dyn GameCommand>) -> Result<()> {
-        self.run_engine(session, command).await
+    pub async fn execute_direct(&self, session: Session, command: Box<dyn GameCommand>, player_id: &str) -> Result<()> {
+        self.run_engine(session, command, player_id).await
     }
 
-    async fn run_engine(&self, session: Session, command: Box<dyn GameCommand>) -> Result<()> {
-        let mut engine_lock = self.engine.lock().await;
-        
-        info!("Executing command: {:?}", command.get_type());
-        
-        engine_lock.execute_command(command)?;
+    async fn run_engine(&self, session: Session, command: Box<dyn GameCommand>, player_id: &str) -> Result<()> {
+        let mut running = self.engine.lock().await;
+        let at = self.clock.now_utc();
+        running.clock.set(at);
+        running.last_active = at;
 
-        // Save state
-        self.repo.save(&session.id.clone(), session).await?;
-        
+        // Let time-based rules catch up first so e.g. an expired turn is not still accepted.
+        // Steps are logged before their events go out, so anything reacting to an event can
+        // read the log up to and including it.
+        let tick_events = running.engine.tick();
+        let mut recorded = RecordedEvent::from_events(&tick_events);
+
+        info!("Executing command: {:?}", command.get_type());
+        let input = StepInput::Command {
+            player_id: player_id.to_string(),
+            command_type: command.registered_type().to_string(),
+            payload: command.to_json(),
+        };
+        let events = match running.engine.execute_command(command) {
+            Ok(events) => events,
+            Err(e) => {
+                // The command changed nothing, but the catch-up above may have.
+                if !recorded.is_empty() {
+                    self.log(&session.id, ReplayStep { at, input: StepInput::Tick, events: recorded }).await;
+                }
+                publish_events(self.event_bus.as_ref(), tick_events);
+                return Err(e.into());
+            }
+        };
+        recorded.extend(RecordedEvent::from_events(&events));
+        self.log(&session.id, ReplayStep { at, input, events: recorded }).await;
+        publish_events(self.event_bus.as_ref(), tick_events);
+        publish_events(self.event_bus.as_ref(), events);
         Ok(())
     }
+
+    async fn log(&self, session_id: &str, step: ReplayStep) {
+        if let Err(e) = self.session_log.append(session_id, step).await {
+            warn!("Could not log a step of {}: {}", session_id, e);
+        }
+    }
+}
+
+pub fn publish_events(event_bus: &dyn EventBus, events: Vec<Box<dyn DomainEvent>>) {
+    for event in events {
+        let event_type = event.event_type();
+        if let Err(e) = event_bus.publish(event) {
+            warn!("Failed to publish {}: {:?}", event_type, e);
+        }
+    }
 }
\ No newline at end of file
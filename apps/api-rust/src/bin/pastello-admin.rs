@@ -0,0 +1,249 @@
+//! Operator tooling for the Pastello API.
+//!
+//! `pastello-admin import-questions questions.csv opentdb.json --dry-run`
+//! `pastello-admin daily-challenge --date 2024-02-14 --game puzzle`
+//! `pastello-admin verify-replay replay-1234.json`
+
+use anyhow::{bail, Context, Result};
+use api_rust::adapters::outbound::clock::SystemClock;
+use api_rust::adapters::outbound::daily_repo::PostgresDailyChallengeRepo;
+use api_rust::adapters::outbound::postgres;
+use api_rust::adapters::outbound::question_repo::{FileQuestionRepo, MemoryQuestionRepo};
+use api_rust::adapters::outbound::rng::SystemRng;
+use api_rust::adapters::outbound::ruleset_repo::YamlRulesetRepo;
+use api_rust::application::services::daily::draw_daily_questions;
+use api_rust::application::services::engine_factory::DefaultEngineFactory;
+use api_rust::application::services::question_import::{self, ImportFormat};
+use api_rust::application::services::replay;
+use api_rust::config::load_config;
+use api_rust::domain::game::{GameEngineFactory, GameType, Player, Session, SessionStatus};
+use api_rust::domain::replay::Replay;
+use api_rust::domain::rules::GameRules;
+use api_rust::ports::{DailyChallengeRepository, QuestionRepository, RulesetRepository};
+use chrono::NaiveDate;
+use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+const DEFAULT_STORE: &str = "data/questions.json";
+
+#[derive(Parser)]
+#[command(name = "pastello-admin", about = "Operator tooling for the Pastello API")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Validate trivia questions from CSV, JSON or Open Trivia DB files and add them to the question store.
+    ImportQuestions {
+        /// Files to import. The format is detected from the extension unless --format is given.
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+        #[arg(long, value_enum)]
+        format: Option<FormatArg>,
+        /// Question store to write. Defaults to `content.questions_path` in config.yaml.
+        #[arg(long)]
+        store: Option<PathBuf>,
+        /// Report what would be imported without writing the store.
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Regenerate a day's daily challenge from its seed and print its opening events, to audit
+    /// what players were given.
+    DailyChallenge {
+        /// UTC date, `YYYY-MM-DD`. Defaults to today.
+        #[arg(long)]
+        date: Option<NaiveDate>,
+        #[arg(long, value_enum)]
+        game: GameArg,
+        /// Ruleset to play, as `id@version`. Defaults to the configured one at its latest
+        /// version; pass the version recorded on the session to reproduce an older day.
+        #[arg(long)]
+        ruleset: Option<String>,
+    },
+    /// Play a replay file exported from `GET /sessions/{id}/replay` back through the engines and
+    /// check it ends the same way. Exits with an error on the first difference.
+    VerifyReplay {
+        file: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum GameArg {
+    Puzzle,
+    Trivia,
+}
+
+impl From<GameArg> for GameType {
+    fn from(game: GameArg) -> Self {
+        match game {
+            GameArg::Puzzle => GameType::Puzzle,
+            GameArg::Trivia => GameType::Trivia,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum FormatArg {
+    Csv,
+    Json,
+    Opentdb,
+}
+
+impl From<FormatArg> for ImportFormat {
+    fn from(format: FormatArg) -> Self {
+        match format {
+            FormatArg::Csv => ImportFormat::Csv,
+            FormatArg::Json => ImportFormat::Json,
+            FormatArg::Opentdb => ImportFormat::OpenTdb,
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    match Cli::parse().command {
+        Command::ImportQuestions { files, format, store, dry_run } => {
+            import_questions(files, format.map(ImportFormat::from), store, dry_run).await
+        }
+        Command::DailyChallenge { date, game, ruleset } => daily_challenge(date, game.into(), ruleset).await,
+        Command::VerifyReplay { file } => verify_replay(file).await,
+    }
+}
+
+async fn verify_replay(file: PathBuf) -> Result<()> {
+    let contents = std::fs::read_to_string(&file).with_context(|| format!("failed to read {}", file.display()))?;
+    let replay: Replay =
+        serde_json::from_str(&contents).with_context(|| format!("{} is not a replay file", file.display()))?;
+    let report = replay::verify(&replay).await?;
+    let Some(mismatch) = report.mismatch else {
+        println!("Replay of {} matches: {} steps and the final state", report.session_id, report.steps);
+        return Ok(());
+    };
+    match mismatch.step {
+        Some(step) => println!("Step {} of {} differs", step, report.steps),
+        None => println!("All {} steps match, but the final state differs", report.steps),
+    }
+    println!("expected: {}", serde_json::to_string_pretty(&mismatch.expected)?);
+    println!("actual:   {}", serde_json::to_string_pretty(&mismatch.actual)?);
+    bail!("replay of {} does not match", report.session_id)
+}
+
+async fn daily_challenge(date: Option<NaiveDate>, game_type: GameType, ruleset: Option<String>) -> Result<()> {
+    // The seed depends on the configured salt, so this must run against the server's config.
+    let config = load_config().context("failed to load config.yaml")?;
+    let date = date.unwrap_or_else(|| chrono::Utc::now().date_naive());
+    let mut challenge = config.daily_challenge.challenge(date, game_type.clone());
+    if ruleset.is_some() {
+        challenge.ruleset_id = ruleset;
+    }
+
+    let (ruleset_id, rules) = match &challenge.ruleset_id {
+        Some(id) => {
+            let rulesets = match config.content.rulesets_path.as_deref() {
+                Some(path) => YamlRulesetRepo::load(path)?,
+                None => YamlRulesetRepo::default(),
+            };
+            let ruleset = rulesets.get(id).await?.with_context(|| format!("unknown ruleset {}", id))?;
+            (Some(ruleset.versioned_id()), ruleset.rules)
+        }
+        None => (None, GameRules::default_for(&game_type)),
+    };
+    println!("Daily challenge {} {:?}", date, game_type);
+    println!("  seed:    {}", challenge.seed);
+    println!("  ruleset: {}", ruleset_id.as_deref().unwrap_or("default rules"));
+
+    // Same fallback as the server: the built-in samples when no questions were imported.
+    let store = config.content.questions_path.as_deref().map(FileQuestionRepo::open).transpose()?;
+    let questions: Arc<dyn QuestionRepository> = match store {
+        Some(store) if !store.is_empty() => Arc::new(store),
+        _ => Arc::new(MemoryQuestionRepo::with_samples()),
+    };
+    // Trivia days that were played ask the questions kept for them, which a later import may no
+    // longer draw; days nobody played yet are drawn the way the server would draw them.
+    let question_ids = match &rules {
+        GameRules::Trivia(rules) => {
+            let kept = match &config.database {
+                Some(database) => {
+                    let pool = postgres::connect(&database.url, 1).await?;
+                    PostgresDailyChallengeRepo::new(pool).get_questions(date).await?
+                }
+                None => None,
+            };
+            match kept {
+                Some(ids) => ids,
+                None => draw_daily_questions(questions.list(&rules.categories).await?, rules, challenge.seed),
+            }
+        }
+        GameRules::Puzzle(_) => Vec::new(),
+    };
+    let factory = DefaultEngineFactory::new(Arc::new(SystemRng::new()), questions, config.scoring);
+    let auditor = Player {
+        id: "audit".to_string(),
+        name: "Audit".to_string(),
+    };
+    let session = Session {
+        id: format!("daily-{}", date),
+        host_id: auditor.id.clone(),
+        game_type,
+        players: vec![auditor],
+        spectators: Vec::new(),
+        ruleset_id,
+        rules,
+        room: None,
+        seed: Some(challenge.seed),
+        daily_challenge: Some(date),
+        question_ids,
+        status: SessionStatus::Active,
+    };
+    let mut engine = factory.create_engine(&session.rules, session.seed, Arc::new(SystemClock::new()));
+    for event in engine.start(&session).await? {
+        println!("{}", serde_json::to_string_pretty(&event.to_json())?);
+    }
+    Ok(())
+}
+
+async fn import_questions(
+    files: Vec<PathBuf>,
+    format: Option<ImportFormat>,
+    store: Option<PathBuf>,
+    dry_run: bool,
+) -> Result<()> {
+    let store_path = store.unwrap_or_else(|| {
+        load_config()
+            .ok()
+            .and_then(|c| c.content.questions_path)
+            .unwrap_or_else(|| DEFAULT_STORE.to_string())
+            .into()
+    });
+    let repo = FileQuestionRepo::open(&store_path)?;
+
+    let mut records = Vec::new();
+    for path in &files {
+        let contents = std::fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+        let format = match format {
+            Some(format) => format,
+            None => ImportFormat::detect(path, &contents)?,
+        };
+        records.extend(question_import::parse(format, &path.display().to_string(), &contents)?);
+    }
+
+    let stored = repo.list(&[]).await?;
+    let report = question_import::validate(records, &stored);
+    print!("{}", report);
+
+    if dry_run {
+        println!("Dry run: {} left unchanged", store_path.display());
+        return Ok(());
+    }
+    if report.accepted.is_empty() {
+        bail!("nothing to import");
+    }
+
+    let imported = report.accepted.len();
+    repo.upsert(report.accepted).await?;
+    println!("Imported {} questions into {} ({} total)", imported, store_path.display(), repo.len());
+    Ok(())
+}
@@ -1,3 +1,12 @@
+use crate::adapters::inbound::ws::flood::FloodPolicy;
+use crate::application::services::game_service::SessionPolicy;
+use crate::application::services::matchmaking::MatchmakingPolicy;
+use crate::domain::anti_cheat::AntiCheatPolicy;
+use crate::domain::daily::DailyChallengePolicy;
+use crate::domain::leaderboard::LeaderboardPolicy;
+use crate::domain::rating::RatingPolicy;
+use crate::domain::room::RoomPolicy;
+use crate::domain::scoring::ScoringPolicy;
 use serde::{Deserialize, Serialize};
 use serde_yaml;
 use std::fs;
@@ -8,6 +17,13 @@ use thiserror::Error;
 pub struct ServerConfig {
     pub host: String,
     pub port: String,
+    /// Port for the gRPC server; gRPC is disabled when unset.
+    #[serde(default)]
+    pub grpc_port: Option<String>,
+    /// Internal port for operator routes such as `/metrics`, which must not be reachable from
+    /// the public listener. They are not served when unset.
+    #[serde(default)]
+    pub internal_port: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -15,10 +31,82 @@ pub struct FirebaseConfig {
     pub user: String,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ContentConfig {
+    /// JSON question store written by `pastello-admin import-questions`. When unset or
+    /// missing, trivia falls back to the built-in sample questions.
+    pub questions_path: Option<String>,
+    /// YAML catalogue of named rules presets. When unset, sessions can only use default rules.
+    pub rulesets_path: Option<String>,
+    /// YAML catalogue of achievements. When unset, no achievements can be unlocked.
+    pub achievements_path: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatabaseConfig {
+    /// Postgres connection string. Profiles and stats live in its `auth` schema. TLS follows
+    /// the URL's `sslmode`.
+    pub url: String,
+    /// Most connections the server keeps open to the database.
+    #[serde(default = "default_pool_size")]
+    pub pool_size: usize,
+}
+
+fn default_pool_size() -> usize {
+    16
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProtocolConfig {
+    /// Oldest client version accepted in the WebSocket handshake (inclusive).
+    pub min_client_version: Option<String>,
+    /// Newest client version accepted (inclusive); unset means no upper bound.
+    pub max_client_version: Option<String>,
+    /// Whether clients using the legacy bare-token handshake, which carries no version, may connect.
+    pub allow_unversioned: bool,
+}
+
+impl Default for ProtocolConfig {
+    fn default() -> Self {
+        Self {
+            min_client_version: None,
+            max_client_version: None,
+            allow_unversioned: true,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     pub server: ServerConfig,
     pub firebase: FirebaseConfig,
+    /// Profiles and stats are kept in memory when unset. When set, the server won't start
+    /// without it.
+    #[serde(default)]
+    pub database: Option<DatabaseConfig>,
+    #[serde(default)]
+    pub scoring: ScoringPolicy,
+    #[serde(default)]
+    pub content: ContentConfig,
+    #[serde(default)]
+    pub protocol: ProtocolConfig,
+    #[serde(default)]
+    pub flood_protection: FloodPolicy,
+    #[serde(default)]
+    pub matchmaking: MatchmakingPolicy,
+    #[serde(default)]
+    pub sessions: SessionPolicy,
+    #[serde(default)]
+    pub rooms: RoomPolicy,
+    #[serde(default)]
+    pub leaderboards: LeaderboardPolicy,
+    #[serde(default)]
+    pub ratings: RatingPolicy,
+    #[serde(default)]
+    pub daily_challenge: DailyChallengePolicy,
+    #[serde(default)]
+    pub anti_cheat: AntiCheatPolicy,
 }
 
 #[derive(Error, Debug)]
@@ -27,6 +115,8 @@ pub enum ConfigError {
     Io(#[from] std::io::Error),
     #[error("Failed to parse config YAML: {0}")]
     Yaml(#[from] serde_yaml::Error),
+    #[error("Invalid {section} config: {reason}")]
+    Invalid { section: &'static str, reason: String },
 }
 
 // FIX: Ensure load_config is public
@@ -35,5 +125,18 @@ pub fn load_config() -> Result<Config, ConfigError> {
     let config_path = "config.yaml"; 
     let contents = fs::read_to_string(config_path)?;
     let config: Config = serde_yaml::from_str(&contents)?;
+    validate(&config)?;
     Ok(config)
+}
+
+/// Rejects settings that parse but can't work, so a bad deploy fails at startup.
+fn validate(config: &Config) -> Result<(), ConfigError> {
+    let invalid = |section| move |reason| ConfigError::Invalid { section, reason };
+    config.scoring.validate().map_err(invalid("scoring"))?;
+    config.rooms.validate().map_err(invalid("rooms"))?;
+    config.ratings.validate().map_err(invalid("ratings"))?;
+    if config.database.as_ref().is_some_and(|database| database.pool_size == 0) {
+        return Err(invalid("database")("pool_size must be at least 1".to_string()));
+    }
+    Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,293 @@
+//! Achievements: badges defined declaratively in the achievement catalogue and awarded once per
+//! player when their condition is first met.
+
+use crate::domain::game::{DomainError, GameSessionID, GameType, PlayerID};
+use crate::domain::profile::{GameStats, PlayerStats};
+use crate::domain::rules::PuzzleDifficulty;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// One achievement from the catalogue.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AchievementDefinition {
+    /// Stable id; renaming it awards the achievement again to everyone who has it.
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub badge_url: Option<String>,
+    pub when: Condition,
+}
+
+impl AchievementDefinition {
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.id.trim().is_empty() || self.name.trim().is_empty() {
+            return Err(DomainError::InvalidPayload("achievement id and name are required".to_string()));
+        }
+        self.when.validate()
+    }
+}
+
+/// What a player has to do to unlock an achievement.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Condition {
+    /// Solve a puzzle, optionally of a given difficulty, within a time and move budget.
+    PuzzleSolved {
+        #[serde(default)]
+        difficulty: Option<PuzzleDifficulty>,
+        #[serde(default)]
+        max_elapsed_ms: Option<i64>,
+        #[serde(default)]
+        max_moves: Option<u32>,
+    },
+    /// Answer `count` trivia questions correctly in a row, across games.
+    AnswerStreak { count: u32 },
+    /// Finish `count` games, of one game type or of any. Counted from the player's lifetime stats.
+    GamesPlayed {
+        count: u32,
+        #[serde(default)]
+        game_type: Option<GameType>,
+    },
+    /// Win `count` games against at least one other player. Counted from the player's lifetime stats.
+    GamesWon {
+        count: u32,
+        #[serde(default)]
+        game_type: Option<GameType>,
+    },
+}
+
+impl Condition {
+    pub fn validate(&self) -> Result<(), DomainError> {
+        let count = match self {
+            Condition::PuzzleSolved { .. } => return Ok(()),
+            Condition::AnswerStreak { count }
+            | Condition::GamesPlayed { count, .. }
+            | Condition::GamesWon { count, .. } => *count,
+        };
+        if count == 0 {
+            return Err(DomainError::InvalidPayload("count must be at least 1".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Whether `progress`, just updated for `trigger`, meets this condition.
+    pub fn is_met(&self, progress: &AchievementProgress, trigger: &Trigger) -> bool {
+        match self {
+            Condition::PuzzleSolved { difficulty, max_elapsed_ms, max_moves } => match trigger {
+                Trigger::PuzzleSolved { difficulty: solved, elapsed_ms, moves } => {
+                    difficulty.is_none_or(|d| d == *solved)
+                        && max_elapsed_ms.is_none_or(|max| *elapsed_ms <= max)
+                        && max_moves.is_none_or(|max| *moves <= max)
+                }
+                _ => false,
+            },
+            Condition::AnswerStreak { count } => progress.answer_streak >= *count,
+            Condition::GamesPlayed { count, game_type } => match trigger {
+                Trigger::GameCounted { stats } => total(stats, game_type.as_ref(), |g| g.games_played) >= *count,
+                _ => false,
+            },
+            Condition::GamesWon { count, game_type } => match trigger {
+                Trigger::GameCounted { stats } => total(stats, game_type.as_ref(), |g| g.wins) >= *count,
+                _ => false,
+            },
+        }
+    }
+}
+
+fn total(stats: &PlayerStats, game_type: Option<&GameType>, count: impl Fn(&GameStats) -> u32) -> u32 {
+    match game_type {
+        Some(game_type) => stats.games.get(game_type).map(&count).unwrap_or(0),
+        None => stats.games.values().map(count).sum(),
+    }
+}
+
+/// What just happened to a player, as far as achievements are concerned.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Trigger {
+    PuzzleSolved {
+        difficulty: PuzzleDifficulty,
+        elapsed_ms: i64,
+        moves: u32,
+    },
+    AnswerGiven { correct: bool },
+    /// A finished game was counted; `stats` already include it.
+    GameCounted { stats: PlayerStats },
+}
+
+/// Running counts that conditions are checked against, beyond the player's stats.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AchievementProgress {
+    /// Correct trivia answers since the last wrong one.
+    pub answer_streak: u32,
+}
+
+impl AchievementProgress {
+    pub fn apply(&mut self, trigger: &Trigger) {
+        match trigger {
+            Trigger::PuzzleSolved { .. } | Trigger::GameCounted { .. } => {}
+            Trigger::AnswerGiven { correct: true } => self.answer_streak += 1,
+            Trigger::AnswerGiven { correct: false } => self.answer_streak = 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnlockedAchievement {
+    pub unlocked_at: DateTime<Utc>,
+    /// Session the achievement was earned in.
+    pub session_id: GameSessionID,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerAchievements {
+    pub player_id: PlayerID,
+    #[serde(default)]
+    pub progress: AchievementProgress,
+    /// By achievement id.
+    #[serde(default)]
+    pub unlocked: BTreeMap<String, UnlockedAchievement>,
+}
+
+impl PlayerAchievements {
+    pub fn new(player_id: &PlayerID) -> Self {
+        Self {
+            player_id: player_id.clone(),
+            progress: AchievementProgress::default(),
+            unlocked: BTreeMap::new(),
+        }
+    }
+
+    /// Applies `trigger` and unlocks every achievement in `catalogue` it completes. Returns the
+    /// newly unlocked achievements; ones already held are never awarded twice.
+    pub fn record<'a>(
+        &mut self,
+        catalogue: &'a [AchievementDefinition],
+        trigger: &Trigger,
+        session_id: &GameSessionID,
+        at: DateTime<Utc>,
+    ) -> Vec<&'a AchievementDefinition> {
+        self.progress.apply(trigger);
+        let mut unlocked = Vec::new();
+        for achievement in catalogue {
+            if self.unlocked.contains_key(&achievement.id) || !achievement.when.is_met(&self.progress, trigger) {
+                continue;
+            }
+            self.unlocked.insert(
+                achievement.id.clone(),
+                UnlockedAchievement {
+                    unlocked_at: at,
+                    session_id: session_id.clone(),
+                },
+            );
+            unlocked.push(achievement);
+        }
+        unlocked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap()
+    }
+
+    fn achievement(id: &str, when: Condition) -> AchievementDefinition {
+        AchievementDefinition {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: String::new(),
+            badge_url: None,
+            when,
+        }
+    }
+
+    fn stats(games: &[(GameType, u32, u32)]) -> PlayerStats {
+        let mut stats = PlayerStats::new(&"p1".to_string());
+        for (game_type, played, won) in games {
+            let game = stats.game_mut(game_type);
+            game.games_played = *played;
+            game.wins = *won;
+        }
+        stats
+    }
+
+    fn record(player: &mut PlayerAchievements, catalogue: &[AchievementDefinition], trigger: Trigger) -> Vec<String> {
+        player
+            .record(catalogue, &trigger, &"s1".to_string(), at())
+            .into_iter()
+            .map(|a| a.id.clone())
+            .collect()
+    }
+
+    #[test]
+    fn game_counts_come_from_lifetime_stats() {
+        let catalogue = [
+            achievement("hundred", Condition::GamesPlayed { count: 100, game_type: None }),
+            achievement("trivia_wins", Condition::GamesWon { count: 3, game_type: Some(GameType::Trivia) }),
+        ];
+        let mut player = PlayerAchievements::new(&"p1".to_string());
+        // Games played before the achievements existed count as soon as the next one is.
+        let trigger = Trigger::GameCounted {
+            stats: stats(&[(GameType::Trivia, 60, 2), (GameType::Puzzle, 40, 9)]),
+        };
+        assert_eq!(record(&mut player, &catalogue, trigger), vec!["hundred"]);
+
+        let trigger = Trigger::GameCounted {
+            stats: stats(&[(GameType::Trivia, 61, 3), (GameType::Puzzle, 40, 9)]),
+        };
+        assert_eq!(record(&mut player, &catalogue, trigger), vec!["trivia_wins"]);
+    }
+
+    #[test]
+    fn achievements_unlock_once() {
+        let catalogue = [achievement("first", Condition::GamesPlayed { count: 1, game_type: None })];
+        let mut player = PlayerAchievements::new(&"p1".to_string());
+        let trigger = Trigger::GameCounted { stats: stats(&[(GameType::Puzzle, 1, 0)]) };
+        assert_eq!(record(&mut player, &catalogue, trigger), vec!["first"]);
+        let trigger = Trigger::GameCounted { stats: stats(&[(GameType::Puzzle, 2, 0)]) };
+        assert!(record(&mut player, &catalogue, trigger).is_empty());
+        assert_eq!(player.unlocked["first"].session_id, "s1");
+    }
+
+    #[test]
+    fn a_wrong_answer_resets_the_streak() {
+        let catalogue = [achievement("streak", Condition::AnswerStreak { count: 3 })];
+        let mut player = PlayerAchievements::new(&"p1".to_string());
+        for correct in [true, true, false, true, true] {
+            assert!(record(&mut player, &catalogue, Trigger::AnswerGiven { correct }).is_empty());
+        }
+        assert_eq!(record(&mut player, &catalogue, Trigger::AnswerGiven { correct: true }), vec!["streak"]);
+    }
+
+    #[test]
+    fn puzzle_solves_must_fit_every_limit() {
+        let when = Condition::PuzzleSolved {
+            difficulty: Some(PuzzleDifficulty::Hard),
+            max_elapsed_ms: Some(60_000),
+            max_moves: None,
+        };
+        let solved = |difficulty, elapsed_ms| Trigger::PuzzleSolved { difficulty, elapsed_ms, moves: 40 };
+        let progress = AchievementProgress::default();
+        assert!(when.is_met(&progress, &solved(PuzzleDifficulty::Hard, 60_000)));
+        assert!(!when.is_met(&progress, &solved(PuzzleDifficulty::Hard, 60_001)));
+        assert!(!when.is_met(&progress, &solved(PuzzleDifficulty::Easy, 1_000)));
+    }
+
+    #[test]
+    fn progress_saved_with_game_counters_still_loads() {
+        let saved = serde_json::json!({
+            "player_id": "p1",
+            "progress": { "answer_streak": 4, "games_played": { "Trivia": 3 }, "games_won": {} },
+            "unlocked": {}
+        });
+        let player: PlayerAchievements = serde_json::from_value(saved).unwrap();
+        assert_eq!(player.progress.answer_streak, 4);
+    }
+}
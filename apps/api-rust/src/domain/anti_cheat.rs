@@ -0,0 +1,355 @@
+//! Cheat detection. A finished result is checked against the session log that produced it:
+//! solve times, the pace of moves, how soon answers came after each question and whether
+//! another account gave the same answers. Results that fail a check are flagged for review
+//! and, for the kinds configured to, kept off the leaderboards. Checks run on finished results,
+//! not as commands arrive: a flagged game has already been played out, only its ranking is held
+//! back.
+
+use crate::domain::game::{GameSessionID, GameType, PlayerID};
+use crate::domain::question::QuestionID;
+use crate::domain::replay::{ReplayStep, StepInput};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheatKind {
+    /// A puzzle solved faster than anyone could, overall or per move.
+    FastSolve,
+    /// More puzzle moves in a short window than a human can make.
+    MoveBurst,
+    /// A trivia answer locked in before the question could have been read.
+    EarlyAnswer,
+    /// Trivia answers identical to another account's on the same questions, mistakes included.
+    SharedAnswers,
+}
+
+impl CheatKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CheatKind::FastSolve => "fast_solve",
+            CheatKind::MoveBurst => "move_burst",
+            CheatKind::EarlyAnswer => "early_answer",
+            CheatKind::SharedAnswers => "shared_answers",
+        }
+    }
+}
+
+/// One suspicious result, kept for a reviewer to look at.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheatFlag {
+    pub session_id: GameSessionID,
+    pub player_id: PlayerID,
+    pub game_type: GameType,
+    pub kind: CheatKind,
+    /// What tripped the check, for the reviewer.
+    pub detail: String,
+    pub flagged_at: DateTime<Utc>,
+}
+
+/// Anti-cheat settings, read from the `anti_cheat` section of `config.yaml`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AntiCheatPolicy {
+    /// Puzzle solves faster than this are flagged.
+    pub min_puzzle_time_ms: i64,
+    /// Least time a human needs per move; a solve faster than `moves * min_ms_per_move` is flagged.
+    pub min_ms_per_move: i64,
+    /// Most puzzle moves a player may make within `burst_window_ms`.
+    pub burst_moves: u32,
+    pub burst_window_ms: i64,
+    /// Trivia answers locked in sooner than this after the question was revealed are flagged.
+    pub min_answer_ms: i64,
+    /// Identical answer sheets are only flagged when they share at least this many wrong
+    /// answers; honest players who know every answer agree too.
+    pub min_shared_wrong_answers: u32,
+    /// Answer sheets are only compared with sheets finished this long before them at most, in
+    /// the same session or another one. Older sheets are dropped.
+    pub shared_answers_window_secs: i64,
+    /// Kinds of flag that keep a result off the leaderboards. Others are only recorded.
+    pub reject: Vec<CheatKind>,
+    /// Players allowed to list flags.
+    pub reviewers: Vec<PlayerID>,
+}
+
+impl Default for AntiCheatPolicy {
+    fn default() -> Self {
+        Self {
+            min_puzzle_time_ms: 3000,
+            min_ms_per_move: 100,
+            burst_moves: 12,
+            burst_window_ms: 1000,
+            min_answer_ms: 300,
+            min_shared_wrong_answers: 4,
+            shared_answers_window_secs: 3600,
+            // Shared answers are only flagged: players on one network often study together.
+            reject: vec![CheatKind::FastSolve, CheatKind::MoveBurst, CheatKind::EarlyAnswer],
+            reviewers: Vec::new(),
+        }
+    }
+}
+
+impl AntiCheatPolicy {
+    pub fn rejects(&self, flags: &[CheatFlag]) -> bool {
+        flags.iter().any(|flag| self.reject.contains(&flag.kind))
+    }
+
+    pub fn is_reviewer(&self, player_id: &PlayerID) -> bool {
+        self.reviewers.contains(player_id)
+    }
+
+    /// Why a solve of `moves` moves in `elapsed_ms` is implausible, if it is.
+    pub fn check_solve(&self, elapsed_ms: i64, moves: u32) -> Option<String> {
+        if elapsed_ms < self.min_puzzle_time_ms {
+            return Some(format!("solved in {}ms, under the {}ms minimum", elapsed_ms, self.min_puzzle_time_ms));
+        }
+        let least = moves as i64 * self.min_ms_per_move;
+        if elapsed_ms < least {
+            return Some(format!("{} moves in {}ms, under the {}ms they take at the fastest", moves, elapsed_ms, least));
+        }
+        None
+    }
+
+    /// Why the pace of moves made at `times` (in order) is implausible, if it is.
+    pub fn check_moves(&self, times: &[DateTime<Utc>]) -> Option<String> {
+        let burst = self.burst_moves as usize + 1;
+        times.windows(burst).find_map(|window| {
+            let span = (window[burst - 1] - window[0]).num_milliseconds();
+            (span < self.burst_window_ms)
+                .then(|| format!("{} moves in {}ms, starting at {}", burst, span, window[0].to_rfc3339()))
+        })
+    }
+
+    /// Why the timing of `sheet`'s answers is implausible, if it is.
+    pub fn check_answers(&self, sheet: &AnswerSheet) -> Option<String> {
+        sheet.answers.iter().find_map(|(index, answer)| {
+            let latency_ms = answer.latency_ms?;
+            (latency_ms < self.min_answer_ms).then(|| {
+                format!("question {} answered {}ms after it was revealed", index + 1, latency_ms)
+            })
+        })
+    }
+
+    /// Oldest finish time of the sheets a sheet finished at `now` is compared with.
+    pub fn shared_answers_since(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        now - chrono::Duration::seconds(self.shared_answers_window_secs)
+    }
+
+    /// Whether `a` and `b`, from different accounts and finished within the window of each
+    /// other, are copies of one another.
+    pub fn shared_answers(&self, a: &AnswerSheet, b: &AnswerSheet) -> bool {
+        if a.player_id == b.player_id || a.questions != b.questions || a.answers.len() != b.answers.len() {
+            return false;
+        }
+        if (a.finished_at - b.finished_at).num_seconds().abs() > self.shared_answers_window_secs {
+            return false;
+        }
+        let mut shared_wrong = 0;
+        for ((index_a, answer_a), (index_b, answer_b)) in a.answers.iter().zip(&b.answers) {
+            if index_a != index_b || !answer_a.same_as(answer_b) {
+                return false;
+            }
+            if answer_a.correct == Some(false) {
+                shared_wrong += 1;
+            }
+        }
+        shared_wrong >= self.min_shared_wrong_answers
+    }
+}
+
+/// One player's trivia answers in a session, read back from its log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnswerSheet {
+    pub session_id: GameSessionID,
+    pub player_id: PlayerID,
+    /// Questions in the order they were asked. Sheets are only compared on the same questions.
+    pub questions: Vec<QuestionID>,
+    /// Answers by question index.
+    pub answers: BTreeMap<u32, SheetAnswer>,
+    /// When the result was reviewed.
+    pub finished_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SheetAnswer {
+    pub answer: String,
+    /// Known once the question has closed.
+    pub correct: Option<bool>,
+    /// Time from the reveal to the answer, known once the question has closed.
+    pub latency_ms: Option<i64>,
+}
+
+impl SheetAnswer {
+    fn same_as(&self, other: &SheetAnswer) -> bool {
+        self.answer.trim().eq_ignore_ascii_case(other.answer.trim())
+    }
+}
+
+impl AnswerSheet {
+    /// `player_id`'s sheet, built from the session's logged steps.
+    pub fn from_steps(
+        session_id: &GameSessionID,
+        player_id: &PlayerID,
+        steps: &[ReplayStep],
+        finished_at: DateTime<Utc>,
+    ) -> Self {
+        let mut sheet = AnswerSheet {
+            session_id: session_id.clone(),
+            player_id: player_id.clone(),
+            questions: Vec::new(),
+            answers: BTreeMap::new(),
+            finished_at,
+        };
+        // Answers are scored right after the correct one is revealed, so this tracks which
+        // question the scores that follow belong to.
+        let mut closing = None;
+        for step in steps {
+            let submitted = match &step.input {
+                StepInput::Command { player_id: by, command_type, payload }
+                    if by == player_id && command_type == "TriviaSubmit" =>
+                {
+                    payload["answer"].as_str()
+                }
+                _ => None,
+            };
+            for event in &step.events {
+                let payload = &event.payload;
+                let for_player = payload["player_id"].as_str() == Some(player_id.as_str());
+                match event.event_type.as_str() {
+                    "trivia.question_revealed" => {
+                        if let Some(id) = payload["question_id"].as_str() {
+                            sheet.questions.push(id.to_string());
+                        }
+                    }
+                    "trivia.answer_locked" if for_player => {
+                        if let (Some(index), Some(answer)) = (payload["question_index"].as_u64(), submitted) {
+                            sheet.answers.insert(
+                                index as u32,
+                                SheetAnswer {
+                                    answer: answer.to_string(),
+                                    correct: None,
+                                    latency_ms: None,
+                                },
+                            );
+                        }
+                    }
+                    "trivia.answer_revealed" => closing = payload["question_index"].as_u64().map(|i| i as u32),
+                    "trivia.answer_accepted" if for_player => {
+                        if let Some(answer) = closing.and_then(|index| sheet.answers.get_mut(&index)) {
+                            answer.correct = payload["correct"].as_bool();
+                            answer.latency_ms = payload["latency_ms"].as_i64();
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        sheet
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(ms: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap() + chrono::Duration::milliseconds(ms)
+    }
+
+    fn answer(answer: &str, correct: bool, latency_ms: i64) -> SheetAnswer {
+        SheetAnswer {
+            answer: answer.to_string(),
+            correct: Some(correct),
+            latency_ms: Some(latency_ms),
+        }
+    }
+
+    /// `player_id`'s sheet with one question per answer, finished `minutes` after noon.
+    /// Answers are given as (answer, correct).
+    fn sheet(player_id: &str, answers: &[(&str, bool)], minutes: i64) -> AnswerSheet {
+        AnswerSheet {
+            session_id: format!("session-{}", player_id),
+            player_id: player_id.to_string(),
+            questions: (0..answers.len()).map(|i| format!("q{}", i)).collect(),
+            answers: answers
+                .iter()
+                .enumerate()
+                .map(|(i, (given, correct))| (i as u32, answer(given, *correct, 2000)))
+                .collect(),
+            finished_at: at(minutes * 60_000),
+        }
+    }
+
+    #[test]
+    fn solves_are_flagged_under_either_minimum() {
+        let policy = AntiCheatPolicy::default();
+        assert!(policy.check_solve(2999, 5).is_some());
+        assert!(policy.check_solve(3000, 5).is_none());
+        // 40 moves take at least 4000ms.
+        assert!(policy.check_solve(3999, 40).is_some());
+        assert!(policy.check_solve(4000, 40).is_none());
+    }
+
+    #[test]
+    fn move_bursts_are_flagged_past_the_allowed_count() {
+        let policy = AntiCheatPolicy::default();
+        let every = |ms: i64, count: i64| (0..count).map(|i| at(i * ms)).collect::<Vec<_>>();
+        // 12 moves within a second are allowed, a 13th is not.
+        assert!(policy.check_moves(&every(50, 12)).is_none());
+        assert!(policy.check_moves(&every(50, 13)).is_some());
+        // 13 moves spread over exactly a second are fine.
+        assert!(policy.check_moves(&every(84, 13)).is_none());
+    }
+
+    #[test]
+    fn answers_are_flagged_under_the_minimum_latency() {
+        let policy = AntiCheatPolicy::default();
+        let mut quick = sheet("a", &[("Paris", true)], 0);
+        quick.answers.insert(0, answer("Paris", true, 299));
+        assert!(policy.check_answers(&quick).is_some());
+        quick.answers.insert(0, answer("Paris", true, 300));
+        assert!(policy.check_answers(&quick).is_none());
+    }
+
+    #[test]
+    fn shared_answers_need_enough_shared_mistakes() {
+        let policy = AntiCheatPolicy::default();
+        let three_wrong = [("a", false), ("b", false), ("c", false), ("d", true), ("e", true)];
+        let four_wrong = [("a", false), ("b", false), ("c", false), ("d", false), ("e", true)];
+        assert!(!policy.shared_answers(&sheet("x", &three_wrong, 0), &sheet("y", &three_wrong, 0)));
+        assert!(policy.shared_answers(&sheet("x", &four_wrong, 0), &sheet("y", &four_wrong, 0)));
+        // The same account, or a different answer anywhere, is not a copy.
+        assert!(!policy.shared_answers(&sheet("x", &four_wrong, 0), &sheet("x", &four_wrong, 0)));
+        let mut other = four_wrong;
+        other[4] = ("f", false);
+        assert!(!policy.shared_answers(&sheet("x", &four_wrong, 0), &sheet("y", &other, 0)));
+        // Honest players who get everything right agree on every answer.
+        let all_right = [("a", true), ("b", true), ("c", true), ("d", true), ("e", true)];
+        assert!(!policy.shared_answers(&sheet("x", &all_right, 0), &sheet("y", &all_right, 0)));
+    }
+
+    #[test]
+    fn shared_answers_are_only_compared_within_the_window() {
+        let policy = AntiCheatPolicy::default();
+        let four_wrong = [("a", false), ("b", false), ("c", false), ("d", false)];
+        assert!(policy.shared_answers(&sheet("x", &four_wrong, 0), &sheet("y", &four_wrong, 60)));
+        assert!(!policy.shared_answers(&sheet("x", &four_wrong, 0), &sheet("y", &four_wrong, 61)));
+    }
+
+    #[test]
+    fn shared_answers_alone_do_not_keep_results_off_the_boards() {
+        let policy = AntiCheatPolicy::default();
+        let flag = |kind| CheatFlag {
+            session_id: "s".to_string(),
+            player_id: "x".to_string(),
+            game_type: GameType::Trivia,
+            kind,
+            detail: String::new(),
+            flagged_at: at(0),
+        };
+        assert!(!policy.rejects(&[flag(CheatKind::SharedAnswers)]));
+        assert!(policy.rejects(&[flag(CheatKind::SharedAnswers), flag(CheatKind::EarlyAnswer)]));
+    }
+}
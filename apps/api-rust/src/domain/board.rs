@@ -0,0 +1,158 @@
+use crate::domain::game::DomainError;
+use crate::ports::Rng;
+use serde::{Deserialize, Serialize};
+
+// The empty slot on a sliding-tile board.
+const BLANK: u32 = 0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pos {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Square sliding-tile board. Tiles are stored row-major; the solved board reads
+/// `1, 2, .., n*n - 1` with the blank in the bottom-right corner.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Board {
+    size: usize,
+    tiles: Vec<u32>,
+}
+
+impl Board {
+    pub fn solved(size: usize) -> Self {
+        let cells = size * size;
+        let tiles = (1..cells as u32).chain(std::iter::once(BLANK)).collect();
+        Self { size, tiles }
+    }
+
+    /// Builds a board scrambled by `moves` random slides drawn from `rng`. A seeded `rng` always
+    /// yields the same board, and the result is solvable because it is reached by legal moves only.
+    pub fn scrambled(size: usize, moves: u32, rng: &dyn Rng) -> Self {
+        let mut board = Self::solved(size);
+        if size < 2 {
+            return board;
+        }
+        let mut previous_blank: Option<Pos> = None;
+
+        // Random moves can wander back to the solution; scramble again until they don't.
+        loop {
+            for _ in 0..moves.max(1) {
+                let blank = board.blank();
+                let candidates: Vec<Pos> = board
+                    .neighbours(blank)
+                    .into_iter()
+                    .filter(|p| Some(*p) != previous_blank)
+                    .collect();
+                // Every cell of a board of size 2 or more has a neighbour besides the one just left.
+                let Some(next) = rng.choose(&candidates).copied() else {
+                    break;
+                };
+                board.swap(blank, next);
+                previous_blank = Some(blank);
+            }
+            if !board.is_solved() {
+                return board;
+            }
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn tiles(&self) -> &[u32] {
+        &self.tiles
+    }
+
+    /// Slides the tile at `from` into the blank at `to`.
+    pub fn slide(&mut self, from: Pos, to: Pos) -> Result<(), DomainError> {
+        if !self.in_bounds(from) || !self.in_bounds(to) {
+            return Err(DomainError::OutOfBounds);
+        }
+        let adjacent = (from.x - to.x).abs() + (from.y - to.y).abs() == 1;
+        if !adjacent || self.tile_at(to) != BLANK || self.tile_at(from) == BLANK {
+            return Err(DomainError::IllegalMove);
+        }
+        self.swap(from, to);
+        Ok(())
+    }
+
+    pub fn tiles_in_place(&self) -> usize {
+        self.tiles
+            .iter()
+            .enumerate()
+            .filter(|(i, tile)| **tile != BLANK && **tile == *i as u32 + 1)
+            .count()
+    }
+
+    /// Share of numbered tiles already in their solved position, 0-100.
+    pub fn percent_in_place(&self) -> u32 {
+        let numbered = self.tiles.len().saturating_sub(1);
+        if numbered == 0 {
+            return 100;
+        }
+        (self.tiles_in_place() * 100 / numbered) as u32
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.tiles_in_place() == self.tiles.len() - 1
+    }
+
+    fn in_bounds(&self, pos: Pos) -> bool {
+        pos.x >= 0 && pos.y >= 0 && (pos.x as usize) < self.size && (pos.y as usize) < self.size
+    }
+
+    fn index(&self, pos: Pos) -> usize {
+        pos.y as usize * self.size + pos.x as usize
+    }
+
+    fn tile_at(&self, pos: Pos) -> u32 {
+        self.tiles[self.index(pos)]
+    }
+
+    fn swap(&mut self, a: Pos, b: Pos) {
+        let (a, b) = (self.index(a), self.index(b));
+        self.tiles.swap(a, b);
+    }
+
+    fn blank(&self) -> Pos {
+        let index = self.tiles.iter().position(|t| *t == BLANK).unwrap_or(0);
+        Pos {
+            x: (index % self.size) as i32,
+            y: (index / self.size) as i32,
+        }
+    }
+
+    fn neighbours(&self, pos: Pos) -> Vec<Pos> {
+        [(0, -1), (1, 0), (0, 1), (-1, 0)]
+            .into_iter()
+            .map(|(dx, dy)| Pos { x: pos.x + dx, y: pos.y + dy })
+            .filter(|p| self.in_bounds(*p))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::rng::SeededRng;
+
+    #[test]
+    fn a_seed_always_scrambles_the_same_board() {
+        let board = Board::scrambled(4, 40, &SeededRng::new(7));
+        assert_eq!(board, Board::scrambled(4, 40, &SeededRng::new(7)));
+        assert_ne!(board, Board::scrambled(4, 40, &SeededRng::new(8)));
+        assert!(!board.is_solved());
+        let mut tiles = board.tiles().to_vec();
+        tiles.sort_unstable();
+        assert_eq!(tiles, (0..16).collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn even_a_single_move_leaves_the_board_unsolved() {
+        for seed in 0..20 {
+            assert!(!Board::scrambled(3, 1, &SeededRng::new(seed)).is_solved());
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Daily challenge: one puzzle and one trivia set per UTC date, the same for every player.
+//! Each game is built from a seed derived only from the date, the game type and a configured
+//! salt, so any past day can be regenerated for an audit.
+
+use crate::domain::game::{GameSessionID, GameType, PlayerID};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Daily challenge settings, read from the `daily_challenge` section of `config.yaml`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DailyChallengePolicy {
+    /// Mixed into every seed. Changing it changes every day's games, past ones included.
+    pub seed_salt: String,
+    /// Ruleset id the challenge is played with, per game type; default rules when absent.
+    pub rulesets: HashMap<GameType, String>,
+}
+
+impl Default for DailyChallengePolicy {
+    fn default() -> Self {
+        Self {
+            seed_salt: "pastello-daily".to_string(),
+            rulesets: HashMap::new(),
+        }
+    }
+}
+
+impl DailyChallengePolicy {
+    /// Seed of the `game_type` challenge on `date`. Stable across builds and platforms: FNV-1a
+    /// over `salt/YYYY-MM-DD/GameType`, finished with the SplitMix64 mixer.
+    pub fn seed(&self, date: NaiveDate, game_type: &GameType) -> u64 {
+        let key = format!("{}/{}/{:?}", self.seed_salt, date.format("%Y-%m-%d"), game_type);
+        let mut hash: u64 = 0xCBF2_9CE4_8422_2325;
+        for byte in key.bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0000_0100_0000_01B3);
+        }
+        hash = (hash ^ (hash >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        hash = (hash ^ (hash >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        hash ^ (hash >> 31)
+    }
+
+    pub fn challenge(&self, date: NaiveDate, game_type: GameType) -> DailyChallenge {
+        DailyChallenge {
+            date,
+            seed: self.seed(date, &game_type),
+            ruleset_id: self.rulesets.get(&game_type).cloned(),
+            game_type,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DailyChallenge {
+    /// UTC date the challenge is for.
+    pub date: NaiveDate,
+    pub game_type: GameType,
+    pub seed: u64,
+    pub ruleset_id: Option<String>,
+}
+
+/// A player's one try at a day's challenge.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DailyAttempt {
+    pub player_id: PlayerID,
+    pub date: NaiveDate,
+    pub game_type: GameType,
+    pub session_id: GameSessionID,
+    pub started_at: DateTime<Utc>,
+}
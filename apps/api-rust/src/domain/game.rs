@@ -1,9 +1,13 @@
+use crate::domain::question::Question;
+use crate::domain::room::PrivateRoom;
+use crate::domain::rules::GameRules;
 use crate::ports::Clock;
 use anyhow::Result;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::any::Any;
+use std::sync::Arc;
 use std::fmt::Debug;
 use std::hash::Hash;
 use thiserror::Error;
@@ -11,7 +15,7 @@ use thiserror::Error;
 pub type GameSessionID = String;
 pub type PlayerID = String;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum GameType {
     Puzzle,
     Trivia,
@@ -23,12 +27,82 @@ pub struct Player {
     pub name: String,
 }
 
+/// How a connection takes part in a session. Spectators receive snapshots and the event
+/// stream but cannot send commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    Player,
+    Spectator,
+}
+
+impl Role {
+    pub fn parse(label: &str) -> Option<Self> {
+        match label.trim().to_ascii_lowercase().as_str() {
+            "player" => Some(Role::Player),
+            "spectator" => Some(Role::Spectator),
+            _ => None,
+        }
+    }
+}
+
+/// Where a session is in its life.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionStatus {
+    /// A private room waiting for its host to start the game. Players join, but no engine
+    /// runs until the host starts it.
+    Lobby,
+    /// Being played, or about to be once its engine starts.
+    #[default]
+    Active,
+    /// Played to the end.
+    Ended,
+    /// Left without a command for too long. Its engine is gone, so it can't be resumed.
+    Cancelled,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Session {
     pub id: GameSessionID,
     pub host_id: PlayerID,
     pub game_type: GameType,
     pub players: Vec<Player>,
+    /// Read-only participants. They don't count toward `max_players`.
+    #[serde(default)]
+    pub spectators: Vec<Player>,
+    /// Ruleset the rules were resolved from, if the session was created from a preset.
+    #[serde(default)]
+    pub ruleset_id: Option<String>,
+    pub rules: GameRules,
+    /// Set for private rooms that players join by code.
+    #[serde(default)]
+    pub room: Option<PrivateRoom>,
+    /// Seed the engine draws every random choice from, so the game replays exactly from it.
+    /// Sessions stored before seeds were recorded have none and use the server's entropy.
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// UTC date of the daily challenge this session is a player's attempt at.
+    #[serde(default)]
+    pub daily_challenge: Option<chrono::NaiveDate>,
+    /// Trivia only: questions the session asks, in order. Drawn from the store when empty.
+    #[serde(default)]
+    pub question_ids: Vec<crate::domain::question::QuestionID>,
+    #[serde(default)]
+    pub status: SessionStatus,
+}
+
+impl Session {
+    /// Role of `player_id` in this session, or `None` if they are not part of it.
+    pub fn role_of(&self, player_id: &PlayerID) -> Option<Role> {
+        if self.host_id == *player_id || self.players.iter().any(|p| p.id == *player_id) {
+            Some(Role::Player)
+        } else if self.spectators.iter().any(|p| p.id == *player_id) {
+            Some(Role::Spectator)
+        } else {
+            None
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -46,8 +120,15 @@ pub fn new_meta(clock: &dyn Clock) -> EventMeta {
 pub trait DomainEvent: Any + Send + Sync + Debug {
     fn event_type(&self) -> &'static str;
     fn session_id(&self) -> &GameSessionID;
+    /// JSON payload pushed to clients inside a `GameEventEnvelope`.
+    fn to_json(&self) -> serde_json::Value;
     fn to_any_box(self: Box<Self>) -> Box<dyn Any + Send>;
     fn clone_box(&self) -> Box<dyn DomainEvent>;
+    /// Whether a connection of `viewer` in `role` is sent the event. Most events go to
+    /// everyone attached to the session.
+    fn visible_to(&self, _viewer: &PlayerID, _role: Role) -> bool {
+        true
+    }
 }
 
 impl Clone for Box<dyn DomainEvent> {
@@ -60,16 +141,78 @@ impl Clone for Box<dyn DomainEvent> {
 
 pub trait GameCommand: Send + Sync + Debug {
     fn get_type(&self) -> String;
+    /// Name the command is registered under in `CommandRegistry`, e.g. `PuzzleMove`.
+    fn registered_type(&self) -> &'static str;
+    /// Payload `CommandRegistry` parses back into this command; replays store commands this way.
+    fn to_json(&self) -> serde_json::Value;
     // FIX: Helper to allow downcasting
     fn into_any(self: Box<Self>) -> Box<dyn Any + Send>;
 }
 
+/// Seats a player who joined after the engine started. Issued by the server when someone
+/// joins a running game; clients can't send it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SeatPlayerCommand {
+    pub player_id: PlayerID,
+}
+
+impl GameCommand for SeatPlayerCommand {
+    fn get_type(&self) -> String { "SeatPlayerCommand".to_string() }
+    fn registered_type(&self) -> &'static str { "SeatPlayer" }
+    fn to_json(&self) -> serde_json::Value { serde_json::to_value(self).unwrap_or_default() }
+    fn into_any(self: Box<Self>) -> Box<dyn Any + Send> { self }
+}
+
+/// Takes a player out of a running game. Issued by the server when the host kicks someone;
+/// clients can't send it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemovePlayerCommand {
+    pub player_id: PlayerID,
+}
+
+impl GameCommand for RemovePlayerCommand {
+    fn get_type(&self) -> String { "RemovePlayerCommand".to_string() }
+    fn registered_type(&self) -> &'static str { "RemovePlayer" }
+    fn to_json(&self) -> serde_json::Value { serde_json::to_value(self).unwrap_or_default() }
+    fn into_any(self: Box<Self>) -> Box<dyn Any + Send> { self }
+}
+
 // --- ENGINE ---
 
 #[async_trait]
 pub trait Engine: Send + Sync + Debug {
     fn game_type(&self) -> GameType;
-    fn execute_command(&mut self, command: Box<dyn GameCommand>) -> Result<(), DomainError>;
+    fn execute_command(
+        &mut self,
+        command: Box<dyn GameCommand>,
+    ) -> Result<Vec<Box<dyn DomainEvent>>, DomainError>;
+
+    /// Called once when the engine is attached to a session, before any command runs.
+    async fn start(&mut self, _session: &Session) -> Result<Vec<Box<dyn DomainEvent>>, DomainError> {
+        Ok(vec![])
+    }
+
+    /// Current state as seen by `viewer` in `role`, sent to clients when they attach. Hidden
+    /// information (e.g. a trivia answer before it is revealed) must be left out.
+    fn snapshot(&self, _viewer: &PlayerID, _role: Role) -> serde_json::Value {
+        serde_json::Value::Null
+    }
+
+    /// Called periodically so time-based rules (e.g. turn timeouts) can fire without a command.
+    fn tick(&mut self) -> Vec<Box<dyn DomainEvent>> {
+        vec![]
+    }
+
+    /// Whether the game has ended. Finished engines are dropped and take no more commands.
+    fn is_finished(&self) -> bool {
+        false
+    }
+
+    /// Trivia questions `start` drew, as stored at the time, so a replay asks the same ones
+    /// whatever the question store holds by then.
+    fn drawn_questions(&self) -> Vec<Question> {
+        Vec::new()
+    }
 
     async fn apply(
         &self,
@@ -80,7 +223,10 @@ pub trait Engine: Send + Sync + Debug {
 
 #[async_trait]
 pub trait GameEngineFactory: Send + Sync + Debug {
-    fn create_engine(&self, game_type: GameType) -> Box<dyn Engine>;
+    /// Builds an engine for the game `rules` belong to, configured by them. With a `seed`,
+    /// every random choice the engine makes is reproducible from it. The engine reads time only
+    /// from `clock`.
+    fn create_engine(&self, rules: &GameRules, seed: Option<u64>, clock: Arc<dyn Clock>) -> Box<dyn Engine>;
 }
 
 #[derive(Error, Debug, Clone)]
@@ -93,6 +239,54 @@ pub enum DomainError {
     OutOfBounds,
     #[error("Nothing to undo")]
     NothingToUndo,
+    #[error("It is not {0}'s turn")]
+    NotYourTurn(PlayerID),
+    #[error("Only the player who made the last move can undo it")]
+    UndoNotAllowed,
+    #[error("Illegal move")]
+    IllegalMove,
+    #[error("Player {0} is not part of this game")]
+    NotAPlayer(PlayerID),
+    #[error("{0} is spectating and cannot send commands")]
+    Spectating(PlayerID),
+    #[error("Only the host can do that")]
+    NotTheHost,
+    #[error("{0} can only list their own sessions")]
+    NotYourSessions(PlayerID),
+    #[error("{0} is not allowed to review cheat flags")]
+    NotAReviewer(PlayerID),
+    #[error("{0} was removed from this room")]
+    Kicked(PlayerID),
+    #[error("No room with join code {0}")]
+    UnknownJoinCode(String),
+    #[error("Join code {0} has expired")]
+    JoinCodeExpired(String),
+    #[error("Wrong room password")]
+    WrongPassword,
+    #[error("Too many wrong passwords for room {0}; try again later")]
+    TooManyAttempts(String),
+    #[error("Room is full")]
+    RoomFull,
+    #[error("Game has already started")]
+    AlreadyStarted,
+    #[error("Game hasn't started yet")]
+    NotStarted,
+    #[error("{0} has already played today's daily challenge")]
+    AlreadyAttempted(PlayerID),
+    #[error("Cannot replay this session: {0}")]
+    NotReplayable(String),
+    #[error("Game has already finished")]
+    GameFinished,
+    #[error("Answer already submitted for this question")]
+    AlreadyAnswered,
+    #[error("Not accepting answers right now")]
+    NotAcceptingAnswers,
+    #[error("Invalid answer: {0}")]
+    InvalidAnswer(String),
+    #[error("Unknown ruleset: {0}")]
+    UnknownRuleset(String),
+    #[error("Invalid rules: {0}")]
+    InvalidRules(String),
     #[error("Invalid command")]
     InvalidCommand,
     #[error("Invalid command payload: {0}")]
@@ -100,3 +294,56 @@ pub enum DomainError {
     #[error("Internal domain error: {0}")]
     Internal(String),
 }
+
+/// What kind of failure a [`DomainError`] is. Every transport maps errors through this, so a
+/// failure reads the same over REST, gRPC and the WebSocket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    NotFound,
+    Invalid,
+    Forbidden,
+    /// A join code or similar that worked once but no longer does.
+    Expired,
+    /// The request is valid but the game isn't in a state to take it.
+    Conflict,
+    /// Too many attempts in a short time; retrying later may work.
+    RateLimited,
+    Internal,
+}
+
+impl DomainError {
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            DomainError::SessionNotFound(_) | DomainError::UnknownJoinCode(_) => ErrorKind::NotFound,
+            DomainError::UnknownRuleset(_)
+            | DomainError::InvalidRules(_)
+            | DomainError::InvalidCommand
+            | DomainError::InvalidPayload(_)
+            | DomainError::InvalidAnswer(_)
+            | DomainError::OutOfBounds
+            | DomainError::IllegalMove
+            | DomainError::WrongEngine => ErrorKind::Invalid,
+            DomainError::NotYourTurn(_)
+            | DomainError::NotAPlayer(_)
+            | DomainError::Spectating(_)
+            | DomainError::NotTheHost
+            | DomainError::NotYourSessions(_)
+            | DomainError::NotAReviewer(_)
+            | DomainError::Kicked(_)
+            | DomainError::WrongPassword
+            | DomainError::UndoNotAllowed => ErrorKind::Forbidden,
+            DomainError::JoinCodeExpired(_) => ErrorKind::Expired,
+            DomainError::NothingToUndo
+            | DomainError::RoomFull
+            | DomainError::AlreadyStarted
+            | DomainError::NotStarted
+            | DomainError::AlreadyAttempted(_)
+            | DomainError::NotReplayable(_)
+            | DomainError::GameFinished
+            | DomainError::AlreadyAnswered
+            | DomainError::NotAcceptingAnswers => ErrorKind::Conflict,
+            DomainError::TooManyAttempts(_) => ErrorKind::RateLimited,
+            DomainError::Internal(_) => ErrorKind::Internal,
+        }
+    }
+}
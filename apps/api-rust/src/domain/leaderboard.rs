@@ -0,0 +1,315 @@
+//! Leaderboards of final trivia scores and puzzle completion times, per game type and per
+//! ruleset, over all time and over the current week and day, plus one board per daily challenge.
+
+use crate::domain::game::{DomainError, GameSessionID, GameType, PlayerID};
+use crate::pb::runecraftstudios::pastello::web::game::v1::LeaderboardPeriod as LeaderboardPeriodPb;
+use chrono::{DateTime, Datelike, Utc};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaderboardPeriod {
+    AllTime,
+    /// ISO week, starting Monday 00:00 UTC.
+    Weekly,
+    /// Calendar day in UTC.
+    Daily,
+    /// The daily challenge of a UTC date. Only challenge sessions are ranked here, so it is
+    /// not part of `ALL`.
+    DailyChallenge,
+}
+
+impl LeaderboardPeriod {
+    pub const ALL: [LeaderboardPeriod; 3] = [LeaderboardPeriod::AllTime, LeaderboardPeriod::Weekly, LeaderboardPeriod::Daily];
+
+    pub fn from_pb(period: LeaderboardPeriodPb) -> Self {
+        match period {
+            LeaderboardPeriodPb::Unspecified | LeaderboardPeriodPb::AllTime => LeaderboardPeriod::AllTime,
+            LeaderboardPeriodPb::Weekly => LeaderboardPeriod::Weekly,
+            LeaderboardPeriodPb::Daily => LeaderboardPeriod::Daily,
+            LeaderboardPeriodPb::DailyChallenge => LeaderboardPeriod::DailyChallenge,
+        }
+    }
+
+    pub fn to_pb(self) -> LeaderboardPeriodPb {
+        match self {
+            LeaderboardPeriod::AllTime => LeaderboardPeriodPb::AllTime,
+            LeaderboardPeriod::Weekly => LeaderboardPeriodPb::Weekly,
+            LeaderboardPeriod::Daily => LeaderboardPeriodPb::Daily,
+            LeaderboardPeriod::DailyChallenge => LeaderboardPeriodPb::DailyChallenge,
+        }
+    }
+
+    /// Label of the window containing `at`, e.g. `2024-W07` or `2024-02-14`.
+    pub fn window(&self, at: DateTime<Utc>) -> String {
+        match self {
+            LeaderboardPeriod::AllTime => "all".to_string(),
+            LeaderboardPeriod::Weekly => {
+                let week = at.iso_week();
+                format!("{}-W{:02}", week.year(), week.week())
+            }
+            LeaderboardPeriod::Daily | LeaderboardPeriod::DailyChallenge => at.format("%Y-%m-%d").to_string(),
+        }
+    }
+}
+
+/// One leaderboard: a game type, optionally narrowed to a ruleset, in one period window.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BoardId {
+    pub game_type: GameType,
+    /// Ruleset id without its `@version`, so small revisions of a preset share a board.
+    pub ruleset_id: Option<String>,
+    pub period: LeaderboardPeriod,
+    pub window: String,
+}
+
+impl BoardId {
+    pub fn new(game_type: GameType, ruleset_id: Option<&str>, period: LeaderboardPeriod, at: DateTime<Utc>) -> Self {
+        Self {
+            game_type,
+            ruleset_id: ruleset_id.map(|id| id.split('@').next().unwrap_or(id).to_string()),
+            window: period.window(at),
+            period,
+        }
+    }
+}
+
+/// What a game type is ranked by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RankOrder {
+    /// Trivia: final score, highest first.
+    HighestFirst,
+    /// Puzzle: completion time in milliseconds, lowest first.
+    LowestFirst,
+}
+
+impl RankOrder {
+    pub fn for_game(game_type: &GameType) -> Self {
+        match game_type {
+            GameType::Trivia => RankOrder::HighestFirst,
+            GameType::Puzzle => RankOrder::LowestFirst,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LeaderboardEntry {
+    pub player_id: PlayerID,
+    pub value: i64,
+    pub achieved_at: DateTime<Utc>,
+    pub session_id: GameSessionID,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RankedEntry {
+    /// 1-based. Equal values are ranked by who got there first, so ranks are never shared.
+    pub rank: u32,
+    #[serde(flatten)]
+    pub entry: LeaderboardEntry,
+}
+
+/// Best result per player, kept sorted so top-N is a slice and a player's rank a binary search.
+#[derive(Debug, Clone)]
+pub struct Leaderboard {
+    order: RankOrder,
+    ranked: Vec<LeaderboardEntry>,
+    best: HashMap<PlayerID, LeaderboardEntry>,
+}
+
+impl Leaderboard {
+    pub fn new(order: RankOrder) -> Self {
+        Self {
+            order,
+            ranked: Vec::new(),
+            best: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.ranked.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranked.is_empty()
+    }
+
+    /// Ordering of two entries on this board; `Less` ranks higher.
+    fn compare(&self, a: &LeaderboardEntry, b: &LeaderboardEntry) -> Ordering {
+        let by_value = match self.order {
+            RankOrder::HighestFirst => b.value.cmp(&a.value),
+            RankOrder::LowestFirst => a.value.cmp(&b.value),
+        };
+        by_value
+            .then(a.achieved_at.cmp(&b.achieved_at))
+            .then_with(|| a.player_id.cmp(&b.player_id))
+    }
+
+    fn position(&self, entry: &LeaderboardEntry) -> usize {
+        self.ranked.partition_point(|e| self.compare(e, entry) == Ordering::Less)
+    }
+
+    /// Records `entry` if it beats the player's best on this board. Returns whether it did.
+    pub fn submit(&mut self, entry: LeaderboardEntry) -> bool {
+        if let Some(best) = self.best.get(&entry.player_id) {
+            if self.compare(&entry, best) != Ordering::Less {
+                return false;
+            }
+            let old = self.position(best);
+            self.ranked.remove(old);
+        }
+        let at = self.position(&entry);
+        self.ranked.insert(at, entry.clone());
+        self.best.insert(entry.player_id.clone(), entry);
+        true
+    }
+
+    pub fn top(&self, limit: usize) -> Vec<RankedEntry> {
+        self.ranked
+            .iter()
+            .take(limit)
+            .enumerate()
+            .map(|(i, entry)| RankedEntry { rank: i as u32 + 1, entry: entry.clone() })
+            .collect()
+    }
+
+    pub fn rank_of(&self, player_id: &PlayerID) -> Option<RankedEntry> {
+        let best = self.best.get(player_id)?;
+        Some(RankedEntry {
+            rank: self.position(best) as u32 + 1,
+            entry: best.clone(),
+        })
+    }
+}
+
+/// Leaderboard settings, read from the `leaderboards` section of `config.yaml`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LeaderboardPolicy {
+    /// Most entries a single query may return.
+    pub max_page_size: u32,
+}
+
+impl Default for LeaderboardPolicy {
+    fn default() -> Self {
+        Self {
+            max_page_size: 100,
+        }
+    }
+}
+
+impl LeaderboardPolicy {
+    pub fn page_size(&self, requested: Option<u32>) -> Result<usize, DomainError> {
+        match requested {
+            None | Some(0) => Ok(10.min(self.max_page_size) as usize),
+            Some(limit) if limit <= self.max_page_size => Ok(limit as usize),
+            Some(limit) => Err(DomainError::InvalidPayload(format!(
+                "limit {} exceeds the maximum of {}",
+                limit, self.max_page_size
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 2, 14, 12, 0, 0).unwrap() + Duration::seconds(secs)
+    }
+
+    fn entry(player: &str, value: i64, secs: i64) -> LeaderboardEntry {
+        LeaderboardEntry {
+            player_id: player.to_string(),
+            value,
+            achieved_at: at(secs),
+            session_id: format!("{}-{}", player, secs),
+        }
+    }
+
+    fn ranking(board: &Leaderboard) -> Vec<(u32, String, i64)> {
+        board.top(usize::MAX).into_iter().map(|r| (r.rank, r.entry.player_id, r.entry.value)).collect()
+    }
+
+    #[test]
+    fn trivia_ranks_the_highest_score_first() {
+        let mut board = Leaderboard::new(RankOrder::for_game(&GameType::Trivia));
+        board.submit(entry("a", 300, 0));
+        board.submit(entry("b", 900, 1));
+        board.submit(entry("c", 600, 2));
+        assert_eq!(
+            ranking(&board),
+            [(1, "b".to_string(), 900), (2, "c".to_string(), 600), (3, "a".to_string(), 300)]
+        );
+    }
+
+    #[test]
+    fn puzzles_rank_the_fastest_time_first() {
+        let mut board = Leaderboard::new(RankOrder::for_game(&GameType::Puzzle));
+        board.submit(entry("slow", 90_000, 0));
+        board.submit(entry("fast", 45_000, 1));
+        assert_eq!(board.rank_of(&"fast".to_string()).unwrap().rank, 1);
+        assert_eq!(board.rank_of(&"slow".to_string()).unwrap().rank, 2);
+    }
+
+    #[test]
+    fn ties_go_to_whoever_got_there_first() {
+        let mut board = Leaderboard::new(RankOrder::HighestFirst);
+        board.submit(entry("late", 500, 10));
+        board.submit(entry("early", 500, 5));
+        assert_eq!(ranking(&board), [(1, "early".to_string(), 500), (2, "late".to_string(), 500)]);
+    }
+
+    #[test]
+    fn only_a_players_best_result_counts() {
+        let mut board = Leaderboard::new(RankOrder::HighestFirst);
+        assert!(board.submit(entry("a", 500, 0)));
+        board.submit(entry("b", 700, 1));
+        assert!(!board.submit(entry("a", 400, 2)));
+        // Matching the best again doesn't count either: the earlier result stands.
+        assert!(!board.submit(entry("a", 500, 3)));
+        assert_eq!(board.rank_of(&"a".to_string()).unwrap().entry.achieved_at, at(0));
+
+        assert!(board.submit(entry("a", 800, 4)));
+        assert_eq!(board.len(), 2);
+        assert_eq!(ranking(&board), [(1, "a".to_string(), 800), (2, "b".to_string(), 700)]);
+        assert!(board.rank_of(&"nobody".to_string()).is_none());
+    }
+
+    #[test]
+    fn top_is_limited_but_ranks_stay_global() {
+        let mut board = Leaderboard::new(RankOrder::HighestFirst);
+        for (i, player) in ["a", "b", "c", "d"].into_iter().enumerate() {
+            board.submit(entry(player, 100 * i as i64, i as i64));
+        }
+        assert_eq!(board.top(2).len(), 2);
+        assert_eq!(board.rank_of(&"a".to_string()).unwrap().rank, 4);
+    }
+
+    #[test]
+    fn windows_follow_iso_weeks_and_utc_days() {
+        let new_year = Utc.with_ymd_and_hms(2024, 12, 31, 23, 0, 0).unwrap();
+        assert_eq!(LeaderboardPeriod::Weekly.window(new_year), "2025-W01");
+        assert_eq!(LeaderboardPeriod::Daily.window(new_year), "2024-12-31");
+        assert_eq!(LeaderboardPeriod::AllTime.window(new_year), "all");
+    }
+
+    #[test]
+    fn ruleset_revisions_share_a_board() {
+        let v1 = BoardId::new(GameType::Trivia, Some("quick-5@1"), LeaderboardPeriod::AllTime, at(0));
+        let v2 = BoardId::new(GameType::Trivia, Some("quick-5@2"), LeaderboardPeriod::AllTime, at(0));
+        assert_eq!(v1, v2);
+        assert_eq!(v1.ruleset_id.as_deref(), Some("quick-5"));
+    }
+
+    #[test]
+    fn page_size_defaults_and_caps() {
+        let policy = LeaderboardPolicy::default();
+        assert_eq!(policy.page_size(None).unwrap(), 10);
+        assert_eq!(policy.page_size(Some(100)).unwrap(), 100);
+        assert!(policy.page_size(Some(101)).is_err());
+    }
+}
@@ -1,3 +1,17 @@
+pub mod achievement;
+pub mod anti_cheat;
+pub mod board;
+pub mod daily;
 pub mod game;
+pub mod leaderboard;
+pub mod outcome;
+pub mod profile;
 pub mod puzzle;
-pub mod trivia;
\ No newline at end of file
+pub mod question;
+pub mod replay;
+pub mod rating;
+pub mod room;
+pub mod rules;
+pub mod scoring;
+pub mod trivia;
+pub mod turn;
\ No newline at end of file
@@ -0,0 +1,112 @@
+//! Ranked results of finished games, aggregated from a session and the events that ended it.
+
+use crate::domain::game::{DomainEvent, GameSessionID, GameType, PlayerID, Session};
+use crate::domain::puzzle::{PuzzleSolved, RaceFinished};
+use crate::domain::rules::{GameRules, PuzzleMode};
+use crate::domain::trivia::TriviaFinished;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::any::Any;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Placement {
+    pub player_id: PlayerID,
+    /// 1-based. Players who tied share a rank and the next rank is skipped (1, 1, 3).
+    pub rank: u32,
+}
+
+/// Final placings of a competitive game.
+#[derive(Debug, Clone, Serialize)]
+pub struct GameOutcome {
+    pub session_id: GameSessionID,
+    pub game_type: GameType,
+    pub ruleset_id: Option<String>,
+    pub finished_at: DateTime<Utc>,
+    /// Best rank first.
+    pub placements: Vec<Placement>,
+}
+
+impl GameOutcome {
+    /// Outcome of `session` if `events` contain the event that ended it: final trivia scores, the
+    /// race standings, or the solve of a turn-based puzzle, which the solver wins and everyone
+    /// else at the table shares second place in. Only seated players are placed; spectators and
+    /// players who left are not.
+    pub fn from_events<'a>(session: &Session, events: impl IntoIterator<Item = &'a dyn DomainEvent>) -> Option<Self> {
+        let seated = |player_id: &PlayerID| session.players.iter().any(|p| p.id == *player_id);
+        for event in events {
+            if event.session_id() != &session.id {
+                continue;
+            }
+            let event = event as &dyn Any;
+            let (finished_at, ranked) = if let Some(e) = event.downcast_ref::<TriviaFinished>() {
+                // Equal scores tie.
+                let ranked: Vec<(PlayerID, i64)> = e
+                    .scores
+                    .iter()
+                    .filter(|s| seated(&s.player_id))
+                    .map(|s| (s.player_id.clone(), -(s.score as i64)))
+                    .collect();
+                (e.meta.at, ranked)
+            } else if let Some(e) = event.downcast_ref::<RaceFinished>() {
+                // Finishers keep their order; everyone still solving ties for the places after them.
+                let ranked: Vec<(PlayerID, i64)> = e
+                    .standings
+                    .iter()
+                    .filter(|s| seated(&s.player_id))
+                    .map(|s| (s.player_id.clone(), if s.finished { s.rank as i64 } else { i64::MAX }))
+                    .collect();
+                (e.meta.at, ranked)
+            } else if let Some(e) = event.downcast_ref::<PuzzleSolved>() {
+                // A racer's solve only ends their own run; the race ends with `RaceFinished`.
+                if matches!(&session.rules, GameRules::Puzzle(rules) if rules.mode == PuzzleMode::Race) {
+                    continue;
+                }
+                let ranked: Vec<(PlayerID, i64)> = session
+                    .players
+                    .iter()
+                    .map(|p| (p.id.clone(), if p.id == e.player_id { 1 } else { 2 }))
+                    .collect();
+                (e.meta.at, ranked)
+            } else {
+                continue;
+            };
+            return Some(Self {
+                session_id: session.id.clone(),
+                game_type: session.game_type.clone(),
+                ruleset_id: session.ruleset_id.clone(),
+                finished_at,
+                placements: competition_ranks(ranked),
+            });
+        }
+        None
+    }
+
+    /// Whether at least two players were placed against each other.
+    pub fn is_contested(&self) -> bool {
+        self.placements.len() >= 2
+    }
+
+    /// Result of `a` against `b` for rating purposes: 1.0 for a win, 0.5 for a tie, 0.0 for a loss.
+    pub fn score_against(a: &Placement, b: &Placement) -> f64 {
+        match a.rank.cmp(&b.rank) {
+            std::cmp::Ordering::Less => 1.0,
+            std::cmp::Ordering::Equal => 0.5,
+            std::cmp::Ordering::Greater => 0.0,
+        }
+    }
+}
+
+/// Ranks players by `key`, lowest first, giving equal keys the same rank.
+fn competition_ranks(mut ranked: Vec<(PlayerID, i64)>) -> Vec<Placement> {
+    ranked.sort_by_key(|(_, key)| *key);
+    let mut placements: Vec<Placement> = Vec::with_capacity(ranked.len());
+    for (i, (player_id, key)) in ranked.iter().enumerate() {
+        let rank = match i {
+            0 => 1,
+            _ if ranked[i - 1].1 == *key => placements[i - 1].rank,
+            _ => i as u32 + 1,
+        };
+        placements.push(Placement { player_id: player_id.clone(), rank });
+    }
+    placements
+}
@@ -0,0 +1,133 @@
+//! Player profiles and the lifetime stats kept for each game type.
+
+use crate::domain::game::{DomainError, GameType, PlayerID};
+use crate::domain::rules::TriviaCategory;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+pub const MAX_DISPLAY_NAME_CHARS: usize = 32;
+pub const MAX_AVATAR_URL_CHARS: usize = 512;
+
+/// Client settings that follow the player between devices.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preferences {
+    pub sound: bool,
+    pub reduced_motion: bool,
+    pub theme: Option<String>,
+    pub locale: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerProfile {
+    pub player_id: PlayerID,
+    pub display_name: String,
+    pub avatar_url: Option<String>,
+    #[serde(default)]
+    pub preferences: Preferences,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PlayerProfile {
+    /// Profile for a player seen for the first time, named after their id until they pick a name.
+    pub fn new(player_id: &PlayerID, now: DateTime<Utc>) -> Self {
+        let display_name = format!("Player {}", player_id.chars().take(6).collect::<String>());
+        Self {
+            player_id: player_id.clone(),
+            display_name,
+            avatar_url: None,
+            preferences: Preferences::default(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), DomainError> {
+        let name = self.display_name.trim();
+        if name.is_empty() || name.chars().count() > MAX_DISPLAY_NAME_CHARS {
+            return Err(DomainError::InvalidPayload(format!(
+                "display_name must be 1 to {} characters",
+                MAX_DISPLAY_NAME_CHARS
+            )));
+        }
+        if name.chars().any(char::is_control) {
+            return Err(DomainError::InvalidPayload("display_name must not contain control characters".to_string()));
+        }
+        if let Some(url) = &self.avatar_url {
+            if !url.starts_with("https://") || url.chars().count() > MAX_AVATAR_URL_CHARS {
+                return Err(DomainError::InvalidPayload(format!(
+                    "avatar_url must be an https URL of at most {} characters",
+                    MAX_AVATAR_URL_CHARS
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CategoryAccuracy {
+    pub answered: u32,
+    pub correct: u32,
+}
+
+impl CategoryAccuracy {
+    /// Share of answered questions that were right, from 0.0 to 1.0.
+    pub fn accuracy(&self) -> f64 {
+        match self.answered {
+            0 => 0.0,
+            answered => self.correct as f64 / answered as f64,
+        }
+    }
+}
+
+/// Lifetime stats for one player in one game type.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameStats {
+    pub games_played: u32,
+    /// Games finished in first place against at least one other player.
+    pub wins: u32,
+    /// Puzzle only: fastest solve, in milliseconds from the start of the game.
+    pub best_puzzle_time_ms: Option<i64>,
+    /// Trivia only: answers given per category.
+    pub trivia_accuracy: BTreeMap<TriviaCategory, CategoryAccuracy>,
+}
+
+impl GameStats {
+    pub fn record_puzzle_time(&mut self, elapsed_ms: i64) {
+        if self.best_puzzle_time_ms.is_none_or(|best| elapsed_ms < best) {
+            self.best_puzzle_time_ms = Some(elapsed_ms);
+        }
+    }
+
+    pub fn record_answer(&mut self, category: TriviaCategory, correct: bool) {
+        let entry = self.trivia_accuracy.entry(category).or_default();
+        entry.answered += 1;
+        if correct {
+            entry.correct += 1;
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerStats {
+    pub player_id: PlayerID,
+    #[serde(default)]
+    pub games: BTreeMap<GameType, GameStats>,
+}
+
+impl PlayerStats {
+    pub fn new(player_id: &PlayerID) -> Self {
+        Self {
+            player_id: player_id.clone(),
+            games: BTreeMap::new(),
+        }
+    }
+
+    pub fn game_mut(&mut self, game_type: &GameType) -> &mut GameStats {
+        self.games.entry(game_type.clone()).or_default()
+    }
+}
@@ -1,12 +1,17 @@
 use crate::domain::game::{
-    DomainError, DomainEvent, EventMeta, GameCommand, GameSessionID, GameType, PlayerID, Session,
+    DomainError, DomainEvent, EventMeta, GameCommand, GameSessionID, GameType, PlayerID, Role, RemovePlayerCommand, SeatPlayerCommand, Session,
 };
+use crate::domain::board::{Board, Pos};
+use crate::domain::rules::{PuzzleMode, PuzzleRules};
+use crate::domain::turn::{TurnChangeReason, TurnOrder, TurnTransition};
 use crate::pb::runecraftstudios::pastello::game::puzzle::v1::{MovePieceCommand, UndoMoveCommand};
 use crate::ports::{Clock, IdGenerator, Rng};
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
 use std::any::Any;
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
 use std::sync::Arc;
 use std::fmt;
 
@@ -14,11 +19,29 @@ use std::fmt;
 
 impl GameCommand for MovePieceCommand {
     fn get_type(&self) -> String { "MovePieceCommand".to_string() }
+    fn registered_type(&self) -> &'static str { "PuzzleMove" }
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "session_id": self.session_id.as_ref().map(|s| s.value.as_str()).unwrap_or_default(),
+            "player_id": self.player_id.as_ref().map(|p| p.value.as_str()).unwrap_or_default(),
+            "from_x": self.from_x,
+            "from_y": self.from_y,
+            "to_x": self.to_x,
+            "to_y": self.to_y,
+        })
+    }
     fn into_any(self: Box<Self>) -> Box<dyn Any + Send> { self }
 }
 
 impl GameCommand for UndoMoveCommand {
     fn get_type(&self) -> String { "UndoMoveCommand".to_string() }
+    fn registered_type(&self) -> &'static str { "PuzzleUndo" }
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "session_id": self.session_id.as_ref().map(|s| s.value.as_str()).unwrap_or_default(),
+            "player_id": self.player_id.as_ref().map(|p| p.value.as_str()).unwrap_or_default(),
+        })
+    }
     fn into_any(self: Box<Self>) -> Box<dyn Any + Send> { self }
 }
 
@@ -27,7 +50,7 @@ impl GameCommand for UndoMoveCommand {
 #[derive(Clone)]
 pub struct EngineDependencies {
     clock: Arc<dyn Clock>,
-    _rng: Arc<dyn Rng>,
+    rng: Arc<dyn Rng>,
     _id_gen: Arc<dyn IdGenerator>,
 }
 
@@ -43,40 +66,181 @@ impl fmt::Debug for EngineDependencies {
 
 // --- EVENTS ---
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+pub struct PuzzleStarted {
+    pub meta: EventMeta,
+    pub session_id: GameSessionID,
+    pub mode: PuzzleMode,
+    pub board: Board,
+}
+
+impl DomainEvent for PuzzleStarted {
+    fn event_type(&self) -> &'static str { "puzzle.started" }
+    fn session_id(&self) -> &GameSessionID { &self.session_id }
+    fn to_json(&self) -> serde_json::Value { serde_json::to_value(self).unwrap_or_default() }
+    fn to_any_box(self: Box<Self>) -> Box<dyn Any + Send> { self }
+    fn clone_box(&self) -> Box<dyn DomainEvent> { Box::new(self.clone()) }
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct PieceMoved {
     pub meta: EventMeta,
     pub session_id: GameSessionID,
     pub player_id: PlayerID,
+    pub from: Pos,
+    pub to: Pos,
+    /// Race mode: the move is on the mover's own board, so only they are sent it.
+    #[serde(skip)]
+    pub private: bool,
 }
 
 impl DomainEvent for PieceMoved {
     fn event_type(&self) -> &'static str { "puzzle.piece_moved" }
     fn session_id(&self) -> &GameSessionID { &self.session_id }
+    fn to_json(&self) -> serde_json::Value { serde_json::to_value(self).unwrap_or_default() }
     fn to_any_box(self: Box<Self>) -> Box<dyn Any + Send> { self }
     fn clone_box(&self) -> Box<dyn DomainEvent> { Box::new(self.clone()) }
+    fn visible_to(&self, viewer: &PlayerID, role: Role) -> bool {
+        !self.private || (role == Role::Player && *viewer == self.player_id)
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct MoveUndone {
     pub meta: EventMeta,
     pub session_id: GameSessionID,
     pub player_id: PlayerID,
+    /// Race mode: see `PieceMoved::private`.
+    #[serde(skip)]
+    pub private: bool,
 }
 
 impl DomainEvent for MoveUndone {
     fn event_type(&self) -> &'static str { "puzzle.move_undone" }
     fn session_id(&self) -> &GameSessionID { &self.session_id }
+    fn to_json(&self) -> serde_json::Value { serde_json::to_value(self).unwrap_or_default() }
+    fn to_any_box(self: Box<Self>) -> Box<dyn Any + Send> { self }
+    fn clone_box(&self) -> Box<dyn DomainEvent> { Box::new(self.clone()) }
+    fn visible_to(&self, viewer: &PlayerID, role: Role) -> bool {
+        !self.private || (role == Role::Player && *viewer == self.player_id)
+    }
+}
+
+/// Race mode only: lets opponents follow a player's board without seeing it.
+#[derive(Debug, Clone, Serialize)]
+pub struct RaceProgress {
+    pub meta: EventMeta,
+    pub session_id: GameSessionID,
+    pub player_id: PlayerID,
+    pub percent_in_place: u32,
+    pub moves: u32,
+}
+
+impl DomainEvent for RaceProgress {
+    fn event_type(&self) -> &'static str { "puzzle.race_progress" }
+    fn session_id(&self) -> &GameSessionID { &self.session_id }
+    fn to_json(&self) -> serde_json::Value { serde_json::to_value(self).unwrap_or_default() }
     fn to_any_box(self: Box<Self>) -> Box<dyn Any + Send> { self }
     fn clone_box(&self) -> Box<dyn DomainEvent> { Box::new(self.clone()) }
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct PuzzleSolved {
+    pub meta: EventMeta,
+    pub session_id: GameSessionID,
+    pub player_id: PlayerID,
+    pub moves: u32,
+    pub elapsed_ms: i64,
+}
+
+impl DomainEvent for PuzzleSolved {
+    fn event_type(&self) -> &'static str { "puzzle.solved" }
+    fn session_id(&self) -> &GameSessionID { &self.session_id }
+    fn to_json(&self) -> serde_json::Value { serde_json::to_value(self).unwrap_or_default() }
+    fn to_any_box(self: Box<Self>) -> Box<dyn Any + Send> { self }
+    fn clone_box(&self) -> Box<dyn DomainEvent> { Box::new(self.clone()) }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RaceStanding {
+    pub rank: u32,
+    pub player_id: PlayerID,
+    pub finished: bool,
+    pub elapsed_ms: Option<i64>,
+    pub moves: u32,
+    pub percent_in_place: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RaceFinished {
+    pub meta: EventMeta,
+    pub session_id: GameSessionID,
+    pub standings: Vec<RaceStanding>,
+}
+
+impl DomainEvent for RaceFinished {
+    fn event_type(&self) -> &'static str { "puzzle.race_finished" }
+    fn session_id(&self) -> &GameSessionID { &self.session_id }
+    fn to_json(&self) -> serde_json::Value { serde_json::to_value(self).unwrap_or_default() }
+    fn to_any_box(self: Box<Self>) -> Box<dyn Any + Send> { self }
+    fn clone_box(&self) -> Box<dyn DomainEvent> { Box::new(self.clone()) }
+}
+
+// --- SNAPSHOT ---
+
+/// State sent to a client when it attaches. In race mode boards are private: racers get their
+/// own, everyone else only the standings.
+#[derive(Debug, Clone, Serialize)]
+pub struct PuzzleSnapshot {
+    pub session_id: GameSessionID,
+    pub mode: PuzzleMode,
+    pub finished: bool,
+    pub board: Option<Board>,
+    pub active_player: Option<PlayerID>,
+    pub standings: Vec<RaceStanding>,
+}
+
 // --- ENGINE STATE ---
 
+/// One player's private board in race mode.
+#[derive(Debug, Clone)]
+pub struct Racer {
+    board: Board,
+    move_history: VecDeque<MovePieceCommand>,
+    // When the racer got their board: the start of the race, or when they joined it.
+    started_at: DateTime<Utc>,
+    finished_at: Option<DateTime<Utc>>,
+}
+
+impl Racer {
+    fn new(board: Board, started_at: DateTime<Utc>) -> Self {
+        Self {
+            board,
+            move_history: VecDeque::new(),
+            started_at,
+            finished_at: None,
+        }
+    }
+
+    /// Moves on the board, not counting undone ones.
+    fn moves(&self) -> u32 {
+        self.move_history.len() as u32
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct State {
-    _board: Vec<Vec<u32>>,
+    session_id: GameSessionID,
+    rules: PuzzleRules,
+    started_at: Option<DateTime<Utc>>,
+    finished: bool,
+    // Turn-based mode: one shared board.
+    board: Board,
     move_history: VecDeque<MovePieceCommand>,
+    turns: TurnOrder,
+    // Race mode: one board per player, all copies of `start_board`.
+    start_board: Board,
+    racers: BTreeMap<PlayerID, Racer>,
 }
 
 // --- ENGINE IMPLEMENTATION ---
@@ -92,13 +256,23 @@ impl PuzzleEngine {
         clock: Arc<dyn Clock>,
         rng: Arc<dyn Rng>,
         id_gen: Arc<dyn IdGenerator>,
+        rules: PuzzleRules,
     ) -> Self {
+        let size = rules.difficulty.board_size();
+        let turn_timeout = chrono::Duration::seconds(rules.turn_timeout_seconds as i64);
         Self {
             state: State {
-                _board: vec![vec![0; 3]; 3],
+                session_id: GameSessionID::new(),
+                rules,
+                started_at: None,
+                finished: false,
+                board: Board::solved(size),
                 move_history: VecDeque::new(),
+                turns: TurnOrder::new(turn_timeout),
+                start_board: Board::solved(size),
+                racers: BTreeMap::new(),
             },
-            deps: EngineDependencies { clock, _rng: rng, _id_gen: id_gen },
+            deps: EngineDependencies { clock, rng, _id_gen: id_gen },
         }
     }
 
@@ -107,23 +281,62 @@ impl PuzzleEngine {
         session_id: &GameSessionID,
         cmd: &MovePieceCommand,
     ) -> Result<Vec<Box<dyn DomainEvent>>, DomainError> {
-        if cmd.to_x > 10 || cmd.to_y > 10 {
-            return Err(DomainError::OutOfBounds);
+        if self.state.finished {
+            return Err(DomainError::GameFinished);
         }
+        let player_id = player_of(cmd);
+        let from = Pos { x: cmd.from_x, y: cmd.from_y };
+        let to = Pos { x: cmd.to_x, y: cmd.to_y };
 
-        self.state.move_history.push_back(cmd.clone());
+        let mut events: Vec<Box<dyn DomainEvent>> = Vec::new();
+        match self.state.rules.mode {
+            PuzzleMode::TurnBased => {
+                self.state.turns.ensure_turn(&player_id)?;
+                self.state.board.slide(from, to)?;
+                self.state.move_history.push_back(cmd.clone());
+                events.push(self.piece_moved(session_id, &player_id, from, to));
 
-        let event = PieceMoved {
-            meta: crate::domain::game::new_meta(self.deps.clock.as_ref()),
-            session_id: session_id.clone(),
-            player_id: cmd
-                .player_id
-                .as_ref()
-                .map(|p| p.value.clone())
-                .unwrap_or_default(),
-        };
+                let now = self.deps.clock.now_utc();
+                if self.state.board.is_solved() {
+                    self.state.finished = true;
+                    let moves = self.state.move_history.len() as u32;
+                    let started_at = self.state.started_at.unwrap_or(now);
+                    events.push(self.solved(&player_id, moves, started_at, now));
+                } else if let Some(transition) = self.state.turns.advance(now, TurnChangeReason::Moved) {
+                    events.push(self.turn_event(transition));
+                }
+            }
+            PuzzleMode::Race => {
+                let racer = self
+                    .state
+                    .racers
+                    .get_mut(&player_id)
+                    .ok_or_else(|| DomainError::NotAPlayer(player_id.clone()))?;
+                if racer.finished_at.is_some() {
+                    return Err(DomainError::GameFinished);
+                }
+                racer.board.slide(from, to)?;
+                racer.move_history.push_back(cmd.clone());
+
+                let now = self.deps.clock.now_utc();
+                let solved = racer.board.is_solved();
+                if solved {
+                    racer.finished_at = Some(now);
+                }
+
+                events.push(self.piece_moved(session_id, &player_id, from, to));
+                events.push(self.progress(&player_id));
+                if solved {
+                    let racer = &self.state.racers[&player_id];
+                    events.push(self.solved(&player_id, racer.moves(), racer.started_at, now));
+                    if self.state.racers.values().all(|r| r.finished_at.is_some()) {
+                        events.push(self.finish_race());
+                    }
+                }
+            }
+        }
 
-        Ok(vec![Box::new(event)])
+        Ok(events)
     }
 
     fn undo_move(
@@ -131,18 +344,208 @@ impl PuzzleEngine {
         session_id: &GameSessionID,
         player_id: &PlayerID,
     ) -> Result<Vec<Box<dyn DomainEvent>>, DomainError> {
-        if self.state.move_history.pop_back().is_none() {
-            return Err(DomainError::NothingToUndo);
+        if self.state.finished {
+            return Err(DomainError::GameFinished);
         }
 
-        let event = MoveUndone {
+        let mut events: Vec<Box<dyn DomainEvent>> = Vec::new();
+        match self.state.rules.mode {
+            PuzzleMode::TurnBased => {
+                let last = self.state.move_history.back().ok_or(DomainError::NothingToUndo)?;
+                if &player_of(last) != player_id {
+                    return Err(DomainError::UndoNotAllowed);
+                }
+                let last = self.state.move_history.pop_back().ok_or(DomainError::NothingToUndo)?;
+                undo_slide(&mut self.state.board, &last)?;
+                events.push(self.move_undone(session_id, player_id));
+
+                // Undoing a move gives the turn back to the player who made it.
+                let now = self.deps.clock.now_utc();
+                if let Some(transition) = self.state.turns.rewind_to(player_id, now) {
+                    events.push(self.turn_event(transition));
+                }
+            }
+            PuzzleMode::Race => {
+                let racer = self
+                    .state
+                    .racers
+                    .get_mut(player_id)
+                    .ok_or_else(|| DomainError::NotAPlayer(player_id.clone()))?;
+                if racer.finished_at.is_some() {
+                    return Err(DomainError::GameFinished);
+                }
+                let last = racer.move_history.pop_back().ok_or(DomainError::NothingToUndo)?;
+                undo_slide(&mut racer.board, &last)?;
+
+                events.push(self.move_undone(session_id, player_id));
+                events.push(self.progress(player_id));
+            }
+        }
+
+        Ok(events)
+    }
+
+    /// Seats a player who joined after the start: a new racer gets the same board everyone
+    /// started with, and a turn-based player goes to the end of the turn order.
+    fn seat_player(&mut self, player_id: &PlayerID) -> Result<Vec<Box<dyn DomainEvent>>, DomainError> {
+        if self.state.finished {
+            return Err(DomainError::GameFinished);
+        }
+        let now = self.deps.clock.now_utc();
+        match self.state.rules.mode {
+            PuzzleMode::TurnBased => {
+                self.state.turns.seat(player_id);
+                // Nobody was seated when the game started, so the first turn is this player's.
+                Ok(self
+                    .state
+                    .turns
+                    .start(now)
+                    .map(|transition| self.turn_event(transition))
+                    .into_iter()
+                    .collect())
+            }
+            PuzzleMode::Race => {
+                if self.state.racers.contains_key(player_id) {
+                    return Ok(vec![]);
+                }
+                let racer = Racer::new(self.state.start_board.clone(), now);
+                self.state.racers.insert(player_id.clone(), racer);
+                Ok(vec![self.progress(player_id)])
+            }
+        }
+    }
+
+    /// Ranks finished racers by time then moves, followed by everyone still solving. Racers
+    /// who joined late are timed from when they joined.
+    fn standings(&self) -> Vec<RaceStanding> {
+        let mut standings: Vec<RaceStanding> = self
+            .state
+            .racers
+            .iter()
+            .map(|(player_id, racer)| RaceStanding {
+                rank: 0,
+                player_id: player_id.clone(),
+                finished: racer.finished_at.is_some(),
+                elapsed_ms: racer.finished_at.map(|at| (at - racer.started_at).num_milliseconds()),
+                moves: racer.moves(),
+                percent_in_place: racer.board.percent_in_place(),
+            })
+            .collect();
+
+        standings.sort_by(|a, b| {
+            b.finished
+                .cmp(&a.finished)
+                .then(a.elapsed_ms.cmp(&b.elapsed_ms))
+                .then(b.percent_in_place.cmp(&a.percent_in_place))
+                .then(a.moves.cmp(&b.moves))
+        });
+        for (i, standing) in standings.iter_mut().enumerate() {
+            standing.rank = i as u32 + 1;
+        }
+        standings
+    }
+
+    /// Takes a kicked player out of the game. In turn-based mode the turn moves on if it was
+    /// theirs; a race ends once everyone still racing has finished.
+    fn remove_player(&mut self, player_id: &PlayerID) -> Result<Vec<Box<dyn DomainEvent>>, DomainError> {
+        if self.state.finished {
+            return Err(DomainError::GameFinished);
+        }
+        let now = self.deps.clock.now_utc();
+        match self.state.rules.mode {
+            PuzzleMode::TurnBased => Ok(self
+                .state
+                .turns
+                .remove(player_id, now)
+                .map(|transition| self.turn_event(transition))
+                .into_iter()
+                .collect()),
+            PuzzleMode::Race => {
+                if self.state.racers.remove(player_id).is_none() {
+                    return Ok(vec![]);
+                }
+                let everyone_done = !self.state.racers.is_empty()
+                    && self.state.racers.values().all(|r| r.finished_at.is_some());
+                Ok(if everyone_done { vec![self.finish_race()] } else { vec![] })
+            }
+        }
+    }
+
+    fn finish_race(&mut self) -> Box<dyn DomainEvent> {
+        self.state.finished = true;
+        Box::new(RaceFinished {
+            meta: crate::domain::game::new_meta(self.deps.clock.as_ref()),
+            session_id: self.state.session_id.clone(),
+            standings: self.standings(),
+        })
+    }
+
+    fn piece_moved(&self, session_id: &GameSessionID, player_id: &PlayerID, from: Pos, to: Pos) -> Box<dyn DomainEvent> {
+        Box::new(PieceMoved {
             meta: crate::domain::game::new_meta(self.deps.clock.as_ref()),
             session_id: session_id.clone(),
             player_id: player_id.clone(),
-        };
+            from,
+            to,
+            private: self.state.rules.mode == PuzzleMode::Race,
+        })
+    }
 
-        Ok(vec![Box::new(event)])
+    fn move_undone(&self, session_id: &GameSessionID, player_id: &PlayerID) -> Box<dyn DomainEvent> {
+        Box::new(MoveUndone {
+            meta: crate::domain::game::new_meta(self.deps.clock.as_ref()),
+            session_id: session_id.clone(),
+            player_id: player_id.clone(),
+            private: self.state.rules.mode == PuzzleMode::Race,
+        })
+    }
+
+    fn progress(&self, player_id: &PlayerID) -> Box<dyn DomainEvent> {
+        let racer = &self.state.racers[player_id];
+        Box::new(RaceProgress {
+            meta: crate::domain::game::new_meta(self.deps.clock.as_ref()),
+            session_id: self.state.session_id.clone(),
+            player_id: player_id.clone(),
+            percent_in_place: racer.board.percent_in_place(),
+            moves: racer.moves(),
+        })
+    }
+
+    fn solved(&self, player_id: &PlayerID, moves: u32, started_at: DateTime<Utc>, at: DateTime<Utc>) -> Box<dyn DomainEvent> {
+        let elapsed_ms = (at - started_at).num_milliseconds();
+        Box::new(PuzzleSolved {
+            meta: crate::domain::game::new_meta(self.deps.clock.as_ref()),
+            session_id: self.state.session_id.clone(),
+            player_id: player_id.clone(),
+            moves,
+            elapsed_ms,
+        })
+    }
+
+    fn turn_event(&self, transition: TurnTransition) -> Box<dyn DomainEvent> {
+        let meta = crate::domain::game::new_meta(self.deps.clock.as_ref());
+        Box::new(transition.into_event(meta, &self.state.session_id))
     }
+
+    fn time_is_up(&self, now: DateTime<Utc>) -> bool {
+        let limit = self.state.rules.time_limit_seconds;
+        match self.state.started_at {
+            Some(started_at) if limit > 0 => now - started_at >= chrono::Duration::seconds(limit as i64),
+            _ => false,
+        }
+    }
+}
+
+fn player_of(cmd: &MovePieceCommand) -> PlayerID {
+    cmd.player_id.as_ref().map(|p| p.value.clone()).unwrap_or_default()
+}
+
+// Reverses a recorded move: the moved tile now sits on `to` and the blank on `from`.
+fn undo_slide(board: &mut Board, cmd: &MovePieceCommand) -> Result<(), DomainError> {
+    board.slide(
+        Pos { x: cmd.to_x, y: cmd.to_y },
+        Pos { x: cmd.from_x, y: cmd.from_y },
+    )
 }
 
 #[async_trait]
@@ -159,13 +562,95 @@ impl crate::domain::game::Engine for PuzzleEngine {
         Ok((session, vec![]))
     }
 
+    async fn start(&mut self, session: &Session) -> Result<Vec<Box<dyn DomainEvent>>, DomainError> {
+        self.state.session_id = session.id.clone();
+
+        // Scrambled once for the whole session, so racers get identical puzzles.
+        let difficulty = self.state.rules.difficulty;
+        let board = Board::scrambled(difficulty.board_size(), difficulty.scramble_moves(), self.deps.rng.as_ref());
+
+        let now = self.deps.clock.now_utc();
+        self.state.started_at = Some(now);
+
+        let mut events: Vec<Box<dyn DomainEvent>> = vec![Box::new(PuzzleStarted {
+            meta: crate::domain::game::new_meta(self.deps.clock.as_ref()),
+            session_id: session.id.clone(),
+            mode: self.state.rules.mode,
+            board: board.clone(),
+        })];
+
+        match self.state.rules.mode {
+            PuzzleMode::TurnBased => {
+                self.state.board = board;
+                for player in &session.players {
+                    self.state.turns.seat(&player.id);
+                }
+                if let Some(transition) = self.state.turns.start(now) {
+                    events.push(self.turn_event(transition));
+                }
+            }
+            PuzzleMode::Race => {
+                for player in &session.players {
+                    self.state.racers.insert(player.id.clone(), Racer::new(board.clone(), now));
+                }
+                self.state.start_board = board;
+            }
+        }
+
+        Ok(events)
+    }
+
+    fn snapshot(&self, viewer: &PlayerID, role: Role) -> serde_json::Value {
+        let (board, standings) = match self.state.rules.mode {
+            PuzzleMode::TurnBased => (Some(self.state.board.clone()), Vec::new()),
+            PuzzleMode::Race => {
+                let own = match role {
+                    Role::Player => self.state.racers.get(viewer).map(|r| r.board.clone()),
+                    Role::Spectator => None,
+                };
+                (own, self.standings())
+            }
+        };
+        let snapshot = PuzzleSnapshot {
+            session_id: self.state.session_id.clone(),
+            mode: self.state.rules.mode,
+            finished: self.state.finished,
+            board,
+            active_player: self.state.turns.active_player().cloned(),
+            standings,
+        };
+        serde_json::to_value(snapshot).unwrap_or_default()
+    }
+
+    fn tick(&mut self) -> Vec<Box<dyn DomainEvent>> {
+        if self.state.finished {
+            return vec![];
+        }
+        let now = self.deps.clock.now_utc();
+        match self.state.rules.mode {
+            PuzzleMode::TurnBased => self
+                .state
+                .turns
+                .expire(now)
+                .map(|transition| self.turn_event(transition))
+                .into_iter()
+                .collect(),
+            PuzzleMode::Race if self.time_is_up(now) => vec![self.finish_race()],
+            PuzzleMode::Race => vec![],
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.state.finished
+    }
+
     fn execute_command(
         &mut self,
         command: Box<dyn crate::domain::game::GameCommand>,
-    ) -> Result<(), DomainError> {
+    ) -> Result<Vec<Box<dyn DomainEvent>>, DomainError> {
         let command_type = command.get_type();
 
-        match command_type.as_str() {
+        let events = match command_type.as_str() {
             "MovePieceCommand" => {
                 // FIX: Cast to Any first using helper
                 let any_cmd = command.into_any();
@@ -176,7 +661,7 @@ impl crate::domain::game::Engine for PuzzleEngine {
                     .as_ref()
                     .map(|s| s.value.clone())
                     .unwrap_or_default();
-                self.move_piece(&session_id, &cmd)?;
+                self.move_piece(&session_id, &cmd)?
             }
             "UndoMoveCommand" => {
                 let any_cmd = command.into_any();
@@ -193,11 +678,255 @@ impl crate::domain::game::Engine for PuzzleEngine {
                     .map(|p| p.value.clone())
                     .unwrap_or_default();
 
-                self.undo_move(&session_id, &player_id)?;
+                self.undo_move(&session_id, &player_id)?
+            }
+            "SeatPlayerCommand" => {
+                let any_cmd = command.into_any();
+                let cmd = any_cmd.downcast::<SeatPlayerCommand>().map_err(|_| DomainError::Internal("Downcast failed".into()))?;
+                self.seat_player(&cmd.player_id)?
+            }
+            "RemovePlayerCommand" => {
+                let any_cmd = command.into_any();
+                let cmd = any_cmd.downcast::<RemovePlayerCommand>().map_err(|_| DomainError::Internal("Downcast failed".into()))?;
+                self.remove_player(&cmd.player_id)?
             }
             _ => return Err(DomainError::InvalidCommand),
+        };
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::{clock::StepClock, id_gen::UuidGenerator, rng::SeededRng};
+    use crate::domain::game::{Engine, Player, SessionStatus};
+    use crate::domain::rules::{GameRules, PuzzleDifficulty};
+    use crate::pb::runecraftstudios::pastello::game::types::v1::PlayerId;
+    use chrono::TimeZone;
+
+    fn session(players: &[&str], rules: &PuzzleRules) -> Session {
+        Session {
+            id: "s1".to_string(),
+            host_id: players[0].to_string(),
+            game_type: GameType::Puzzle,
+            players: players.iter().map(|id| Player { id: id.to_string(), name: id.to_string() }).collect(),
+            spectators: Vec::new(),
+            ruleset_id: None,
+            rules: GameRules::Puzzle(rules.clone()),
+            room: None,
+            seed: Some(7),
+            daily_challenge: None,
+            question_ids: Vec::new(),
+            status: SessionStatus::Active,
         }
+    }
+
+    /// An easy puzzle for `players`, started on a clock the test moves by hand.
+    async fn started(players: &[&str], mode: PuzzleMode, time_limit_seconds: u32) -> (PuzzleEngine, Arc<StepClock>) {
+        let clock = Arc::new(StepClock::new(Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap()));
+        let rules = PuzzleRules {
+            difficulty: PuzzleDifficulty::Easy,
+            max_players: players.len() as u32,
+            mode,
+            time_limit_seconds,
+            ..PuzzleRules::default()
+        };
+        let mut engine = PuzzleEngine::new(clock.clone(), Arc::new(SeededRng::new(7)), Arc::new(UuidGenerator::new()), rules.clone());
+        engine.start(&session(players, &rules)).await.unwrap();
+        (engine, clock)
+    }
+
+    fn later(clock: &StepClock, secs: i64) {
+        clock.set(clock.now_utc() + chrono::Duration::seconds(secs));
+    }
+
+    /// Some legal slide on `board`: a tile next to the blank, into it.
+    fn legal_move(board: &Board) -> (Pos, Pos) {
+        let size = board.size() as i32;
+        let blank = board.tiles().iter().position(|tile| *tile == 0).unwrap() as i32;
+        let to = Pos { x: blank % size, y: blank / size };
+        let from = [(1, 0), (-1, 0), (0, 1), (0, -1)]
+            .iter()
+            .map(|(dx, dy)| Pos { x: to.x + dx, y: to.y + dy })
+            .find(|p| p.x >= 0 && p.y >= 0 && p.x < size && p.y < size)
+            .unwrap();
+        (from, to)
+    }
+
+    fn move_command(player_id: &str, (from, to): (Pos, Pos)) -> Box<MovePieceCommand> {
+        Box::new(MovePieceCommand {
+            session_id: None,
+            player_id: Some(PlayerId { value: player_id.to_string() }),
+            from_x: from.x,
+            from_y: from.y,
+            to_x: to.x,
+            to_y: to.y,
+        })
+    }
+
+    fn undo_command(player_id: &str) -> Box<UndoMoveCommand> {
+        Box::new(UndoMoveCommand {
+            session_id: None,
+            player_id: Some(PlayerId { value: player_id.to_string() }),
+        })
+    }
+
+    fn types(events: &[Box<dyn DomainEvent>]) -> Vec<&'static str> {
+        events.iter().map(|e| e.event_type()).collect()
+    }
+
+    fn active(engine: &PuzzleEngine) -> &str {
+        engine.state.turns.active_player().map(String::as_str).unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn players_move_in_turn() {
+        let (mut engine, _) = started(&["a", "b"], PuzzleMode::TurnBased, 0).await;
+        assert_eq!(active(&engine), "a");
+
+        let out_of_turn = move_command("b", legal_move(&engine.state.board));
+        assert!(matches!(engine.execute_command(out_of_turn), Err(DomainError::NotYourTurn(p)) if p == "b"));
+
+        let events = engine.execute_command(move_command("a", legal_move(&engine.state.board))).unwrap();
+        assert_eq!(types(&events), ["puzzle.piece_moved", "game.turn_changed"]);
+        assert_eq!(active(&engine), "b");
+    }
+
+    #[tokio::test]
+    async fn only_the_player_who_moved_may_undo() {
+        let (mut engine, _) = started(&["a", "b"], PuzzleMode::TurnBased, 0).await;
+        assert!(matches!(engine.execute_command(undo_command("a")), Err(DomainError::NothingToUndo)));
+
+        let before = engine.state.board.clone();
+        engine.execute_command(move_command("a", legal_move(&engine.state.board))).unwrap();
+        assert!(matches!(engine.execute_command(undo_command("b")), Err(DomainError::UndoNotAllowed)));
+
+        let events = engine.execute_command(undo_command("a")).unwrap();
+        assert_eq!(types(&events), ["puzzle.move_undone", "game.turn_changed"]);
+        assert_eq!(engine.state.board, before);
+        assert_eq!(active(&engine), "a");
+    }
+
+    #[tokio::test]
+    async fn an_idle_turn_times_out_to_the_next_player() {
+        let (mut engine, clock) = started(&["a", "b"], PuzzleMode::TurnBased, 0).await;
+        later(&clock, 29);
+        assert!(engine.tick().is_empty());
+        later(&clock, 1);
+        let events = engine.tick();
+        assert_eq!(types(&events), ["game.turn_changed"]);
+        assert_eq!(events[0].to_json()["reason"], "TimedOut");
+        assert_eq!(active(&engine), "b");
+    }
+
+    #[tokio::test]
+    async fn the_solving_move_ends_the_game() {
+        let (mut engine, _) = started(&["a", "b"], PuzzleMode::TurnBased, 0).await;
+        // One slide away from solved: tile 8 sits where the blank belongs.
+        let mut board = Board::solved(3);
+        board.slide(Pos { x: 1, y: 2 }, Pos { x: 2, y: 2 }).unwrap();
+        engine.state.board = board;
+
+        let solve = move_command("a", (Pos { x: 2, y: 2 }, Pos { x: 1, y: 2 }));
+        let events = engine.execute_command(solve).unwrap();
+        assert_eq!(types(&events), ["puzzle.piece_moved", "puzzle.solved"]);
+        assert!(engine.is_finished());
+        assert!(engine.tick().is_empty());
+        let late = move_command("b", legal_move(&engine.state.board));
+        assert!(matches!(engine.execute_command(late), Err(DomainError::GameFinished)));
+    }
+
+    /// Puts `player_id` one slide away from solved; returns the solving move.
+    fn nearly_solve(engine: &mut PuzzleEngine, player_id: &str) -> (Pos, Pos) {
+        let mut board = Board::solved(3);
+        board.slide(Pos { x: 1, y: 2 }, Pos { x: 2, y: 2 }).unwrap();
+        engine.state.racers.get_mut(player_id).unwrap().board = board;
+        (Pos { x: 2, y: 2 }, Pos { x: 1, y: 2 })
+    }
+
+    #[tokio::test]
+    async fn racers_start_on_identical_private_boards() {
+        let (mut engine, _) = started(&["a", "b"], PuzzleMode::Race, 0).await;
+        let board = |engine: &PuzzleEngine, id: &str| engine.state.racers[id].board.clone();
+        assert_eq!(board(&engine, "a"), board(&engine, "b"));
+        assert!(!board(&engine, "a").is_solved());
+
+        let events = engine.execute_command(move_command("a", legal_move(&engine.state.racers["a"].board))).unwrap();
+        assert_eq!(types(&events), ["puzzle.piece_moved", "puzzle.race_progress"]);
+        assert_ne!(board(&engine, "a"), board(&engine, "b"));
+        // Racers never wait for a turn, and their moves stay off other boards.
+        assert!(engine.execute_command(move_command("b", legal_move(&engine.state.racers["b"].board))).is_ok());
+        assert!(!events[0].visible_to(&"b".to_string(), Role::Player));
+
+        let snapshot = engine.snapshot(&"a".to_string(), Role::Spectator);
+        assert!(snapshot["board"].is_null());
+        assert_eq!(snapshot["standings"].as_array().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn the_race_finishes_once_everyone_has_solved() {
+        let (mut engine, clock) = started(&["a", "b", "c"], PuzzleMode::Race, 0).await;
+        later(&clock, 10);
+        let solve = nearly_solve(&mut engine, "b");
+        let events = engine.execute_command(move_command("b", solve)).unwrap();
+        assert_eq!(types(&events), ["puzzle.piece_moved", "puzzle.race_progress", "puzzle.solved"]);
+        assert_eq!(events[2].to_json()["elapsed_ms"], 10_000);
+        assert!(!engine.is_finished());
+
+        later(&clock, 5);
+        let solve = nearly_solve(&mut engine, "a");
+        engine.execute_command(move_command("a", solve)).unwrap();
+        later(&clock, 5);
+        let solve = nearly_solve(&mut engine, "c");
+        let events = engine.execute_command(move_command("c", solve)).unwrap();
+        assert_eq!(types(&events).last(), Some(&"puzzle.race_finished"));
+        assert!(engine.is_finished());
+
+        let standings = events.last().unwrap().to_json()["standings"].clone();
+        let order: Vec<_> = standings.as_array().unwrap().iter().map(|s| s["player_id"].clone()).collect();
+        assert_eq!(order, ["b", "a", "c"]);
+        assert_eq!(standings[0]["rank"], 1);
+    }
+
+    #[tokio::test]
+    async fn unfinished_racers_rank_behind_by_progress() {
+        let (mut engine, _) = started(&["a", "b", "c"], PuzzleMode::Race, 0).await;
+        let solve = nearly_solve(&mut engine, "c");
+        engine.execute_command(move_command("c", solve)).unwrap();
+        nearly_solve(&mut engine, "b");
+        engine.state.racers.get_mut("a").unwrap().board = Board::scrambled(3, 30, &SeededRng::new(1));
+
+        let order: Vec<_> = engine.standings().into_iter().map(|s| (s.player_id, s.finished)).collect();
+        assert_eq!(order, [("c".to_string(), true), ("b".to_string(), false), ("a".to_string(), false)]);
+    }
+
+    #[tokio::test]
+    async fn the_time_limit_ends_the_race() {
+        let (mut engine, clock) = started(&["a", "b"], PuzzleMode::Race, 60).await;
+        later(&clock, 59);
+        assert!(engine.tick().is_empty());
+        later(&clock, 1);
+        assert_eq!(types(&engine.tick()), ["puzzle.race_finished"]);
+        assert!(engine.is_finished());
+        assert!(engine.tick().is_empty());
+    }
+
+    #[tokio::test]
+    async fn late_joiners_race_on_the_seeded_board() {
+        let (mut engine, clock) = started(&["a"], PuzzleMode::Race, 0).await;
+        let start_board = engine.state.racers["a"].board.clone();
+        engine.execute_command(move_command("a", legal_move(&start_board))).unwrap();
 
-        Ok(())
+        later(&clock, 30);
+        let events = engine.execute_command(Box::new(SeatPlayerCommand { player_id: "b".to_string() })).unwrap();
+        assert_eq!(types(&events), ["puzzle.race_progress"]);
+        assert_eq!(engine.state.racers["b"].board, start_board);
+        // Their time runs from when they joined.
+        later(&clock, 4);
+        let solve = nearly_solve(&mut engine, "b");
+        let events = engine.execute_command(move_command("b", solve)).unwrap();
+        assert_eq!(events[2].to_json()["elapsed_ms"], 4_000);
     }
 }
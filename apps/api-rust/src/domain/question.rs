@@ -0,0 +1,245 @@
+use crate::domain::game::DomainError;
+use crate::domain::rules::TriviaCategory;
+use serde::{Deserialize, Serialize};
+
+pub type QuestionID = String;
+
+/// How a question is answered, including the solution. Never send this to clients;
+/// use `ClientFormat` instead.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum QuestionFormat {
+    FreeText {
+        answer: String,
+        /// Other spellings that also count as correct.
+        #[serde(default)]
+        aliases: Vec<String>,
+    },
+    /// Answered with the index of the chosen option, as shown to the player.
+    MultipleChoice { options: Vec<String>, correct: usize },
+    TrueFalse { answer: bool },
+    Numeric { value: f64, tolerance: f64 },
+}
+
+/// The answer format as the client sees it: enough to render the question, nothing more.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ClientFormat {
+    FreeText,
+    MultipleChoice { options: Vec<String> },
+    TrueFalse,
+    Numeric,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Question {
+    pub id: QuestionID,
+    pub category: TriviaCategory,
+    pub prompt: String,
+    pub format: QuestionFormat,
+    #[serde(default)]
+    pub hint: Option<String>,
+}
+
+impl Question {
+    /// Checks `answer` against the solution. Answers that do not fit the format at all
+    /// (e.g. "maybe" to a true/false question) are rejected rather than marked wrong.
+    pub fn check(&self, answer: &str) -> Result<bool, DomainError> {
+        match &self.format {
+            QuestionFormat::FreeText { answer: expected, aliases } => {
+                let given = normalize_answer(answer);
+                Ok(std::iter::once(expected)
+                    .chain(aliases)
+                    .any(|candidate| normalize_answer(candidate) == given))
+            }
+            QuestionFormat::MultipleChoice { options, correct } => {
+                let index = parse_option(answer, options.len())?;
+                Ok(index == *correct)
+            }
+            QuestionFormat::TrueFalse { answer: expected } => Ok(parse_bool(answer)? == *expected),
+            QuestionFormat::Numeric { value, tolerance } => {
+                let given: f64 = answer
+                    .trim()
+                    .parse()
+                    .map_err(|_| DomainError::InvalidAnswer(format!("'{}' is not a number", answer.trim())))?;
+                Ok((given - value).abs() <= *tolerance)
+            }
+        }
+    }
+
+    /// Human-readable form of a submitted answer, used to group answers in the breakdown.
+    /// Assumes `answer` already passed `check`.
+    pub fn display_answer(&self, answer: &str) -> String {
+        match &self.format {
+            QuestionFormat::MultipleChoice { options, .. } => parse_option(answer, options.len())
+                .map(|i| options[i].clone())
+                .unwrap_or_else(|_| answer.trim().to_string()),
+            QuestionFormat::TrueFalse { .. } => parse_bool(answer)
+                .map(|b| b.to_string())
+                .unwrap_or_else(|_| answer.trim().to_string()),
+            QuestionFormat::FreeText { .. } | QuestionFormat::Numeric { .. } => answer.trim().to_string(),
+        }
+    }
+
+    pub fn correct_answer(&self) -> String {
+        match &self.format {
+            QuestionFormat::FreeText { answer, .. } => answer.clone(),
+            QuestionFormat::MultipleChoice { options, correct } => options.get(*correct).cloned().unwrap_or_default(),
+            QuestionFormat::TrueFalse { answer } => answer.to_string(),
+            QuestionFormat::Numeric { value, .. } => value.to_string(),
+        }
+    }
+
+    pub fn client_format(&self) -> ClientFormat {
+        match &self.format {
+            QuestionFormat::FreeText { .. } => ClientFormat::FreeText,
+            QuestionFormat::MultipleChoice { options, .. } => ClientFormat::MultipleChoice { options: options.clone() },
+            QuestionFormat::TrueFalse { .. } => ClientFormat::TrueFalse,
+            QuestionFormat::Numeric { .. } => ClientFormat::Numeric,
+        }
+    }
+
+    /// Every answer a player could give, for formats with a fixed set of choices.
+    pub fn choices(&self) -> Vec<String> {
+        match &self.format {
+            QuestionFormat::MultipleChoice { options, .. } => options.clone(),
+            QuestionFormat::TrueFalse { .. } => vec!["true".to_string(), "false".to_string()],
+            QuestionFormat::FreeText { .. } | QuestionFormat::Numeric { .. } => Vec::new(),
+        }
+    }
+}
+
+/// Canonical form used to compare free-text answers.
+pub fn normalize_answer(text: &str) -> String {
+    text.split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn parse_option(answer: &str, options: usize) -> Result<usize, DomainError> {
+    answer
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .filter(|i| *i < options)
+        .ok_or_else(|| DomainError::InvalidAnswer(format!("expected an option index below {}", options)))
+}
+
+fn parse_bool(answer: &str) -> Result<bool, DomainError> {
+    match normalize_answer(answer).as_str() {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        other => Err(DomainError::InvalidAnswer(format!("'{}' is not true or false", other))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn question(format: QuestionFormat) -> Question {
+        Question {
+            id: "q1".to_string(),
+            category: TriviaCategory::GeneralKnowledge,
+            prompt: "?".to_string(),
+            format,
+            hint: Some("a hint".to_string()),
+        }
+    }
+
+    fn free_text() -> Question {
+        question(QuestionFormat::FreeText {
+            answer: "New York City".to_string(),
+            aliases: vec!["NYC".to_string()],
+        })
+    }
+
+    fn multiple_choice() -> Question {
+        question(QuestionFormat::MultipleChoice {
+            options: ["Mars", "Venus", "Jupiter"].map(str::to_string).to_vec(),
+            correct: 2,
+        })
+    }
+
+    fn numeric() -> Question {
+        question(QuestionFormat::Numeric { value: 2.5, tolerance: 0.25 })
+    }
+
+    fn rejected(result: Result<bool, DomainError>) -> bool {
+        matches!(result, Err(DomainError::InvalidAnswer(_)))
+    }
+
+    #[test]
+    fn free_text_ignores_case_spacing_and_accepts_aliases() {
+        let question = free_text();
+        for answer in ["New York City", "  new   york city ", "nyc"] {
+            assert!(question.check(answer).unwrap(), "{}", answer);
+        }
+        for answer in ["New York", "", "NYC!"] {
+            assert!(!question.check(answer).unwrap(), "{}", answer);
+        }
+    }
+
+    #[test]
+    fn multiple_choice_takes_an_option_index() {
+        let question = multiple_choice();
+        assert!(question.check("2").unwrap());
+        assert!(question.check(" 2 ").unwrap());
+        assert!(!question.check("0").unwrap());
+        for answer in ["3", "-1", "Jupiter", ""] {
+            assert!(rejected(question.check(answer)), "{}", answer);
+        }
+        assert_eq!(question.display_answer("2"), "Jupiter");
+    }
+
+    #[test]
+    fn true_false_takes_only_true_or_false() {
+        let question = question(QuestionFormat::TrueFalse { answer: false });
+        assert!(question.check("false").unwrap());
+        assert!(question.check(" FALSE ").unwrap());
+        assert!(!question.check("true").unwrap());
+        assert!(rejected(question.check("maybe")));
+        assert!(rejected(question.check("0")));
+        assert_eq!(question.display_answer("True"), "true");
+    }
+
+    #[test]
+    fn numbers_count_within_the_tolerance() {
+        let question = numeric();
+        let cases = [("2.5", true), ("2.75", true), ("2.25", true), ("2.76", false), ("2.24", false), ("-2.5", false)];
+        for (answer, correct) in cases {
+            assert_eq!(question.check(answer).unwrap(), correct, "{}", answer);
+        }
+        let exact = self::question(QuestionFormat::Numeric { value: 42.0, tolerance: 0.0 });
+        assert!(exact.check("42").unwrap());
+        assert!(!exact.check("42.0001").unwrap());
+        assert!(rejected(question.check("pi")));
+    }
+
+    #[test]
+    fn the_client_format_never_carries_the_solution() {
+        let cases = [
+            (free_text(), serde_json::json!({ "kind": "free_text" })),
+            (multiple_choice(), serde_json::json!({ "kind": "multiple_choice", "options": ["Mars", "Venus", "Jupiter"] })),
+            (question(QuestionFormat::TrueFalse { answer: true }), serde_json::json!({ "kind": "true_false" })),
+            (numeric(), serde_json::json!({ "kind": "numeric" })),
+        ];
+        for (question, expected) in cases {
+            let client = serde_json::to_value(question.client_format()).unwrap();
+            assert_eq!(client, expected);
+            for leak in ["answer", "aliases", "correct", "value", "tolerance"] {
+                assert!(client.get(leak).is_none(), "{} leaked in {}", leak, client);
+            }
+        }
+    }
+
+    #[test]
+    fn correct_answers_read_as_shown_to_players() {
+        assert_eq!(free_text().correct_answer(), "New York City");
+        assert_eq!(multiple_choice().correct_answer(), "Jupiter");
+        assert_eq!(numeric().correct_answer(), "2.5");
+        assert_eq!(multiple_choice().choices().len(), 3);
+        assert!(numeric().choices().is_empty());
+    }
+}
@@ -0,0 +1,374 @@
+//! Glicko-2 skill ratings for competitive games, kept per player and game type.
+//!
+//! Each finished game is one rating period. A game with several players is rated as if every
+//! player had played everyone else at the table at once, winning against those placed below
+//! them and tying with those placed level.
+
+use crate::domain::game::{GameSessionID, GameType, PlayerID};
+use crate::domain::outcome::GameOutcome;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::f64::consts::PI;
+
+// Converts between the public Glicko scale and the internal Glicko-2 scale.
+const GLICKO2_SCALE: f64 = 173.7178;
+const BASE_RATING: f64 = 1500.0;
+// Convergence tolerance of the volatility iteration.
+const EPSILON: f64 = 0.000001;
+// Bound on each loop of the volatility iteration. Valid inputs converge in well under a dozen
+// steps; the bound only stops degenerate ones from spinning forever.
+const MAX_ITERATIONS: u32 = 100;
+
+/// One rated game in a player's history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RatingChange {
+    pub session_id: GameSessionID,
+    pub at: DateTime<Utc>,
+    pub rank: u32,
+    pub players: u32,
+    pub rating_before: f64,
+    pub rating_after: f64,
+    pub deviation_after: f64,
+}
+
+/// A player's rating in one game type, on the familiar 1500-centred Glicko scale.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SkillRating {
+    pub rating: f64,
+    /// Uncertainty of `rating`; shrinks with every game and grows back while the player is idle.
+    pub deviation: f64,
+    /// How erratic the player's results are.
+    pub volatility: f64,
+    #[serde(default)]
+    pub games_rated: u32,
+    #[serde(default)]
+    pub last_rated_at: Option<DateTime<Utc>>,
+    /// Most recent game last, trimmed to `RatingPolicy::history_len`.
+    #[serde(default)]
+    pub history: Vec<RatingChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerRatings {
+    pub player_id: PlayerID,
+    #[serde(default)]
+    pub games: BTreeMap<GameType, SkillRating>,
+}
+
+impl PlayerRatings {
+    pub fn new(player_id: &PlayerID) -> Self {
+        Self {
+            player_id: player_id.clone(),
+            games: BTreeMap::new(),
+        }
+    }
+}
+
+/// Rating settings, read from the `ratings` section of `config.yaml`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RatingPolicy {
+    pub initial_rating: f64,
+    pub initial_deviation: f64,
+    pub initial_volatility: f64,
+    /// Glicko-2 system constant; smaller values keep volatility from changing quickly.
+    pub tau: f64,
+    /// Days without a game after which a player's deviation grows as if a rating period passed.
+    pub idle_period_days: u32,
+    /// Rated games kept in each player's history per game type.
+    pub history_len: usize,
+}
+
+impl Default for RatingPolicy {
+    fn default() -> Self {
+        Self {
+            initial_rating: BASE_RATING,
+            initial_deviation: 350.0,
+            initial_volatility: 0.06,
+            tau: 0.5,
+            idle_period_days: 7,
+            history_len: 50,
+        }
+    }
+}
+
+/// A rating on the internal Glicko-2 scale.
+#[derive(Debug, Clone, Copy)]
+struct Glicko2 {
+    mu: f64,
+    phi: f64,
+    sigma: f64,
+}
+
+impl RatingPolicy {
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.initial_rating.is_finite() {
+            return Err("initial_rating must be a finite number".to_string());
+        }
+        for (name, value) in [
+            ("initial_deviation", self.initial_deviation),
+            ("initial_volatility", self.initial_volatility),
+            ("tau", self.tau),
+        ] {
+            if !value.is_finite() || value <= 0.0 {
+                return Err(format!("{} must be a positive number, got {}", name, value));
+            }
+        }
+        Ok(())
+    }
+
+    /// Rating of a player who has not played the game type yet.
+    pub fn unrated(&self) -> SkillRating {
+        SkillRating {
+            rating: self.initial_rating,
+            deviation: self.initial_deviation,
+            volatility: self.initial_volatility,
+            games_rated: 0,
+            last_rated_at: None,
+            history: Vec::new(),
+        }
+    }
+
+    /// New ratings of every placed player, computed from their ratings before the game.
+    /// Players missing from `before` are rated from `unrated`.
+    pub fn rate(&self, outcome: &GameOutcome, before: &HashMap<PlayerID, SkillRating>) -> HashMap<PlayerID, SkillRating> {
+        let current: Vec<(SkillRating, Glicko2)> = outcome
+            .placements
+            .iter()
+            .map(|p| {
+                let rating = before.get(&p.player_id).cloned().unwrap_or_else(|| self.unrated());
+                let scaled = self.to_glicko2(&rating, outcome.finished_at);
+                (rating, scaled)
+            })
+            .collect();
+
+        let mut rated = HashMap::with_capacity(current.len());
+        for (i, placement) in outcome.placements.iter().enumerate() {
+            let results: Vec<(Glicko2, f64)> = outcome
+                .placements
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(j, other)| (current[j].1, GameOutcome::score_against(placement, other)))
+                .collect();
+            let (previous, scaled) = &current[i];
+            let updated = self.update(*scaled, &results);
+
+            let mut rating = previous.clone();
+            rating.rating = updated.mu * GLICKO2_SCALE + BASE_RATING;
+            rating.deviation = (updated.phi * GLICKO2_SCALE).min(self.initial_deviation);
+            rating.volatility = updated.sigma;
+            rating.games_rated += 1;
+            rating.last_rated_at = Some(outcome.finished_at);
+            rating.history.push(RatingChange {
+                session_id: outcome.session_id.clone(),
+                at: outcome.finished_at,
+                rank: placement.rank,
+                players: outcome.placements.len() as u32,
+                rating_before: previous.rating,
+                rating_after: rating.rating,
+                deviation_after: rating.deviation,
+            });
+            let excess = rating.history.len().saturating_sub(self.history_len);
+            rating.history.drain(..excess);
+            rated.insert(placement.player_id.clone(), rating);
+        }
+        rated
+    }
+
+    /// `rating` on the Glicko-2 scale at `at`, with its deviation grown for every idle period.
+    fn to_glicko2(&self, rating: &SkillRating, at: DateTime<Utc>) -> Glicko2 {
+        let mut phi = rating.deviation / GLICKO2_SCALE;
+        if let Some(last) = rating.last_rated_at {
+            let idle_periods = match self.idle_period_days {
+                0 => 0,
+                days => (at - last).num_days().max(0) / days as i64,
+            };
+            phi = (phi * phi + idle_periods as f64 * rating.volatility * rating.volatility).sqrt();
+        }
+        Glicko2 {
+            mu: (rating.rating - BASE_RATING) / GLICKO2_SCALE,
+            phi: phi.min(self.initial_deviation / GLICKO2_SCALE),
+            sigma: rating.volatility,
+        }
+    }
+
+    /// One Glicko-2 rating period for `player` against `results` of (opponent, score).
+    fn update(&self, player: Glicko2, results: &[(Glicko2, f64)]) -> Glicko2 {
+        if results.is_empty() {
+            return player;
+        }
+        let mut inverse_variance = 0.0;
+        let mut improvement = 0.0;
+        for (opponent, score) in results {
+            let g = g(opponent.phi);
+            let expected = 1.0 / (1.0 + (-g * (player.mu - opponent.mu)).exp());
+            inverse_variance += g * g * expected * (1.0 - expected);
+            improvement += g * (score - expected);
+        }
+        let variance = 1.0 / inverse_variance;
+        let delta = variance * improvement;
+
+        let sigma = self.volatility(player, variance, delta);
+        let phi_star = (player.phi * player.phi + sigma * sigma).sqrt();
+        let phi = 1.0 / (1.0 / (phi_star * phi_star) + 1.0 / variance).sqrt();
+        Glicko2 {
+            mu: player.mu + phi * phi * improvement,
+            phi,
+            sigma,
+        }
+    }
+
+    /// New volatility, found with the Illinois variant of regula falsi as in Glickman's paper.
+    /// Keeps the old volatility if the iteration can't run or doesn't converge.
+    fn volatility(&self, player: Glicko2, variance: f64, delta: f64) -> f64 {
+        if !(self.tau.is_finite() && self.tau > 0.0 && variance.is_finite() && delta.is_finite()) {
+            return player.sigma;
+        }
+        let phi2 = player.phi * player.phi;
+        let tau2 = self.tau * self.tau;
+        let a = (player.sigma * player.sigma).ln();
+        let f = |x: f64| {
+            let ex = x.exp();
+            ex * (delta * delta - phi2 - variance - ex) / (2.0 * (phi2 + variance + ex).powi(2)) - (x - a) / tau2
+        };
+
+        let mut lower = a;
+        let mut upper = if delta * delta > phi2 + variance {
+            (delta * delta - phi2 - variance).ln()
+        } else {
+            let mut k = 1.0;
+            while f(a - k * self.tau) < 0.0 {
+                if k >= MAX_ITERATIONS as f64 {
+                    return player.sigma;
+                }
+                k += 1.0;
+            }
+            a - k * self.tau
+        };
+        let mut f_lower = f(lower);
+        let mut f_upper = f(upper);
+        let mut iterations = 0;
+        while (upper - lower).abs() > EPSILON {
+            iterations += 1;
+            if iterations > MAX_ITERATIONS || !(f_lower.is_finite() && f_upper.is_finite()) {
+                return player.sigma;
+            }
+            let next = lower + (lower - upper) * f_lower / (f_upper - f_lower);
+            let f_next = f(next);
+            if f_next * f_upper <= 0.0 {
+                lower = upper;
+                f_lower = f_upper;
+            } else {
+                f_lower /= 2.0;
+            }
+            upper = next;
+            f_upper = f_next;
+        }
+        (lower / 2.0).exp()
+    }
+}
+
+fn g(phi: f64) -> f64 {
+    1.0 / (1.0 + 3.0 * phi * phi / (PI * PI)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::outcome::Placement;
+    use chrono::TimeZone;
+
+    fn scaled(rating: f64, deviation: f64) -> Glicko2 {
+        Glicko2 {
+            mu: (rating - BASE_RATING) / GLICKO2_SCALE,
+            phi: deviation / GLICKO2_SCALE,
+            sigma: 0.06,
+        }
+    }
+
+    fn outcome(placements: &[(&str, u32)]) -> GameOutcome {
+        GameOutcome {
+            session_id: "s1".to_string(),
+            game_type: GameType::Trivia,
+            ruleset_id: None,
+            finished_at: Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap(),
+            placements: placements
+                .iter()
+                .map(|(player, rank)| Placement { player_id: player.to_string(), rank: *rank })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn matches_the_worked_example_in_glickmans_paper() {
+        let policy = RatingPolicy::default();
+        let player = scaled(1500.0, 200.0);
+        let results = [
+            (scaled(1400.0, 30.0), 1.0),
+            (scaled(1550.0, 100.0), 0.0),
+            (scaled(1700.0, 300.0), 0.0),
+        ];
+        let updated = policy.update(player, &results);
+        assert!((updated.mu * GLICKO2_SCALE + BASE_RATING - 1464.06).abs() < 0.01);
+        assert!((updated.phi * GLICKO2_SCALE - 151.52).abs() < 0.01);
+        assert!((updated.sigma - 0.05999).abs() < 0.00001);
+    }
+
+    #[test]
+    fn winners_gain_what_losers_lose_between_equal_players() {
+        let policy = RatingPolicy::default();
+        let rated = policy.rate(&outcome(&[("winner", 1), ("loser", 2)]), &HashMap::new());
+        let (winner, loser) = (&rated["winner"], &rated["loser"]);
+        assert!(winner.rating > 1500.0);
+        assert!((winner.rating - 1500.0 + loser.rating - 1500.0).abs() < 1e-9);
+        assert!(winner.deviation < policy.initial_deviation);
+        assert_eq!(winner.games_rated, 1);
+        assert_eq!(winner.history[0].rating_before, 1500.0);
+    }
+
+    #[test]
+    fn ties_leave_equal_players_where_they_were() {
+        let rated = RatingPolicy::default().rate(&outcome(&[("a", 1), ("b", 1)]), &HashMap::new());
+        assert!((rated["a"].rating - 1500.0).abs() < 1e-9);
+        assert!((rated["b"].rating - 1500.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn history_is_trimmed_to_its_length() {
+        let policy = RatingPolicy {
+            history_len: 2,
+            ..RatingPolicy::default()
+        };
+        let mut before = HashMap::new();
+        for _ in 0..3 {
+            before = policy.rate(&outcome(&[("a", 1), ("b", 2)]), &before);
+        }
+        assert_eq!(before["a"].games_rated, 3);
+        assert_eq!(before["a"].history.len(), 2);
+    }
+
+    #[test]
+    fn volatility_gives_up_on_degenerate_settings() {
+        let player = scaled(1500.0, 200.0);
+        for tau in [0.0, -0.5, f64::NAN] {
+            let policy = RatingPolicy { tau, ..RatingPolicy::default() };
+            assert_eq!(policy.volatility(player, 1.0, 5.0), player.sigma);
+        }
+    }
+
+    #[test]
+    fn validate_rejects_settings_the_update_cannot_use() {
+        assert!(RatingPolicy::default().validate().is_ok());
+        for policy in [
+            RatingPolicy { tau: 0.0, ..RatingPolicy::default() },
+            RatingPolicy { tau: f64::NAN, ..RatingPolicy::default() },
+            RatingPolicy { initial_deviation: -1.0, ..RatingPolicy::default() },
+            RatingPolicy { initial_volatility: f64::INFINITY, ..RatingPolicy::default() },
+            RatingPolicy { initial_rating: f64::NAN, ..RatingPolicy::default() },
+        ] {
+            assert!(policy.validate().is_err(), "{:?}", policy);
+        }
+    }
+}
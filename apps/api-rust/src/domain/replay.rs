@@ -0,0 +1,113 @@
+//! Replays: everything needed to play a session again through its engine and check that it
+//! ends the same way. Engines draw randomness from the session's seed and read time from a clock
+//! that only moves between steps, so recording each step's inputs and time is enough.
+
+use crate::domain::game::{DomainEvent, Player, Session};
+use crate::domain::question::Question;
+use crate::domain::scoring::ScoringPolicy;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Bumped whenever a change to the file format would break reading older replays.
+pub const REPLAY_VERSION: u32 = 1;
+
+/// One thing that happened to a session's engine, with the events it produced.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayStep {
+    /// Engine time for the whole step.
+    pub at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub input: StepInput,
+    #[serde(default)]
+    pub events: Vec<RecordedEvent>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "step", rename_all = "snake_case")]
+pub enum StepInput {
+    /// The engine was attached to the session, seating these players. Players kicked later
+    /// are gone from the session but were part of the game.
+    Start {
+        players: Vec<Player>,
+        /// Trivia questions the engine drew. Moved to `Replay::questions` on export.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        questions: Vec<Question>,
+    },
+    /// Time-based rules ran. Only ticks that produced events are recorded; the others change
+    /// nothing.
+    Tick,
+    /// Time-based rules ran, then a command was accepted. Rejected commands change nothing and
+    /// are not recorded.
+    Command {
+        player_id: String,
+        /// Name in `CommandRegistry`, e.g. `PuzzleMove`.
+        command_type: String,
+        payload: serde_json::Value,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedEvent {
+    pub event_type: String,
+    pub payload: serde_json::Value,
+}
+
+impl RecordedEvent {
+    pub fn from_events(events: &[Box<dyn DomainEvent>]) -> Vec<Self> {
+        events
+            .iter()
+            .map(|event| RecordedEvent {
+                event_type: event.event_type().to_string(),
+                payload: event.to_json(),
+            })
+            .collect()
+    }
+}
+
+/// A self-contained replay file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Replay {
+    pub version: u32,
+    /// The session, without its private room. Carries the rules and the seed.
+    pub session: Session,
+    pub scoring: ScoringPolicy,
+    /// Trivia questions the engine drew, as stored when the game started, so later edits to the
+    /// question store don't change the game. `session.question_ids` pins their order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub questions: Vec<Question>,
+    pub steps: Vec<ReplayStep>,
+    /// Spectator snapshot of the engine when the game ended.
+    pub final_state: serde_json::Value,
+    pub exported_at: DateTime<Utc>,
+}
+
+/// The closed log of a finished session.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recording {
+    pub steps: Vec<ReplayStep>,
+    /// Spectator snapshot of the engine after its last step.
+    pub final_state: serde_json::Value,
+}
+
+/// Where a replay stopped matching its recording.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReplayMismatch {
+    /// Index into `steps`, or `None` for the final state.
+    pub step: Option<usize>,
+    pub expected: serde_json::Value,
+    pub actual: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReplayReport {
+    pub session_id: String,
+    pub steps: usize,
+    /// `None` when every step and the final state matched.
+    pub mismatch: Option<ReplayMismatch>,
+}
+
+impl ReplayReport {
+    pub fn matched(&self) -> bool {
+        self.mismatch.is_none()
+    }
+}
@@ -0,0 +1,176 @@
+//! Private rooms: sessions joined with a short code the host shares, optionally behind a
+//! password, instead of being seated by the host or the matchmaker.
+
+use crate::domain::game::{DomainError, DomainEvent, EventMeta, GameSessionID, PlayerID};
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+
+/// Letters and digits that can't be mistaken for one another when read aloud or off a
+/// screen (no 0/O, 1/I/L).
+pub const JOIN_CODE_ALPHABET: &[u8] = b"ABCDEFGHJKMNPQRSTUVWXYZ23456789";
+pub const JOIN_CODE_LENGTH: usize = 6;
+
+/// Room settings, read from the `rooms` section of `config.yaml`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RoomPolicy {
+    /// How long a join code stays valid after the room is created.
+    pub code_ttl_secs: u32,
+    /// Wrong passwords a room takes before it stops checking them for a while.
+    pub max_password_attempts: u32,
+    /// How long a room turns every password away once it has taken too many wrong ones.
+    pub password_lockout_secs: u32,
+}
+
+impl Default for RoomPolicy {
+    fn default() -> Self {
+        Self {
+            code_ttl_secs: 3600,
+            max_password_attempts: 5,
+            password_lockout_secs: 60,
+        }
+    }
+}
+
+impl RoomPolicy {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.max_password_attempts == 0 {
+            return Err("max_password_attempts must be at least 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Canonical form of a code as typed by a player: upper case, separators and spaces dropped.
+pub fn normalize_join_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrivateRoom {
+    pub join_code: String,
+    pub expires_at: DateTime<Utc>,
+    // Argon2 PHC string; the password itself is never stored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    password_hash: Option<String>,
+    /// Players the host removed. They can't rejoin with the code.
+    #[serde(default)]
+    pub kicked: Vec<PlayerID>,
+    // Wrong passwords since the last lockout or correct password.
+    #[serde(default)]
+    failed_attempts: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    locked_until: Option<DateTime<Utc>>,
+}
+
+impl PrivateRoom {
+    /// Sets up a room, hashing `password` with `salt`. Hashing is deliberately slow, so call
+    /// this off the async runtime.
+    pub fn new(
+        join_code: String,
+        expires_at: DateTime<Utc>,
+        password: Option<&str>,
+        salt: &[u8; 16],
+    ) -> Result<Self, DomainError> {
+        let password_hash = match password.filter(|p| !p.is_empty()) {
+            Some(password) => {
+                let salt = SaltString::encode_b64(salt).map_err(|e| DomainError::Internal(e.to_string()))?;
+                let hash = Argon2::default()
+                    .hash_password(password.as_bytes(), &salt)
+                    .map_err(|e| DomainError::Internal(e.to_string()))?;
+                Some(hash.to_string())
+            }
+            None => None,
+        };
+        Ok(Self {
+            join_code,
+            expires_at,
+            password_hash,
+            kicked: Vec::new(),
+            failed_attempts: 0,
+            locked_until: None,
+        })
+    }
+
+    pub fn has_password(&self) -> bool {
+        self.password_hash.is_some()
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now >= self.expires_at
+    }
+
+    /// Turns password attempts away while the room is locked out after too many wrong ones.
+    pub fn ensure_accepts_attempts(&self, now: DateTime<Utc>) -> Result<(), DomainError> {
+        match self.locked_until {
+            Some(until) if now < until => Err(DomainError::TooManyAttempts(self.join_code.clone())),
+            _ => Ok(()),
+        }
+    }
+
+    /// Counts the outcome of a password check. The last wrong password allowed by `policy`
+    /// locks the room out for `password_lockout_secs`; a correct one starts the count over.
+    pub fn record_attempt(&mut self, correct: bool, now: DateTime<Utc>, policy: &RoomPolicy) {
+        if correct {
+            self.failed_attempts = 0;
+            return;
+        }
+        self.failed_attempts += 1;
+        if self.failed_attempts >= policy.max_password_attempts {
+            self.failed_attempts = 0;
+            self.locked_until = Some(now + chrono::Duration::seconds(policy.password_lockout_secs as i64));
+        }
+    }
+
+    /// Rooms without a password accept any (or no) password. Verifying is deliberately slow,
+    /// so call this off the async runtime.
+    pub fn check_password(&self, password: Option<&str>) -> Result<(), DomainError> {
+        let Some(stored) = &self.password_hash else {
+            return Ok(());
+        };
+        let hash = PasswordHash::new(stored).map_err(|e| DomainError::Internal(e.to_string()))?;
+        Argon2::default()
+            .verify_password(password.unwrap_or_default().as_bytes(), &hash)
+            .map_err(|_| DomainError::WrongPassword)
+    }
+}
+
+// --- EVENTS ---
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PlayerJoined {
+    pub meta: EventMeta,
+    pub session_id: GameSessionID,
+    pub player_id: PlayerID,
+    pub players: u32,
+}
+
+impl DomainEvent for PlayerJoined {
+    fn event_type(&self) -> &'static str { "session.player_joined" }
+    fn session_id(&self) -> &GameSessionID { &self.session_id }
+    fn to_json(&self) -> serde_json::Value { serde_json::to_value(self).unwrap_or_default() }
+    fn to_any_box(self: Box<Self>) -> Box<dyn Any + Send> { self }
+    fn clone_box(&self) -> Box<dyn DomainEvent> { Box::new(self.clone()) }
+}
+
+/// The host removed a player. Their connections to the session are closed after this event.
+#[derive(Debug, Clone, Serialize)]
+pub struct PlayerKicked {
+    pub meta: EventMeta,
+    pub session_id: GameSessionID,
+    pub player_id: PlayerID,
+}
+
+impl DomainEvent for PlayerKicked {
+    fn event_type(&self) -> &'static str { "session.player_kicked" }
+    fn session_id(&self) -> &GameSessionID { &self.session_id }
+    fn to_json(&self) -> serde_json::Value { serde_json::to_value(self).unwrap_or_default() }
+    fn to_any_box(self: Box<Self>) -> Box<dyn Any + Send> { self }
+    fn clone_box(&self) -> Box<dyn DomainEvent> { Box::new(self.clone()) }
+}
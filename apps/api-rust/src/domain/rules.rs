@@ -0,0 +1,326 @@
+use crate::domain::game::{DomainError, GameType};
+use crate::pb::runecraftstudios::pastello::game::puzzle::v1 as puzzle_pb;
+use crate::pb::runecraftstudios::pastello::game::trivia::v1 as trivia_pb;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Upper bound on `max_players` for any game type.
+pub const MAX_PLAYERS: u32 = 16;
+
+// --- PUZZLE ---
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum PuzzleDifficulty {
+    #[default]
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl PuzzleDifficulty {
+    /// Side length of the square board.
+    pub fn board_size(&self) -> usize {
+        match self {
+            PuzzleDifficulty::Easy => 3,
+            PuzzleDifficulty::Medium => 4,
+            PuzzleDifficulty::Hard => 5,
+        }
+    }
+
+    /// Number of random blank moves applied to the solved board when scrambling.
+    pub fn scramble_moves(&self) -> u32 {
+        match self {
+            PuzzleDifficulty::Easy => 30,
+            PuzzleDifficulty::Medium => 80,
+            PuzzleDifficulty::Hard => 200,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum PuzzleMode {
+    /// Players share one board and take turns.
+    #[default]
+    TurnBased,
+    /// Every player solves their own identically seeded board.
+    Race,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PuzzleRules {
+    pub difficulty: PuzzleDifficulty,
+    pub allow_hints: bool,
+    /// 0 means no limit.
+    pub time_limit_seconds: u32,
+    pub max_players: u32,
+    pub mode: PuzzleMode,
+    /// How long the active player has to move in turn-based mode before the turn passes on.
+    pub turn_timeout_seconds: u32,
+}
+
+impl Default for PuzzleRules {
+    fn default() -> Self {
+        Self {
+            difficulty: PuzzleDifficulty::default(),
+            allow_hints: false,
+            time_limit_seconds: 0,
+            max_players: 1,
+            mode: PuzzleMode::default(),
+            turn_timeout_seconds: 30,
+        }
+    }
+}
+
+impl PuzzleRules {
+    pub const MIN_TURN_TIMEOUT_SECONDS: u32 = 5;
+    pub const MAX_TURN_TIMEOUT_SECONDS: u32 = 600;
+
+    pub fn validate(&self) -> Result<(), DomainError> {
+        validate_max_players(self.max_players)?;
+        if !(Self::MIN_TURN_TIMEOUT_SECONDS..=Self::MAX_TURN_TIMEOUT_SECONDS).contains(&self.turn_timeout_seconds) {
+            return Err(DomainError::InvalidRules(format!(
+                "turn_timeout_seconds must be between {} and {}",
+                Self::MIN_TURN_TIMEOUT_SECONDS,
+                Self::MAX_TURN_TIMEOUT_SECONDS
+            )));
+        }
+        if self.mode == PuzzleMode::Race && self.max_players < 2 {
+            return Err(DomainError::InvalidRules("race mode needs max_players of at least 2".to_string()));
+        }
+        Ok(())
+    }
+}
+
+impl From<&puzzle_pb::PuzzleRules> for PuzzleRules {
+    fn from(rules: &puzzle_pb::PuzzleRules) -> Self {
+        let difficulty = match rules.difficulty() {
+            puzzle_pb::PuzzleDifficulty::Medium => PuzzleDifficulty::Medium,
+            puzzle_pb::PuzzleDifficulty::Hard => PuzzleDifficulty::Hard,
+            puzzle_pb::PuzzleDifficulty::Easy | puzzle_pb::PuzzleDifficulty::Unspecified => {
+                PuzzleDifficulty::Easy
+            }
+        };
+        let mode = match rules.mode() {
+            puzzle_pb::PuzzleMode::Race => PuzzleMode::Race,
+            puzzle_pb::PuzzleMode::TurnBased | puzzle_pb::PuzzleMode::Unspecified => {
+                PuzzleMode::TurnBased
+            }
+        };
+        Self {
+            difficulty,
+            allow_hints: rules.allow_hints,
+            time_limit_seconds: rules.time_limit_seconds,
+            max_players: rules.max_players,
+            mode,
+            // Not part of the proto rules yet.
+            turn_timeout_seconds: PuzzleRules::default().turn_timeout_seconds,
+        }
+    }
+}
+
+// --- TRIVIA ---
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum TriviaCategory {
+    GeneralKnowledge,
+    Science,
+    History,
+}
+
+impl TriviaCategory {
+    pub const ALL: [TriviaCategory; 3] = [TriviaCategory::GeneralKnowledge, TriviaCategory::Science, TriviaCategory::History];
+
+    pub fn from_pb(category: trivia_pb::TriviaCategory) -> Option<Self> {
+        match category {
+            trivia_pb::TriviaCategory::GeneralKnowledge => Some(TriviaCategory::GeneralKnowledge),
+            trivia_pb::TriviaCategory::Science => Some(TriviaCategory::Science),
+            trivia_pb::TriviaCategory::History => Some(TriviaCategory::History),
+            trivia_pb::TriviaCategory::Unspecified => None,
+        }
+    }
+
+    /// Parses a category label from imported content. Accepts our own names in any case or
+    /// separator style ("GeneralKnowledge", "general_knowledge", "General Knowledge") and
+    /// the Open Trivia DB labels, whose subcategories ("Science: Computers") fold into ours.
+    pub fn parse(label: &str) -> Option<Self> {
+        let key: String = label
+            .chars()
+            .filter(|c| c.is_alphanumeric() || *c == ':' || *c == '&')
+            .flat_map(char::to_lowercase)
+            .collect();
+        let head = key.split(':').next().unwrap_or_default();
+        match head {
+            "generalknowledge" => Some(TriviaCategory::GeneralKnowledge),
+            "science" | "science&nature" => Some(TriviaCategory::Science),
+            "history" => Some(TriviaCategory::History),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TriviaRules {
+    pub num_questions: u32,
+    pub seconds_per_question: u32,
+    pub negative_marking: bool,
+    /// Empty means every category.
+    pub categories: Vec<TriviaCategory>,
+    pub max_players: u32,
+    /// Relative chance of each category coming up for a question; categories left out count as 1.
+    /// Empty draws evenly from all questions instead. Only set through rulesets for now.
+    pub category_weights: BTreeMap<TriviaCategory, u32>,
+}
+
+impl Default for TriviaRules {
+    fn default() -> Self {
+        Self {
+            num_questions: 10,
+            seconds_per_question: 20,
+            negative_marking: false,
+            categories: Vec::new(),
+            max_players: 1,
+            category_weights: BTreeMap::new(),
+        }
+    }
+}
+
+impl TriviaRules {
+    pub const MAX_QUESTIONS: u32 = 100;
+    pub const MIN_SECONDS_PER_QUESTION: u32 = 5;
+    pub const MAX_SECONDS_PER_QUESTION: u32 = 300;
+
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.num_questions == 0 || self.num_questions > Self::MAX_QUESTIONS {
+            return Err(DomainError::InvalidRules(format!(
+                "num_questions must be between 1 and {}",
+                Self::MAX_QUESTIONS
+            )));
+        }
+        if !(Self::MIN_SECONDS_PER_QUESTION..=Self::MAX_SECONDS_PER_QUESTION).contains(&self.seconds_per_question) {
+            return Err(DomainError::InvalidRules(format!(
+                "seconds_per_question must be between {} and {}",
+                Self::MIN_SECONDS_PER_QUESTION,
+                Self::MAX_SECONDS_PER_QUESTION
+            )));
+        }
+        let asked = match self.categories.is_empty() {
+            true => TriviaCategory::ALL.as_slice(),
+            false => self.categories.as_slice(),
+        };
+        if !asked.iter().any(|c| self.category_weight(*c) > 0) {
+            return Err(DomainError::InvalidRules("category_weights leave no category to ask".to_string()));
+        }
+        validate_max_players(self.max_players)
+    }
+
+    /// Weight of `category` in `category_weights`.
+    pub fn category_weight(&self, category: TriviaCategory) -> u32 {
+        self.category_weights.get(&category).copied().unwrap_or(1)
+    }
+}
+
+impl From<&trivia_pb::TriviaRules> for TriviaRules {
+    fn from(rules: &trivia_pb::TriviaRules) -> Self {
+        Self {
+            num_questions: rules.num_questions,
+            seconds_per_question: rules.seconds_per_question,
+            negative_marking: rules.negative_marking,
+            categories: rules.categories().filter_map(TriviaCategory::from_pb).collect(),
+            max_players: rules.max_players,
+            // Not part of the proto rules yet.
+            category_weights: BTreeMap::new(),
+        }
+    }
+}
+
+fn validate_max_players(max_players: u32) -> Result<(), DomainError> {
+    if max_players == 0 || max_players > MAX_PLAYERS {
+        return Err(DomainError::InvalidRules(format!(
+            "max_players must be between 1 and {}",
+            MAX_PLAYERS
+        )));
+    }
+    Ok(())
+}
+
+// --- GAME RULES ---
+
+/// Rules for one session, tagged with the game they apply to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "game", rename_all = "snake_case")]
+pub enum GameRules {
+    Puzzle(PuzzleRules),
+    Trivia(TriviaRules),
+}
+
+impl GameRules {
+    pub fn default_for(game_type: &GameType) -> Self {
+        match game_type {
+            GameType::Puzzle => GameRules::Puzzle(PuzzleRules::default()),
+            GameType::Trivia => GameRules::Trivia(TriviaRules::default()),
+        }
+    }
+
+    pub fn game_type(&self) -> GameType {
+        match self {
+            GameRules::Puzzle(_) => GameType::Puzzle,
+            GameRules::Trivia(_) => GameType::Trivia,
+        }
+    }
+
+    pub fn max_players(&self) -> u32 {
+        match self {
+            GameRules::Puzzle(rules) => rules.max_players,
+            GameRules::Trivia(rules) => rules.max_players,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), DomainError> {
+        match self {
+            GameRules::Puzzle(rules) => rules.validate(),
+            GameRules::Trivia(rules) => rules.validate(),
+        }
+    }
+}
+
+// --- RULESETS ---
+
+/// A named, versioned rules preset that sessions can reference by id, e.g. "quick-5".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ruleset {
+    pub id: String,
+    pub version: u32,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub rules: GameRules,
+}
+
+impl Ruleset {
+    /// The `id@version` form, which pins this exact version when resolving.
+    pub fn versioned_id(&self) -> String {
+        format!("{}@{}", self.id, self.version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn category_weights_must_leave_a_category_to_ask() {
+        let rules = |categories: Vec<TriviaCategory>, weights: &[(TriviaCategory, u32)]| TriviaRules {
+            categories,
+            category_weights: weights.iter().copied().collect(),
+            ..TriviaRules::default()
+        };
+        assert!(rules(vec![], &[]).validate().is_ok());
+        assert!(rules(vec![], &[(TriviaCategory::Science, 0)]).validate().is_ok());
+        assert!(rules(vec![TriviaCategory::Science], &[(TriviaCategory::Science, 0)]).validate().is_err());
+        let none = TriviaCategory::ALL.map(|c| (c, 0));
+        assert!(rules(vec![], &none).validate().is_err());
+    }
+}
@@ -0,0 +1,191 @@
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+
+/// How trivia answers are turned into points. Loaded from the `scoring` section of
+/// `config.yaml`; any field left out falls back to the default below.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScoringPolicy {
+    /// Points for a correct answer given the instant the question is revealed.
+    pub max_points: i32,
+    /// Points for a correct answer given at (or after) the question's time limit.
+    pub min_points: i32,
+    /// Multiplier added for each consecutive correct answer before this one.
+    pub streak_step: f64,
+    pub max_streak_multiplier: f64,
+    /// Extra points for the first correct answer to a question when several people play.
+    pub first_correct_bonus: i32,
+    /// Points taken away for a wrong answer when the rules enable negative marking.
+    pub wrong_answer_penalty: i32,
+}
+
+impl Default for ScoringPolicy {
+    fn default() -> Self {
+        Self {
+            max_points: 100,
+            min_points: 20,
+            streak_step: 0.1,
+            max_streak_multiplier: 2.0,
+            first_correct_bonus: 25,
+            wrong_answer_penalty: 25,
+        }
+    }
+}
+
+/// Everything about a single answer that affects its score.
+#[derive(Debug, Clone, Copy)]
+pub struct AnswerContext {
+    pub correct: bool,
+    pub latency: Duration,
+    pub time_limit: Duration,
+    /// Consecutive correct answers before this one.
+    pub streak: u32,
+    pub first_correct: bool,
+    pub multiplayer: bool,
+    pub negative_marking: bool,
+}
+
+impl ScoringPolicy {
+    /// Checks the settings make sense together: points never go negative or up with latency,
+    /// and streaks can only multiply a score up.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.min_points < 0 || self.max_points < self.min_points {
+            return Err("points must satisfy 0 <= min_points <= max_points".to_string());
+        }
+        if !self.streak_step.is_finite() || self.streak_step < 0.0 {
+            return Err("streak_step must be a number of at least 0".to_string());
+        }
+        if !self.max_streak_multiplier.is_finite() || self.max_streak_multiplier < 1.0 {
+            return Err("max_streak_multiplier must be a number of at least 1".to_string());
+        }
+        if self.first_correct_bonus < 0 || self.wrong_answer_penalty < 0 {
+            return Err("first_correct_bonus and wrong_answer_penalty can't be negative".to_string());
+        }
+        Ok(())
+    }
+
+    /// Most points a single question can give: an instant answer at the top streak, first in.
+    pub fn max_question_score(&self) -> i32 {
+        (self.max_points as f64 * self.max_streak_multiplier.max(1.0)).round() as i32 + self.first_correct_bonus.max(0)
+    }
+
+    pub fn score(&self, answer: &AnswerContext) -> i32 {
+        if !answer.correct {
+            return if answer.negative_marking { -self.wrong_answer_penalty } else { 0 };
+        }
+
+        // Linear decay from max_points to min_points over the time limit.
+        let limit_ms = answer.time_limit.num_milliseconds().max(1) as f64;
+        let elapsed = (answer.latency.num_milliseconds().max(0) as f64 / limit_ms).min(1.0);
+        let base = self.max_points as f64 - (self.max_points - self.min_points) as f64 * elapsed;
+
+        let multiplier = (1.0 + self.streak_step * answer.streak as f64).min(self.max_streak_multiplier);
+        let bonus = if answer.first_correct && answer.multiplayer { self.first_correct_bonus } else { 0 };
+
+        (base * multiplier).round() as i32 + bonus
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A correct solo answer after `latency_ms` of a 20 second limit, with no streak.
+    fn answer(latency_ms: i64) -> AnswerContext {
+        AnswerContext {
+            correct: true,
+            latency: Duration::milliseconds(latency_ms),
+            time_limit: Duration::seconds(20),
+            streak: 0,
+            first_correct: false,
+            multiplayer: false,
+            negative_marking: false,
+        }
+    }
+
+    #[test]
+    fn correct_answers_decay_linearly_over_the_time_limit() {
+        let policy = ScoringPolicy::default();
+        let cases = [
+            ("zero latency", 0, 100),
+            ("a quarter in", 5_000, 80),
+            ("halfway", 10_000, 60),
+            ("just before the deadline", 19_999, 20),
+            ("full deadline", 20_000, 20),
+            ("past the deadline", 25_000, 20),
+            ("clock skew", -500, 100),
+        ];
+        for (case, latency_ms, expected) in cases {
+            assert_eq!(policy.score(&answer(latency_ms)), expected, "{}", case);
+        }
+    }
+
+    #[test]
+    fn streaks_multiply_up_to_the_cap() {
+        let policy = ScoringPolicy::default();
+        let cases = [
+            ("streak reset", 0, 100),
+            ("one before", 1, 110),
+            ("five before", 5, 150),
+            ("at the cap", 10, 200),
+            ("past the cap", 25, 200),
+        ];
+        for (case, streak, expected) in cases {
+            let context = AnswerContext { streak, ..answer(0) };
+            assert_eq!(policy.score(&context), expected, "{}", case);
+        }
+        // The multiplier applies after the decay: halfway at a streak of five.
+        assert_eq!(policy.score(&AnswerContext { streak: 5, ..answer(10_000) }), 90);
+    }
+
+    #[test]
+    fn the_first_correct_bonus_needs_company() {
+        let policy = ScoringPolicy::default();
+        let cases = [
+            ("first, with others", true, true, 125),
+            ("first, alone", true, false, 100),
+            ("not first", false, true, 100),
+        ];
+        for (case, first_correct, multiplayer, expected) in cases {
+            let context = AnswerContext { first_correct, multiplayer, ..answer(0) };
+            assert_eq!(policy.score(&context), expected, "{}", case);
+        }
+        // The bonus is not multiplied by the streak.
+        let context = AnswerContext { first_correct: true, multiplayer: true, streak: 10, ..answer(0) };
+        assert_eq!(policy.score(&context), 225);
+        assert_eq!(policy.max_question_score(), 225);
+    }
+
+    #[test]
+    fn wrong_answers_only_cost_points_with_negative_marking() {
+        let policy = ScoringPolicy::default();
+        let wrong = AnswerContext { correct: false, streak: 5, first_correct: true, multiplayer: true, ..answer(0) };
+        assert_eq!(policy.score(&wrong), 0);
+        assert_eq!(policy.score(&AnswerContext { negative_marking: true, ..wrong }), -25);
+    }
+
+    #[test]
+    fn a_zero_time_limit_scores_as_the_deadline() {
+        let policy = ScoringPolicy::default();
+        let context = AnswerContext { time_limit: Duration::zero(), ..answer(0) };
+        assert_eq!(policy.score(&context), 100);
+        let context = AnswerContext { time_limit: Duration::zero(), ..answer(5) };
+        assert_eq!(policy.score(&context), 20);
+    }
+
+    #[test]
+    fn inconsistent_settings_are_rejected() {
+        assert!(ScoringPolicy::default().validate().is_ok());
+        let invalid = [
+            ScoringPolicy { min_points: -1, ..ScoringPolicy::default() },
+            ScoringPolicy { max_points: 10, ..ScoringPolicy::default() },
+            ScoringPolicy { streak_step: -0.1, ..ScoringPolicy::default() },
+            ScoringPolicy { max_streak_multiplier: 0.5, ..ScoringPolicy::default() },
+            ScoringPolicy { max_streak_multiplier: f64::NAN, ..ScoringPolicy::default() },
+            ScoringPolicy { wrong_answer_penalty: -5, ..ScoringPolicy::default() },
+        ];
+        for policy in invalid {
+            assert!(policy.validate().is_err(), "{:?}", policy);
+        }
+    }
+}
@@ -1,24 +1,48 @@
 use crate::domain::game::{
-    DomainError, DomainEvent, EventMeta, GameCommand, GameSessionID, GameType, PlayerID, Session,
+    DomainError, DomainEvent, EventMeta, GameCommand, GameSessionID, GameType, PlayerID, Role, RemovePlayerCommand, SeatPlayerCommand, Session,
 };
-use crate::ports::{Clock, IdGenerator, Rng};
+use crate::domain::question::{normalize_answer, ClientFormat, Question, QuestionFormat, QuestionID};
+use crate::domain::rules::{TriviaCategory, TriviaRules};
+use crate::domain::scoring::{AnswerContext, ScoringPolicy};
+use crate::ports::{Clock, IdGenerator, QuestionRepository, Rng};
 use crate::pb::runecraftstudios::pastello::game::trivia::v1::{SubmitAnswerCommand, RevealHintCommand};
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
 use std::any::Any;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 use std::fmt;
 
+// How long the correct answer stays on screen before the scoreboard.
+const ANSWER_REVEAL_SECS: i64 = 5;
+// How long the scoreboard stays on screen before the next question.
+const SCOREBOARD_SECS: i64 = 5;
+
 // --- IMPLEMENT GameCommand for Protobuf structs ---
 
 impl GameCommand for SubmitAnswerCommand {
     fn get_type(&self) -> String { "SubmitAnswerCommand".to_string() }
+    fn registered_type(&self) -> &'static str { "TriviaSubmit" }
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "session_id": self.session_id.as_ref().map(|s| s.value.as_str()).unwrap_or_default(),
+            "player_id": self.player_id.as_ref().map(|p| p.value.as_str()).unwrap_or_default(),
+            "answer": self.answer,
+        })
+    }
     fn into_any(self: Box<Self>) -> Box<dyn Any + Send> { self }
 }
 
 impl GameCommand for RevealHintCommand {
     fn get_type(&self) -> String { "RevealHintCommand".to_string() }
+    fn registered_type(&self) -> &'static str { "TriviaHint" }
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "session_id": self.session_id.as_ref().map(|s| s.value.as_str()).unwrap_or_default(),
+        })
+    }
     fn into_any(self: Box<Self>) -> Box<dyn Any + Send> { self }
 }
 
@@ -27,8 +51,12 @@ impl GameCommand for RevealHintCommand {
 #[derive(Clone)]
 pub struct EngineDependencies {
     clock: Arc<dyn Clock>,
-    _rng: Arc<dyn Rng>,
+    // Separate generators for picking questions and ordering their options, so a change to how
+    // options are shuffled can't change which questions a seed draws.
+    rng: Arc<dyn Rng>,
+    option_rng: Arc<dyn Rng>,
     _id_gen: Arc<dyn IdGenerator>,
+    questions: Arc<dyn QuestionRepository>,
 }
 
 impl fmt::Debug for EngineDependencies {
@@ -36,47 +64,237 @@ impl fmt::Debug for EngineDependencies {
         f.debug_struct("EngineDependencies")
             .field("clock", &"Arc<dyn Clock>")
             .field("rng", &"Arc<dyn Rng>")
+            .field("option_rng", &"Arc<dyn Rng>")
             .field("id_gen", &"Arc<dyn IdGenerator>")
+            .field("questions", &"Arc<dyn QuestionRepository>")
             .finish()
     }
 }
 
 // --- EVENTS ---
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+pub struct QuestionRevealed {
+    pub meta: EventMeta,
+    pub session_id: GameSessionID,
+    pub question_index: u32,
+    pub question_id: QuestionID,
+    pub category: TriviaCategory,
+    pub prompt: String,
+    pub format: ClientFormat,
+    pub seconds_to_answer: u32,
+}
+
+impl DomainEvent for QuestionRevealed {
+    fn event_type(&self) -> &'static str { "trivia.question_revealed" }
+    fn session_id(&self) -> &GameSessionID { &self.session_id }
+    fn to_json(&self) -> serde_json::Value { serde_json::to_value(self).unwrap_or_default() }
+    fn to_any_box(self: Box<Self>) -> Box<dyn Any + Send> { self }
+    fn clone_box(&self) -> Box<dyn DomainEvent> { Box::new(self.clone()) }
+}
+
+/// A player has locked in an answer. Deliberately says nothing about whether it is right.
+#[derive(Debug, Clone, Serialize)]
+pub struct AnswerLocked {
+    pub meta: EventMeta,
+    pub session_id: GameSessionID,
+    pub question_index: u32,
+    pub player_id: PlayerID,
+    pub answers_in: u32,
+    pub players: u32,
+}
+
+impl DomainEvent for AnswerLocked {
+    fn event_type(&self) -> &'static str { "trivia.answer_locked" }
+    fn session_id(&self) -> &GameSessionID { &self.session_id }
+    fn to_json(&self) -> serde_json::Value { serde_json::to_value(self).unwrap_or_default() }
+    fn to_any_box(self: Box<Self>) -> Box<dyn Any + Send> { self }
+    fn clone_box(&self) -> Box<dyn DomainEvent> { Box::new(self.clone()) }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CloseReason {
+    AllAnswered,
+    TimeUp,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct QuestionClosed {
+    pub meta: EventMeta,
+    pub session_id: GameSessionID,
+    pub question_index: u32,
+    pub reason: CloseReason,
+}
+
+impl DomainEvent for QuestionClosed {
+    fn event_type(&self) -> &'static str { "trivia.question_closed" }
+    fn session_id(&self) -> &GameSessionID { &self.session_id }
+    fn to_json(&self) -> serde_json::Value { serde_json::to_value(self).unwrap_or_default() }
+    fn to_any_box(self: Box<Self>) -> Box<dyn Any + Send> { self }
+    fn clone_box(&self) -> Box<dyn DomainEvent> { Box::new(self.clone()) }
+}
+
+/// How many players picked a given answer.
+#[derive(Debug, Clone, Serialize)]
+pub struct AnswerTally {
+    pub answer: String,
+    pub count: u32,
+    pub correct: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AnswerRevealed {
+    pub meta: EventMeta,
+    pub session_id: GameSessionID,
+    pub question_index: u32,
+    pub correct_answer: String,
+    pub breakdown: Vec<AnswerTally>,
+}
+
+impl DomainEvent for AnswerRevealed {
+    fn event_type(&self) -> &'static str { "trivia.answer_revealed" }
+    fn session_id(&self) -> &GameSessionID { &self.session_id }
+    fn to_json(&self) -> serde_json::Value { serde_json::to_value(self).unwrap_or_default() }
+    fn to_any_box(self: Box<Self>) -> Box<dyn Any + Send> { self }
+    fn clone_box(&self) -> Box<dyn DomainEvent> { Box::new(self.clone()) }
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct AnswerAccepted {
     pub meta: EventMeta,
     pub session_id: GameSessionID,
     pub player_id: PlayerID,
-    pub points_awarded: i32,
+    pub correct: bool,
+    pub delta_score: i32,
+    pub total_score: i32,
+    pub streak: u32,
+    pub latency_ms: i64,
 }
 
 impl DomainEvent for AnswerAccepted {
     fn event_type(&self) -> &'static str { "trivia.answer_accepted" }
     fn session_id(&self) -> &GameSessionID { &self.session_id }
+    fn to_json(&self) -> serde_json::Value { serde_json::to_value(self).unwrap_or_default() }
     fn to_any_box(self: Box<Self>) -> Box<dyn Any + Send> { self }
     fn clone_box(&self) -> Box<dyn DomainEvent> { Box::new(self.clone()) }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct HintRevealed {
     pub meta: EventMeta,
     pub session_id: GameSessionID,
+    pub hint_text: String,
 }
 
 impl DomainEvent for HintRevealed {
     fn event_type(&self) -> &'static str { "trivia.hint_revealed" }
     fn session_id(&self) -> &GameSessionID { &self.session_id }
+    fn to_json(&self) -> serde_json::Value { serde_json::to_value(self).unwrap_or_default() }
+    fn to_any_box(self: Box<Self>) -> Box<dyn Any + Send> { self }
+    fn clone_box(&self) -> Box<dyn DomainEvent> { Box::new(self.clone()) }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FinalScore {
+    pub rank: u32,
+    pub player_id: PlayerID,
+    pub score: i32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScoreboardShown {
+    pub meta: EventMeta,
+    pub session_id: GameSessionID,
+    pub question_index: u32,
+    pub questions_total: u32,
+    pub scores: Vec<FinalScore>,
+}
+
+impl DomainEvent for ScoreboardShown {
+    fn event_type(&self) -> &'static str { "trivia.scoreboard" }
+    fn session_id(&self) -> &GameSessionID { &self.session_id }
+    fn to_json(&self) -> serde_json::Value { serde_json::to_value(self).unwrap_or_default() }
     fn to_any_box(self: Box<Self>) -> Box<dyn Any + Send> { self }
     fn clone_box(&self) -> Box<dyn DomainEvent> { Box::new(self.clone()) }
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct TriviaFinished {
+    pub meta: EventMeta,
+    pub session_id: GameSessionID,
+    pub scores: Vec<FinalScore>,
+}
+
+impl DomainEvent for TriviaFinished {
+    fn event_type(&self) -> &'static str { "trivia.finished" }
+    fn session_id(&self) -> &GameSessionID { &self.session_id }
+    fn to_json(&self) -> serde_json::Value { serde_json::to_value(self).unwrap_or_default() }
+    fn to_any_box(self: Box<Self>) -> Box<dyn Any + Send> { self }
+    fn clone_box(&self) -> Box<dyn DomainEvent> { Box::new(self.clone()) }
+}
+
+// --- SNAPSHOT ---
+
+#[derive(Debug, Clone, Serialize)]
+pub struct QuestionView {
+    pub question_id: QuestionID,
+    pub category: TriviaCategory,
+    pub prompt: String,
+    pub format: ClientFormat,
+    pub seconds_to_answer: u32,
+}
+
+/// State sent to a client when it attaches. The correct answer stays hidden until it is
+/// revealed, and only players see their own locked answer.
+#[derive(Debug, Clone, Serialize)]
+pub struct TriviaSnapshot {
+    pub session_id: GameSessionID,
+    pub phase: RoundPhase,
+    pub question_index: u32,
+    pub questions_total: u32,
+    pub question: Option<QuestionView>,
+    pub answered: Vec<PlayerID>,
+    pub your_answer: Option<String>,
+    pub correct_answer: Option<String>,
+    pub scores: Vec<FinalScore>,
+}
+
 // --- ENGINE STATE ---
 
+/// Each question runs through these phases in order; `tick` moves between them on a timer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum RoundPhase {
+    Waiting,
+    Answering,
+    AnswerReveal,
+    Scoreboard,
+    Finished,
+}
+
+#[derive(Debug, Clone)]
+pub struct LockedAnswer {
+    pub player_id: PlayerID,
+    pub answer: String,
+    pub correct: bool,
+    pub latency: chrono::Duration,
+}
+
 #[derive(Debug, Clone)]
 pub struct State {
-    pub scores: HashMap<PlayerID, i32>,
+    pub session_id: GameSessionID,
+    pub rules: TriviaRules,
+    pub scoring: ScoringPolicy,
+    pub questions: Vec<Question>,
+    // `questions` as stored, before their options were shuffled.
+    pub drawn: Vec<Question>,
     pub question_index: u32,
+    pub phase: RoundPhase,
+    pub phase_started_at: DateTime<Utc>,
+    pub players: Vec<PlayerID>,
+    pub scores: HashMap<PlayerID, i32>,
+    pub streaks: HashMap<PlayerID, u32>,
+    // Answers locked in for the current question, in the order they arrived.
+    pub locked: Vec<LockedAnswer>,
 }
 
 // --- ENGINE IMPLEMENTATION ---
@@ -91,48 +309,345 @@ impl TriviaEngine {
     pub fn new(
         clock: Arc<dyn Clock>,
         rng: Arc<dyn Rng>,
+        option_rng: Arc<dyn Rng>,
         id_gen: Arc<dyn IdGenerator>,
+        questions: Arc<dyn QuestionRepository>,
+        rules: TriviaRules,
+        scoring: ScoringPolicy,
     ) -> Self {
+        let phase_started_at = clock.now_utc();
         Self {
             state: State {
-                scores: HashMap::new(),
+                session_id: GameSessionID::new(),
+                rules,
+                scoring,
+                questions: Vec::new(),
+                drawn: Vec::new(),
                 question_index: 0,
+                phase: RoundPhase::Waiting,
+                phase_started_at,
+                players: Vec::new(),
+                scores: HashMap::new(),
+                streaks: HashMap::new(),
+                locked: Vec::new(),
             },
-            deps: EngineDependencies { clock, _rng: rng, _id_gen: id_gen },
+            deps: EngineDependencies { clock, rng, option_rng, _id_gen: id_gen, questions },
         }
     }
 
+    fn current_question(&self) -> Option<&Question> {
+        self.state.questions.get(self.state.question_index as usize)
+    }
+
+    fn time_limit(&self) -> chrono::Duration {
+        chrono::Duration::seconds(self.state.rules.seconds_per_question as i64)
+    }
+
+    fn enter(&mut self, phase: RoundPhase) {
+        self.state.phase = phase;
+        self.state.phase_started_at = self.deps.clock.now_utc();
+    }
+
     fn submit_answer(
         &mut self,
-        _session_id: &GameSessionID,
+        session_id: &GameSessionID,
         cmd: &SubmitAnswerCommand,
     ) -> Result<Vec<Box<dyn DomainEvent>>, DomainError> {
-        let player_id_str = cmd.player_id.as_ref().map(|p| p.value.clone()).unwrap_or_default();
+        let player_id = cmd.player_id.as_ref().map(|p| p.value.clone()).unwrap_or_default();
+        match self.state.phase {
+            RoundPhase::Answering => {}
+            RoundPhase::Finished => return Err(DomainError::GameFinished),
+            _ => return Err(DomainError::NotAcceptingAnswers),
+        }
+        if !self.state.players.contains(&player_id) {
+            return Err(DomainError::NotAPlayer(player_id));
+        }
+        if self.state.locked.iter().any(|l| l.player_id == player_id) {
+            return Err(DomainError::AlreadyAnswered);
+        }
+        let question = self.current_question().ok_or(DomainError::GameFinished)?;
+        let correct = question.check(&cmd.answer)?;
 
-        *self.state.scores.entry(player_id_str.clone()).or_insert(0) += 10;
-        self.state.question_index += 1;
+        let latency = self.deps.clock.now_utc() - self.state.phase_started_at;
+        self.state.locked.push(LockedAnswer {
+            player_id: player_id.clone(),
+            answer: cmd.answer.clone(),
+            correct,
+            latency,
+        });
 
-        let event = AnswerAccepted {
+        let mut events: Vec<Box<dyn DomainEvent>> = vec![Box::new(AnswerLocked {
             meta: crate::domain::game::new_meta(self.deps.clock.as_ref()),
-            session_id: _session_id.clone(),
-            player_id: player_id_str,
-            points_awarded: 10,
-        };
+            session_id: session_id.clone(),
+            question_index: self.state.question_index,
+            player_id,
+            answers_in: self.state.locked.len() as u32,
+            players: self.state.players.len() as u32,
+        })];
 
-        Ok(vec![Box::new(event)])
+        if self.state.locked.len() >= self.state.players.len() {
+            events.extend(self.close_question(CloseReason::AllAnswered));
+        }
+
+        Ok(events)
+    }
+
+    /// Seats a player who joined after the start. They can answer from the current question on.
+    fn seat_player(&mut self, player_id: &PlayerID) -> Result<Vec<Box<dyn DomainEvent>>, DomainError> {
+        if self.state.phase == RoundPhase::Finished {
+            return Err(DomainError::GameFinished);
+        }
+        if !self.state.players.contains(player_id) {
+            self.state.players.push(player_id.clone());
+        }
+        Ok(vec![])
+    }
+
+    /// Takes a kicked player out of the game, along with any answer they locked in. The question
+    /// closes if everyone still playing has already answered it.
+    fn remove_player(&mut self, player_id: &PlayerID) -> Result<Vec<Box<dyn DomainEvent>>, DomainError> {
+        if self.state.phase == RoundPhase::Finished {
+            return Err(DomainError::GameFinished);
+        }
+        self.state.players.retain(|p| p != player_id);
+        self.state.locked.retain(|l| l.player_id != *player_id);
+        let all_answered = !self.state.players.is_empty() && self.state.locked.len() >= self.state.players.len();
+        if self.state.phase == RoundPhase::Answering && all_answered {
+            return Ok(self.close_question(CloseReason::AllAnswered));
+        }
+        Ok(vec![])
     }
 
     fn reveal_hint(
         &mut self,
-        _session_id: &GameSessionID,
+        session_id: &GameSessionID,
     ) -> Result<Vec<Box<dyn DomainEvent>>, DomainError> {
+        if self.state.phase != RoundPhase::Answering {
+            return Err(DomainError::NotAcceptingAnswers);
+        }
+        let hint_text = self
+            .current_question()
+            .and_then(|q| q.hint.clone())
+            .unwrap_or_default();
         let event = HintRevealed {
             meta: crate::domain::game::new_meta(self.deps.clock.as_ref()),
-            session_id: _session_id.clone(),
+            session_id: session_id.clone(),
+            hint_text,
         };
 
         Ok(vec![Box::new(event)])
     }
+
+    /// Stops accepting answers, scores everything locked in and reveals the correct answer.
+    fn close_question(&mut self, reason: CloseReason) -> Vec<Box<dyn DomainEvent>> {
+        let Some(question) = self.current_question().cloned() else {
+            return vec![self.finish()];
+        };
+        let question_index = self.state.question_index;
+        let mut events: Vec<Box<dyn DomainEvent>> = vec![Box::new(QuestionClosed {
+            meta: crate::domain::game::new_meta(self.deps.clock.as_ref()),
+            session_id: self.state.session_id.clone(),
+            question_index,
+            reason,
+        })];
+
+        let locked = std::mem::take(&mut self.state.locked);
+        events.push(Box::new(AnswerRevealed {
+            meta: crate::domain::game::new_meta(self.deps.clock.as_ref()),
+            session_id: self.state.session_id.clone(),
+            question_index,
+            correct_answer: question.correct_answer(),
+            breakdown: tally(&question, &locked),
+        }));
+
+        // Locked answers are in arrival order, so the first correct one earns the bonus.
+        let mut first_correct_taken = false;
+        for answer in &locked {
+            let correct = answer.correct;
+            let streak = self.state.streaks.get(&answer.player_id).copied().unwrap_or(0);
+            let delta_score = self.state.scoring.score(&AnswerContext {
+                correct,
+                latency: answer.latency,
+                time_limit: self.time_limit(),
+                streak,
+                first_correct: correct && !first_correct_taken,
+                multiplayer: self.state.players.len() > 1,
+                negative_marking: self.state.rules.negative_marking,
+            });
+            first_correct_taken |= correct;
+
+            let streak = if correct { streak + 1 } else { 0 };
+            self.state.streaks.insert(answer.player_id.clone(), streak);
+            let total = self.state.scores.entry(answer.player_id.clone()).or_insert(0);
+            *total += delta_score;
+
+            events.push(Box::new(AnswerAccepted {
+                meta: crate::domain::game::new_meta(self.deps.clock.as_ref()),
+                session_id: self.state.session_id.clone(),
+                player_id: answer.player_id.clone(),
+                correct,
+                delta_score,
+                total_score: *total,
+                streak,
+                latency_ms: answer.latency.num_milliseconds(),
+            }));
+        }
+
+        // Anyone who did not answer in time loses their streak.
+        for player_id in &self.state.players {
+            if !locked.iter().any(|l| &l.player_id == player_id) {
+                self.state.streaks.insert(player_id.clone(), 0);
+            }
+        }
+
+        self.enter(RoundPhase::AnswerReveal);
+        events
+    }
+
+    fn show_scoreboard(&mut self) -> Vec<Box<dyn DomainEvent>> {
+        self.enter(RoundPhase::Scoreboard);
+        vec![Box::new(ScoreboardShown {
+            meta: crate::domain::game::new_meta(self.deps.clock.as_ref()),
+            session_id: self.state.session_id.clone(),
+            question_index: self.state.question_index,
+            questions_total: self.state.questions.len() as u32,
+            scores: self.ranked_scores(),
+        })]
+    }
+
+    /// Moves on to the next question, or ends the game after the last one.
+    fn next_question(&mut self) -> Vec<Box<dyn DomainEvent>> {
+        self.state.question_index += 1;
+        self.reveal_current()
+    }
+
+    fn reveal_current(&mut self) -> Vec<Box<dyn DomainEvent>> {
+        let Some(question) = self.current_question().cloned() else {
+            return vec![self.finish()];
+        };
+        self.enter(RoundPhase::Answering);
+
+        vec![Box::new(QuestionRevealed {
+            meta: crate::domain::game::new_meta(self.deps.clock.as_ref()),
+            session_id: self.state.session_id.clone(),
+            question_index: self.state.question_index,
+            format: question.client_format(),
+            question_id: question.id,
+            category: question.category,
+            prompt: question.prompt,
+            seconds_to_answer: self.state.rules.seconds_per_question,
+        })]
+    }
+
+    fn ranked_scores(&self) -> Vec<FinalScore> {
+        let mut scores: Vec<FinalScore> = self
+            .state
+            .players
+            .iter()
+            .map(|player_id| FinalScore {
+                rank: 0,
+                player_id: player_id.clone(),
+                score: self.state.scores.get(player_id).copied().unwrap_or(0),
+            })
+            .collect();
+        scores.sort_by_key(|s| std::cmp::Reverse(s.score));
+        for (i, score) in scores.iter_mut().enumerate() {
+            score.rank = i as u32 + 1;
+        }
+        scores
+    }
+
+    fn finish(&mut self) -> Box<dyn DomainEvent> {
+        self.enter(RoundPhase::Finished);
+        Box::new(TriviaFinished {
+            meta: crate::domain::game::new_meta(self.deps.clock.as_ref()),
+            session_id: self.state.session_id.clone(),
+            scores: self.ranked_scores(),
+        })
+    }
+}
+
+/// Counts locked-in answers per option. Formats with fixed choices list every choice, even
+/// unpicked ones; free-text and numeric answers are grouped by what players typed.
+fn tally(question: &Question, locked: &[LockedAnswer]) -> Vec<AnswerTally> {
+    let correct_answer = question.correct_answer();
+    let mut breakdown: Vec<AnswerTally> = question
+        .choices()
+        .into_iter()
+        .map(|choice| AnswerTally {
+            correct: choice == correct_answer,
+            answer: choice,
+            count: 0,
+        })
+        .collect();
+    if breakdown.is_empty() {
+        breakdown.push(AnswerTally {
+            answer: correct_answer,
+            count: 0,
+            correct: true,
+        });
+    }
+
+    for answer in locked {
+        let shown = question.display_answer(&answer.answer);
+        let key = normalize_answer(&shown);
+        match breakdown.iter_mut().find(|t| normalize_answer(&t.answer) == key) {
+            Some(existing) => existing.count += 1,
+            None => breakdown.push(AnswerTally {
+                answer: shown,
+                count: 1,
+                correct: answer.correct,
+            }),
+        }
+    }
+    breakdown
+}
+
+/// Picks `rules.num_questions` of `questions` in a random order, drawn through the Rng port so
+/// the adapter controls the order. Stores list in no particular order, so this starts from a
+/// fixed one for seeded sessions. With `category_weights` set, each question's category is
+/// picked by weight first, among the categories that still have questions left.
+pub fn draw_questions(mut questions: Vec<Question>, rules: &TriviaRules, rng: &dyn Rng) -> Vec<Question> {
+    questions.sort_by(|a, b| a.id.cmp(&b.id));
+    let count = rules.num_questions as usize;
+    if rules.category_weights.is_empty() {
+        rng.shuffle(&mut questions);
+        questions.truncate(count);
+        return questions;
+    }
+
+    let mut pools: BTreeMap<TriviaCategory, Vec<Question>> = BTreeMap::new();
+    for question in questions {
+        pools.entry(question.category).or_default().push(question);
+    }
+    let mut pools: Vec<(TriviaCategory, Vec<Question>)> = pools.into_iter().collect();
+    let mut drawn = Vec::with_capacity(count);
+    while drawn.len() < count {
+        let weights: Vec<f64> = pools
+            .iter()
+            .map(|(category, pool)| match pool.is_empty() {
+                true => 0.0,
+                false => rules.category_weight(*category) as f64,
+            })
+            .collect();
+        let Some(index) = rng.weighted_index(&weights) else {
+            break;
+        };
+        let pool = &mut pools[index].1;
+        let pick = rng.range_u64(0..pool.len() as u64) as usize;
+        drawn.push(pool.remove(pick));
+    }
+    drawn
+}
+
+/// Shuffles multiple-choice options in place and keeps `correct` pointing at the right one.
+fn shuffle_options(question: &mut Question, rng: &dyn Rng) {
+    if let QuestionFormat::MultipleChoice { options, correct } = &mut question.format {
+        let mut order: Vec<usize> = (0..options.len()).collect();
+        rng.shuffle(&mut order);
+        *options = order.iter().map(|i| options[*i].clone()).collect();
+        *correct = order.iter().position(|i| *i == *correct).unwrap_or(*correct);
+    }
 }
 
 #[async_trait]
@@ -149,29 +664,423 @@ impl crate::domain::game::Engine for TriviaEngine {
         Ok((session, vec![]))
     }
 
+    async fn start(&mut self, session: &Session) -> Result<Vec<Box<dyn DomainEvent>>, DomainError> {
+        self.state.session_id = session.id.clone();
+        self.state.players = session.players.iter().map(|p| p.id.clone()).collect();
+
+        let mut questions = if session.question_ids.is_empty() {
+            let stored = self
+                .deps
+                .questions
+                .list(&self.state.rules.categories)
+                .await
+                .map_err(|e| DomainError::Internal(e.to_string()))?;
+            draw_questions(stored, &self.state.rules, self.deps.rng.as_ref())
+        } else {
+            self.deps
+                .questions
+                .get(&session.question_ids)
+                .await
+                .map_err(|e| DomainError::Internal(e.to_string()))?
+        };
+        self.state.drawn = questions.clone();
+        for question in &mut questions {
+            shuffle_options(question, self.deps.option_rng.as_ref());
+        }
+        self.state.questions = questions;
+
+        Ok(self.reveal_current())
+    }
+
+    fn drawn_questions(&self) -> Vec<Question> {
+        self.state.drawn.clone()
+    }
+
+    fn snapshot(&self, viewer: &PlayerID, role: Role) -> serde_json::Value {
+        let question = match self.state.phase {
+            RoundPhase::Waiting | RoundPhase::Finished => None,
+            _ => self.current_question(),
+        };
+        let revealed = matches!(self.state.phase, RoundPhase::AnswerReveal | RoundPhase::Scoreboard);
+        let your_answer = match role {
+            Role::Player => self
+                .state
+                .locked
+                .iter()
+                .find(|a| a.player_id == *viewer)
+                .map(|a| question.map_or(a.answer.clone(), |q| q.display_answer(&a.answer))),
+            Role::Spectator => None,
+        };
+
+        let snapshot = TriviaSnapshot {
+            session_id: self.state.session_id.clone(),
+            phase: self.state.phase,
+            question_index: self.state.question_index,
+            questions_total: self.state.questions.len() as u32,
+            question: question.map(|q| QuestionView {
+                question_id: q.id.clone(),
+                category: q.category,
+                prompt: q.prompt.clone(),
+                format: q.client_format(),
+                seconds_to_answer: self.state.rules.seconds_per_question,
+            }),
+            answered: self.state.locked.iter().map(|a| a.player_id.clone()).collect(),
+            your_answer,
+            correct_answer: question.filter(|_| revealed).map(Question::correct_answer),
+            scores: self.ranked_scores(),
+        };
+        serde_json::to_value(snapshot).unwrap_or_default()
+    }
+
+    fn tick(&mut self) -> Vec<Box<dyn DomainEvent>> {
+        let elapsed = self.deps.clock.now_utc() - self.state.phase_started_at;
+        match self.state.phase {
+            RoundPhase::Answering if elapsed >= self.time_limit() => self.close_question(CloseReason::TimeUp),
+            RoundPhase::AnswerReveal if elapsed >= chrono::Duration::seconds(ANSWER_REVEAL_SECS) => {
+                self.show_scoreboard()
+            }
+            RoundPhase::Scoreboard if elapsed >= chrono::Duration::seconds(SCOREBOARD_SECS) => self.next_question(),
+            _ => vec![],
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.state.phase == RoundPhase::Finished
+    }
+
     fn execute_command(
         &mut self,
         command: Box<dyn crate::domain::game::GameCommand>,
-    ) -> Result<(), DomainError> {
+    ) -> Result<Vec<Box<dyn DomainEvent>>, DomainError> {
         let command_type = command.get_type();
 
-        match command_type.as_str() {
+        let events = match command_type.as_str() {
             "SubmitAnswerCommand" => {
                 // FIX: Cast to Any before downcasting
                 let any_cmd = command.into_any();
                 let cmd = any_cmd.downcast::<SubmitAnswerCommand>().map_err(|_| DomainError::Internal("Downcast failed".into()))?;
                 let session_id = cmd.session_id.as_ref().map(|s| s.value.clone()).unwrap_or_default();
-                self.submit_answer(&session_id, &cmd)?;
+                self.submit_answer(&session_id, &cmd)?
             }
             "RevealHintCommand" => {
                  let any_cmd = command.into_any();
                 let cmd = any_cmd.downcast::<RevealHintCommand>().map_err(|_| DomainError::Internal("Downcast failed".into()))?;
                 let session_id = cmd.session_id.as_ref().map(|s| s.value.clone()).unwrap_or_default();
-                self.reveal_hint(&session_id)?;
+                self.reveal_hint(&session_id)?
+            }
+            "SeatPlayerCommand" => {
+                let any_cmd = command.into_any();
+                let cmd = any_cmd.downcast::<SeatPlayerCommand>().map_err(|_| DomainError::Internal("Downcast failed".into()))?;
+                self.seat_player(&cmd.player_id)?
+            }
+            "RemovePlayerCommand" => {
+                let any_cmd = command.into_any();
+                let cmd = any_cmd.downcast::<RemovePlayerCommand>().map_err(|_| DomainError::Internal("Downcast failed".into()))?;
+                self.remove_player(&cmd.player_id)?
             }
             _ => return Err(DomainError::InvalidCommand),
+        };
+
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::{clock::StepClock, id_gen::UuidGenerator, question_repo::MemoryQuestionRepo, rng::SeededRng};
+    use crate::application::services::engine_factory::{OPTION_STREAM, QUESTION_STREAM};
+    use crate::domain::game::{Engine, Player, SessionStatus};
+    use crate::domain::rules::GameRules;
+    use crate::pb::runecraftstudios::pastello::game::types::v1::PlayerId;
+
+    fn question(id: &str, prompt: &str, answer: &str) -> Question {
+        Question {
+            id: id.to_string(),
+            category: TriviaCategory::GeneralKnowledge,
+            prompt: prompt.to_string(),
+            format: QuestionFormat::FreeText { answer: answer.to_string(), aliases: Vec::new() },
+            hint: None,
+        }
+    }
+
+    fn session(players: &[&str], rules: &TriviaRules, question_ids: &[&str]) -> Session {
+        Session {
+            id: "s1".to_string(),
+            host_id: players[0].to_string(),
+            game_type: GameType::Trivia,
+            players: players.iter().map(|id| Player { id: id.to_string(), name: id.to_string() }).collect(),
+            spectators: Vec::new(),
+            ruleset_id: None,
+            rules: GameRules::Trivia(rules.clone()),
+            room: None,
+            seed: Some(7),
+            daily_challenge: None,
+            question_ids: question_ids.iter().map(|id| id.to_string()).collect(),
+            status: SessionStatus::Active,
+        }
+    }
+
+    /// An engine drawing from `seed` the way `DefaultEngineFactory` seeds one.
+    fn engine(questions: Vec<Question>, rules: &TriviaRules, seed: u64) -> TriviaEngine {
+        TriviaEngine::new(
+            Arc::new(StepClock::new(Utc::now())),
+            Arc::new(SeededRng::with_stream(seed, QUESTION_STREAM)),
+            Arc::new(SeededRng::with_stream(seed, OPTION_STREAM)),
+            Arc::new(UuidGenerator::new()),
+            Arc::new(MemoryQuestionRepo::new(questions)),
+            rules.clone(),
+            ScoringPolicy::default(),
+        )
+    }
+
+    async fn started(players: &[&str]) -> TriviaEngine {
+        let rules = TriviaRules {
+            num_questions: 1,
+            max_players: players.len() as u32,
+            ..TriviaRules::default()
+        };
+        let mut engine = engine(vec![question("q1", "Capital of France?", "Paris")], &rules, 7);
+        engine.start(&session(players, &rules, &[])).await.unwrap();
+        engine
+    }
+
+    fn answer(engine: &mut TriviaEngine, player_id: &str) -> Vec<&'static str> {
+        let command = SubmitAnswerCommand {
+            session_id: None,
+            player_id: Some(PlayerId { value: player_id.to_string() }),
+            answer: "Paris".to_string(),
+        };
+        engine.execute_command(Box::new(command)).unwrap().iter().map(|e| e.event_type()).collect()
+    }
+
+    fn remove(engine: &mut TriviaEngine, player_id: &str) -> Vec<&'static str> {
+        let command = RemovePlayerCommand { player_id: player_id.to_string() };
+        engine.execute_command(Box::new(command)).unwrap().iter().map(|e| e.event_type()).collect()
+    }
+
+    #[tokio::test]
+    async fn removing_the_last_player_yet_to_answer_closes_the_question() {
+        let mut engine = started(&["host", "bob", "carol"]).await;
+        answer(&mut engine, "host");
+
+        assert!(remove(&mut engine, "carol").is_empty());
+        assert_eq!(engine.state.phase, RoundPhase::Answering);
+        let events = remove(&mut engine, "bob");
+        assert!(events.contains(&"trivia.question_closed"), "{:?}", events);
+        assert_eq!(engine.state.phase, RoundPhase::AnswerReveal);
+    }
+
+    #[tokio::test]
+    async fn removed_players_lose_their_locked_answer() {
+        let mut engine = started(&["host", "bob"]).await;
+        answer(&mut engine, "bob");
+        assert!(remove(&mut engine, "bob").is_empty());
+        assert!(engine.state.locked.is_empty());
+        assert_eq!(engine.state.phase, RoundPhase::Answering);
+        assert!(answer(&mut engine, "host").contains(&"trivia.question_closed"));
+    }
+
+    #[tokio::test]
+    async fn pinned_questions_are_asked_in_their_order() {
+        let questions = ["q1", "q2", "q3"].map(|id| question(id, id, "x")).to_vec();
+        let rules = TriviaRules::default();
+        let mut engine = engine(questions, &rules, 7);
+        engine.start(&session(&["host"], &rules, &["q3", "gone", "q1"])).await.unwrap();
+        let asked: Vec<&str> = engine.state.questions.iter().map(|q| q.id.as_str()).collect();
+        assert_eq!(asked, ["q3", "q1"]);
+    }
+
+    fn multiple_choice(id: &str) -> Question {
+        Question {
+            format: QuestionFormat::MultipleChoice {
+                options: ["a", "b", "c", "d"].map(str::to_string).to_vec(),
+                correct: 0,
+            },
+            ..question(id, id, "a")
+        }
+    }
+
+    async fn drawn(questions: Vec<Question>, seed: u64) -> Vec<Question> {
+        let rules = TriviaRules { num_questions: 5, ..TriviaRules::default() };
+        let mut engine = engine(questions, &rules, seed);
+        engine.start(&session(&["host"], &rules, &[])).await.unwrap();
+        engine.state.questions
+    }
+
+    #[tokio::test]
+    async fn a_seed_replays_the_same_questions_and_options() {
+        let store: Vec<Question> = (0..12).map(|i| multiple_choice(&format!("q{:02}", i))).collect();
+        let mut reversed = store.clone();
+        reversed.reverse();
+        let first = drawn(store.clone(), 42).await;
+        assert_eq!(first, drawn(reversed, 42).await);
+        assert_ne!(first, drawn(store, 43).await);
+        for question in &first {
+            let QuestionFormat::MultipleChoice { options, correct } = &question.format else {
+                unreachable!()
+            };
+            assert_eq!(options[*correct], "a");
         }
+    }
+
+    #[tokio::test]
+    async fn option_shuffles_do_not_shift_which_questions_are_drawn() {
+        let ids = |questions: Vec<Question>| questions.into_iter().map(|q| q.id).collect::<Vec<_>>();
+        let plain = (0..12).map(|i| question(&format!("q{:02}", i), "?", "x")).collect();
+        let with_options = (0..12).map(|i| multiple_choice(&format!("q{:02}", i))).collect();
+        assert_eq!(ids(drawn(plain, 42).await), ids(drawn(with_options, 42).await));
+    }
+
+    #[test]
+    fn weighted_draws_skip_unweighted_categories_and_fall_back_when_one_runs_out() {
+        let store: Vec<Question> = (0..30)
+            .map(|i| Question {
+                category: TriviaCategory::ALL[i % 3],
+                ..question(&format!("q{:02}", i), "?", "x")
+            })
+            .collect();
+        let weighted = |science, history, count| TriviaRules {
+            num_questions: count,
+            category_weights: [
+                (TriviaCategory::GeneralKnowledge, 0),
+                (TriviaCategory::Science, science),
+                (TriviaCategory::History, history),
+            ]
+            .into(),
+            ..TriviaRules::default()
+        };
+        let rng = SeededRng::new(3);
+        let categories = |drawn: Vec<Question>| drawn.into_iter().map(|q| q.category).collect::<Vec<_>>();
+
+        let drawn = categories(draw_questions(store.clone(), &weighted(1, 0, 8), &rng));
+        assert_eq!(drawn, [TriviaCategory::Science; 8]);
+
+        // Ten science questions, then the rest from history; general knowledge never comes up.
+        let drawn = categories(draw_questions(store, &weighted(100, 1, 15), &rng));
+        assert_eq!(drawn.len(), 15);
+        assert_eq!(drawn.iter().filter(|c| **c == TriviaCategory::Science).count(), 10);
+        assert!(!drawn.contains(&TriviaCategory::GeneralKnowledge));
+    }
+
+    /// Two free-text questions for `players`, 20 seconds each, on a clock the test moves.
+    async fn timed(players: &[&str]) -> (TriviaEngine, Arc<StepClock>) {
+        let clock = Arc::new(StepClock::new(Utc::now()));
+        let rules = TriviaRules {
+            num_questions: 2,
+            seconds_per_question: 20,
+            max_players: players.len() as u32,
+            ..TriviaRules::default()
+        };
+        let questions = vec![question("q1", "Capital of France?", "Paris"), question("q2", "Capital of Italy?", "Rome")];
+        let mut engine = TriviaEngine::new(
+            clock.clone(),
+            Arc::new(SeededRng::with_stream(7, QUESTION_STREAM)),
+            Arc::new(SeededRng::with_stream(7, OPTION_STREAM)),
+            Arc::new(UuidGenerator::new()),
+            Arc::new(MemoryQuestionRepo::new(questions)),
+            rules.clone(),
+            ScoringPolicy::default(),
+        );
+        engine.start(&session(players, &rules, &["q1", "q2"])).await.unwrap();
+        (engine, clock)
+    }
+
+    fn tick_after(engine: &mut TriviaEngine, clock: &StepClock, secs: i64) -> Vec<&'static str> {
+        clock.set(clock.now_utc() + chrono::Duration::seconds(secs));
+        engine.tick().iter().map(|e| e.event_type()).collect()
+    }
+
+    fn submit(engine: &mut TriviaEngine, player_id: &str, answer: &str) -> Result<Vec<Box<dyn DomainEvent>>, DomainError> {
+        let command = SubmitAnswerCommand {
+            session_id: None,
+            player_id: Some(PlayerId { value: player_id.to_string() }),
+            answer: answer.to_string(),
+        };
+        engine.execute_command(Box::new(command))
+    }
+
+    #[tokio::test]
+    async fn nothing_happens_before_the_start() {
+        let rules = TriviaRules::default();
+        let mut engine = engine(vec![question("q1", "Capital of France?", "Paris")], &rules, 7);
+        assert_eq!(engine.state.phase, RoundPhase::Waiting);
+        assert!(engine.tick().is_empty());
+        assert!(matches!(submit(&mut engine, "host", "Paris"), Err(DomainError::NotAcceptingAnswers)));
+        assert!(engine.snapshot(&"host".to_string(), Role::Player)["question"].is_null());
+    }
+
+    #[tokio::test]
+    async fn ticks_walk_each_question_through_its_phases() {
+        let (mut engine, clock) = timed(&["host", "bob"]).await;
+        assert_eq!(engine.state.phase, RoundPhase::Answering);
+
+        assert!(tick_after(&mut engine, &clock, 19).is_empty());
+        assert_eq!(tick_after(&mut engine, &clock, 1), ["trivia.question_closed", "trivia.answer_revealed"]);
+        assert_eq!(engine.state.phase, RoundPhase::AnswerReveal);
+        assert!(matches!(submit(&mut engine, "host", "Paris"), Err(DomainError::NotAcceptingAnswers)));
+
+        assert!(tick_after(&mut engine, &clock, ANSWER_REVEAL_SECS - 1).is_empty());
+        assert_eq!(tick_after(&mut engine, &clock, 1), ["trivia.scoreboard"]);
+        assert_eq!(engine.state.phase, RoundPhase::Scoreboard);
+
+        assert!(tick_after(&mut engine, &clock, SCOREBOARD_SECS - 1).is_empty());
+        assert_eq!(tick_after(&mut engine, &clock, 1), ["trivia.question_revealed"]);
+        assert_eq!((engine.state.phase, engine.state.question_index), (RoundPhase::Answering, 1));
+
+        // After the last question the scoreboard gives way to the end of the game.
+        tick_after(&mut engine, &clock, 20);
+        tick_after(&mut engine, &clock, ANSWER_REVEAL_SECS);
+        assert_eq!(tick_after(&mut engine, &clock, SCOREBOARD_SECS), ["trivia.finished"]);
+        assert_eq!(engine.state.phase, RoundPhase::Finished);
+        assert!(engine.is_finished());
+        assert!(tick_after(&mut engine, &clock, 60).is_empty());
+        assert!(matches!(submit(&mut engine, "host", "Rome"), Err(DomainError::GameFinished)));
+    }
+
+    #[tokio::test]
+    async fn the_question_closes_as_soon_as_everyone_has_answered() {
+        let (mut engine, clock) = timed(&["host", "bob"]).await;
+        clock.set(clock.now_utc() + chrono::Duration::seconds(3));
+
+        let events = submit(&mut engine, "host", "Paris").unwrap();
+        assert_eq!(events.iter().map(|e| e.event_type()).collect::<Vec<_>>(), ["trivia.answer_locked"]);
+        assert!(matches!(submit(&mut engine, "host", "Paris"), Err(DomainError::AlreadyAnswered)));
+
+        let events = submit(&mut engine, "bob", "Rome").unwrap();
+        let types: Vec<_> = events.iter().map(|e| e.event_type()).collect();
+        assert_eq!(
+            types,
+            [
+                "trivia.answer_locked",
+                "trivia.question_closed",
+                "trivia.answer_revealed",
+                "trivia.answer_accepted",
+                "trivia.answer_accepted"
+            ]
+        );
+        assert_eq!(events[1].to_json()["reason"], "AllAnswered");
+        assert_eq!(engine.state.phase, RoundPhase::AnswerReveal);
+        // The reveal runs from the early close, not from the question's deadline.
+        assert_eq!(tick_after(&mut engine, &clock, ANSWER_REVEAL_SECS), ["trivia.scoreboard"]);
+    }
+
+    #[tokio::test]
+    async fn streaks_build_on_correct_answers_and_reset_otherwise() {
+        let (mut engine, clock) = timed(&["host", "bob"]).await;
+        submit(&mut engine, "host", "Paris").unwrap();
+        let events = submit(&mut engine, "bob", "Rome").unwrap();
+        let streak = |events: &[Box<dyn DomainEvent>], i: usize| events[i].to_json()["streak"].clone();
+        assert_eq!((streak(&events, 3), streak(&events, 4)), (serde_json::json!(1), serde_json::json!(0)));
+        assert_eq!(events[3].to_json()["delta_score"], 125);
 
-        Ok(())
+        tick_after(&mut engine, &clock, ANSWER_REVEAL_SECS);
+        tick_after(&mut engine, &clock, SCOREBOARD_SECS);
+        // Only bob answers the second question; host's streak lapses with the time.
+        submit(&mut engine, "bob", "Rome").unwrap();
+        clock.set(clock.now_utc() + chrono::Duration::seconds(20));
+        let events = engine.tick();
+        assert_eq!(streak(&events, 2), serde_json::json!(1));
+        assert_eq!(engine.state.streaks["host"], 0);
     }
 }
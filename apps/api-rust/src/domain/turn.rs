@@ -0,0 +1,300 @@
+use crate::domain::game::{DomainError, DomainEvent, EventMeta, GameSessionID, PlayerID};
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::any::Any;
+
+// --- EVENTS ---
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum TurnChangeReason {
+    Started,
+    Moved,
+    Undone,
+    TimedOut,
+    /// The active player was removed from the game.
+    PlayerLeft,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TurnChanged {
+    pub meta: EventMeta,
+    pub session_id: GameSessionID,
+    pub previous_player_id: Option<PlayerID>,
+    pub active_player_id: PlayerID,
+    pub turn_number: u32,
+    pub reason: TurnChangeReason,
+}
+
+impl DomainEvent for TurnChanged {
+    fn event_type(&self) -> &'static str { "game.turn_changed" }
+    fn session_id(&self) -> &GameSessionID { &self.session_id }
+    fn to_json(&self) -> serde_json::Value { serde_json::to_value(self).unwrap_or_default() }
+    fn to_any_box(self: Box<Self>) -> Box<dyn Any + Send> { self }
+    fn clone_box(&self) -> Box<dyn DomainEvent> { Box::new(self.clone()) }
+}
+
+/// Result of a turn rotation; the engine turns it into a `TurnChanged` event.
+#[derive(Debug, Clone)]
+pub struct TurnTransition {
+    pub previous_player_id: Option<PlayerID>,
+    pub active_player_id: PlayerID,
+    pub turn_number: u32,
+    pub reason: TurnChangeReason,
+}
+
+impl TurnTransition {
+    pub fn into_event(self, meta: EventMeta, session_id: &GameSessionID) -> TurnChanged {
+        TurnChanged {
+            meta,
+            session_id: session_id.clone(),
+            previous_player_id: self.previous_player_id,
+            active_player_id: self.active_player_id,
+            turn_number: self.turn_number,
+            reason: self.reason,
+        }
+    }
+}
+
+// --- TURN ORDER ---
+
+/// Round-robin turn order for engines where several players act on one shared state.
+#[derive(Debug, Clone)]
+pub struct TurnOrder {
+    players: Vec<PlayerID>,
+    active: usize,
+    turn_number: u32,
+    turn_started_at: Option<DateTime<Utc>>,
+    timeout: Duration,
+}
+
+impl TurnOrder {
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            players: Vec::new(),
+            active: 0,
+            turn_number: 0,
+            turn_started_at: None,
+            timeout,
+        }
+    }
+
+    /// Adds a player to the end of the rotation. Seating the same player twice is a no-op.
+    pub fn seat(&mut self, player_id: &PlayerID) {
+        if !self.players.contains(player_id) {
+            self.players.push(player_id.clone());
+        }
+    }
+
+    pub fn active_player(&self) -> Option<&PlayerID> {
+        self.players.get(self.active)
+    }
+
+    pub fn is_started(&self) -> bool {
+        self.turn_started_at.is_some()
+    }
+
+    /// Starts the first turn. Returns `None` if nobody is seated or the order already started.
+    pub fn start(&mut self, now: DateTime<Utc>) -> Option<TurnTransition> {
+        if self.is_started() || self.players.is_empty() {
+            return None;
+        }
+        self.active = 0;
+        self.turn_number = 1;
+        self.turn_started_at = Some(now);
+        Some(self.transition(None, TurnChangeReason::Started))
+    }
+
+    pub fn ensure_turn(&self, player_id: &PlayerID) -> Result<(), DomainError> {
+        match self.active_player() {
+            Some(active) if active == player_id => Ok(()),
+            _ => Err(DomainError::NotYourTurn(player_id.clone())),
+        }
+    }
+
+    /// Passes the turn to the next seated player.
+    pub fn advance(&mut self, now: DateTime<Utc>, reason: TurnChangeReason) -> Option<TurnTransition> {
+        if self.players.is_empty() {
+            return None;
+        }
+        let previous = self.active_player().cloned();
+        self.active = (self.active + 1) % self.players.len();
+        self.turn_number += 1;
+        self.turn_started_at = Some(now);
+        Some(self.transition(previous, reason))
+    }
+
+    /// Takes `player_id` out of the rotation. If it was their turn, the next player's starts.
+    pub fn remove(&mut self, player_id: &PlayerID, now: DateTime<Utc>) -> Option<TurnTransition> {
+        let index = self.players.iter().position(|p| p == player_id)?;
+        self.players.remove(index);
+        if self.players.is_empty() {
+            // The next player to be seated starts over with the first turn.
+            self.active = 0;
+            self.turn_started_at = None;
+            return None;
+        }
+        if index < self.active {
+            self.active -= 1;
+            return None;
+        }
+        if index > self.active || !self.is_started() {
+            return None;
+        }
+        self.active %= self.players.len();
+        self.turn_number += 1;
+        self.turn_started_at = Some(now);
+        Some(self.transition(Some(player_id.clone()), TurnChangeReason::PlayerLeft))
+    }
+
+    /// Hands the turn back to `player_id`, used when a player undoes their own move.
+    pub fn rewind_to(&mut self, player_id: &PlayerID, now: DateTime<Utc>) -> Option<TurnTransition> {
+        let index = self.players.iter().position(|p| p == player_id)?;
+        let previous = self.active_player().cloned();
+        self.active = index;
+        self.turn_number += 1;
+        self.turn_started_at = Some(now);
+        Some(self.transition(previous, TurnChangeReason::Undone))
+    }
+
+    /// Advances the turn if the active player has run out of time.
+    pub fn expire(&mut self, now: DateTime<Utc>) -> Option<TurnTransition> {
+        let started_at = self.turn_started_at?;
+        if now - started_at < self.timeout {
+            return None;
+        }
+        self.advance(now, TurnChangeReason::TimedOut)
+    }
+
+    fn transition(&self, previous_player_id: Option<PlayerID>, reason: TurnChangeReason) -> TurnTransition {
+        TurnTransition {
+            previous_player_id,
+            active_player_id: self.players[self.active].clone(),
+            turn_number: self.turn_number,
+            reason,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::clock::StepClock;
+    use crate::ports::Clock;
+    use chrono::TimeZone;
+
+    fn clock() -> StepClock {
+        StepClock::new(Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap())
+    }
+
+    fn later(clock: &StepClock, secs: i64) -> DateTime<Utc> {
+        clock.set(clock.now_utc() + Duration::seconds(secs));
+        clock.now_utc()
+    }
+
+    /// A started order of `players`, 30 seconds a turn.
+    fn order(players: &[&str], clock: &StepClock) -> TurnOrder {
+        let mut order = TurnOrder::new(Duration::seconds(30));
+        for player in players {
+            order.seat(&player.to_string());
+        }
+        order.start(clock.now_utc());
+        order
+    }
+
+    fn active(order: &TurnOrder) -> &str {
+        order.active_player().map(String::as_str).unwrap_or_default()
+    }
+
+    #[test]
+    fn turns_start_with_the_first_seat_and_go_round() {
+        let clock = clock();
+        let mut order = TurnOrder::new(Duration::seconds(30));
+        assert!(order.start(clock.now_utc()).is_none());
+        order.seat(&"a".to_string());
+        order.seat(&"b".to_string());
+        order.seat(&"a".to_string());
+
+        let started = order.start(clock.now_utc()).unwrap();
+        assert_eq!((started.active_player_id.as_str(), started.turn_number), ("a", 1));
+        assert_eq!(started.reason, TurnChangeReason::Started);
+        assert!(order.start(clock.now_utc()).is_none());
+
+        let moved = order.advance(later(&clock, 1), TurnChangeReason::Moved).unwrap();
+        assert_eq!(moved.previous_player_id.as_deref(), Some("a"));
+        assert_eq!((moved.active_player_id.as_str(), moved.turn_number), ("b", 2));
+        order.advance(later(&clock, 1), TurnChangeReason::Moved);
+        assert_eq!(active(&order), "a");
+    }
+
+    #[test]
+    fn only_the_active_player_may_act() {
+        let clock = clock();
+        let order = order(&["a", "b"], &clock);
+        assert!(order.ensure_turn(&"a".to_string()).is_ok());
+        assert!(matches!(order.ensure_turn(&"b".to_string()), Err(DomainError::NotYourTurn(p)) if p == "b"));
+    }
+
+    #[test]
+    fn removing_the_active_seat_passes_the_turn_on() {
+        let clock = clock();
+        let mut order = order(&["a", "b", "c"], &clock);
+        order.advance(later(&clock, 1), TurnChangeReason::Moved);
+
+        let left = order.remove(&"b".to_string(), later(&clock, 1)).unwrap();
+        assert_eq!(left.reason, TurnChangeReason::PlayerLeft);
+        assert_eq!(left.previous_player_id.as_deref(), Some("b"));
+        assert_eq!((left.active_player_id.as_str(), left.turn_number), ("c", 3));
+
+        // Removing the last seat wraps round to the first.
+        assert_eq!(order.remove(&"c".to_string(), later(&clock, 1)).unwrap().active_player_id, "a");
+        assert!(order.remove(&"nobody".to_string(), clock.now_utc()).is_none());
+    }
+
+    #[test]
+    fn removing_other_seats_keeps_the_turn() {
+        let clock = clock();
+        let mut order = order(&["a", "b", "c"], &clock);
+        order.advance(later(&clock, 1), TurnChangeReason::Moved);
+
+        assert!(order.remove(&"a".to_string(), clock.now_utc()).is_none());
+        assert_eq!(active(&order), "b");
+        assert!(order.remove(&"c".to_string(), clock.now_utc()).is_none());
+        assert_eq!(active(&order), "b");
+
+        // With everyone gone the next player seated starts over.
+        assert!(order.remove(&"b".to_string(), clock.now_utc()).is_none());
+        assert!(!order.is_started());
+        order.seat(&"d".to_string());
+        assert_eq!(order.start(clock.now_utc()).unwrap().turn_number, 1);
+    }
+
+    #[test]
+    fn rewinding_hands_the_turn_back() {
+        let clock = clock();
+        let mut order = order(&["a", "b"], &clock);
+        order.advance(later(&clock, 1), TurnChangeReason::Moved);
+
+        let undone = order.rewind_to(&"a".to_string(), later(&clock, 1)).unwrap();
+        assert_eq!(undone.reason, TurnChangeReason::Undone);
+        assert_eq!(undone.previous_player_id.as_deref(), Some("b"));
+        assert_eq!((undone.active_player_id.as_str(), undone.turn_number), ("a", 3));
+        assert!(order.rewind_to(&"nobody".to_string(), clock.now_utc()).is_none());
+    }
+
+    #[test]
+    fn turns_expire_after_the_timeout_from_their_start() {
+        let clock = clock();
+        let mut unstarted = TurnOrder::new(Duration::seconds(30));
+        unstarted.seat(&"a".to_string());
+        assert!(unstarted.expire(later(&clock, 60)).is_none());
+
+        let mut order = order(&["a", "b"], &clock);
+        assert!(order.expire(later(&clock, 29)).is_none());
+        let expired = order.expire(later(&clock, 1)).unwrap();
+        assert_eq!(expired.reason, TurnChangeReason::TimedOut);
+        assert_eq!(expired.active_player_id, "b");
+        // The new turn gets its own 30 seconds.
+        assert!(order.expire(later(&clock, 29)).is_none());
+        assert_eq!(order.expire(later(&clock, 1)).unwrap().active_player_id, "a");
+    }
+}
@@ -0,0 +1,155 @@
+// --- MODULE DECLARATIONS ---
+pub mod adapters;
+pub mod application;
+pub mod config;
+pub mod domain;
+pub mod pb;
+pub mod ports;
+
+// --- APPSTATE (Composition Root) ---
+use crate::application::services::achievement::AchievementService;
+use crate::application::services::anti_cheat::AntiCheatService;
+use crate::application::services::daily::DailyChallengeService;
+use crate::application::services::game_service::GameService;
+use crate::application::services::leaderboard::LeaderboardService;
+use crate::application::services::matchmaking::MatchmakingService;
+use crate::application::services::profile::ProfileService;
+use crate::application::services::rating::RatingService;
+use crate::application::services::replay::ReplayService;
+use crate::adapters::inbound::ws::auth::Authenticator;
+use crate::adapters::inbound::ws::flood::FloodGuard;
+use crate::adapters::inbound::ws::handshake::ProtocolPolicy;
+use std::sync::Arc;
+use axum::extract::FromRef; 
+
+#[derive(Clone)]
+pub struct AppState {
+    pub game_service: Arc<GameService>,
+    pub matchmaker: Arc<MatchmakingService>,
+    pub profiles: Arc<ProfileService>,
+    pub leaderboards: Arc<LeaderboardService>,
+    pub ratings: Arc<RatingService>,
+    pub achievements: Arc<AchievementService>,
+    pub daily: Arc<DailyChallengeService>,
+    pub replays: Arc<ReplayService>,
+    pub anti_cheat: Arc<AntiCheatService>,
+    pub authenticator: Arc<dyn Authenticator>, 
+    pub protocol: ProtocolPolicy,
+    pub flood: Arc<FloodGuard>,
+}
+
+impl AppState {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        game_service: Arc<GameService>,
+        matchmaker: Arc<MatchmakingService>,
+        profiles: Arc<ProfileService>,
+        leaderboards: Arc<LeaderboardService>,
+        ratings: Arc<RatingService>,
+        achievements: Arc<AchievementService>,
+        daily: Arc<DailyChallengeService>,
+        replays: Arc<ReplayService>,
+        anti_cheat: Arc<AntiCheatService>,
+        authenticator: Arc<dyn Authenticator>,
+        protocol: ProtocolPolicy,
+        flood: Arc<FloodGuard>,
+    ) -> Self {
+        Self {
+            game_service,
+            matchmaker,
+            profiles,
+            leaderboards,
+            ratings,
+            achievements,
+            daily,
+            replays,
+            anti_cheat,
+            authenticator,
+            protocol,
+            flood,
+        }
+    }
+}
+
+// Implement FromRef for Authenticator to be extracted from AppState
+impl FromRef<AppState> for Arc<dyn Authenticator> {
+    fn from_ref(state: &AppState) -> Self {
+        state.authenticator.clone()
+    }
+}
+
+#[cfg(test)]
+impl AppState {
+    /// Every service wired to in-memory adapters with default policies, behind `authenticator`.
+    pub(crate) fn for_tests(authenticator: Arc<dyn Authenticator>) -> Self {
+        use crate::adapters::outbound::{
+            achievement_catalogue::YamlAchievementCatalogue, cheat_flag_repo::MemoryCheatFlagRepo, clock::SystemClock,
+            daily_repo::MemoryDailyChallengeRepo, eventbus::BroadcastEventBus, id_gen::UuidGenerator,
+            leaderboard_repo::MemoryLeaderboardRepo, memory_repo::MemoryRepo, profile_repo::MemoryProfileRepo,
+            question_repo::MemoryQuestionRepo, rng::SystemRng, ruleset_repo::YamlRulesetRepo,
+            session_log::MemorySessionLog,
+        };
+        use crate::application::services::command_registry::CommandRegistry;
+        use crate::application::services::engine_factory::DefaultEngineFactory;
+        use crate::application::services::game_service::SessionPolicy;
+        use crate::config::ProtocolConfig;
+
+        let clock = Arc::new(SystemClock::new());
+        let rng = Arc::new(SystemRng::new());
+        let questions = Arc::new(MemoryQuestionRepo::default());
+        let profile_repo = Arc::new(MemoryProfileRepo::new());
+        let session_log = Arc::new(MemorySessionLog::new(SessionPolicy::default().recordings_kept));
+        let engine_factory = Arc::new(DefaultEngineFactory::new(rng.clone(), questions.clone(), Default::default()));
+        let game_service = Arc::new(GameService::new(
+            Arc::new(MemoryRepo::new()),
+            Arc::new(BroadcastEventBus::new()),
+            clock.clone(),
+            Arc::new(UuidGenerator::new()),
+            rng,
+            engine_factory.clone(),
+            Arc::new(CommandRegistry::new(engine_factory)),
+            Arc::new(YamlRulesetRepo::default()),
+            profile_repo.clone(),
+            session_log.clone(),
+            Default::default(),
+            Default::default(),
+        ));
+        let anti_cheat = Arc::new(AntiCheatService::new(
+            Arc::new(MemoryCheatFlagRepo::new()),
+            session_log,
+            clock.clone(),
+            Default::default(),
+        ));
+        Self::new(
+            game_service.clone(),
+            Arc::new(MatchmakingService::new(game_service.clone(), clock.clone(), Default::default())),
+            Arc::new(ProfileService::new(profile_repo.clone(), game_service.clone(), clock.clone())),
+            Arc::new(LeaderboardService::new(
+                Arc::new(MemoryLeaderboardRepo::new()),
+                game_service.clone(),
+                anti_cheat.clone(),
+                clock.clone(),
+                Default::default(),
+                Default::default(),
+            )),
+            Arc::new(RatingService::new(profile_repo.clone(), game_service.clone(), Default::default())),
+            Arc::new(AchievementService::new(
+                profile_repo,
+                game_service.clone(),
+                Arc::new(YamlAchievementCatalogue::default()),
+            )),
+            Arc::new(DailyChallengeService::new(
+                Arc::new(MemoryDailyChallengeRepo::new()),
+                questions,
+                game_service.clone(),
+                clock.clone(),
+                Default::default(),
+            )),
+            Arc::new(ReplayService::new(game_service, clock, Default::default())),
+            anti_cheat,
+            authenticator,
+            ProtocolPolicy::from_config(&ProtocolConfig::default()).unwrap(),
+            Arc::new(FloodGuard::new(Default::default())),
+        )
+    }
+}
@@ -1,49 +1,34 @@
-// --- MODULE DECLARATIONS ---
-pub mod adapters;
-pub mod application;
-pub mod config;
-pub mod domain;
-pub mod pb;
-pub mod ports;
-
-// --- APPSTATE (Composition Root) ---
-use crate::application::services::game_service::GameService;
-use crate::adapters::inbound::ws::auth::Authenticator; 
-use std::sync::Arc;
-use axum::extract::FromRef; 
-
-#[derive(Clone)]
-pub struct AppState {
-    pub game_service: Arc<GameService>,
-    pub authenticator: Arc<dyn Authenticator>, 
-}
-
-impl AppState {
-    pub fn new(game_service: Arc<GameService>, authenticator: Arc<dyn Authenticator>) -> Self {
-        Self { game_service, authenticator }
-    }
-}
-
-// Implement FromRef for Authenticator to be extracted from AppState
-impl FromRef<AppState> for Arc<dyn Authenticator> {
-    fn from_ref(state: &AppState) -> Self {
-        state.authenticator.clone()
-    }
-}
-
 // --- IMPORTS ---
-use crate::{
+use api_rust::{
     adapters::outbound::{
-        clock::SystemClock, eventbus::NopEventBus, id_gen::UuidGenerator,
+        achievement_catalogue::YamlAchievementCatalogue,
+        cheat_flag_repo::MemoryCheatFlagRepo,
+        clock::SystemClock, daily_repo::{MemoryDailyChallengeRepo, PostgresDailyChallengeRepo}, eventbus::BroadcastEventBus, id_gen::UuidGenerator,
+        leaderboard_repo::MemoryLeaderboardRepo,
         memory_repo::MemoryRepo,
+        postgres,
+        profile_repo::{MemoryProfileRepo, PostgresProfileRepo},
+        question_repo::{FileQuestionRepo, MemoryQuestionRepo},
         rng::SystemRng,
+        ruleset_repo::YamlRulesetRepo,
+        session_log::MemorySessionLog,
     },
     application::services::{
-        command_registry::CommandRegistry, engine_factory::DefaultEngineFactory, 
+        achievement::AchievementService, anti_cheat::AntiCheatService, command_registry::CommandRegistry, daily::DailyChallengeService, engine_factory::DefaultEngineFactory,
+        game_service::GameService, leaderboard::LeaderboardService, matchmaking::MatchmakingService,
+        profile::ProfileService, rating::RatingService, replay::ReplayService,
     },
-    config::load_config,
+    config::{load_config, DatabaseConfig},
 };
-use adapters::inbound::ws::router as ws_router;
+use api_rust::adapters::inbound::grpc;
+use api_rust::adapters::inbound::http::router as http_router;
+use api_rust::adapters::inbound::ws::{internal_router, router as ws_router};
+use api_rust::adapters::inbound::ws::auth::{Authenticator, StubAuthenticator};
+use api_rust::adapters::inbound::ws::flood::FloodGuard;
+use api_rust::adapters::inbound::ws::handshake::ProtocolPolicy;
+use api_rust::ports::{DailyChallengeRepository, ProfileRepository, QuestionRepository};
+use api_rust::{ports, AppState};
+use std::sync::Arc;
 use axum::{
     routing::get, 
     Router,
@@ -51,7 +36,6 @@ use axum::{
 use std::net::SocketAddr;
 use tracing::info;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
-use crate::adapters::inbound::ws::auth::StubAuthenticator;
 
 #[tokio::main]
 async fn main() {
@@ -68,10 +52,30 @@ async fn main() {
 
     // --- Dependency Injection (Adapters) ---
     let game_repo = Arc::new(MemoryRepo::new());
-    let event_bus = Arc::new(NopEventBus::new());
+    let event_bus = Arc::new(BroadcastEventBus::new());
     let clock = Arc::new(SystemClock::new());
     let id_gen = Arc::new(UuidGenerator::new());
     let rng = Arc::new(SystemRng::new());
+    let question_repo = load_question_repo(config.content.questions_path.as_deref());
+    let ruleset_repo = Arc::new(match config.content.rulesets_path.as_deref() {
+        Some(path) => YamlRulesetRepo::load(path).expect("Failed to load rulesets"),
+        None => YamlRulesetRepo::default(),
+    });
+    info!("Loaded {} rulesets", ruleset_repo.len());
+    let achievement_catalogue = Arc::new(match config.content.achievements_path.as_deref() {
+        Some(path) => YamlAchievementCatalogue::load(path).expect("Failed to load achievements"),
+        None => YamlAchievementCatalogue::default(),
+    });
+    info!("Loaded {} achievements", achievement_catalogue.len());
+    let database = connect_database(config.database.as_ref()).await;
+    let (profile_repo, daily_repo): (Arc<dyn ProfileRepository>, Arc<dyn DailyChallengeRepository>) = match database {
+        Some(pool) => (
+            Arc::new(PostgresProfileRepo::new(pool.clone())),
+            Arc::new(PostgresDailyChallengeRepo::new(pool)),
+        ),
+        None => (Arc::new(MemoryProfileRepo::new()), Arc::new(MemoryDailyChallengeRepo::new())),
+    };
+    let session_log = Arc::new(MemorySessionLog::new(config.sessions.recordings_kept));
     
     // Initialize authenticator
     let authenticator: Arc<dyn Authenticator> = Arc::new(StubAuthenticator::new( 
@@ -81,8 +85,9 @@ async fn main() {
     // --- Dependency Injection (Services) ---
     // NOTE: For trait objects used in AppState or shared, we must specify Send/Sync/'static bounds.
     let engine_factory = Arc::new(DefaultEngineFactory::new(
-        clock.clone() as Arc<dyn ports::Clock + Send + Sync>, 
         rng.clone() as Arc<dyn ports::Rng + Send + Sync>,
+        question_repo.clone(),
+        config.scoring.clone(),
     ));
     
     // Pass engine_factory to CommandRegistry::new()
@@ -93,18 +98,142 @@ async fn main() {
         event_bus.clone(),
         clock.clone(),
         id_gen.clone(),
+        rng.clone(),
         engine_factory.clone(),
         command_registry.clone(),
+        ruleset_repo.clone(),
+        profile_repo.clone(),
+        session_log.clone(),
+        config.rooms.clone(),
+        config.sessions.clone(),
     ));
 
+    let profiles = Arc::new(ProfileService::new(profile_repo.clone(), game_service.clone(), clock.clone()));
+    // Subscribe before any session can start so no finished game is missed.
+    let stats_events = game_service.subscribe_events();
+    let stats_profiles = profiles.clone();
+    tokio::spawn(async move { stats_profiles.run(stats_events).await });
+
+    let anti_cheat = Arc::new(AntiCheatService::new(
+        Arc::new(MemoryCheatFlagRepo::new()),
+        session_log.clone(),
+        clock.clone(),
+        config.anti_cheat.clone(),
+    ));
+
+    let leaderboards = Arc::new(LeaderboardService::new(
+        Arc::new(MemoryLeaderboardRepo::new()),
+        game_service.clone(),
+        anti_cheat.clone(),
+        clock.clone(),
+        config.leaderboards.clone(),
+        config.scoring.clone(),
+    ));
+    let leaderboard_events = game_service.subscribe_events();
+    let ranking_leaderboards = leaderboards.clone();
+    tokio::spawn(async move { ranking_leaderboards.run(leaderboard_events).await });
+
+    let ratings = Arc::new(RatingService::new(profile_repo.clone(), game_service.clone(), config.ratings.clone()));
+    let rating_events = game_service.subscribe_events();
+    let rating_service = ratings.clone();
+    tokio::spawn(async move { rating_service.run(rating_events).await });
+
+    let achievements = Arc::new(AchievementService::new(profile_repo, game_service.clone(), achievement_catalogue));
+    let achievement_events = game_service.subscribe_events();
+    let achievement_games = profiles.subscribe_games();
+    let achievement_service = achievements.clone();
+    tokio::spawn(async move { achievement_service.run(achievement_events, achievement_games).await });
+
+    let daily = Arc::new(DailyChallengeService::new(
+        daily_repo,
+        question_repo.clone(),
+        game_service.clone(),
+        clock.clone(),
+        config.daily_challenge.clone(),
+    ));
+
+    let replays = Arc::new(ReplayService::new(
+        game_service.clone(),
+        clock.clone(),
+        config.scoring.clone(),
+    ));
+
+    let matchmaker = Arc::new(MatchmakingService::new(
+        game_service.clone(),
+        clock.clone(),
+        config.matchmaking.clone(),
+    ));
+
+    // --- Engine Ticker ---
+    // Drives time-based rules (e.g. turn timeouts) and matchmaking even when no commands arrive.
+    let ticker_service = game_service.clone();
+    let ticker_matchmaker = matchmaker.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(1));
+        loop {
+            interval.tick().await;
+            ticker_service.tick_engines().await;
+            ticker_matchmaker.tick().await;
+        }
+    });
+
     // --- State Construction ---
     // Pass authenticator to AppState::new
-    let app_state = AppState::new(game_service, authenticator); 
+    let protocol = ProtocolPolicy::from_config(&config.protocol).expect("Invalid protocol config");
+    let app_state = AppState::new(
+        game_service,
+        matchmaker,
+        profiles,
+        leaderboards,
+        ratings,
+        achievements,
+        daily,
+        replays,
+        anti_cheat,
+        authenticator,
+        protocol,
+        Arc::new(FloodGuard::new(config.flood_protection.clone())),
+    );
+
+    // --- gRPC Server ---
+    // Runs on its own port next to axum, sharing the same services.
+    if let Some(grpc_port) = &config.server.grpc_port {
+        let grpc_addr = format!("{}:{}", server_addr, grpc_port)
+            .parse::<SocketAddr>()
+            .expect("Invalid gRPC address");
+        let grpc_service = grpc::server(app_state.clone());
+        info!("Starting gRPC server on {}", grpc_addr);
+        tokio::spawn(async move {
+            if let Err(e) = tonic::transport::Server::builder()
+                .add_service(grpc_service)
+                .serve(grpc_addr)
+                .await
+            {
+                tracing::error!("gRPC server stopped: {}", e);
+            }
+        });
+    }
     
+    // --- Internal Server ---
+    // Operator routes stay off the public listener.
+    if let Some(internal_port) = &config.server.internal_port {
+        let internal_addr = format!("{}:{}", server_addr, internal_port)
+            .parse::<SocketAddr>()
+            .expect("Invalid internal address");
+        let internal_app = internal_router().with_state(app_state.clone());
+        info!("Starting internal server on {}", internal_addr);
+        let listener = tokio::net::TcpListener::bind(&internal_addr).await.unwrap();
+        tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, internal_app.into_make_service()).await {
+                tracing::error!("Internal server stopped: {}", e);
+            }
+        });
+    }
+
     // --- Router Setup ---
     let app = Router::new()
         .route("/", get(|| async { "Pastello API is running" }))
-        .nest("/v1", ws_router())
+        .nest("/v1", ws_router().merge(http_router()))
         .with_state(app_state);
 
     // --- Server Start ---
@@ -117,4 +246,30 @@ async fn main() {
     axum::serve(listener, app.into_make_service())
         .await
         .unwrap();
-}
\ No newline at end of file
+}
+
+fn load_question_repo(path: Option<&str>) -> Arc<dyn QuestionRepository> {
+    if let Some(path) = path {
+        let store = FileQuestionRepo::open(path).expect("Failed to load question store");
+        if !store.is_empty() {
+            info!("Loaded {} trivia questions from {}", store.len(), path);
+            return Arc::new(store);
+        }
+    }
+    info!("No imported trivia questions, using built-in samples");
+    Arc::new(MemoryQuestionRepo::with_samples())
+}
+
+async fn connect_database(database: Option<&DatabaseConfig>) -> Option<deadpool_postgres::Pool> {
+    let Some(database) = database else {
+        info!("No database configured, keeping profiles and daily challenge attempts in memory");
+        return None;
+    };
+    // A configured database that can't be reached is a deploy error, not a reason to quietly
+    // drop every profile written until the next restart.
+    let pool = postgres::connect(&database.url, database.pool_size)
+        .await
+        .unwrap_or_else(|e| panic!("Database unavailable: {:#}", e));
+    info!("Storing profiles and daily challenge attempts in Postgres ({} connections at most)", database.pool_size);
+    Some(pool)
+}
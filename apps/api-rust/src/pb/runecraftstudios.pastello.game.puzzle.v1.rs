@@ -55,6 +55,8 @@ pub struct PuzzleRules {
     pub time_limit_seconds: u32,
     #[prost(uint32, tag="4")]
     pub max_players: u32,
+    #[prost(enumeration="PuzzleMode", tag="5")]
+    pub mode: i32,
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
@@ -88,4 +90,256 @@ impl PuzzleDifficulty {
         }
     }
 }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PuzzleMode {
+    Unspecified = 0,
+    /// Players share one board and take turns.
+    TurnBased = 1,
+    /// Every player solves their own identically seeded board.
+    Race = 2,
+}
+impl PuzzleMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            PuzzleMode::Unspecified => "PUZZLE_MODE_UNSPECIFIED",
+            PuzzleMode::TurnBased => "PUZZLE_MODE_TURN_BASED",
+            PuzzleMode::Race => "PUZZLE_MODE_RACE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PUZZLE_MODE_UNSPECIFIED" => Some(Self::Unspecified),
+            "PUZZLE_MODE_TURN_BASED" => Some(Self::TurnBased),
+            "PUZZLE_MODE_RACE" => Some(Self::Race),
+            _ => None,
+        }
+    }
+}
+/// Encoded file descriptor set for the `runecraftstudios.pastello.game.puzzle.v1` package
+pub const FILE_DESCRIPTOR_SET: &[u8] = &[
+    0x0a, 0x9d, 0x0f, 0x0a, 0x37, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74,
+    0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x67,
+    0x61, 0x6d, 0x65, 0x2f, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x2f, 0x76, 0x31, 0x2f, 0x63, 0x6f,
+    0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x28, 0x72, 0x75,
+    0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70,
+    0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x70, 0x75, 0x7a,
+    0x7a, 0x6c, 0x65, 0x2e, 0x76, 0x31, 0x1a, 0x33, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66,
+    0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c,
+    0x6f, 0x2f, 0x67, 0x61, 0x6d, 0x65, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2f, 0x76, 0x31, 0x2f,
+    0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x8d, 0x02, 0x0a, 0x10,
+    0x4d, 0x6f, 0x76, 0x65, 0x50, 0x69, 0x65, 0x63, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64,
+    0x12, 0x55, 0x0a, 0x0a, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01,
+    0x20, 0x01, 0x28, 0x0b, 0x32, 0x36, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74,
+    0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f,
+    0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x47,
+    0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x52, 0x09, 0x73, 0x65,
+    0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x12, 0x4e, 0x0a, 0x09, 0x70, 0x6c, 0x61, 0x79, 0x65,
+    0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e,
+    0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61,
+    0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65,
+    0x73, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x49, 0x64, 0x52, 0x08, 0x70,
+    0x6c, 0x61, 0x79, 0x65, 0x72, 0x49, 0x64, 0x12, 0x15, 0x0a, 0x06, 0x66, 0x72, 0x6f, 0x6d, 0x5f,
+    0x78, 0x18, 0x03, 0x20, 0x01, 0x28, 0x05, 0x52, 0x05, 0x66, 0x72, 0x6f, 0x6d, 0x58, 0x12, 0x15,
+    0x0a, 0x06, 0x66, 0x72, 0x6f, 0x6d, 0x5f, 0x79, 0x18, 0x04, 0x20, 0x01, 0x28, 0x05, 0x52, 0x05,
+    0x66, 0x72, 0x6f, 0x6d, 0x59, 0x12, 0x11, 0x0a, 0x04, 0x74, 0x6f, 0x5f, 0x78, 0x18, 0x05, 0x20,
+    0x01, 0x28, 0x05, 0x52, 0x03, 0x74, 0x6f, 0x58, 0x12, 0x11, 0x0a, 0x04, 0x74, 0x6f, 0x5f, 0x79,
+    0x18, 0x06, 0x20, 0x01, 0x28, 0x05, 0x52, 0x03, 0x74, 0x6f, 0x59, 0x22, 0xb8, 0x01, 0x0a, 0x0f,
+    0x55, 0x6e, 0x64, 0x6f, 0x4d, 0x6f, 0x76, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x12,
+    0x55, 0x0a, 0x0a, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x0b, 0x32, 0x36, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73,
+    0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e,
+    0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61,
+    0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x52, 0x09, 0x73, 0x65, 0x73,
+    0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x12, 0x4e, 0x0a, 0x09, 0x70, 0x6c, 0x61, 0x79, 0x65, 0x72,
+    0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e, 0x65,
+    0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73,
+    0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73,
+    0x2e, 0x76, 0x31, 0x2e, 0x50, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x49, 0x64, 0x52, 0x08, 0x70, 0x6c,
+    0x61, 0x79, 0x65, 0x72, 0x49, 0x64, 0x22, 0xbc, 0x01, 0x0a, 0x0f, 0x50, 0x69, 0x65, 0x63, 0x65,
+    0x4d, 0x6f, 0x76, 0x65, 0x64, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x55, 0x0a, 0x0a, 0x73, 0x65,
+    0x73, 0x73, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x36,
+    0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f,
+    0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e,
+    0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73,
+    0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x52, 0x09, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49,
+    0x64, 0x12, 0x15, 0x0a, 0x06, 0x66, 0x72, 0x6f, 0x6d, 0x5f, 0x78, 0x18, 0x02, 0x20, 0x01, 0x28,
+    0x05, 0x52, 0x05, 0x66, 0x72, 0x6f, 0x6d, 0x58, 0x12, 0x15, 0x0a, 0x06, 0x66, 0x72, 0x6f, 0x6d,
+    0x5f, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x05, 0x52, 0x05, 0x66, 0x72, 0x6f, 0x6d, 0x59, 0x12,
+    0x11, 0x0a, 0x04, 0x74, 0x6f, 0x5f, 0x78, 0x18, 0x04, 0x20, 0x01, 0x28, 0x05, 0x52, 0x03, 0x74,
+    0x6f, 0x58, 0x12, 0x11, 0x0a, 0x04, 0x74, 0x6f, 0x5f, 0x79, 0x18, 0x05, 0x20, 0x01, 0x28, 0x05,
+    0x52, 0x03, 0x74, 0x6f, 0x59, 0x22, 0x68, 0x0a, 0x0f, 0x4d, 0x6f, 0x76, 0x65, 0x55, 0x6e, 0x64,
+    0x6f, 0x6e, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x55, 0x0a, 0x0a, 0x73, 0x65, 0x73, 0x73,
+    0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x36, 0x2e, 0x72,
+    0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e,
+    0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79,
+    0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69,
+    0x6f, 0x6e, 0x49, 0x64, 0x52, 0x09, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x42,
+    0x66, 0x5a, 0x64, 0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f, 0x72, 0x75,
+    0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x2d, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f,
+    0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6e, 0x61,
+    0x6c, 0x2f, 0x67, 0x65, 0x6e, 0x2f, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73,
+    0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f,
+    0x67, 0x61, 0x6d, 0x65, 0x2f, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x2f, 0x76, 0x31, 0x3b, 0x70,
+    0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x76, 0x31, 0x4a, 0x9e, 0x07, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00,
+    0x1f, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01,
+    0x02, 0x12, 0x03, 0x01, 0x00, 0x31, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x03, 0x00,
+    0x3d, 0x0a, 0x08, 0x0a, 0x01, 0x08, 0x12, 0x03, 0x05, 0x00, 0x7b, 0x0a, 0x09, 0x0a, 0x02, 0x08,
+    0x0b, 0x12, 0x03, 0x05, 0x00, 0x7b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x07, 0x00,
+    0x0e, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x07, 0x08, 0x18, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x08, 0x02, 0x47, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x08, 0x02, 0x37, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x08, 0x38, 0x42, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x08, 0x45, 0x46, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x09,
+    0x02, 0x47, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x06, 0x12, 0x03, 0x09, 0x02, 0x32,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x09, 0x38, 0x41, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x09, 0x45, 0x46, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x0a, 0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x02, 0x05, 0x12, 0x03, 0x0a, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01,
+    0x12, 0x03, 0x0a, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03,
+    0x0a, 0x11, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x0b, 0x02, 0x13,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03, 0x0b, 0x02, 0x07, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x0b, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x0b, 0x11, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00,
+    0x02, 0x04, 0x12, 0x03, 0x0c, 0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x05,
+    0x12, 0x03, 0x0c, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03,
+    0x0c, 0x08, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12, 0x03, 0x0c, 0x11,
+    0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x0d, 0x02, 0x13, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x05, 0x12, 0x03, 0x0d, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x0d, 0x08, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x05, 0x03, 0x12, 0x03, 0x0d, 0x11, 0x12, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04,
+    0x10, 0x00, 0x13, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x10, 0x08, 0x17,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x11, 0x02, 0x47, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x11, 0x02, 0x37, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x11, 0x38, 0x42, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x11, 0x45, 0x46, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12,
+    0x03, 0x12, 0x02, 0x47, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x06, 0x12, 0x03, 0x12,
+    0x02, 0x32, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x12, 0x38, 0x41,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x12, 0x45, 0x46, 0x0a, 0x0a,
+    0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x15, 0x00, 0x1b, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02,
+    0x01, 0x12, 0x03, 0x15, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03,
+    0x16, 0x02, 0x47, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x16, 0x02,
+    0x37, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x16, 0x38, 0x42, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x16, 0x45, 0x46, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x17, 0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x01, 0x05, 0x12, 0x03, 0x17, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x17, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12,
+    0x03, 0x17, 0x11, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x02, 0x12, 0x03, 0x18, 0x02,
+    0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x05, 0x12, 0x03, 0x18, 0x02, 0x07, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x18, 0x08, 0x0e, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x02, 0x03, 0x12, 0x03, 0x18, 0x11, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x02, 0x02, 0x03, 0x12, 0x03, 0x19, 0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03,
+    0x05, 0x12, 0x03, 0x19, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x01, 0x12,
+    0x03, 0x19, 0x08, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x03, 0x12, 0x03, 0x19,
+    0x11, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x04, 0x12, 0x03, 0x1a, 0x02, 0x13, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x04, 0x05, 0x12, 0x03, 0x1a, 0x02, 0x07, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x04, 0x01, 0x12, 0x03, 0x1a, 0x08, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x04, 0x03, 0x12, 0x03, 0x1a, 0x11, 0x12, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12,
+    0x04, 0x1d, 0x00, 0x1f, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x1d, 0x08,
+    0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x1e, 0x02, 0x47, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x1e, 0x02, 0x37, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1e, 0x38, 0x42, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x1e, 0x45, 0x46, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+    0x0a, 0xf5, 0x0b, 0x0a, 0x34, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74,
+    0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x67,
+    0x61, 0x6d, 0x65, 0x2f, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x2f, 0x76, 0x31, 0x2f, 0x72, 0x75,
+    0x6c, 0x65, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x28, 0x72, 0x75, 0x6e, 0x65, 0x63,
+    0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74,
+    0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65,
+    0x2e, 0x76, 0x31, 0x22, 0xa3, 0x02, 0x0a, 0x0b, 0x50, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x52, 0x75,
+    0x6c, 0x65, 0x73, 0x12, 0x5a, 0x0a, 0x0a, 0x64, 0x69, 0x66, 0x66, 0x69, 0x63, 0x75, 0x6c, 0x74,
+    0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x3a, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72,
+    0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65,
+    0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x2e,
+    0x76, 0x31, 0x2e, 0x50, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x44, 0x69, 0x66, 0x66, 0x69, 0x63, 0x75,
+    0x6c, 0x74, 0x79, 0x52, 0x0a, 0x64, 0x69, 0x66, 0x66, 0x69, 0x63, 0x75, 0x6c, 0x74, 0x79, 0x12,
+    0x1f, 0x0a, 0x0b, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x5f, 0x68, 0x69, 0x6e, 0x74, 0x73, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x08, 0x52, 0x0a, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x48, 0x69, 0x6e, 0x74, 0x73,
+    0x12, 0x2c, 0x0a, 0x12, 0x74, 0x69, 0x6d, 0x65, 0x5f, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x5f, 0x73,
+    0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x10, 0x74, 0x69,
+    0x6d, 0x65, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x53, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x12, 0x1f,
+    0x0a, 0x0b, 0x6d, 0x61, 0x78, 0x5f, 0x70, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x73, 0x18, 0x04, 0x20,
+    0x01, 0x28, 0x0d, 0x52, 0x0a, 0x6d, 0x61, 0x78, 0x50, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x73, 0x12,
+    0x48, 0x0a, 0x04, 0x6d, 0x6f, 0x64, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x34, 0x2e,
+    0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73,
+    0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x70,
+    0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x4d,
+    0x6f, 0x64, 0x65, 0x52, 0x04, 0x6d, 0x6f, 0x64, 0x65, 0x2a, 0x8b, 0x01, 0x0a, 0x10, 0x50, 0x75,
+    0x7a, 0x7a, 0x6c, 0x65, 0x44, 0x69, 0x66, 0x66, 0x69, 0x63, 0x75, 0x6c, 0x74, 0x79, 0x12, 0x21,
+    0x0a, 0x1d, 0x50, 0x55, 0x5a, 0x5a, 0x4c, 0x45, 0x5f, 0x44, 0x49, 0x46, 0x46, 0x49, 0x43, 0x55,
+    0x4c, 0x54, 0x59, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10,
+    0x00, 0x12, 0x1a, 0x0a, 0x16, 0x50, 0x55, 0x5a, 0x5a, 0x4c, 0x45, 0x5f, 0x44, 0x49, 0x46, 0x46,
+    0x49, 0x43, 0x55, 0x4c, 0x54, 0x59, 0x5f, 0x45, 0x41, 0x53, 0x59, 0x10, 0x01, 0x12, 0x1c, 0x0a,
+    0x18, 0x50, 0x55, 0x5a, 0x5a, 0x4c, 0x45, 0x5f, 0x44, 0x49, 0x46, 0x46, 0x49, 0x43, 0x55, 0x4c,
+    0x54, 0x59, 0x5f, 0x4d, 0x45, 0x44, 0x49, 0x55, 0x4d, 0x10, 0x02, 0x12, 0x1a, 0x0a, 0x16, 0x50,
+    0x55, 0x5a, 0x5a, 0x4c, 0x45, 0x5f, 0x44, 0x49, 0x46, 0x46, 0x49, 0x43, 0x55, 0x4c, 0x54, 0x59,
+    0x5f, 0x48, 0x41, 0x52, 0x44, 0x10, 0x03, 0x2a, 0x5b, 0x0a, 0x0a, 0x50, 0x75, 0x7a, 0x7a, 0x6c,
+    0x65, 0x4d, 0x6f, 0x64, 0x65, 0x12, 0x1b, 0x0a, 0x17, 0x50, 0x55, 0x5a, 0x5a, 0x4c, 0x45, 0x5f,
+    0x4d, 0x4f, 0x44, 0x45, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44,
+    0x10, 0x00, 0x12, 0x1a, 0x0a, 0x16, 0x50, 0x55, 0x5a, 0x5a, 0x4c, 0x45, 0x5f, 0x4d, 0x4f, 0x44,
+    0x45, 0x5f, 0x54, 0x55, 0x52, 0x4e, 0x5f, 0x42, 0x41, 0x53, 0x45, 0x44, 0x10, 0x01, 0x12, 0x14,
+    0x0a, 0x10, 0x50, 0x55, 0x5a, 0x5a, 0x4c, 0x45, 0x5f, 0x4d, 0x4f, 0x44, 0x45, 0x5f, 0x52, 0x41,
+    0x43, 0x45, 0x10, 0x02, 0x42, 0x66, 0x5a, 0x64, 0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63,
+    0x6f, 0x6d, 0x2f, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x2d, 0x73, 0x74, 0x75,
+    0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x69, 0x6e,
+    0x74, 0x65, 0x72, 0x6e, 0x61, 0x6c, 0x2f, 0x67, 0x65, 0x6e, 0x2f, 0x72, 0x75, 0x6e, 0x65, 0x63,
+    0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74,
+    0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x67, 0x61, 0x6d, 0x65, 0x2f, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65,
+    0x2f, 0x76, 0x31, 0x3b, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x76, 0x31, 0x4a, 0x91, 0x06, 0x0a,
+    0x06, 0x12, 0x04, 0x00, 0x00, 0x1b, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00,
+    0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x01, 0x00, 0x31, 0x0a, 0x08, 0x0a, 0x01, 0x08,
+    0x12, 0x03, 0x04, 0x00, 0x7b, 0x0a, 0x09, 0x0a, 0x02, 0x08, 0x0b, 0x12, 0x03, 0x04, 0x00, 0x7b,
+    0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04, 0x06, 0x00, 0x0b, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
+    0x05, 0x00, 0x01, 0x12, 0x03, 0x06, 0x05, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00,
+    0x12, 0x03, 0x07, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x07, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x07, 0x22,
+    0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01, 0x12, 0x03, 0x08, 0x02, 0x1d, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x08, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x08, 0x1b, 0x1c, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00,
+    0x02, 0x02, 0x12, 0x03, 0x09, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x01,
+    0x12, 0x03, 0x09, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03,
+    0x09, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x03, 0x12, 0x03, 0x0a, 0x02, 0x1d,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x0a, 0x02, 0x18, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x0a, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02,
+    0x05, 0x01, 0x12, 0x04, 0x0d, 0x00, 0x13, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x01, 0x01, 0x12,
+    0x03, 0x0d, 0x05, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0e, 0x02,
+    0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0e, 0x02, 0x19, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x02, 0x12, 0x03, 0x0e, 0x1c, 0x1d, 0x0a, 0x36, 0x0a,
+    0x04, 0x05, 0x01, 0x02, 0x01, 0x12, 0x03, 0x10, 0x02, 0x1d, 0x1a, 0x29, 0x20, 0x50, 0x6c, 0x61,
+    0x79, 0x65, 0x72, 0x73, 0x20, 0x73, 0x68, 0x61, 0x72, 0x65, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x62,
+    0x6f, 0x61, 0x72, 0x64, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x61, 0x6b, 0x65, 0x20, 0x74, 0x75,
+    0x72, 0x6e, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x10, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x02, 0x12, 0x03, 0x10, 0x1b,
+    0x1c, 0x0a, 0x46, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x02, 0x12, 0x03, 0x12, 0x02, 0x17, 0x1a, 0x39,
+    0x20, 0x45, 0x76, 0x65, 0x72, 0x79, 0x20, 0x70, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x20, 0x73, 0x6f,
+    0x6c, 0x76, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x69, 0x72, 0x20, 0x6f, 0x77, 0x6e, 0x20, 0x69,
+    0x64, 0x65, 0x6e, 0x74, 0x69, 0x63, 0x61, 0x6c, 0x6c, 0x79, 0x20, 0x73, 0x65, 0x65, 0x64, 0x65,
+    0x64, 0x20, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02,
+    0x02, 0x01, 0x12, 0x03, 0x12, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x02, 0x02,
+    0x12, 0x03, 0x12, 0x15, 0x16, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x15, 0x00, 0x1b,
+    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x15, 0x08, 0x13, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x16, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x00, 0x06, 0x12, 0x03, 0x16, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x16, 0x13, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x16, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x17, 0x02,
+    0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x17, 0x02, 0x06, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x17, 0x07, 0x12, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x17, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x00, 0x02, 0x02, 0x12, 0x03, 0x18, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02,
+    0x05, 0x12, 0x03, 0x18, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x18, 0x09, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x18,
+    0x1e, 0x1f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x19, 0x02, 0x19, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03, 0x19, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x19, 0x09, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x19, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02,
+    0x04, 0x12, 0x03, 0x1a, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x06, 0x12,
+    0x03, 0x1a, 0x02, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x1a,
+    0x0d, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12, 0x03, 0x1a, 0x14, 0x15,
+    0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+];
 // @@protoc_insertion_point(module)
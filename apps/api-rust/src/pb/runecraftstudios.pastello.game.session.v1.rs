@@ -18,6 +18,24 @@ pub struct GameSession {
     /// optional external reference
     #[prost(string, tag="6")]
     pub ruleset_id: ::prost::alloc::string::String,
+    /// Read-only participants; not counted toward max_players.
+    #[prost(message, repeated, tag="7")]
+    pub spectator_ids: ::prost::alloc::vec::Vec<super::super::types::v1::PlayerId>,
+    /// Private rooms only, and only sent to the host: the code players join with, and when it stops working.
+    #[prost(string, tag="8")]
+    pub join_code: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="9")]
+    pub join_code_expires_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(bool, tag="10")]
+    pub has_password: bool,
+}
+/// Makes the new session a private room that players join by code.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PrivateRoomOptions {
+    /// Optional; players must give it along with the code.
+    #[prost(string, tag="1")]
+    pub password: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -26,6 +44,8 @@ pub struct CreateGameSessionRequest {
     pub game_type: i32,
     #[prost(message, repeated, tag="2")]
     pub player_ids: ::prost::alloc::vec::Vec<super::super::types::v1::PlayerId>,
+    #[prost(message, optional, tag="3")]
+    pub private_room: ::core::option::Option<PrivateRoomOptions>,
     #[prost(oneof="create_game_session_request::Config", tags="10, 11, 12")]
     pub config: ::core::option::Option<create_game_session_request::Config>,
 }
@@ -48,4 +68,249 @@ pub struct CreateGameSessionResponse {
     #[prost(message, optional, tag="1")]
     pub session: ::core::option::Option<GameSession>,
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetGameSessionRequest {
+    #[prost(message, optional, tag="1")]
+    pub id: ::core::option::Option<super::super::types::v1::GameSessionId>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetGameSessionResponse {
+    #[prost(message, optional, tag="1")]
+    pub session: ::core::option::Option<GameSession>,
+}
+/// Encoded file descriptor set for the `runecraftstudios.pastello.game.session.v1` package
+pub const FILE_DESCRIPTOR_SET: &[u8] = &[
+    0x0a, 0xce, 0x1c, 0x0a, 0x37, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74,
+    0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x67,
+    0x61, 0x6d, 0x65, 0x2f, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2f, 0x76, 0x31, 0x2f, 0x73,
+    0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x29, 0x72, 0x75,
+    0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70,
+    0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x73, 0x65, 0x73,
+    0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x1a, 0x1f, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2f,
+    0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2f, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61,
+    0x6d, 0x70, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x33, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72,
+    0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65,
+    0x6c, 0x6c, 0x6f, 0x2f, 0x67, 0x61, 0x6d, 0x65, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2f, 0x76,
+    0x31, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x34, 0x72,
+    0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f,
+    0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x67, 0x61, 0x6d, 0x65, 0x2f, 0x74, 0x72,
+    0x69, 0x76, 0x69, 0x61, 0x2f, 0x76, 0x31, 0x2f, 0x72, 0x75, 0x6c, 0x65, 0x73, 0x2e, 0x70, 0x72,
+    0x6f, 0x74, 0x6f, 0x1a, 0x34, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74,
+    0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x67,
+    0x61, 0x6d, 0x65, 0x2f, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x2f, 0x76, 0x31, 0x2f, 0x72, 0x75,
+    0x6c, 0x65, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x86, 0x05, 0x0a, 0x0b, 0x47, 0x61,
+    0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x46, 0x0a, 0x02, 0x69, 0x64, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x36, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66,
+    0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c,
+    0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e,
+    0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x52, 0x02, 0x69,
+    0x64, 0x12, 0x4e, 0x0a, 0x09, 0x67, 0x61, 0x6d, 0x65, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x0e, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74,
+    0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f,
+    0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x47,
+    0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x52, 0x08, 0x67, 0x61, 0x6d, 0x65, 0x54, 0x79, 0x70,
+    0x65, 0x12, 0x50, 0x0a, 0x0a, 0x70, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x73, 0x18,
+    0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66,
+    0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c,
+    0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e,
+    0x50, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x49, 0x64, 0x52, 0x09, 0x70, 0x6c, 0x61, 0x79, 0x65, 0x72,
+    0x49, 0x64, 0x73, 0x12, 0x4e, 0x0a, 0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x18, 0x04, 0x20,
+    0x01, 0x28, 0x0e, 0x32, 0x36, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73,
+    0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e,
+    0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x65,
+    0x73, 0x73, 0x69, 0x6f, 0x6e, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x06, 0x73, 0x74, 0x61,
+    0x74, 0x75, 0x73, 0x12, 0x39, 0x0a, 0x0a, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x5f, 0x61,
+    0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65,
+    0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74,
+    0x61, 0x6d, 0x70, 0x52, 0x09, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x41, 0x74, 0x12, 0x1d,
+    0x0a, 0x0a, 0x72, 0x75, 0x6c, 0x65, 0x73, 0x65, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x06, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x09, 0x72, 0x75, 0x6c, 0x65, 0x73, 0x65, 0x74, 0x49, 0x64, 0x12, 0x56, 0x0a,
+    0x0d, 0x73, 0x70, 0x65, 0x63, 0x74, 0x61, 0x74, 0x6f, 0x72, 0x5f, 0x69, 0x64, 0x73, 0x18, 0x07,
+    0x20, 0x03, 0x28, 0x0b, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74,
+    0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f,
+    0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x50,
+    0x6c, 0x61, 0x79, 0x65, 0x72, 0x49, 0x64, 0x52, 0x0c, 0x73, 0x70, 0x65, 0x63, 0x74, 0x61, 0x74,
+    0x6f, 0x72, 0x49, 0x64, 0x73, 0x12, 0x1b, 0x0a, 0x09, 0x6a, 0x6f, 0x69, 0x6e, 0x5f, 0x63, 0x6f,
+    0x64, 0x65, 0x18, 0x08, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x6a, 0x6f, 0x69, 0x6e, 0x43, 0x6f,
+    0x64, 0x65, 0x12, 0x4b, 0x0a, 0x14, 0x6a, 0x6f, 0x69, 0x6e, 0x5f, 0x63, 0x6f, 0x64, 0x65, 0x5f,
+    0x65, 0x78, 0x70, 0x69, 0x72, 0x65, 0x73, 0x5f, 0x61, 0x74, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62,
+    0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x11, 0x6a, 0x6f,
+    0x69, 0x6e, 0x43, 0x6f, 0x64, 0x65, 0x45, 0x78, 0x70, 0x69, 0x72, 0x65, 0x73, 0x41, 0x74, 0x12,
+    0x21, 0x0a, 0x0c, 0x68, 0x61, 0x73, 0x5f, 0x70, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x18,
+    0x0a, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0b, 0x68, 0x61, 0x73, 0x50, 0x61, 0x73, 0x73, 0x77, 0x6f,
+    0x72, 0x64, 0x22, 0x30, 0x0a, 0x12, 0x50, 0x72, 0x69, 0x76, 0x61, 0x74, 0x65, 0x52, 0x6f, 0x6f,
+    0x6d, 0x4f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x12, 0x1a, 0x0a, 0x08, 0x70, 0x61, 0x73, 0x73,
+    0x77, 0x6f, 0x72, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x70, 0x61, 0x73, 0x73,
+    0x77, 0x6f, 0x72, 0x64, 0x22, 0xeb, 0x03, 0x0a, 0x18, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x47,
+    0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x12, 0x4e, 0x0a, 0x09, 0x67, 0x61, 0x6d, 0x65, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01,
+    0x20, 0x01, 0x28, 0x0e, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74,
+    0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f,
+    0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x47,
+    0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x52, 0x08, 0x67, 0x61, 0x6d, 0x65, 0x54, 0x79, 0x70,
+    0x65, 0x12, 0x50, 0x0a, 0x0a, 0x70, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x73, 0x18,
+    0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66,
+    0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c,
+    0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e,
+    0x50, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x49, 0x64, 0x52, 0x09, 0x70, 0x6c, 0x61, 0x79, 0x65, 0x72,
+    0x49, 0x64, 0x73, 0x12, 0x60, 0x0a, 0x0c, 0x70, 0x72, 0x69, 0x76, 0x61, 0x74, 0x65, 0x5f, 0x72,
+    0x6f, 0x6f, 0x6d, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x3d, 0x2e, 0x72, 0x75, 0x6e, 0x65,
+    0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73,
+    0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x73, 0x65, 0x73, 0x73, 0x69,
+    0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x72, 0x69, 0x76, 0x61, 0x74, 0x65, 0x52, 0x6f, 0x6f,
+    0x6d, 0x4f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x52, 0x0b, 0x70, 0x72, 0x69, 0x76, 0x61, 0x74,
+    0x65, 0x52, 0x6f, 0x6f, 0x6d, 0x12, 0x4f, 0x0a, 0x06, 0x74, 0x72, 0x69, 0x76, 0x69, 0x61, 0x18,
+    0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x35, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66,
+    0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c,
+    0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x72, 0x69, 0x76, 0x69, 0x61, 0x2e, 0x76, 0x31,
+    0x2e, 0x54, 0x72, 0x69, 0x76, 0x69, 0x61, 0x52, 0x75, 0x6c, 0x65, 0x73, 0x48, 0x00, 0x52, 0x06,
+    0x74, 0x72, 0x69, 0x76, 0x69, 0x61, 0x12, 0x4f, 0x0a, 0x06, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65,
+    0x18, 0x0b, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x35, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61,
+    0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c,
+    0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x2e, 0x76,
+    0x31, 0x2e, 0x50, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x52, 0x75, 0x6c, 0x65, 0x73, 0x48, 0x00, 0x52,
+    0x06, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x12, 0x1f, 0x0a, 0x0a, 0x72, 0x75, 0x6c, 0x65, 0x73,
+    0x65, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x09, 0x72,
+    0x75, 0x6c, 0x65, 0x73, 0x65, 0x74, 0x49, 0x64, 0x42, 0x08, 0x0a, 0x06, 0x63, 0x6f, 0x6e, 0x66,
+    0x69, 0x67, 0x22, 0x6d, 0x0a, 0x19, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x47, 0x61, 0x6d, 0x65,
+    0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
+    0x50, 0x0a, 0x07, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x36, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64,
+    0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d,
+    0x65, 0x2e, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d,
+    0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x07, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f,
+    0x6e, 0x22, 0x5f, 0x0a, 0x15, 0x47, 0x65, 0x74, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73,
+    0x69, 0x6f, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x46, 0x0a, 0x02, 0x69, 0x64,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x36, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61,
+    0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c,
+    0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31,
+    0x2e, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x52, 0x02,
+    0x69, 0x64, 0x22, 0x6a, 0x0a, 0x16, 0x47, 0x65, 0x74, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73,
+    0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x50, 0x0a, 0x07,
+    0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x36, 0x2e,
+    0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73,
+    0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x73,
+    0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65,
+    0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x07, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x42, 0x68,
+    0x5a, 0x66, 0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f, 0x72, 0x75, 0x6e,
+    0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x2d, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70,
+    0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6e, 0x61, 0x6c,
+    0x2f, 0x67, 0x65, 0x6e, 0x2f, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74,
+    0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x67,
+    0x61, 0x6d, 0x65, 0x2f, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2f, 0x76, 0x31, 0x3b, 0x73,
+    0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x76, 0x31, 0x4a, 0xce, 0x0d, 0x0a, 0x06, 0x12, 0x04, 0x00,
+    0x00, 0x36, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a,
+    0x01, 0x02, 0x12, 0x03, 0x01, 0x00, 0x32, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x03,
+    0x00, 0x29, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x03, 0x04, 0x00, 0x3d, 0x0a, 0x09, 0x0a,
+    0x02, 0x03, 0x02, 0x12, 0x03, 0x05, 0x00, 0x3e, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x03, 0x12, 0x03,
+    0x06, 0x00, 0x3e, 0x0a, 0x08, 0x0a, 0x01, 0x08, 0x12, 0x03, 0x08, 0x00, 0x7d, 0x0a, 0x09, 0x0a,
+    0x02, 0x08, 0x0b, 0x12, 0x03, 0x08, 0x00, 0x7d, 0x0a, 0x37, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04,
+    0x0c, 0x00, 0x19, 0x01, 0x32, 0x2b, 0x20, 0x4e, 0x4f, 0x54, 0x45, 0x3a, 0x20, 0x47, 0x61, 0x6d,
+    0x65, 0x54, 0x79, 0x70, 0x65, 0x2f, 0x49, 0x64, 0x73, 0x20, 0x6e, 0x6f, 0x77, 0x20, 0x63, 0x6f,
+    0x6d, 0x65, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31,
+    0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x0c, 0x08, 0x13, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0d, 0x02, 0x3f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x00, 0x06, 0x12, 0x03, 0x0d, 0x02, 0x37, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x0d, 0x38, 0x3a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x0d, 0x3d, 0x3e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x0e, 0x02,
+    0x46, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x06, 0x12, 0x03, 0x0e, 0x02, 0x32, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0e, 0x38, 0x41, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0e, 0x44, 0x45, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x00, 0x02, 0x02, 0x12, 0x03, 0x0f, 0x02, 0x4b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02,
+    0x04, 0x12, 0x03, 0x0f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x06, 0x12,
+    0x03, 0x0f, 0x0b, 0x3b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x0f,
+    0x3c, 0x46, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x0f, 0x49, 0x4a,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x10, 0x02, 0x43, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x03, 0x06, 0x12, 0x03, 0x10, 0x02, 0x37, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x10, 0x38, 0x3e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x03, 0x03, 0x12, 0x03, 0x10, 0x41, 0x42, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12,
+    0x03, 0x11, 0x02, 0x2b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x06, 0x12, 0x03, 0x11,
+    0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x11, 0x1c, 0x26,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12, 0x03, 0x11, 0x29, 0x2a, 0x0a, 0x2a,
+    0x0a, 0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x12, 0x02, 0x18, 0x22, 0x1d, 0x20, 0x6f, 0x70,
+    0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x20, 0x65, 0x78, 0x74, 0x65, 0x72, 0x6e, 0x61, 0x6c, 0x20,
+    0x72, 0x65, 0x66, 0x65, 0x72, 0x65, 0x6e, 0x63, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x05, 0x05, 0x12, 0x03, 0x12, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05,
+    0x01, 0x12, 0x03, 0x12, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x03, 0x12,
+    0x03, 0x12, 0x16, 0x17, 0x0a, 0x46, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x06, 0x12, 0x03, 0x14, 0x02,
+    0x4e, 0x1a, 0x39, 0x20, 0x52, 0x65, 0x61, 0x64, 0x2d, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x70, 0x61,
+    0x72, 0x74, 0x69, 0x63, 0x69, 0x70, 0x61, 0x6e, 0x74, 0x73, 0x3b, 0x20, 0x6e, 0x6f, 0x74, 0x20,
+    0x63, 0x6f, 0x75, 0x6e, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x77, 0x61, 0x72, 0x64, 0x20, 0x6d,
+    0x61, 0x78, 0x5f, 0x70, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x06, 0x04, 0x12, 0x03, 0x14, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x06, 0x06, 0x12, 0x03, 0x14, 0x0b, 0x3b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06,
+    0x01, 0x12, 0x03, 0x14, 0x3c, 0x49, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x03, 0x12,
+    0x03, 0x14, 0x4c, 0x4d, 0x0a, 0x59, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x07, 0x12, 0x03, 0x16, 0x02,
+    0x17, 0x1a, 0x4c, 0x20, 0x50, 0x72, 0x69, 0x76, 0x61, 0x74, 0x65, 0x20, 0x72, 0x6f, 0x6f, 0x6d,
+    0x73, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x3a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x64, 0x65,
+    0x20, 0x70, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x73, 0x20, 0x6a, 0x6f, 0x69, 0x6e, 0x20, 0x77, 0x69,
+    0x74, 0x68, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x69, 0x74, 0x20,
+    0x73, 0x74, 0x6f, 0x70, 0x73, 0x20, 0x77, 0x6f, 0x72, 0x6b, 0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x05, 0x12, 0x03, 0x16, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x03, 0x16, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x07, 0x03, 0x12, 0x03, 0x16, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02,
+    0x08, 0x12, 0x03, 0x17, 0x02, 0x35, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x06, 0x12,
+    0x03, 0x17, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x01, 0x12, 0x03, 0x17,
+    0x1c, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x08, 0x03, 0x12, 0x03, 0x17, 0x33, 0x34,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x09, 0x12, 0x03, 0x18, 0x02, 0x19, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x09, 0x05, 0x12, 0x03, 0x18, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x09, 0x01, 0x12, 0x03, 0x18, 0x07, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x09, 0x03, 0x12, 0x03, 0x18, 0x16, 0x18, 0x0a, 0x4d, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x1c,
+    0x00, 0x1f, 0x01, 0x1a, 0x41, 0x20, 0x4d, 0x61, 0x6b, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x6e, 0x65, 0x77, 0x20, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x61, 0x20, 0x70, 0x72,
+    0x69, 0x76, 0x61, 0x74, 0x65, 0x20, 0x72, 0x6f, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20,
+    0x70, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x73, 0x20, 0x6a, 0x6f, 0x69, 0x6e, 0x20, 0x62, 0x79, 0x20,
+    0x63, 0x6f, 0x64, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x1c,
+    0x08, 0x1a, 0x0a, 0x42, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x1e, 0x02, 0x16, 0x1a,
+    0x35, 0x20, 0x4f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x3b, 0x20, 0x70, 0x6c, 0x61, 0x79,
+    0x65, 0x72, 0x73, 0x20, 0x6d, 0x75, 0x73, 0x74, 0x20, 0x67, 0x69, 0x76, 0x65, 0x20, 0x69, 0x74,
+    0x20, 0x61, 0x6c, 0x6f, 0x6e, 0x67, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x63, 0x6f, 0x64, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12,
+    0x03, 0x1e, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1e,
+    0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1e, 0x14, 0x15,
+    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x21, 0x00, 0x2a, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x02, 0x01, 0x12, 0x03, 0x21, 0x08, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00,
+    0x12, 0x03, 0x22, 0x02, 0x41, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03,
+    0x22, 0x02, 0x32, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x22, 0x33,
+    0x3c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x22, 0x3f, 0x40, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x23, 0x02, 0x4b, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x01, 0x04, 0x12, 0x03, 0x23, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x01, 0x06, 0x12, 0x03, 0x23, 0x0b, 0x3b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x23, 0x3c, 0x46, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12,
+    0x03, 0x23, 0x49, 0x4a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x02, 0x12, 0x03, 0x24, 0x02,
+    0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x06, 0x12, 0x03, 0x24, 0x02, 0x14, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x24, 0x15, 0x21, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x02, 0x03, 0x12, 0x03, 0x24, 0x24, 0x25, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x02, 0x08, 0x00, 0x12, 0x04, 0x25, 0x02, 0x29, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x08,
+    0x00, 0x01, 0x12, 0x03, 0x25, 0x08, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12,
+    0x03, 0x26, 0x04, 0x45, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x06, 0x12, 0x03, 0x26,
+    0x04, 0x38, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03, 0x26, 0x39, 0x3f,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x03, 0x12, 0x03, 0x26, 0x42, 0x44, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x02, 0x02, 0x04, 0x12, 0x03, 0x27, 0x04, 0x45, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x04, 0x06, 0x12, 0x03, 0x27, 0x04, 0x38, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x04, 0x01, 0x12, 0x03, 0x27, 0x39, 0x3f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x04, 0x03,
+    0x12, 0x03, 0x27, 0x42, 0x44, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x05, 0x12, 0x03, 0x28,
+    0x04, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x05, 0x12, 0x03, 0x28, 0x04, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x01, 0x12, 0x03, 0x28, 0x0b, 0x15, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x03, 0x12, 0x03, 0x28, 0x18, 0x1a, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x03, 0x12, 0x04, 0x2c, 0x00, 0x2e, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12,
+    0x03, 0x2c, 0x08, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x2d, 0x02,
+    0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x2d, 0x02, 0x0d, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2d, 0x0e, 0x15, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2d, 0x18, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
+    0x04, 0x12, 0x04, 0x30, 0x00, 0x32, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03,
+    0x30, 0x08, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x31, 0x02, 0x3f,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x06, 0x12, 0x03, 0x31, 0x02, 0x37, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x31, 0x38, 0x3a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x31, 0x3d, 0x3e, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x05,
+    0x12, 0x04, 0x34, 0x00, 0x36, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x34,
+    0x08, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x35, 0x02, 0x1a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x06, 0x12, 0x03, 0x35, 0x02, 0x0d, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x35, 0x0e, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x35, 0x18, 0x19, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+    0x33,
+];
 // @@protoc_insertion_point(module)
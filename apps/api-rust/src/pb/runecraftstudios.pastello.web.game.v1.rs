@@ -5,7 +5,7 @@
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ClientEnvelope {
-    #[prost(oneof="client_envelope::Message", tags="1, 2")]
+    #[prost(oneof="client_envelope::Message", tags="1, 2, 3, 4, 5")]
     pub message: ::core::option::Option<client_envelope::Message>,
 }
 /// Nested message and enum types in `ClientEnvelope`.
@@ -17,6 +17,12 @@ pub mod client_envelope {
         StartGame(super::StartGameCommand),
         #[prost(message, tag="2")]
         GameCommand(super::GameCommandEnvelope),
+        #[prost(message, tag="3")]
+        JoinQueue(super::JoinQueueCommand),
+        #[prost(message, tag="4")]
+        LeaveQueue(super::LeaveQueueCommand),
+        #[prost(message, tag="5")]
+        GetLeaderboard(super::GetLeaderboardQuery),
     }
 }
 /// Sent by the client to initiate a game session.
@@ -27,6 +33,35 @@ pub struct StartGameCommand {
     #[prost(enumeration="super::super::super::game::types::v1::GameType", tag="1")]
     pub game_type: i32,
 }
+/// Enters the quick-play queue for a game type. Joining again replaces the earlier entry.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct JoinQueueCommand {
+    #[prost(enumeration="super::super::super::game::types::v1::GameType", tag="1")]
+    pub game_type: i32,
+    /// Ignored: players are matched on the skill rating stored on their profile.
+    #[prost(double, optional, tag="2")]
+    pub rating: ::core::option::Option<f64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct LeaveQueueCommand {
+}
+/// Reads a leaderboard; answered with a Leaderboard message.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetLeaderboardQuery {
+    #[prost(enumeration="super::super::super::game::types::v1::GameType", tag="1")]
+    pub game_type: i32,
+    /// Only sessions played with this ruleset; empty ranks every session of the game type.
+    #[prost(string, tag="2")]
+    pub ruleset_id: ::prost::alloc::string::String,
+    #[prost(enumeration="LeaderboardPeriod", tag="3")]
+    pub period: i32,
+    /// Entries to return; 0 uses the server default.
+    #[prost(uint32, tag="4")]
+    pub limit: u32,
+}
 /// GameCommandEnvelope wraps all in-game actions.
 /// The Rust code `ws/mod.rs` expects this structure.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -55,7 +90,7 @@ pub mod game_command_envelope {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ServerEnvelope {
-    #[prost(oneof="server_envelope::Message", tags="1, 2, 3, 4")]
+    #[prost(oneof="server_envelope::Message", tags="1, 2, 3, 4, 5, 6, 7, 8")]
     pub message: ::core::option::Option<server_envelope::Message>,
 }
 /// Nested message and enum types in `ServerEnvelope`.
@@ -75,8 +110,97 @@ pub mod server_envelope {
         /// e.g., A specific game event
         #[prost(message, tag="4")]
         GameEvent(super::GameEventEnvelope),
+        /// Matchmaking progress for a queued player.
+        #[prost(message, tag="5")]
+        QueueStatus(super::QueueStatus),
+        /// The player was placed in a session; attach to it to play.
+        #[prost(message, tag="6")]
+        MatchFound(super::MatchFound),
+        /// Reply to GetLeaderboardQuery.
+        #[prost(message, tag="7")]
+        Leaderboard(super::Leaderboard),
+        /// The player earned an achievement.
+        #[prost(message, tag="8")]
+        AchievementUnlocked(super::AchievementUnlocked),
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct QueueStatus {
+    #[prost(enumeration="super::super::super::game::types::v1::GameType", tag="1")]
+    pub game_type: i32,
+    /// 1-based place in the queue; 0 once the player has left it.
+    #[prost(uint32, tag="2")]
+    pub position: u32,
+    #[prost(uint32, tag="3")]
+    pub queued: u32,
+    #[prost(uint32, tag="4")]
+    pub waited_seconds: u32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MatchFound {
+    #[prost(message, optional, tag="1")]
+    pub session_id: ::core::option::Option<super::super::super::game::types::v1::GameSessionId>,
+    #[prost(enumeration="super::super::super::game::types::v1::GameType", tag="2")]
+    pub game_type: i32,
+    #[prost(message, repeated, tag="3")]
+    pub player_ids: ::prost::alloc::vec::Vec<super::super::super::game::types::v1::PlayerId>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Leaderboard {
+    #[prost(enumeration="super::super::super::game::types::v1::GameType", tag="1")]
+    pub game_type: i32,
+    #[prost(string, tag="2")]
+    pub ruleset_id: ::prost::alloc::string::String,
+    #[prost(enumeration="LeaderboardPeriod", tag="3")]
+    pub period: i32,
+    /// Window the board covers, e.g. "2024-W07" or "2024-02-14"; "all" for all time.
+    #[prost(string, tag="4")]
+    pub window: ::prost::alloc::string::String,
+    /// Players ranked on the board.
+    #[prost(uint32, tag="5")]
+    pub total: u32,
+    #[prost(message, repeated, tag="6")]
+    pub entries: ::prost::alloc::vec::Vec<LeaderboardEntry>,
+    /// The caller's own standing; unset if they have no result on this board.
+    #[prost(message, optional, tag="7")]
+    pub me: ::core::option::Option<LeaderboardEntry>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LeaderboardEntry {
+    #[prost(uint32, tag="1")]
+    pub rank: u32,
+    #[prost(message, optional, tag="2")]
+    pub player_id: ::core::option::Option<super::super::super::game::types::v1::PlayerId>,
+    /// Final score for trivia, completion time in milliseconds for puzzles.
+    #[prost(int64, tag="3")]
+    pub value: i64,
+    #[prost(message, optional, tag="4")]
+    pub achieved_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag="5")]
+    pub session_id: ::core::option::Option<super::super::super::game::types::v1::GameSessionId>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AchievementUnlocked {
+    #[prost(string, tag="1")]
+    pub achievement_id: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub description: ::prost::alloc::string::String,
+    /// Empty if the achievement has no badge image.
+    #[prost(string, tag="4")]
+    pub badge_url: ::prost::alloc::string::String,
+    #[prost(message, optional, tag="5")]
+    pub unlocked_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// Session the achievement was earned in.
+    #[prost(message, optional, tag="6")]
+    pub session_id: ::core::option::Option<super::super::super::game::types::v1::GameSessionId>,
+}
 /// GameEventEnvelope wraps all game-specific events.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -97,4 +221,728 @@ pub mod game_event_envelope {
         TriviaEvent(::prost::alloc::string::String),
     }
 }
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum LeaderboardPeriod {
+    /// Treated as all time.
+    Unspecified = 0,
+    AllTime = 1,
+    /// Current ISO week, from Monday 00:00 UTC.
+    Weekly = 2,
+    /// Current day in UTC.
+    Daily = 3,
+    /// Today's daily challenge: one attempt per player on the same seeded game.
+    DailyChallenge = 4,
+}
+impl LeaderboardPeriod {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            LeaderboardPeriod::Unspecified => "LEADERBOARD_PERIOD_UNSPECIFIED",
+            LeaderboardPeriod::AllTime => "LEADERBOARD_PERIOD_ALL_TIME",
+            LeaderboardPeriod::Weekly => "LEADERBOARD_PERIOD_WEEKLY",
+            LeaderboardPeriod::Daily => "LEADERBOARD_PERIOD_DAILY",
+            LeaderboardPeriod::DailyChallenge => "LEADERBOARD_PERIOD_DAILY_CHALLENGE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "LEADERBOARD_PERIOD_UNSPECIFIED" => Some(Self::Unspecified),
+            "LEADERBOARD_PERIOD_ALL_TIME" => Some(Self::AllTime),
+            "LEADERBOARD_PERIOD_WEEKLY" => Some(Self::Weekly),
+            "LEADERBOARD_PERIOD_DAILY" => Some(Self::Daily),
+            "LEADERBOARD_PERIOD_DAILY_CHALLENGE" => Some(Self::DailyChallenge),
+            _ => None,
+        }
+    }
+}
+/// Encoded file descriptor set for the `runecraftstudios.pastello.web.game.v1` package
+pub const FILE_DESCRIPTOR_SET: &[u8] = &[
+    0x0a, 0x8e, 0x4b, 0x0a, 0x34, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74,
+    0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x77,
+    0x65, 0x62, 0x2f, 0x67, 0x61, 0x6d, 0x65, 0x2f, 0x76, 0x31, 0x2f, 0x65, 0x6e, 0x76, 0x65, 0x6c,
+    0x6f, 0x70, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x25, 0x72, 0x75, 0x6e, 0x65, 0x63,
+    0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74,
+    0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31,
+    0x1a, 0x37, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69,
+    0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x67, 0x61, 0x6d, 0x65,
+    0x2f, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x2f, 0x76, 0x31, 0x2f, 0x63, 0x6f, 0x6d, 0x6d, 0x61,
+    0x6e, 0x64, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x37, 0x72, 0x75, 0x6e, 0x65, 0x63,
+    0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74,
+    0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x67, 0x61, 0x6d, 0x65, 0x2f, 0x74, 0x72, 0x69, 0x76, 0x69, 0x61,
+    0x2f, 0x76, 0x31, 0x2f, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x73, 0x2e, 0x70, 0x72, 0x6f,
+    0x74, 0x6f, 0x1a, 0x33, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75,
+    0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x67, 0x61,
+    0x6d, 0x65, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2f, 0x76, 0x31, 0x2f, 0x74, 0x79, 0x70, 0x65,
+    0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x1f, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2f,
+    0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2f, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61,
+    0x6d, 0x70, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0xf4, 0x03, 0x0a, 0x0e, 0x43, 0x6c, 0x69,
+    0x65, 0x6e, 0x74, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x12, 0x58, 0x0a, 0x0a, 0x73,
+    0x74, 0x61, 0x72, 0x74, 0x5f, 0x67, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32,
+    0x37, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69,
+    0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e,
+    0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x74, 0x61, 0x72, 0x74, 0x47, 0x61, 0x6d,
+    0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x48, 0x00, 0x52, 0x09, 0x73, 0x74, 0x61, 0x72,
+    0x74, 0x47, 0x61, 0x6d, 0x65, 0x12, 0x5f, 0x0a, 0x0c, 0x67, 0x61, 0x6d, 0x65, 0x5f, 0x63, 0x6f,
+    0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x3a, 0x2e, 0x72, 0x75,
+    0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70,
+    0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65,
+    0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x45,
+    0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x48, 0x00, 0x52, 0x0b, 0x67, 0x61, 0x6d, 0x65, 0x43,
+    0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x12, 0x58, 0x0a, 0x0a, 0x6a, 0x6f, 0x69, 0x6e, 0x5f, 0x71,
+    0x75, 0x65, 0x75, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x37, 0x2e, 0x72, 0x75, 0x6e,
+    0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61,
+    0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e,
+    0x76, 0x31, 0x2e, 0x4a, 0x6f, 0x69, 0x6e, 0x51, 0x75, 0x65, 0x75, 0x65, 0x43, 0x6f, 0x6d, 0x6d,
+    0x61, 0x6e, 0x64, 0x48, 0x00, 0x52, 0x09, 0x6a, 0x6f, 0x69, 0x6e, 0x51, 0x75, 0x65, 0x75, 0x65,
+    0x12, 0x5b, 0x0a, 0x0b, 0x6c, 0x65, 0x61, 0x76, 0x65, 0x5f, 0x71, 0x75, 0x65, 0x75, 0x65, 0x18,
+    0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x38, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66,
+    0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c,
+    0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x65,
+    0x61, 0x76, 0x65, 0x51, 0x75, 0x65, 0x75, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x48,
+    0x00, 0x52, 0x0a, 0x6c, 0x65, 0x61, 0x76, 0x65, 0x51, 0x75, 0x65, 0x75, 0x65, 0x12, 0x65, 0x0a,
+    0x0f, 0x67, 0x65, 0x74, 0x5f, 0x6c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64,
+    0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x3a, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61,
+    0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c,
+    0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x47,
+    0x65, 0x74, 0x4c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x51, 0x75, 0x65,
+    0x72, 0x79, 0x48, 0x00, 0x52, 0x0e, 0x67, 0x65, 0x74, 0x4c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x62,
+    0x6f, 0x61, 0x72, 0x64, 0x42, 0x09, 0x0a, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x22,
+    0x62, 0x0a, 0x10, 0x53, 0x74, 0x61, 0x72, 0x74, 0x47, 0x61, 0x6d, 0x65, 0x43, 0x6f, 0x6d, 0x6d,
+    0x61, 0x6e, 0x64, 0x12, 0x4e, 0x0a, 0x09, 0x67, 0x61, 0x6d, 0x65, 0x5f, 0x74, 0x79, 0x70, 0x65,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61,
+    0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c,
+    0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31,
+    0x2e, 0x47, 0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x52, 0x08, 0x67, 0x61, 0x6d, 0x65, 0x54,
+    0x79, 0x70, 0x65, 0x22, 0x8a, 0x01, 0x0a, 0x10, 0x4a, 0x6f, 0x69, 0x6e, 0x51, 0x75, 0x65, 0x75,
+    0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x12, 0x4e, 0x0a, 0x09, 0x67, 0x61, 0x6d, 0x65,
+    0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x31, 0x2e, 0x72, 0x75,
+    0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70,
+    0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70,
+    0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x52, 0x08,
+    0x67, 0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x12, 0x1b, 0x0a, 0x06, 0x72, 0x61, 0x74, 0x69,
+    0x6e, 0x67, 0x18, 0x02, 0x20, 0x01, 0x28, 0x01, 0x48, 0x00, 0x52, 0x06, 0x72, 0x61, 0x74, 0x69,
+    0x6e, 0x67, 0x88, 0x01, 0x01, 0x42, 0x09, 0x0a, 0x07, 0x5f, 0x72, 0x61, 0x74, 0x69, 0x6e, 0x67,
+    0x22, 0x13, 0x0a, 0x11, 0x4c, 0x65, 0x61, 0x76, 0x65, 0x51, 0x75, 0x65, 0x75, 0x65, 0x43, 0x6f,
+    0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x22, 0xec, 0x01, 0x0a, 0x13, 0x47, 0x65, 0x74, 0x4c, 0x65, 0x61,
+    0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x51, 0x75, 0x65, 0x72, 0x79, 0x12, 0x4e, 0x0a,
+    0x09, 0x67, 0x61, 0x6d, 0x65, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e,
+    0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64,
+    0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d,
+    0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x54,
+    0x79, 0x70, 0x65, 0x52, 0x08, 0x67, 0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x12, 0x1d, 0x0a,
+    0x0a, 0x72, 0x75, 0x6c, 0x65, 0x73, 0x65, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28,
+    0x09, 0x52, 0x09, 0x72, 0x75, 0x6c, 0x65, 0x73, 0x65, 0x74, 0x49, 0x64, 0x12, 0x50, 0x0a, 0x06,
+    0x70, 0x65, 0x72, 0x69, 0x6f, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x38, 0x2e, 0x72,
+    0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e,
+    0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d,
+    0x65, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64,
+    0x50, 0x65, 0x72, 0x69, 0x6f, 0x64, 0x52, 0x06, 0x70, 0x65, 0x72, 0x69, 0x6f, 0x64, 0x12, 0x14,
+    0x0a, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x05, 0x6c,
+    0x69, 0x6d, 0x69, 0x74, 0x22, 0xa3, 0x03, 0x0a, 0x13, 0x47, 0x61, 0x6d, 0x65, 0x43, 0x6f, 0x6d,
+    0x6d, 0x61, 0x6e, 0x64, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x12, 0x5d, 0x0a, 0x0b,
+    0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x5f, 0x6d, 0x6f, 0x76, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x0b, 0x32, 0x3a, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75,
+    0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61,
+    0x6d, 0x65, 0x2e, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x4d, 0x6f, 0x76,
+    0x65, 0x50, 0x69, 0x65, 0x63, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x48, 0x00, 0x52,
+    0x0a, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x4d, 0x6f, 0x76, 0x65, 0x12, 0x5c, 0x0a, 0x0b, 0x70,
+    0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x5f, 0x75, 0x6e, 0x64, 0x6f, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x39, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64,
+    0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d,
+    0x65, 0x2e, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x55, 0x6e, 0x64, 0x6f,
+    0x4d, 0x6f, 0x76, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x48, 0x00, 0x52, 0x0a, 0x70,
+    0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x55, 0x6e, 0x64, 0x6f, 0x12, 0x64, 0x0a, 0x0d, 0x74, 0x72, 0x69,
+    0x76, 0x69, 0x61, 0x5f, 0x73, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x3d, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64,
+    0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d,
+    0x65, 0x2e, 0x74, 0x72, 0x69, 0x76, 0x69, 0x61, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x75, 0x62, 0x6d,
+    0x69, 0x74, 0x41, 0x6e, 0x73, 0x77, 0x65, 0x72, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x48,
+    0x00, 0x52, 0x0c, 0x74, 0x72, 0x69, 0x76, 0x69, 0x61, 0x53, 0x75, 0x62, 0x6d, 0x69, 0x74, 0x12,
+    0x5e, 0x0a, 0x0b, 0x74, 0x72, 0x69, 0x76, 0x69, 0x61, 0x5f, 0x68, 0x69, 0x6e, 0x74, 0x18, 0x04,
+    0x20, 0x01, 0x28, 0x0b, 0x32, 0x3b, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74,
+    0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f,
+    0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x72, 0x69, 0x76, 0x69, 0x61, 0x2e, 0x76, 0x31, 0x2e,
+    0x52, 0x65, 0x76, 0x65, 0x61, 0x6c, 0x48, 0x69, 0x6e, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
+    0x64, 0x48, 0x00, 0x52, 0x0a, 0x74, 0x72, 0x69, 0x76, 0x69, 0x61, 0x48, 0x69, 0x6e, 0x74, 0x42,
+    0x09, 0x0a, 0x07, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x22, 0xca, 0x04, 0x0a, 0x0e, 0x53,
+    0x65, 0x72, 0x76, 0x65, 0x72, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x12, 0x21, 0x0a,
+    0x0b, 0x61, 0x75, 0x74, 0x68, 0x5f, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x09, 0x48, 0x00, 0x52, 0x0a, 0x61, 0x75, 0x74, 0x68, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73,
+    0x12, 0x1f, 0x0a, 0x0a, 0x67, 0x61, 0x6d, 0x65, 0x5f, 0x73, 0x74, 0x61, 0x74, 0x65, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x09, 0x67, 0x61, 0x6d, 0x65, 0x53, 0x74, 0x61, 0x74,
+    0x65, 0x12, 0x16, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09,
+    0x48, 0x00, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x12, 0x59, 0x0a, 0x0a, 0x67, 0x61, 0x6d,
+    0x65, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x38, 0x2e,
+    0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73,
+    0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61,
+    0x6d, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x45,
+    0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x48, 0x00, 0x52, 0x09, 0x67, 0x61, 0x6d, 0x65, 0x45,
+    0x76, 0x65, 0x6e, 0x74, 0x12, 0x57, 0x0a, 0x0c, 0x71, 0x75, 0x65, 0x75, 0x65, 0x5f, 0x73, 0x74,
+    0x61, 0x74, 0x75, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x32, 0x2e, 0x72, 0x75, 0x6e,
+    0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61,
+    0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e,
+    0x76, 0x31, 0x2e, 0x51, 0x75, 0x65, 0x75, 0x65, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x48, 0x00,
+    0x52, 0x0b, 0x71, 0x75, 0x65, 0x75, 0x65, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x54, 0x0a,
+    0x0b, 0x6d, 0x61, 0x74, 0x63, 0x68, 0x5f, 0x66, 0x6f, 0x75, 0x6e, 0x64, 0x18, 0x06, 0x20, 0x01,
+    0x28, 0x0b, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74,
+    0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77,
+    0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x4d, 0x61, 0x74, 0x63, 0x68,
+    0x46, 0x6f, 0x75, 0x6e, 0x64, 0x48, 0x00, 0x52, 0x0a, 0x6d, 0x61, 0x74, 0x63, 0x68, 0x46, 0x6f,
+    0x75, 0x6e, 0x64, 0x12, 0x56, 0x0a, 0x0b, 0x6c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61,
+    0x72, 0x64, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x32, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63,
+    0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74,
+    0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31,
+    0x2e, 0x4c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x48, 0x00, 0x52, 0x0b,
+    0x6c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x12, 0x6f, 0x0a, 0x14, 0x61,
+    0x63, 0x68, 0x69, 0x65, 0x76, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x5f, 0x75, 0x6e, 0x6c, 0x6f, 0x63,
+    0x6b, 0x65, 0x64, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x3a, 0x2e, 0x72, 0x75, 0x6e, 0x65,
+    0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73,
+    0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76,
+    0x31, 0x2e, 0x41, 0x63, 0x68, 0x69, 0x65, 0x76, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x55, 0x6e, 0x6c,
+    0x6f, 0x63, 0x6b, 0x65, 0x64, 0x48, 0x00, 0x52, 0x13, 0x61, 0x63, 0x68, 0x69, 0x65, 0x76, 0x65,
+    0x6d, 0x65, 0x6e, 0x74, 0x55, 0x6e, 0x6c, 0x6f, 0x63, 0x6b, 0x65, 0x64, 0x42, 0x09, 0x0a, 0x07,
+    0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x22, 0xb8, 0x01, 0x0a, 0x0b, 0x51, 0x75, 0x65, 0x75,
+    0x65, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x4e, 0x0a, 0x09, 0x67, 0x61, 0x6d, 0x65, 0x5f,
+    0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e,
+    0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61,
+    0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65,
+    0x73, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x52, 0x08, 0x67,
+    0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x12, 0x1a, 0x0a, 0x08, 0x70, 0x6f, 0x73, 0x69, 0x74,
+    0x69, 0x6f, 0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x08, 0x70, 0x6f, 0x73, 0x69, 0x74,
+    0x69, 0x6f, 0x6e, 0x12, 0x16, 0x0a, 0x06, 0x71, 0x75, 0x65, 0x75, 0x65, 0x64, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x0d, 0x52, 0x06, 0x71, 0x75, 0x65, 0x75, 0x65, 0x64, 0x12, 0x25, 0x0a, 0x0e, 0x77,
+    0x61, 0x69, 0x74, 0x65, 0x64, 0x5f, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x18, 0x04, 0x20,
+    0x01, 0x28, 0x0d, 0x52, 0x0d, 0x77, 0x61, 0x69, 0x74, 0x65, 0x64, 0x53, 0x65, 0x63, 0x6f, 0x6e,
+    0x64, 0x73, 0x22, 0x85, 0x02, 0x0a, 0x0a, 0x4d, 0x61, 0x74, 0x63, 0x68, 0x46, 0x6f, 0x75, 0x6e,
+    0x64, 0x12, 0x55, 0x0a, 0x0a, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x36, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66,
+    0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c,
+    0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e,
+    0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x52, 0x09, 0x73,
+    0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x12, 0x4e, 0x0a, 0x09, 0x67, 0x61, 0x6d, 0x65,
+    0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x31, 0x2e, 0x72, 0x75,
+    0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70,
+    0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70,
+    0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x52, 0x08,
+    0x67, 0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x12, 0x50, 0x0a, 0x0a, 0x70, 0x6c, 0x61, 0x79,
+    0x65, 0x72, 0x5f, 0x69, 0x64, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x31, 0x2e, 0x72,
+    0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e,
+    0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79,
+    0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x49, 0x64, 0x52,
+    0x09, 0x70, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x49, 0x64, 0x73, 0x22, 0x98, 0x03, 0x0a, 0x0b, 0x4c,
+    0x65, 0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x12, 0x4e, 0x0a, 0x09, 0x67, 0x61,
+    0x6d, 0x65, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x31, 0x2e,
+    0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73,
+    0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74,
+    0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65,
+    0x52, 0x08, 0x67, 0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x72, 0x75,
+    0x6c, 0x65, 0x73, 0x65, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09,
+    0x72, 0x75, 0x6c, 0x65, 0x73, 0x65, 0x74, 0x49, 0x64, 0x12, 0x50, 0x0a, 0x06, 0x70, 0x65, 0x72,
+    0x69, 0x6f, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x38, 0x2e, 0x72, 0x75, 0x6e, 0x65,
+    0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73,
+    0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76,
+    0x31, 0x2e, 0x4c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x50, 0x65, 0x72,
+    0x69, 0x6f, 0x64, 0x52, 0x06, 0x70, 0x65, 0x72, 0x69, 0x6f, 0x64, 0x12, 0x16, 0x0a, 0x06, 0x77,
+    0x69, 0x6e, 0x64, 0x6f, 0x77, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x77, 0x69, 0x6e,
+    0x64, 0x6f, 0x77, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x18, 0x05, 0x20, 0x01,
+    0x28, 0x0d, 0x52, 0x05, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x12, 0x51, 0x0a, 0x07, 0x65, 0x6e, 0x74,
+    0x72, 0x69, 0x65, 0x73, 0x18, 0x06, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x37, 0x2e, 0x72, 0x75, 0x6e,
+    0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61,
+    0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e,
+    0x76, 0x31, 0x2e, 0x4c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x45, 0x6e,
+    0x74, 0x72, 0x79, 0x52, 0x07, 0x65, 0x6e, 0x74, 0x72, 0x69, 0x65, 0x73, 0x12, 0x47, 0x0a, 0x02,
+    0x6d, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x37, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63,
+    0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74,
+    0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31,
+    0x2e, 0x4c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x45, 0x6e, 0x74, 0x72,
+    0x79, 0x52, 0x02, 0x6d, 0x65, 0x22, 0xa0, 0x02, 0x0a, 0x10, 0x4c, 0x65, 0x61, 0x64, 0x65, 0x72,
+    0x62, 0x6f, 0x61, 0x72, 0x64, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x12, 0x0a, 0x04, 0x72, 0x61,
+    0x6e, 0x6b, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x04, 0x72, 0x61, 0x6e, 0x6b, 0x12, 0x4e,
+    0x0a, 0x09, 0x70, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28,
+    0x0b, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75,
+    0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61,
+    0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x6c, 0x61, 0x79,
+    0x65, 0x72, 0x49, 0x64, 0x52, 0x08, 0x70, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x49, 0x64, 0x12, 0x14,
+    0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x76,
+    0x61, 0x6c, 0x75, 0x65, 0x12, 0x3b, 0x0a, 0x0b, 0x61, 0x63, 0x68, 0x69, 0x65, 0x76, 0x65, 0x64,
+    0x5f, 0x61, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67,
+    0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65,
+    0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x0a, 0x61, 0x63, 0x68, 0x69, 0x65, 0x76, 0x65, 0x64, 0x41,
+    0x74, 0x12, 0x55, 0x0a, 0x0a, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18,
+    0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x36, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66,
+    0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c,
+    0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e,
+    0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x52, 0x09, 0x73,
+    0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x22, 0xa3, 0x02, 0x0a, 0x13, 0x41, 0x63, 0x68,
+    0x69, 0x65, 0x76, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x55, 0x6e, 0x6c, 0x6f, 0x63, 0x6b, 0x65, 0x64,
+    0x12, 0x25, 0x0a, 0x0e, 0x61, 0x63, 0x68, 0x69, 0x65, 0x76, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x5f,
+    0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0d, 0x61, 0x63, 0x68, 0x69, 0x65, 0x76,
+    0x65, 0x6d, 0x65, 0x6e, 0x74, 0x49, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18,
+    0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x20, 0x0a, 0x0b, 0x64,
+    0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x0b, 0x64, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1b, 0x0a,
+    0x09, 0x62, 0x61, 0x64, 0x67, 0x65, 0x5f, 0x75, 0x72, 0x6c, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x08, 0x62, 0x61, 0x64, 0x67, 0x65, 0x55, 0x72, 0x6c, 0x12, 0x3b, 0x0a, 0x0b, 0x75, 0x6e,
+    0x6c, 0x6f, 0x63, 0x6b, 0x65, 0x64, 0x5f, 0x61, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32,
+    0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75,
+    0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x0a, 0x75, 0x6e, 0x6c,
+    0x6f, 0x63, 0x6b, 0x65, 0x64, 0x41, 0x74, 0x12, 0x55, 0x0a, 0x0a, 0x73, 0x65, 0x73, 0x73, 0x69,
+    0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x36, 0x2e, 0x72, 0x75,
+    0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70,
+    0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70,
+    0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f,
+    0x6e, 0x49, 0x64, 0x52, 0x09, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x22, 0x66,
+    0x0a, 0x11, 0x47, 0x61, 0x6d, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x45, 0x6e, 0x76, 0x65, 0x6c,
+    0x6f, 0x70, 0x65, 0x12, 0x23, 0x0a, 0x0c, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x5f, 0x65, 0x76,
+    0x65, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x0b, 0x70, 0x75, 0x7a,
+    0x7a, 0x6c, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x23, 0x0a, 0x0c, 0x74, 0x72, 0x69, 0x76,
+    0x69, 0x61, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00,
+    0x52, 0x0b, 0x74, 0x72, 0x69, 0x76, 0x69, 0x61, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x42, 0x07, 0x0a,
+    0x05, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x2a, 0xbd, 0x01, 0x0a, 0x11, 0x4c, 0x65, 0x61, 0x64, 0x65,
+    0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x50, 0x65, 0x72, 0x69, 0x6f, 0x64, 0x12, 0x22, 0x0a, 0x1e,
+    0x4c, 0x45, 0x41, 0x44, 0x45, 0x52, 0x42, 0x4f, 0x41, 0x52, 0x44, 0x5f, 0x50, 0x45, 0x52, 0x49,
+    0x4f, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00,
+    0x12, 0x1f, 0x0a, 0x1b, 0x4c, 0x45, 0x41, 0x44, 0x45, 0x52, 0x42, 0x4f, 0x41, 0x52, 0x44, 0x5f,
+    0x50, 0x45, 0x52, 0x49, 0x4f, 0x44, 0x5f, 0x41, 0x4c, 0x4c, 0x5f, 0x54, 0x49, 0x4d, 0x45, 0x10,
+    0x01, 0x12, 0x1d, 0x0a, 0x19, 0x4c, 0x45, 0x41, 0x44, 0x45, 0x52, 0x42, 0x4f, 0x41, 0x52, 0x44,
+    0x5f, 0x50, 0x45, 0x52, 0x49, 0x4f, 0x44, 0x5f, 0x57, 0x45, 0x45, 0x4b, 0x4c, 0x59, 0x10, 0x02,
+    0x12, 0x1c, 0x0a, 0x18, 0x4c, 0x45, 0x41, 0x44, 0x45, 0x52, 0x42, 0x4f, 0x41, 0x52, 0x44, 0x5f,
+    0x50, 0x45, 0x52, 0x49, 0x4f, 0x44, 0x5f, 0x44, 0x41, 0x49, 0x4c, 0x59, 0x10, 0x03, 0x12, 0x26,
+    0x0a, 0x22, 0x4c, 0x45, 0x41, 0x44, 0x45, 0x52, 0x42, 0x4f, 0x41, 0x52, 0x44, 0x5f, 0x50, 0x45,
+    0x52, 0x49, 0x4f, 0x44, 0x5f, 0x44, 0x41, 0x49, 0x4c, 0x59, 0x5f, 0x43, 0x48, 0x41, 0x4c, 0x4c,
+    0x45, 0x4e, 0x47, 0x45, 0x10, 0x04, 0x4a, 0xb0, 0x2b, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00, 0x95,
+    0x01, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01,
+    0x02, 0x12, 0x03, 0x02, 0x00, 0x2e, 0x0a, 0x2a, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x05, 0x00,
+    0x41, 0x1a, 0x1f, 0x20, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x20, 0x67, 0x61, 0x6d, 0x65, 0x2d,
+    0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x63, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64,
+    0x73, 0x0a, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x03, 0x06, 0x00, 0x41, 0x0a, 0x25, 0x0a,
+    0x02, 0x03, 0x02, 0x12, 0x03, 0x08, 0x00, 0x3d, 0x1a, 0x1a, 0x20, 0x49, 0x6d, 0x70, 0x6f, 0x72,
+    0x74, 0x20, 0x73, 0x68, 0x61, 0x72, 0x65, 0x64, 0x20, 0x67, 0x61, 0x6d, 0x65, 0x20, 0x74, 0x79,
+    0x70, 0x65, 0x73, 0x0a, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x03, 0x12, 0x03, 0x09, 0x00, 0x29, 0x0a,
+    0x88, 0x01, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x0d, 0x00, 0x15, 0x01, 0x1a, 0x7c, 0x20, 0x43,
+    0x6c, 0x69, 0x65, 0x6e, 0x74, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x20, 0x69, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x70, 0x2d, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x20, 0x6d,
+    0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x20, 0x6f, 0x76, 0x65, 0x72, 0x20, 0x57, 0x65, 0x62, 0x53,
+    0x6f, 0x63, 0x6b, 0x65, 0x74, 0x2e, 0x0a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x52, 0x75, 0x73, 0x74,
+    0x20, 0x63, 0x6f, 0x64, 0x65, 0x20, 0x60, 0x77, 0x73, 0x2f, 0x6d, 0x6f, 0x64, 0x2e, 0x72, 0x73,
+    0x60, 0x20, 0x65, 0x78, 0x70, 0x65, 0x63, 0x74, 0x73, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x73,
+    0x74, 0x72, 0x75, 0x63, 0x74, 0x75, 0x72, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00,
+    0x01, 0x12, 0x03, 0x0d, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x00, 0x08, 0x00, 0x12, 0x04,
+    0x0e, 0x02, 0x14, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x08, 0x00, 0x01, 0x12, 0x03, 0x0e,
+    0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0f, 0x04, 0x24, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x0f, 0x04, 0x14, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0f, 0x15, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0f, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02,
+    0x01, 0x12, 0x03, 0x10, 0x04, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x06, 0x12,
+    0x03, 0x10, 0x04, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x10,
+    0x18, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x10, 0x27, 0x28,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x11, 0x04, 0x24, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x02, 0x06, 0x12, 0x03, 0x11, 0x04, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x11, 0x15, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x02, 0x03, 0x12, 0x03, 0x11, 0x22, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12,
+    0x03, 0x12, 0x04, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x06, 0x12, 0x03, 0x12,
+    0x04, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x12, 0x16, 0x21,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x12, 0x24, 0x25, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x13, 0x04, 0x2c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x04, 0x06, 0x12, 0x03, 0x13, 0x04, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x04, 0x01, 0x12, 0x03, 0x13, 0x18, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03,
+    0x12, 0x03, 0x13, 0x2a, 0x2b, 0x0a, 0x81, 0x01, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x19, 0x00,
+    0x1b, 0x01, 0x1a, 0x75, 0x20, 0x53, 0x65, 0x6e, 0x74, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x69, 0x6e, 0x69, 0x74, 0x69,
+    0x61, 0x74, 0x65, 0x20, 0x61, 0x20, 0x67, 0x61, 0x6d, 0x65, 0x20, 0x73, 0x65, 0x73, 0x73, 0x69,
+    0x6f, 0x6e, 0x2e, 0x0a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x52, 0x75, 0x73, 0x74, 0x20, 0x63, 0x6f,
+    0x64, 0x65, 0x20, 0x60, 0x77, 0x73, 0x2f, 0x6d, 0x6f, 0x64, 0x2e, 0x72, 0x73, 0x60, 0x20, 0x65,
+    0x78, 0x70, 0x65, 0x63, 0x74, 0x73, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x68,
+    0x61, 0x76, 0x65, 0x20, 0x61, 0x20, 0x60, 0x67, 0x61, 0x6d, 0x65, 0x5f, 0x74, 0x79, 0x70, 0x65,
+    0x60, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01,
+    0x12, 0x03, 0x19, 0x08, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x1a,
+    0x02, 0x41, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x1a, 0x02, 0x32,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1a, 0x33, 0x3c, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1a, 0x3f, 0x40, 0x0a, 0x64, 0x0a, 0x02,
+    0x04, 0x02, 0x12, 0x04, 0x1e, 0x00, 0x22, 0x01, 0x1a, 0x58, 0x20, 0x45, 0x6e, 0x74, 0x65, 0x72,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x71, 0x75, 0x69, 0x63, 0x6b, 0x2d, 0x70, 0x6c, 0x61, 0x79,
+    0x20, 0x71, 0x75, 0x65, 0x75, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x67, 0x61, 0x6d,
+    0x65, 0x20, 0x74, 0x79, 0x70, 0x65, 0x2e, 0x20, 0x4a, 0x6f, 0x69, 0x6e, 0x69, 0x6e, 0x67, 0x20,
+    0x61, 0x67, 0x61, 0x69, 0x6e, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x73, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x65, 0x61, 0x72, 0x6c, 0x69, 0x65, 0x72, 0x20, 0x65, 0x6e, 0x74, 0x72, 0x79,
+    0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x1e, 0x08, 0x18, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x1f, 0x02, 0x41, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x1f, 0x02, 0x32, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x1f, 0x33, 0x3c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x1f, 0x3f, 0x40, 0x0a, 0x58, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x21,
+    0x02, 0x1d, 0x1a, 0x4b, 0x20, 0x49, 0x67, 0x6e, 0x6f, 0x72, 0x65, 0x64, 0x3a, 0x20, 0x70, 0x6c,
+    0x61, 0x79, 0x65, 0x72, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x6d, 0x61, 0x74, 0x63, 0x68, 0x65,
+    0x64, 0x20, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6b, 0x69, 0x6c, 0x6c, 0x20, 0x72,
+    0x61, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x20, 0x6f, 0x6e, 0x20,
+    0x74, 0x68, 0x65, 0x69, 0x72, 0x20, 0x70, 0x72, 0x6f, 0x66, 0x69, 0x6c, 0x65, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x04, 0x12, 0x03, 0x21, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12, 0x03, 0x21, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x21, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x21, 0x1b, 0x1c, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x03, 0x24,
+    0x00, 0x1c, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x24, 0x08, 0x19, 0x0a, 0x0a,
+    0x0a, 0x02, 0x05, 0x00, 0x12, 0x04, 0x26, 0x00, 0x30, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00,
+    0x01, 0x12, 0x03, 0x26, 0x05, 0x16, 0x0a, 0x23, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03,
+    0x28, 0x02, 0x25, 0x1a, 0x16, 0x20, 0x54, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x61, 0x73,
+    0x20, 0x61, 0x6c, 0x6c, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x28, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02,
+    0x00, 0x02, 0x12, 0x03, 0x28, 0x23, 0x24, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01, 0x12,
+    0x03, 0x29, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x29,
+    0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x29, 0x20, 0x21,
+    0x0a, 0x37, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x02, 0x12, 0x03, 0x2b, 0x02, 0x20, 0x1a, 0x2a, 0x20,
+    0x43, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x49, 0x53, 0x4f, 0x20, 0x77, 0x65, 0x65, 0x6b,
+    0x2c, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x4d, 0x6f, 0x6e, 0x64, 0x61, 0x79, 0x20, 0x30, 0x30,
+    0x3a, 0x30, 0x30, 0x20, 0x55, 0x54, 0x43, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02,
+    0x02, 0x01, 0x12, 0x03, 0x2b, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x02,
+    0x12, 0x03, 0x2b, 0x1e, 0x1f, 0x0a, 0x22, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x03, 0x12, 0x03, 0x2d,
+    0x02, 0x1f, 0x1a, 0x15, 0x20, 0x43, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x64, 0x61, 0x79,
+    0x20, 0x69, 0x6e, 0x20, 0x55, 0x54, 0x43, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02,
+    0x03, 0x01, 0x12, 0x03, 0x2d, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x02,
+    0x12, 0x03, 0x2d, 0x1d, 0x1e, 0x0a, 0x57, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x04, 0x12, 0x03, 0x2f,
+    0x02, 0x29, 0x1a, 0x4a, 0x20, 0x54, 0x6f, 0x64, 0x61, 0x79, 0x27, 0x73, 0x20, 0x64, 0x61, 0x69,
+    0x6c, 0x79, 0x20, 0x63, 0x68, 0x61, 0x6c, 0x6c, 0x65, 0x6e, 0x67, 0x65, 0x3a, 0x20, 0x6f, 0x6e,
+    0x65, 0x20, 0x61, 0x74, 0x74, 0x65, 0x6d, 0x70, 0x74, 0x20, 0x70, 0x65, 0x72, 0x20, 0x70, 0x6c,
+    0x61, 0x79, 0x65, 0x72, 0x20, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d, 0x65,
+    0x20, 0x73, 0x65, 0x65, 0x64, 0x65, 0x64, 0x20, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x2f, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x2f, 0x27, 0x28, 0x0a, 0x47, 0x0a, 0x02, 0x04, 0x04,
+    0x12, 0x04, 0x33, 0x00, 0x3a, 0x01, 0x1a, 0x3b, 0x20, 0x52, 0x65, 0x61, 0x64, 0x73, 0x20, 0x61,
+    0x20, 0x6c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x3b, 0x20, 0x61, 0x6e,
+    0x73, 0x77, 0x65, 0x72, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x61, 0x20, 0x4c, 0x65,
+    0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67,
+    0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x33, 0x08, 0x1b, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x34, 0x02, 0x41, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x00, 0x06, 0x12, 0x03, 0x34, 0x02, 0x32, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x34, 0x33, 0x3c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x34, 0x3f, 0x40, 0x0a, 0x62, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03,
+    0x36, 0x02, 0x18, 0x1a, 0x55, 0x20, 0x4f, 0x6e, 0x6c, 0x79, 0x20, 0x73, 0x65, 0x73, 0x73, 0x69,
+    0x6f, 0x6e, 0x73, 0x20, 0x70, 0x6c, 0x61, 0x79, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20,
+    0x74, 0x68, 0x69, 0x73, 0x20, 0x72, 0x75, 0x6c, 0x65, 0x73, 0x65, 0x74, 0x3b, 0x20, 0x65, 0x6d,
+    0x70, 0x74, 0x79, 0x20, 0x72, 0x61, 0x6e, 0x6b, 0x73, 0x20, 0x65, 0x76, 0x65, 0x72, 0x79, 0x20,
+    0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67,
+    0x61, 0x6d, 0x65, 0x20, 0x74, 0x79, 0x70, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04,
+    0x02, 0x01, 0x05, 0x12, 0x03, 0x36, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x36, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12,
+    0x03, 0x36, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x02, 0x12, 0x03, 0x37, 0x02,
+    0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x06, 0x12, 0x03, 0x37, 0x02, 0x13, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x37, 0x14, 0x1a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x37, 0x1d, 0x1e, 0x0a, 0x3c, 0x0a, 0x04, 0x04,
+    0x04, 0x02, 0x03, 0x12, 0x03, 0x39, 0x02, 0x13, 0x1a, 0x2f, 0x20, 0x45, 0x6e, 0x74, 0x72, 0x69,
+    0x65, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x3b, 0x20, 0x30, 0x20,
+    0x75, 0x73, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x20,
+    0x64, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
+    0x03, 0x05, 0x12, 0x03, 0x39, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x01,
+    0x12, 0x03, 0x39, 0x09, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x03, 0x03, 0x12, 0x03,
+    0x39, 0x11, 0x12, 0x0a, 0x6f, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x3e, 0x00, 0x45, 0x01, 0x1a,
+    0x63, 0x20, 0x47, 0x61, 0x6d, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x45, 0x6e, 0x76,
+    0x65, 0x6c, 0x6f, 0x70, 0x65, 0x20, 0x77, 0x72, 0x61, 0x70, 0x73, 0x20, 0x61, 0x6c, 0x6c, 0x20,
+    0x69, 0x6e, 0x2d, 0x67, 0x61, 0x6d, 0x65, 0x20, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x2e,
+    0x0a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x52, 0x75, 0x73, 0x74, 0x20, 0x63, 0x6f, 0x64, 0x65, 0x20,
+    0x60, 0x77, 0x73, 0x2f, 0x6d, 0x6f, 0x64, 0x2e, 0x72, 0x73, 0x60, 0x20, 0x65, 0x78, 0x70, 0x65,
+    0x63, 0x74, 0x73, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x73, 0x74, 0x72, 0x75, 0x63, 0x74, 0x75,
+    0x72, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x3e, 0x08, 0x1b,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x05, 0x08, 0x00, 0x12, 0x04, 0x3f, 0x02, 0x44, 0x03, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x08, 0x00, 0x01, 0x12, 0x03, 0x3f, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x40, 0x04, 0x4e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x00, 0x06, 0x12, 0x03, 0x40, 0x04, 0x3d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x40, 0x3e, 0x49, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x40, 0x4c, 0x4d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01, 0x12, 0x03, 0x41, 0x04, 0x4d,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x06, 0x12, 0x03, 0x41, 0x04, 0x3c, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x41, 0x3d, 0x48, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x41, 0x4b, 0x4c, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05,
+    0x02, 0x02, 0x12, 0x03, 0x42, 0x04, 0x53, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x06,
+    0x12, 0x03, 0x42, 0x04, 0x40, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x42, 0x41, 0x4e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x42, 0x51,
+    0x52, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x03, 0x12, 0x03, 0x43, 0x04, 0x4f, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x03, 0x06, 0x12, 0x03, 0x43, 0x04, 0x3e, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x03, 0x01, 0x12, 0x03, 0x43, 0x3f, 0x4a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x03, 0x03, 0x12, 0x03, 0x43, 0x4d, 0x4e, 0x0a, 0x9c, 0x01, 0x0a, 0x02, 0x04, 0x06, 0x12,
+    0x04, 0x49, 0x00, 0x5c, 0x01, 0x1a, 0x8f, 0x01, 0x20, 0x53, 0x65, 0x72, 0x76, 0x65, 0x72, 0x45,
+    0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74,
+    0x6f, 0x70, 0x2d, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
+    0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72,
+    0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x2e, 0x0a,
+    0x20, 0x59, 0x6f, 0x75, 0x72, 0x20, 0x52, 0x75, 0x73, 0x74, 0x20, 0x63, 0x6f, 0x64, 0x65, 0x20,
+    0x77, 0x69, 0x6c, 0x6c, 0x20, 0x6e, 0x65, 0x65, 0x64, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x74,
+    0x6f, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x61, 0x6e,
+    0x64, 0x20, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x73,
+    0x20, 0x62, 0x61, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03,
+    0x49, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x06, 0x08, 0x00, 0x12, 0x04, 0x4a, 0x02, 0x5b,
+    0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x08, 0x00, 0x01, 0x12, 0x03, 0x4a, 0x08, 0x0f, 0x0a,
+    0x32, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x4c, 0x04, 0x1b, 0x1a, 0x25, 0x20, 0x65,
+    0x2e, 0x67, 0x2e, 0x2c, 0x20, 0x22, 0x41, 0x55, 0x54, 0x48, 0x5f, 0x53, 0x55, 0x43, 0x43, 0x45,
+    0x53, 0x53, 0x22, 0x2c, 0x20, 0x22, 0x41, 0x55, 0x54, 0x48, 0x5f, 0x46, 0x41, 0x49, 0x4c, 0x45,
+    0x44, 0x22, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x05, 0x12, 0x03, 0x4c, 0x04,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4c, 0x0b, 0x16, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x4c, 0x19, 0x1a, 0x0a, 0x39, 0x0a,
+    0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x4e, 0x04, 0x1a, 0x1a, 0x2c, 0x20, 0x65, 0x2e, 0x67,
+    0x2e, 0x2c, 0x20, 0x41, 0x20, 0x4a, 0x53, 0x4f, 0x4e, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x75, 0x6c, 0x6c, 0x20, 0x67, 0x61, 0x6d,
+    0x65, 0x20, 0x73, 0x74, 0x61, 0x74, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01,
+    0x05, 0x12, 0x03, 0x4e, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x4e, 0x0b, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x03, 0x4e,
+    0x18, 0x19, 0x0a, 0x28, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x02, 0x12, 0x03, 0x50, 0x04, 0x15, 0x1a,
+    0x1b, 0x20, 0x65, 0x2e, 0x67, 0x2e, 0x2c, 0x20, 0x22, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e,
+    0x20, 0x6e, 0x6f, 0x74, 0x20, 0x66, 0x6f, 0x75, 0x6e, 0x64, 0x22, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x02, 0x05, 0x12, 0x03, 0x50, 0x04, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x02, 0x01, 0x12, 0x03, 0x50, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02,
+    0x03, 0x12, 0x03, 0x50, 0x13, 0x14, 0x0a, 0x2a, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x03, 0x12, 0x03,
+    0x52, 0x04, 0x25, 0x1a, 0x1d, 0x20, 0x65, 0x2e, 0x67, 0x2e, 0x2c, 0x20, 0x41, 0x20, 0x73, 0x70,
+    0x65, 0x63, 0x69, 0x66, 0x69, 0x63, 0x20, 0x67, 0x61, 0x6d, 0x65, 0x20, 0x65, 0x76, 0x65, 0x6e,
+    0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x06, 0x12, 0x03, 0x52, 0x04, 0x15,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x01, 0x12, 0x03, 0x52, 0x16, 0x20, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x03, 0x03, 0x12, 0x03, 0x52, 0x23, 0x24, 0x0a, 0x38, 0x0a, 0x04,
+    0x04, 0x06, 0x02, 0x04, 0x12, 0x03, 0x54, 0x04, 0x21, 0x1a, 0x2b, 0x20, 0x4d, 0x61, 0x74, 0x63,
+    0x68, 0x6d, 0x61, 0x6b, 0x69, 0x6e, 0x67, 0x20, 0x70, 0x72, 0x6f, 0x67, 0x72, 0x65, 0x73, 0x73,
+    0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x71, 0x75, 0x65, 0x75, 0x65, 0x64, 0x20, 0x70, 0x6c,
+    0x61, 0x79, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x06, 0x12,
+    0x03, 0x54, 0x04, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x01, 0x12, 0x03, 0x54,
+    0x10, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x04, 0x03, 0x12, 0x03, 0x54, 0x1f, 0x20,
+    0x0a, 0x48, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x05, 0x12, 0x03, 0x56, 0x04, 0x1f, 0x1a, 0x3b, 0x20,
+    0x54, 0x68, 0x65, 0x20, 0x70, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x20, 0x77, 0x61, 0x73, 0x20, 0x70,
+    0x6c, 0x61, 0x63, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x61, 0x20, 0x73, 0x65, 0x73, 0x73, 0x69,
+    0x6f, 0x6e, 0x3b, 0x20, 0x61, 0x74, 0x74, 0x61, 0x63, 0x68, 0x20, 0x74, 0x6f, 0x20, 0x69, 0x74,
+    0x20, 0x74, 0x6f, 0x20, 0x70, 0x6c, 0x61, 0x79, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x05, 0x06, 0x12, 0x03, 0x56, 0x04, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05,
+    0x01, 0x12, 0x03, 0x56, 0x0f, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x03, 0x12,
+    0x03, 0x56, 0x1d, 0x1e, 0x0a, 0x2c, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x06, 0x12, 0x03, 0x58, 0x04,
+    0x20, 0x1a, 0x1f, 0x20, 0x52, 0x65, 0x70, 0x6c, 0x79, 0x20, 0x74, 0x6f, 0x20, 0x47, 0x65, 0x74,
+    0x4c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x51, 0x75, 0x65, 0x72, 0x79,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x06, 0x12, 0x03, 0x58, 0x04, 0x0f,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x01, 0x12, 0x03, 0x58, 0x10, 0x1b, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x03, 0x12, 0x03, 0x58, 0x1e, 0x1f, 0x0a, 0x30, 0x0a, 0x04,
+    0x04, 0x06, 0x02, 0x07, 0x12, 0x03, 0x5a, 0x04, 0x31, 0x1a, 0x23, 0x20, 0x54, 0x68, 0x65, 0x20,
+    0x70, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x20, 0x65, 0x61, 0x72, 0x6e, 0x65, 0x64, 0x20, 0x61, 0x6e,
+    0x20, 0x61, 0x63, 0x68, 0x69, 0x65, 0x76, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x07, 0x06, 0x12, 0x03, 0x5a, 0x04, 0x17, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x07, 0x01, 0x12, 0x03, 0x5a, 0x18, 0x2c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x07, 0x03, 0x12, 0x03, 0x5a, 0x2f, 0x30, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04,
+    0x5e, 0x00, 0x64, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x5e, 0x08, 0x13,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x5f, 0x02, 0x41, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x07, 0x02, 0x00, 0x06, 0x12, 0x03, 0x5f, 0x02, 0x32, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x5f, 0x33, 0x3c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x5f, 0x3f, 0x40, 0x0a, 0x49, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x01, 0x12,
+    0x03, 0x61, 0x02, 0x16, 0x1a, 0x3c, 0x20, 0x31, 0x2d, 0x62, 0x61, 0x73, 0x65, 0x64, 0x20, 0x70,
+    0x6c, 0x61, 0x63, 0x65, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x71, 0x75, 0x65, 0x75,
+    0x65, 0x3b, 0x20, 0x30, 0x20, 0x6f, 0x6e, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6c,
+    0x61, 0x79, 0x65, 0x72, 0x20, 0x68, 0x61, 0x73, 0x20, 0x6c, 0x65, 0x66, 0x74, 0x20, 0x69, 0x74,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x05, 0x12, 0x03, 0x61, 0x02, 0x08,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01, 0x12, 0x03, 0x61, 0x09, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x03, 0x61, 0x14, 0x15, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x07, 0x02, 0x02, 0x12, 0x03, 0x62, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
+    0x02, 0x05, 0x12, 0x03, 0x62, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x01,
+    0x12, 0x03, 0x62, 0x09, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x03, 0x12, 0x03,
+    0x62, 0x12, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x03, 0x12, 0x03, 0x63, 0x02, 0x1c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x05, 0x12, 0x03, 0x63, 0x02, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x01, 0x12, 0x03, 0x63, 0x09, 0x17, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x03, 0x03, 0x12, 0x03, 0x63, 0x1a, 0x1b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x08,
+    0x12, 0x04, 0x66, 0x00, 0x6a, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x03, 0x66,
+    0x08, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x67, 0x02, 0x47, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x06, 0x12, 0x03, 0x67, 0x02, 0x37, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x67, 0x38, 0x42, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x67, 0x45, 0x46, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02,
+    0x01, 0x12, 0x03, 0x68, 0x02, 0x41, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x06, 0x12,
+    0x03, 0x68, 0x02, 0x32, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x03, 0x68,
+    0x33, 0x3c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x03, 0x68, 0x3f, 0x40,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x02, 0x12, 0x03, 0x69, 0x02, 0x4b, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x02, 0x04, 0x12, 0x03, 0x69, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x02, 0x06, 0x12, 0x03, 0x69, 0x0b, 0x3b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x02, 0x01, 0x12, 0x03, 0x69, 0x3c, 0x46, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x03,
+    0x12, 0x03, 0x69, 0x49, 0x4a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x6c, 0x00, 0x77,
+    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x6c, 0x08, 0x13, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x6d, 0x02, 0x41, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
+    0x02, 0x00, 0x06, 0x12, 0x03, 0x6d, 0x02, 0x32, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x6d, 0x33, 0x3c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x6d, 0x3f, 0x40, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x03, 0x6e, 0x02,
+    0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x05, 0x12, 0x03, 0x6e, 0x02, 0x08, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x6e, 0x09, 0x13, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x03, 0x6e, 0x16, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x09, 0x02, 0x02, 0x12, 0x03, 0x6f, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02,
+    0x06, 0x12, 0x03, 0x6f, 0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x6f, 0x14, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x03, 0x12, 0x03, 0x6f,
+    0x1d, 0x1e, 0x0a, 0x5c, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x03, 0x12, 0x03, 0x71, 0x02, 0x14, 0x1a,
+    0x4f, 0x20, 0x57, 0x69, 0x6e, 0x64, 0x6f, 0x77, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6f, 0x61,
+    0x72, 0x64, 0x20, 0x63, 0x6f, 0x76, 0x65, 0x72, 0x73, 0x2c, 0x20, 0x65, 0x2e, 0x67, 0x2e, 0x20,
+    0x22, 0x32, 0x30, 0x32, 0x34, 0x2d, 0x57, 0x30, 0x37, 0x22, 0x20, 0x6f, 0x72, 0x20, 0x22, 0x32,
+    0x30, 0x32, 0x34, 0x2d, 0x30, 0x32, 0x2d, 0x31, 0x34, 0x22, 0x3b, 0x20, 0x22, 0x61, 0x6c, 0x6c,
+    0x22, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x05, 0x12, 0x03, 0x71, 0x02, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x01, 0x12, 0x03, 0x71, 0x09, 0x0f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x03, 0x03, 0x12, 0x03, 0x71, 0x12, 0x13, 0x0a, 0x2b, 0x0a, 0x04, 0x04, 0x09,
+    0x02, 0x04, 0x12, 0x03, 0x73, 0x02, 0x13, 0x1a, 0x1e, 0x20, 0x50, 0x6c, 0x61, 0x79, 0x65, 0x72,
+    0x73, 0x20, 0x72, 0x61, 0x6e, 0x6b, 0x65, 0x64, 0x20, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x62, 0x6f, 0x61, 0x72, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x05,
+    0x12, 0x03, 0x73, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x01, 0x12, 0x03,
+    0x73, 0x09, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x04, 0x03, 0x12, 0x03, 0x73, 0x11,
+    0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x05, 0x12, 0x03, 0x74, 0x02, 0x28, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x05, 0x04, 0x12, 0x03, 0x74, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x05, 0x06, 0x12, 0x03, 0x74, 0x0b, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
+    0x02, 0x05, 0x01, 0x12, 0x03, 0x74, 0x1c, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x05,
+    0x03, 0x12, 0x03, 0x74, 0x26, 0x27, 0x0a, 0x55, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x06, 0x12, 0x03,
+    0x76, 0x02, 0x1a, 0x1a, 0x48, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x65, 0x72,
+    0x27, 0x73, 0x20, 0x6f, 0x77, 0x6e, 0x20, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x3b,
+    0x20, 0x75, 0x6e, 0x73, 0x65, 0x74, 0x20, 0x69, 0x66, 0x20, 0x74, 0x68, 0x65, 0x79, 0x20, 0x68,
+    0x61, 0x76, 0x65, 0x20, 0x6e, 0x6f, 0x20, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x20, 0x6f, 0x6e,
+    0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x06, 0x06, 0x12, 0x03, 0x76, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x06, 0x01, 0x12, 0x03, 0x76, 0x13, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
+    0x06, 0x03, 0x12, 0x03, 0x76, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x05, 0x79,
+    0x00, 0x80, 0x01, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x03, 0x79, 0x08, 0x18,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x03, 0x7a, 0x02, 0x12, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0a, 0x02, 0x00, 0x05, 0x12, 0x03, 0x7a, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0a, 0x02, 0x00, 0x01, 0x12, 0x03, 0x7a, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x7a, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x01, 0x12,
+    0x03, 0x7b, 0x02, 0x41, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x06, 0x12, 0x03, 0x7b,
+    0x02, 0x32, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x01, 0x12, 0x03, 0x7b, 0x33, 0x3c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x03, 0x12, 0x03, 0x7b, 0x3f, 0x40, 0x0a, 0x53,
+    0x0a, 0x04, 0x04, 0x0a, 0x02, 0x02, 0x12, 0x03, 0x7d, 0x02, 0x12, 0x1a, 0x46, 0x20, 0x46, 0x69,
+    0x6e, 0x61, 0x6c, 0x20, 0x73, 0x63, 0x6f, 0x72, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x72,
+    0x69, 0x76, 0x69, 0x61, 0x2c, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x69, 0x6f, 0x6e,
+    0x20, 0x74, 0x69, 0x6d, 0x65, 0x20, 0x69, 0x6e, 0x20, 0x6d, 0x69, 0x6c, 0x6c, 0x69, 0x73, 0x65,
+    0x63, 0x6f, 0x6e, 0x64, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65,
+    0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x05, 0x12, 0x03, 0x7d, 0x02,
+    0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x01, 0x12, 0x03, 0x7d, 0x08, 0x0d, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x03, 0x12, 0x03, 0x7d, 0x10, 0x11, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x0a, 0x02, 0x03, 0x12, 0x03, 0x7e, 0x02, 0x2c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
+    0x02, 0x03, 0x06, 0x12, 0x03, 0x7e, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03,
+    0x01, 0x12, 0x03, 0x7e, 0x1c, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x03, 0x12,
+    0x03, 0x7e, 0x2a, 0x2b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x04, 0x12, 0x03, 0x7f, 0x02,
+    0x47, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x03, 0x7f, 0x02, 0x37, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x03, 0x7f, 0x38, 0x42, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x03, 0x7f, 0x45, 0x46, 0x0a, 0x0c, 0x0a, 0x02, 0x04,
+    0x0b, 0x12, 0x06, 0x82, 0x01, 0x00, 0x8b, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0b, 0x01,
+    0x12, 0x04, 0x82, 0x01, 0x08, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x04,
+    0x83, 0x01, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x05, 0x12, 0x04, 0x83,
+    0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01, 0x12, 0x04, 0x83, 0x01,
+    0x09, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03, 0x12, 0x04, 0x83, 0x01, 0x1a,
+    0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x01, 0x12, 0x04, 0x84, 0x01, 0x02, 0x12, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x05, 0x12, 0x04, 0x84, 0x01, 0x02, 0x08, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x01, 0x12, 0x04, 0x84, 0x01, 0x09, 0x0d, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0b, 0x02, 0x01, 0x03, 0x12, 0x04, 0x84, 0x01, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x0b, 0x02, 0x02, 0x12, 0x04, 0x85, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b,
+    0x02, 0x02, 0x05, 0x12, 0x04, 0x85, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0x85, 0x01, 0x09, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02,
+    0x03, 0x12, 0x04, 0x85, 0x01, 0x17, 0x18, 0x0a, 0x3c, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x03, 0x12,
+    0x04, 0x87, 0x01, 0x02, 0x17, 0x1a, 0x2e, 0x20, 0x45, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x69, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x68, 0x69, 0x65, 0x76, 0x65, 0x6d, 0x65, 0x6e, 0x74,
+    0x20, 0x68, 0x61, 0x73, 0x20, 0x6e, 0x6f, 0x20, 0x62, 0x61, 0x64, 0x67, 0x65, 0x20, 0x69, 0x6d,
+    0x61, 0x67, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x03, 0x05, 0x12, 0x04,
+    0x87, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x03, 0x01, 0x12, 0x04, 0x87,
+    0x01, 0x09, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x03, 0x03, 0x12, 0x04, 0x87, 0x01,
+    0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x04, 0x12, 0x04, 0x88, 0x01, 0x02, 0x2c,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x04, 0x06, 0x12, 0x04, 0x88, 0x01, 0x02, 0x1b, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x04, 0x01, 0x12, 0x04, 0x88, 0x01, 0x1c, 0x27, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0b, 0x02, 0x04, 0x03, 0x12, 0x04, 0x88, 0x01, 0x2a, 0x2b, 0x0a, 0x36, 0x0a,
+    0x04, 0x04, 0x0b, 0x02, 0x05, 0x12, 0x04, 0x8a, 0x01, 0x02, 0x47, 0x1a, 0x28, 0x20, 0x53, 0x65,
+    0x73, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x68, 0x69, 0x65, 0x76,
+    0x65, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x77, 0x61, 0x73, 0x20, 0x65, 0x61, 0x72, 0x6e, 0x65, 0x64,
+    0x20, 0x69, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x05, 0x06, 0x12, 0x04,
+    0x8a, 0x01, 0x02, 0x37, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x05, 0x01, 0x12, 0x04, 0x8a,
+    0x01, 0x38, 0x42, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x05, 0x03, 0x12, 0x04, 0x8a, 0x01,
+    0x45, 0x46, 0x0a, 0x41, 0x0a, 0x02, 0x04, 0x0c, 0x12, 0x06, 0x8e, 0x01, 0x00, 0x95, 0x01, 0x01,
+    0x1a, 0x33, 0x20, 0x47, 0x61, 0x6d, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x45, 0x6e, 0x76, 0x65,
+    0x6c, 0x6f, 0x70, 0x65, 0x20, 0x77, 0x72, 0x61, 0x70, 0x73, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x67,
+    0x61, 0x6d, 0x65, 0x2d, 0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x63, 0x20, 0x65, 0x76, 0x65,
+    0x6e, 0x74, 0x73, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0c, 0x01, 0x12, 0x04, 0x8e, 0x01,
+    0x08, 0x19, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x0c, 0x08, 0x00, 0x12, 0x06, 0x8f, 0x01, 0x02, 0x94,
+    0x01, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x08, 0x00, 0x01, 0x12, 0x04, 0x8f, 0x01, 0x08,
+    0x0d, 0x0a, 0x2d, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x00, 0x12, 0x04, 0x91, 0x01, 0x04, 0x1c, 0x1a,
+    0x1f, 0x20, 0x50, 0x6c, 0x61, 0x63, 0x65, 0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x20, 0x66, 0x6f,
+    0x72, 0x20, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x05, 0x12, 0x04, 0x91, 0x01, 0x04, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x01, 0x12, 0x04, 0x91, 0x01, 0x0b, 0x17, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x03, 0x12, 0x04, 0x91, 0x01, 0x1a, 0x1b, 0x0a, 0x2d, 0x0a,
+    0x04, 0x04, 0x0c, 0x02, 0x01, 0x12, 0x04, 0x93, 0x01, 0x04, 0x1c, 0x1a, 0x1f, 0x20, 0x50, 0x6c,
+    0x61, 0x63, 0x65, 0x68, 0x6f, 0x6c, 0x64, 0x65, 0x72, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x72,
+    0x69, 0x76, 0x69, 0x61, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0c, 0x02, 0x01, 0x05, 0x12, 0x04, 0x93, 0x01, 0x04, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0c, 0x02, 0x01, 0x01, 0x12, 0x04, 0x93, 0x01, 0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c,
+    0x02, 0x01, 0x03, 0x12, 0x04, 0x93, 0x01, 0x1a, 0x1b, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+    0x33, 0x0a, 0xfb, 0x09, 0x0a, 0x33, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73,
+    0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f,
+    0x77, 0x65, 0x62, 0x2f, 0x67, 0x61, 0x6d, 0x65, 0x2f, 0x76, 0x31, 0x2f, 0x73, 0x65, 0x72, 0x76,
+    0x69, 0x63, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x25, 0x72, 0x75, 0x6e, 0x65, 0x63,
+    0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74,
+    0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31,
+    0x1a, 0x37, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69,
+    0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x67, 0x61, 0x6d, 0x65,
+    0x2f, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2f, 0x76, 0x31, 0x2f, 0x73, 0x65, 0x73, 0x73,
+    0x69, 0x6f, 0x6e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x34, 0x72, 0x75, 0x6e, 0x65, 0x63,
+    0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74,
+    0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x77, 0x65, 0x62, 0x2f, 0x67, 0x61, 0x6d, 0x65, 0x2f, 0x76, 0x31,
+    0x2f, 0x65, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x32,
+    0xc4, 0x03, 0x0a, 0x0b, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12,
+    0x9e, 0x01, 0x0a, 0x11, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65,
+    0x73, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x43, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66,
+    0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c,
+    0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x76,
+    0x31, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73,
+    0x69, 0x6f, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x44, 0x2e, 0x72, 0x75, 0x6e,
+    0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61,
+    0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x73, 0x65, 0x73, 0x73,
+    0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x47, 0x61, 0x6d,
+    0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x12, 0x95, 0x01, 0x0a, 0x0e, 0x47, 0x65, 0x74, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73,
+    0x69, 0x6f, 0x6e, 0x12, 0x40, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73,
+    0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e,
+    0x67, 0x61, 0x6d, 0x65, 0x2e, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e,
+    0x47, 0x65, 0x74, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65,
+    0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x41, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66,
+    0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c,
+    0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x76,
+    0x31, 0x2e, 0x47, 0x65, 0x74, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e,
+    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x7c, 0x0a, 0x08, 0x50, 0x6c, 0x61, 0x79,
+    0x47, 0x61, 0x6d, 0x65, 0x12, 0x35, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74,
+    0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f,
+    0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6c, 0x69,
+    0x65, 0x6e, 0x74, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x1a, 0x35, 0x2e, 0x72, 0x75,
+    0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70,
+    0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65,
+    0x2e, 0x76, 0x31, 0x2e, 0x53, 0x65, 0x72, 0x76, 0x65, 0x72, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f,
+    0x70, 0x65, 0x28, 0x01, 0x30, 0x01, 0x4a, 0xde, 0x04, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x13,
+    0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02,
+    0x12, 0x03, 0x02, 0x00, 0x2e, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04, 0x00, 0x41,
+    0x0a, 0x09, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x03, 0x05, 0x00, 0x3e, 0x0a, 0xb2, 0x01, 0x0a, 0x02,
+    0x06, 0x00, 0x12, 0x04, 0x09, 0x00, 0x13, 0x01, 0x1a, 0xa5, 0x01, 0x20, 0x47, 0x61, 0x6d, 0x65,
+    0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67,
+    0x52, 0x50, 0x43, 0x20, 0x73, 0x75, 0x72, 0x66, 0x61, 0x63, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x67, 0x61, 0x6d, 0x65, 0x20, 0x65, 0x6e, 0x67, 0x69, 0x6e, 0x65, 0x2c, 0x20,
+    0x66, 0x6f, 0x72, 0x20, 0x62, 0x61, 0x63, 0x6b, 0x65, 0x6e, 0x64, 0x20, 0x73, 0x65, 0x72, 0x76,
+    0x69, 0x63, 0x65, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x62, 0x6f, 0x74, 0x73, 0x2e, 0x0a, 0x20,
+    0x43, 0x61, 0x6c, 0x6c, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x61, 0x75, 0x74, 0x68, 0x65, 0x6e,
+    0x74, 0x69, 0x63, 0x61, 0x74, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x61, 0x6e, 0x20,
+    0x60, 0x61, 0x75, 0x74, 0x68, 0x6f, 0x72, 0x69, 0x7a, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x3a, 0x20,
+    0x42, 0x65, 0x61, 0x72, 0x65, 0x72, 0x20, 0x3c, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x3e, 0x60, 0x20,
+    0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x20, 0x65, 0x6e, 0x74, 0x72, 0x79, 0x2e, 0x0a,
+    0x0a, 0x0a, 0x0a, 0x03, 0x06, 0x00, 0x01, 0x12, 0x03, 0x09, 0x08, 0x13, 0x0a, 0x0c, 0x0a, 0x04,
+    0x06, 0x00, 0x02, 0x00, 0x12, 0x04, 0x0a, 0x02, 0x0b, 0x54, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x0a, 0x06, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00,
+    0x02, 0x12, 0x03, 0x0a, 0x18, 0x5a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x0b, 0x0f, 0x52, 0x0a, 0x0c, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x01, 0x12, 0x04, 0x0d, 0x02,
+    0x0e, 0x51, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0d, 0x06, 0x14,
+    0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x0d, 0x15, 0x54, 0x0a, 0x0c,
+    0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0e, 0x0f, 0x4f, 0x0a, 0xb2, 0x01, 0x0a,
+    0x04, 0x06, 0x00, 0x02, 0x02, 0x12, 0x03, 0x12, 0x02, 0x46, 0x1a, 0xa4, 0x01, 0x20, 0x50, 0x6c,
+    0x61, 0x79, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x65, 0x78, 0x69, 0x73, 0x74, 0x69, 0x6e, 0x67, 0x20,
+    0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x64, 0x20, 0x62,
+    0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x78, 0x2d, 0x67, 0x61, 0x6d, 0x65, 0x2d, 0x73, 0x65,
+    0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2d, 0x69, 0x64, 0x60, 0x20, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61,
+    0x74, 0x61, 0x20, 0x65, 0x6e, 0x74, 0x72, 0x79, 0x2e, 0x20, 0x43, 0x61, 0x72, 0x72, 0x69, 0x65,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x65, 0x6e, 0x76, 0x65,
+    0x6c, 0x6f, 0x70, 0x65, 0x73, 0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x57, 0x65, 0x62,
+    0x53, 0x6f, 0x63, 0x6b, 0x65, 0x74, 0x3a, 0x20, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x73,
+    0x20, 0x69, 0x6e, 0x2c, 0x20, 0x67, 0x61, 0x6d, 0x65, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x73,
+    0x20, 0x61, 0x6e, 0x64, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x73, 0x20, 0x6f, 0x75, 0x74, 0x2e,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x12, 0x06, 0x0e, 0x0a,
+    0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x12, 0x0f, 0x15, 0x0a, 0x0c, 0x0a,
+    0x05, 0x06, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x12, 0x16, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x06,
+    0x00, 0x02, 0x02, 0x06, 0x12, 0x03, 0x12, 0x2f, 0x35, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02,
+    0x02, 0x03, 0x12, 0x03, 0x12, 0x36, 0x44, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+];
+include!("runecraftstudios.pastello.web.game.v1.tonic.rs");
 // @@protoc_insertion_point(module)
@@ -1,7 +1,18 @@
-use crate::domain::game::Session;
+use crate::domain::achievement::{AchievementDefinition, PlayerAchievements};
+use crate::domain::anti_cheat::{AnswerSheet, CheatFlag};
+use crate::domain::daily::DailyAttempt;
+use crate::domain::game::{DomainEvent, GameType, Session};
+use crate::domain::leaderboard::{BoardId, LeaderboardEntry, RankedEntry};
+use crate::domain::profile::{PlayerProfile, PlayerStats};
+use crate::domain::question::{Question, QuestionID};
+use crate::domain::rating::PlayerRatings;
+use crate::domain::replay::{Recording, ReplayStep};
+use crate::domain::rules::{Ruleset, TriviaCategory};
 use async_trait::async_trait;
 use anyhow::Result;
-use std::any::Any; // Needed for EventBus trait
+use chrono::NaiveDate;
+use std::ops::Range;
+use tokio::sync::broadcast;
 
 #[async_trait]
 pub trait Clock: Send + Sync {
@@ -9,22 +20,245 @@ pub trait Clock: Send + Sync {
 }
 
 pub trait EventBus: Send + Sync {
-    fn publish(&self, _event: Box<dyn Any + Send>) -> Result<()> {
+    fn publish(&self, _event: Box<dyn DomainEvent>) -> Result<()> {
         Ok(())
     }
+
+    /// Receives every event published after the call; consumers filter by session.
+    fn subscribe(&self) -> broadcast::Receiver<Box<dyn DomainEvent>>;
 }
 
 #[async_trait]
 pub trait GameRepository: Send + Sync {
     async fn get(&self, id: &str) -> Result<Option<Session>>;
     async fn save(&self, id: &str, session: Session) -> Result<()>;
+    /// Sessions `player_id` hosts or is seated in.
+    async fn list_by_player(&self, player_id: &str) -> Result<Vec<Session>>;
+    /// The private room most recently given `code`, expired or not.
+    async fn get_by_join_code(&self, code: &str) -> Result<Option<Session>>;
+}
+
+/// Steps each session's engine has gone through, in order, for exporting replays.
+#[async_trait]
+pub trait SessionLogRepository: Send + Sync {
+    async fn append(&self, session_id: &str, step: ReplayStep) -> Result<()>;
+    /// Steps of a running session, or of a finished one while its recording is kept.
+    async fn steps(&self, session_id: &str) -> Result<Vec<ReplayStep>>;
+
+    /// Closes the log of a session whose engine was dropped, with the engine's last state.
+    /// Nothing more is appended, and the oldest recordings may be dropped to make room.
+    async fn finish(&self, session_id: &str, final_state: serde_json::Value) -> Result<()>;
+
+    /// The recording of a finished session, unless it was dropped.
+    async fn recording(&self, session_id: &str) -> Result<Option<Recording>>;
+}
+
+#[async_trait]
+pub trait QuestionRepository: Send + Sync {
+    /// Questions in any of `categories`, or every question when `categories` is empty.
+    async fn list(&self, categories: &[TriviaCategory]) -> Result<Vec<Question>>;
+
+    /// The stored questions among `ids`, in the order given.
+    async fn get(&self, ids: &[QuestionID]) -> Result<Vec<Question>>;
+
+    /// Adds `questions`, replacing any stored question with the same id.
+    async fn upsert(&self, questions: Vec<Question>) -> Result<()>;
+}
+
+#[async_trait]
+pub trait ProfileRepository: Send + Sync {
+    async fn get_profile(&self, player_id: &str) -> Result<Option<PlayerProfile>>;
+    async fn save_profile(&self, profile: PlayerProfile) -> Result<()>;
+    async fn get_stats(&self, player_id: &str) -> Result<Option<PlayerStats>>;
+    async fn save_stats(&self, stats: PlayerStats) -> Result<()>;
+    async fn get_ratings(&self, player_id: &str) -> Result<Option<PlayerRatings>>;
+    async fn save_ratings(&self, ratings: PlayerRatings) -> Result<()>;
+    async fn get_achievements(&self, player_id: &str) -> Result<Option<PlayerAchievements>>;
+    async fn save_achievements(&self, achievements: PlayerAchievements) -> Result<()>;
+}
+
+#[async_trait]
+pub trait LeaderboardRepository: Send + Sync {
+    /// Records `entry` if it improves the player's best on `board`. Returns whether it did.
+    async fn submit(&self, board: &BoardId, entry: LeaderboardEntry) -> Result<bool>;
+    async fn top(&self, board: &BoardId, limit: usize) -> Result<Vec<RankedEntry>>;
+    async fn rank_of(&self, board: &BoardId, player_id: &str) -> Result<Option<RankedEntry>>;
+    /// Number of players ranked on `board`.
+    async fn count(&self, board: &BoardId) -> Result<usize>;
+}
+
+#[async_trait]
+pub trait DailyChallengeRepository: Send + Sync {
+    async fn get_attempt(&self, player_id: &str, date: NaiveDate, game_type: &GameType) -> Result<Option<DailyAttempt>>;
+    /// Records `attempt` unless the player already has one for its date and game type.
+    /// Returns whether it did.
+    async fn record_attempt(&self, attempt: DailyAttempt) -> Result<bool>;
+    /// Ids of the questions `date`'s trivia challenge asks, in order, once they have been drawn.
+    async fn get_questions(&self, date: NaiveDate) -> Result<Option<Vec<QuestionID>>>;
+    /// Keeps `question_ids` as `date`'s questions unless some were kept already. Returns the
+    /// ones kept, so concurrent first attempts all play the same set.
+    async fn record_questions(&self, date: NaiveDate, question_ids: Vec<QuestionID>) -> Result<Vec<QuestionID>>;
+}
+
+#[async_trait]
+pub trait CheatFlagRepository: Send + Sync {
+    async fn record(&self, flags: Vec<CheatFlag>) -> Result<()>;
+    /// Flags, oldest first, narrowed to a player and/or a session when given.
+    async fn list(&self, player_id: Option<&str>, session_id: Option<&str>) -> Result<Vec<CheatFlag>>;
+    /// Keeps `sheet` and returns the sheets kept before it for the same questions. Sheets
+    /// finished before `since` are dropped and no longer returned.
+    async fn add_sheet(&self, sheet: AnswerSheet, since: chrono::DateTime<chrono::Utc>) -> Result<Vec<AnswerSheet>>;
+}
+
+#[async_trait]
+pub trait RulesetRepository: Send + Sync {
+    /// Resolves `id` to its latest version, or to an exact version when given as `id@version`.
+    async fn get(&self, id: &str) -> Result<Option<Ruleset>>;
+    async fn list(&self) -> Result<Vec<Ruleset>>;
+}
+
+#[async_trait]
+pub trait AchievementCatalogue: Send + Sync {
+    async fn list(&self) -> Result<Vec<AchievementDefinition>>;
 }
 
 pub trait IdGenerator: Send + Sync {
     fn new_id(&self) -> String;
+    /// Short code players type to join a private room; see `domain::room::JOIN_CODE_ALPHABET`.
+    fn new_join_code(&self) -> String;
 }
 
-#[async_trait]
+/// Source of randomness for engines. Draws are synchronous and allocation-free; everything is
+/// derived from `next_u64`, so a seeded adapter makes every method reproducible.
 pub trait Rng: Send + Sync {
-    async fn rand_int(&self, min: i32, max: i32) -> i32;
+    fn next_u64(&self) -> u64;
+
+    /// Uniform in `range`; an empty range yields its start.
+    fn range_u64(&self, range: Range<u64>) -> u64 {
+        let span = range.end.saturating_sub(range.start);
+        if span == 0 {
+            return range.start;
+        }
+        // Lemire's multiply-shift, redrawing the few values that would favour the low end.
+        let threshold = span.wrapping_neg() % span;
+        loop {
+            let product = self.next_u64() as u128 * span as u128;
+            if product as u64 >= threshold {
+                return range.start + (product >> 64) as u64;
+            }
+        }
+    }
+
+    /// Uniform in `[0, 1)`.
+    fn next_f64(&self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// `true` with probability `p`, clamped to `[0, 1]`; NaN counts as 0. Draws once whatever
+    /// `p` is, so later draws don't depend on it.
+    fn bool_with_probability(&self, p: f64) -> bool {
+        // `next_f64` is below 1, so `p = 1` always holds and `p = 0` never does.
+        self.next_f64() < p.clamp(0.0, 1.0)
+    }
+
+    /// Index into `weights` picked in proportion to its weight. Zero, negative and non-finite
+    /// weights are never picked; `None` when no weight is positive.
+    fn weighted_index(&self, weights: &[f64]) -> Option<usize> {
+        let usable = |w: f64| w.is_finite() && w > 0.0;
+        let total: f64 = weights.iter().copied().filter(|w| usable(*w)).sum();
+        if total <= 0.0 || !total.is_finite() {
+            return None;
+        }
+        let mut target = self.next_f64() * total;
+        for (i, weight) in weights.iter().copied().enumerate() {
+            if !usable(weight) {
+                continue;
+            }
+            if target < weight {
+                return Some(i);
+            }
+            target -= weight;
+        }
+        // Rounding can leave a sliver of `target`; it belongs to the last usable weight.
+        weights.iter().rposition(|w| usable(*w))
+    }
+}
+
+// Generic helpers live on the trait object so engines can call them through `Arc<dyn Rng>`.
+impl dyn Rng + '_ {
+    /// Fisher-Yates shuffle of `items` in place.
+    pub fn shuffle<T>(&self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.range_u64(0..i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+
+    /// A uniformly chosen element of `items`, or `None` if it is empty.
+    pub fn choose<'a, T>(&self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            return None;
+        }
+        items.get(self.range_u64(0..items.len() as u64) as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::outbound::rng::SeededRng;
+
+    #[test]
+    fn ranges_stay_in_bounds() {
+        let rng = SeededRng::new(1);
+        for _ in 0..1000 {
+            assert!((10..13).contains(&rng.range_u64(10..13)));
+        }
+        assert_eq!(rng.range_u64(5..5), 5);
+    }
+
+    #[test]
+    fn weighted_index_never_picks_unusable_weights() {
+        let rng = SeededRng::new(1);
+        let weights = [0.0, 2.0, f64::NAN, -1.0, 1.0];
+        let mut picked = [0; 5];
+        for _ in 0..3000 {
+            picked[rng.weighted_index(&weights).unwrap()] += 1;
+        }
+        assert_eq!((picked[0], picked[2], picked[3]), (0, 0, 0));
+        assert!(picked[1] > picked[4] * 3 / 2, "{:?}", picked);
+        assert_eq!(rng.weighted_index(&[0.0, f64::INFINITY]), None);
+        assert_eq!(rng.weighted_index(&[]), None);
+    }
+
+    #[test]
+    fn probabilities_are_clamped_and_hit_their_rate() {
+        let rng = SeededRng::new(1);
+        for _ in 0..1000 {
+            assert!(!rng.bool_with_probability(0.0));
+            assert!(!rng.bool_with_probability(-0.5));
+            assert!(!rng.bool_with_probability(f64::NAN));
+            assert!(rng.bool_with_probability(1.0));
+            assert!(rng.bool_with_probability(3.0));
+        }
+
+        let hits = |seed| {
+            let rng = SeededRng::new(seed);
+            (0..10_000).filter(|_| rng.bool_with_probability(0.3)).count()
+        };
+        assert!((2_800..3_200).contains(&hits(7)), "{}", hits(7));
+        assert_eq!(hits(7), hits(7));
+    }
+
+    #[test]
+    fn shuffle_and_choose_work_through_the_trait_object() {
+        let rng: &dyn Rng = &SeededRng::new(1);
+        let mut items: Vec<u32> = (0..20).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<u32>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..20).collect::<Vec<u32>>());
+        assert!(rng.choose::<u32>(&[]).is_none());
+        assert_eq!(rng.choose(&[9]), Some(&9));
+    }
 }
@@ -51,3 +51,53 @@ CREATE TABLE game.game_session_users (
   joined_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
   UNIQUE(game_session_id, user_id)
 );
+
+-- Public profile and lifetime stats of players, keyed by the id the identity provider issues
+CREATE TABLE auth.player_profiles (
+  player_id VARCHAR(128) PRIMARY KEY,
+  display_name VARCHAR(64) NOT NULL,
+  avatar_url VARCHAR(512),
+  preferences JSONB NOT NULL DEFAULT '{}',
+  created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE auth.player_stats (
+  player_id VARCHAR(128) NOT NULL,
+  game_type VARCHAR(32) NOT NULL,  -- e.g., 'Trivia', 'Puzzle'
+  stats JSONB NOT NULL,
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  PRIMARY KEY (player_id, game_type)
+);
+
+-- Glicko-2 rating and recent rating history of players per game type
+CREATE TABLE auth.player_ratings (
+  player_id VARCHAR(128) NOT NULL,
+  game_type VARCHAR(32) NOT NULL,  -- e.g., 'Trivia', 'Puzzle'
+  rating JSONB NOT NULL,
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  PRIMARY KEY (player_id, game_type)
+);
+
+-- Unlocked achievements and the progress towards the rest, one document per player
+CREATE TABLE auth.player_achievements (
+  player_id VARCHAR(128) PRIMARY KEY,
+  achievements JSONB NOT NULL,
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- One daily challenge attempt per player, date and game type
+CREATE TABLE game.daily_attempts (
+  player_id VARCHAR(128) NOT NULL,
+  date DATE NOT NULL,
+  game_type VARCHAR(32) NOT NULL,  -- e.g., 'Trivia', 'Puzzle'
+  session_id VARCHAR(64) NOT NULL,
+  started_at TIMESTAMPTZ NOT NULL,
+  PRIMARY KEY (player_id, date, game_type)
+);
+
+-- Questions each day's trivia challenge asks, in order, fixed when the day is first played
+CREATE TABLE game.daily_questions (
+  date DATE PRIMARY KEY,
+  question_ids TEXT[] NOT NULL
+);
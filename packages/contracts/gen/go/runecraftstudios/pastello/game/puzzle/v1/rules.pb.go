@@ -72,6 +72,57 @@ func (PuzzleDifficulty) EnumDescriptor() ([]byte, []int) {
 	return file_runecraftstudios_pastello_game_puzzle_v1_rules_proto_rawDescGZIP(), []int{0}
 }
 
+type PuzzleMode int32
+
+const (
+	PuzzleMode_PUZZLE_MODE_UNSPECIFIED PuzzleMode = 0
+	// Players share one board and take turns.
+	PuzzleMode_PUZZLE_MODE_TURN_BASED PuzzleMode = 1
+	// Every player solves their own identically seeded board.
+	PuzzleMode_PUZZLE_MODE_RACE PuzzleMode = 2
+)
+
+// Enum value maps for PuzzleMode.
+var (
+	PuzzleMode_name = map[int32]string{
+		0: "PUZZLE_MODE_UNSPECIFIED",
+		1: "PUZZLE_MODE_TURN_BASED",
+		2: "PUZZLE_MODE_RACE",
+	}
+	PuzzleMode_value = map[string]int32{
+		"PUZZLE_MODE_UNSPECIFIED": 0,
+		"PUZZLE_MODE_TURN_BASED":  1,
+		"PUZZLE_MODE_RACE":        2,
+	}
+)
+
+func (x PuzzleMode) Enum() *PuzzleMode {
+	p := new(PuzzleMode)
+	*p = x
+	return p
+}
+
+func (x PuzzleMode) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (PuzzleMode) Descriptor() protoreflect.EnumDescriptor {
+	return file_runecraftstudios_pastello_game_puzzle_v1_rules_proto_enumTypes[1].Descriptor()
+}
+
+func (PuzzleMode) Type() protoreflect.EnumType {
+	return &file_runecraftstudios_pastello_game_puzzle_v1_rules_proto_enumTypes[1]
+}
+
+func (x PuzzleMode) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use PuzzleMode.Descriptor instead.
+func (PuzzleMode) EnumDescriptor() ([]byte, []int) {
+	return file_runecraftstudios_pastello_game_puzzle_v1_rules_proto_rawDescGZIP(), []int{1}
+}
+
 type PuzzleRules struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
@@ -81,6 +132,7 @@ type PuzzleRules struct {
 	AllowHints       bool             `protobuf:"varint,2,opt,name=allow_hints,json=allowHints,proto3" json:"allow_hints,omitempty"`
 	TimeLimitSeconds uint32           `protobuf:"varint,3,opt,name=time_limit_seconds,json=timeLimitSeconds,proto3" json:"time_limit_seconds,omitempty"`
 	MaxPlayers       uint32           `protobuf:"varint,4,opt,name=max_players,json=maxPlayers,proto3" json:"max_players,omitempty"`
+	Mode             PuzzleMode       `protobuf:"varint,5,opt,name=mode,proto3,enum=runecraftstudios.pastello.game.puzzle.v1.PuzzleMode" json:"mode,omitempty"`
 }
 
 func (x *PuzzleRules) Reset() {
@@ -143,6 +195,13 @@ func (x *PuzzleRules) GetMaxPlayers() uint32 {
 	return 0
 }
 
+func (x *PuzzleRules) GetMode() PuzzleMode {
+	if x != nil {
+		return x.Mode
+	}
+	return PuzzleMode_PUZZLE_MODE_UNSPECIFIED
+}
+
 var File_runecraftstudios_pastello_game_puzzle_v1_rules_proto protoreflect.FileDescriptor
 
 var file_runecraftstudios_pastello_game_puzzle_v1_rules_proto_rawDesc = []byte{
@@ -152,7 +211,7 @@ var file_runecraftstudios_pastello_game_puzzle_v1_rules_proto_rawDesc = []byte{
 	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x28, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66,
 	0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c,
 	0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x2e, 0x76, 0x31,
-	0x22, 0xd9, 0x01, 0x0a, 0x0b, 0x50, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x52, 0x75, 0x6c, 0x65, 0x73,
+	0x22, 0xa3, 0x02, 0x0a, 0x0b, 0x50, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x52, 0x75, 0x6c, 0x65, 0x73,
 	0x12, 0x5a, 0x0a, 0x0a, 0x64, 0x69, 0x66, 0x66, 0x69, 0x63, 0x75, 0x6c, 0x74, 0x79, 0x18, 0x01,
 	0x20, 0x01, 0x28, 0x0e, 0x32, 0x3a, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74,
 	0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f,
@@ -165,40 +224,50 @@ var file_runecraftstudios_pastello_game_puzzle_v1_rules_proto_rawDesc = []byte{
 	0x6e, 0x64, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x10, 0x74, 0x69, 0x6d, 0x65, 0x4c,
 	0x69, 0x6d, 0x69, 0x74, 0x53, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x12, 0x1f, 0x0a, 0x0b, 0x6d,
 	0x61, 0x78, 0x5f, 0x70, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0d,
-	0x52, 0x0a, 0x6d, 0x61, 0x78, 0x50, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x73, 0x2a, 0x8b, 0x01, 0x0a,
-	0x10, 0x50, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x44, 0x69, 0x66, 0x66, 0x69, 0x63, 0x75, 0x6c, 0x74,
-	0x79, 0x12, 0x21, 0x0a, 0x1d, 0x50, 0x55, 0x5a, 0x5a, 0x4c, 0x45, 0x5f, 0x44, 0x49, 0x46, 0x46,
-	0x49, 0x43, 0x55, 0x4c, 0x54, 0x59, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49,
-	0x45, 0x44, 0x10, 0x00, 0x12, 0x1a, 0x0a, 0x16, 0x50, 0x55, 0x5a, 0x5a, 0x4c, 0x45, 0x5f, 0x44,
-	0x49, 0x46, 0x46, 0x49, 0x43, 0x55, 0x4c, 0x54, 0x59, 0x5f, 0x45, 0x41, 0x53, 0x59, 0x10, 0x01,
-	0x12, 0x1c, 0x0a, 0x18, 0x50, 0x55, 0x5a, 0x5a, 0x4c, 0x45, 0x5f, 0x44, 0x49, 0x46, 0x46, 0x49,
-	0x43, 0x55, 0x4c, 0x54, 0x59, 0x5f, 0x4d, 0x45, 0x44, 0x49, 0x55, 0x4d, 0x10, 0x02, 0x12, 0x1a,
+	0x52, 0x0a, 0x6d, 0x61, 0x78, 0x50, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x73, 0x12, 0x48, 0x0a, 0x04,
+	0x6d, 0x6f, 0x64, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x34, 0x2e, 0x72, 0x75, 0x6e,
+	0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61,
+	0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x70, 0x75, 0x7a, 0x7a,
+	0x6c, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x4d, 0x6f, 0x64, 0x65,
+	0x52, 0x04, 0x6d, 0x6f, 0x64, 0x65, 0x2a, 0x8b, 0x01, 0x0a, 0x10, 0x50, 0x75, 0x7a, 0x7a, 0x6c,
+	0x65, 0x44, 0x69, 0x66, 0x66, 0x69, 0x63, 0x75, 0x6c, 0x74, 0x79, 0x12, 0x21, 0x0a, 0x1d, 0x50,
+	0x55, 0x5a, 0x5a, 0x4c, 0x45, 0x5f, 0x44, 0x49, 0x46, 0x46, 0x49, 0x43, 0x55, 0x4c, 0x54, 0x59,
+	0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x1a,
 	0x0a, 0x16, 0x50, 0x55, 0x5a, 0x5a, 0x4c, 0x45, 0x5f, 0x44, 0x49, 0x46, 0x46, 0x49, 0x43, 0x55,
-	0x4c, 0x54, 0x59, 0x5f, 0x48, 0x41, 0x52, 0x44, 0x10, 0x03, 0x42, 0xf2, 0x02, 0x0a, 0x2c, 0x63,
-	0x6f, 0x6d, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64,
-	0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d,
-	0x65, 0x2e, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x2e, 0x76, 0x31, 0x42, 0x0a, 0x52, 0x75, 0x6c,
-	0x65, 0x73, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50, 0x01, 0x5a, 0x71, 0x67, 0x69, 0x74, 0x68, 0x75,
-	0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x2d,
-	0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f,
-	0x2f, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x73, 0x2f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61,
-	0x63, 0x74, 0x73, 0x2f, 0x67, 0x65, 0x6e, 0x2f, 0x67, 0x6f, 0x2f, 0x72, 0x75, 0x6e, 0x65, 0x63,
-	0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74,
-	0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x67, 0x61, 0x6d, 0x65, 0x2f, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65,
-	0x2f, 0x76, 0x31, 0x3b, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x76, 0x31, 0xa2, 0x02, 0x04, 0x52,
-	0x50, 0x47, 0x50, 0xaa, 0x02, 0x28, 0x52, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73,
-	0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x50, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e,
-	0x47, 0x61, 0x6d, 0x65, 0x2e, 0x50, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x2e, 0x56, 0x31, 0xca, 0x02,
-	0x28, 0x52, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f,
-	0x73, 0x5c, 0x50, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x5c, 0x47, 0x61, 0x6d, 0x65, 0x5c,
-	0x50, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x5c, 0x56, 0x31, 0xe2, 0x02, 0x34, 0x52, 0x75, 0x6e, 0x65,
-	0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x5c, 0x50, 0x61, 0x73,
-	0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x5c, 0x47, 0x61, 0x6d, 0x65, 0x5c, 0x50, 0x75, 0x7a, 0x7a, 0x6c,
-	0x65, 0x5c, 0x56, 0x31, 0x5c, 0x47, 0x50, 0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61,
-	0xea, 0x02, 0x2c, 0x52, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64,
-	0x69, 0x6f, 0x73, 0x3a, 0x3a, 0x50, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x3a, 0x3a, 0x47,
-	0x61, 0x6d, 0x65, 0x3a, 0x3a, 0x50, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x3a, 0x3a, 0x56, 0x31, 0x62,
-	0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+	0x4c, 0x54, 0x59, 0x5f, 0x45, 0x41, 0x53, 0x59, 0x10, 0x01, 0x12, 0x1c, 0x0a, 0x18, 0x50, 0x55,
+	0x5a, 0x5a, 0x4c, 0x45, 0x5f, 0x44, 0x49, 0x46, 0x46, 0x49, 0x43, 0x55, 0x4c, 0x54, 0x59, 0x5f,
+	0x4d, 0x45, 0x44, 0x49, 0x55, 0x4d, 0x10, 0x02, 0x12, 0x1a, 0x0a, 0x16, 0x50, 0x55, 0x5a, 0x5a,
+	0x4c, 0x45, 0x5f, 0x44, 0x49, 0x46, 0x46, 0x49, 0x43, 0x55, 0x4c, 0x54, 0x59, 0x5f, 0x48, 0x41,
+	0x52, 0x44, 0x10, 0x03, 0x2a, 0x5b, 0x0a, 0x0a, 0x50, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x4d, 0x6f,
+	0x64, 0x65, 0x12, 0x1b, 0x0a, 0x17, 0x50, 0x55, 0x5a, 0x5a, 0x4c, 0x45, 0x5f, 0x4d, 0x4f, 0x44,
+	0x45, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12,
+	0x1a, 0x0a, 0x16, 0x50, 0x55, 0x5a, 0x5a, 0x4c, 0x45, 0x5f, 0x4d, 0x4f, 0x44, 0x45, 0x5f, 0x54,
+	0x55, 0x52, 0x4e, 0x5f, 0x42, 0x41, 0x53, 0x45, 0x44, 0x10, 0x01, 0x12, 0x14, 0x0a, 0x10, 0x50,
+	0x55, 0x5a, 0x5a, 0x4c, 0x45, 0x5f, 0x4d, 0x4f, 0x44, 0x45, 0x5f, 0x52, 0x41, 0x43, 0x45, 0x10,
+	0x02, 0x42, 0xf2, 0x02, 0x0a, 0x2c, 0x63, 0x6f, 0x6d, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72,
+	0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65,
+	0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x2e,
+	0x76, 0x31, 0x42, 0x0a, 0x52, 0x75, 0x6c, 0x65, 0x73, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50, 0x01,
+	0x5a, 0x71, 0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f, 0x72, 0x75, 0x6e,
+	0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x2d, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70,
+	0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x73,
+	0x2f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x73, 0x2f, 0x67, 0x65, 0x6e, 0x2f, 0x67,
+	0x6f, 0x2f, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69,
+	0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x67, 0x61, 0x6d, 0x65,
+	0x2f, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x2f, 0x76, 0x31, 0x3b, 0x70, 0x75, 0x7a, 0x7a, 0x6c,
+	0x65, 0x76, 0x31, 0xa2, 0x02, 0x04, 0x52, 0x50, 0x47, 0x50, 0xaa, 0x02, 0x28, 0x52, 0x75, 0x6e,
+	0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x50, 0x61,
+	0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x2e, 0x50, 0x75, 0x7a, 0x7a,
+	0x6c, 0x65, 0x2e, 0x56, 0x31, 0xca, 0x02, 0x28, 0x52, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66,
+	0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x5c, 0x50, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c,
+	0x6f, 0x5c, 0x47, 0x61, 0x6d, 0x65, 0x5c, 0x50, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x5c, 0x56, 0x31,
+	0xe2, 0x02, 0x34, 0x52, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64,
+	0x69, 0x6f, 0x73, 0x5c, 0x50, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x5c, 0x47, 0x61, 0x6d,
+	0x65, 0x5c, 0x50, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x5c, 0x56, 0x31, 0x5c, 0x47, 0x50, 0x42, 0x4d,
+	0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x2c, 0x52, 0x75, 0x6e, 0x65, 0x63, 0x72,
+	0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x3a, 0x3a, 0x50, 0x61, 0x73, 0x74,
+	0x65, 0x6c, 0x6c, 0x6f, 0x3a, 0x3a, 0x47, 0x61, 0x6d, 0x65, 0x3a, 0x3a, 0x50, 0x75, 0x7a, 0x7a,
+	0x6c, 0x65, 0x3a, 0x3a, 0x56, 0x31, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
 }
 
 var (
@@ -213,19 +282,21 @@ func file_runecraftstudios_pastello_game_puzzle_v1_rules_proto_rawDescGZIP() []b
 	return file_runecraftstudios_pastello_game_puzzle_v1_rules_proto_rawDescData
 }
 
-var file_runecraftstudios_pastello_game_puzzle_v1_rules_proto_enumTypes = make([]protoimpl.EnumInfo, 1)
+var file_runecraftstudios_pastello_game_puzzle_v1_rules_proto_enumTypes = make([]protoimpl.EnumInfo, 2)
 var file_runecraftstudios_pastello_game_puzzle_v1_rules_proto_msgTypes = make([]protoimpl.MessageInfo, 1)
 var file_runecraftstudios_pastello_game_puzzle_v1_rules_proto_goTypes = []interface{}{
 	(PuzzleDifficulty)(0), // 0: runecraftstudios.pastello.game.puzzle.v1.PuzzleDifficulty
-	(*PuzzleRules)(nil),   // 1: runecraftstudios.pastello.game.puzzle.v1.PuzzleRules
+	(PuzzleMode)(0),       // 1: runecraftstudios.pastello.game.puzzle.v1.PuzzleMode
+	(*PuzzleRules)(nil),   // 2: runecraftstudios.pastello.game.puzzle.v1.PuzzleRules
 }
 var file_runecraftstudios_pastello_game_puzzle_v1_rules_proto_depIdxs = []int32{
 	0, // 0: runecraftstudios.pastello.game.puzzle.v1.PuzzleRules.difficulty:type_name -> runecraftstudios.pastello.game.puzzle.v1.PuzzleDifficulty
-	1, // [1:1] is the sub-list for method output_type
-	1, // [1:1] is the sub-list for method input_type
-	1, // [1:1] is the sub-list for extension type_name
-	1, // [1:1] is the sub-list for extension extendee
-	0, // [0:1] is the sub-list for field type_name
+	1, // 1: runecraftstudios.pastello.game.puzzle.v1.PuzzleRules.mode:type_name -> runecraftstudios.pastello.game.puzzle.v1.PuzzleMode
+	2, // [2:2] is the sub-list for method output_type
+	2, // [2:2] is the sub-list for method input_type
+	2, // [2:2] is the sub-list for extension type_name
+	2, // [2:2] is the sub-list for extension extendee
+	0, // [0:2] is the sub-list for field type_name
 }
 
 func init() { file_runecraftstudios_pastello_game_puzzle_v1_rules_proto_init() }
@@ -252,7 +323,7 @@ func file_runecraftstudios_pastello_game_puzzle_v1_rules_proto_init() {
 		File: protoimpl.DescBuilder{
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: file_runecraftstudios_pastello_game_puzzle_v1_rules_proto_rawDesc,
-			NumEnums:      1,
+			NumEnums:      2,
 			NumMessages:   1,
 			NumExtensions: 0,
 			NumServices:   0,
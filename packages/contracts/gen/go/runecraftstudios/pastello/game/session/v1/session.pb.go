@@ -35,6 +35,12 @@ type GameSession struct {
 	Status    v1.SessionStatus       `protobuf:"varint,4,opt,name=status,proto3,enum=runecraftstudios.pastello.game.types.v1.SessionStatus" json:"status,omitempty"`
 	CreatedAt *timestamppb.Timestamp `protobuf:"bytes,5,opt,name=created_at,json=createdAt,proto3" json:"created_at,omitempty"`
 	RulesetId string                 `protobuf:"bytes,6,opt,name=ruleset_id,json=rulesetId,proto3" json:"ruleset_id,omitempty"` // optional external reference
+	// Read-only participants; not counted toward max_players.
+	SpectatorIds []*v1.PlayerId `protobuf:"bytes,7,rep,name=spectator_ids,json=spectatorIds,proto3" json:"spectator_ids,omitempty"`
+	// Private rooms only, and only sent to the host: the code players join with, and when it stops working.
+	JoinCode          string                 `protobuf:"bytes,8,opt,name=join_code,json=joinCode,proto3" json:"join_code,omitempty"`
+	JoinCodeExpiresAt *timestamppb.Timestamp `protobuf:"bytes,9,opt,name=join_code_expires_at,json=joinCodeExpiresAt,proto3" json:"join_code_expires_at,omitempty"`
+	HasPassword       bool                   `protobuf:"varint,10,opt,name=has_password,json=hasPassword,proto3" json:"has_password,omitempty"`
 }
 
 func (x *GameSession) Reset() {
@@ -111,13 +117,91 @@ func (x *GameSession) GetRulesetId() string {
 	return ""
 }
 
+func (x *GameSession) GetSpectatorIds() []*v1.PlayerId {
+	if x != nil {
+		return x.SpectatorIds
+	}
+	return nil
+}
+
+func (x *GameSession) GetJoinCode() string {
+	if x != nil {
+		return x.JoinCode
+	}
+	return ""
+}
+
+func (x *GameSession) GetJoinCodeExpiresAt() *timestamppb.Timestamp {
+	if x != nil {
+		return x.JoinCodeExpiresAt
+	}
+	return nil
+}
+
+func (x *GameSession) GetHasPassword() bool {
+	if x != nil {
+		return x.HasPassword
+	}
+	return false
+}
+
+// Makes the new session a private room that players join by code.
+type PrivateRoomOptions struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// Optional; players must give it along with the code.
+	Password string `protobuf:"bytes,1,opt,name=password,proto3" json:"password,omitempty"`
+}
+
+func (x *PrivateRoomOptions) Reset() {
+	*x = PrivateRoomOptions{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes[1]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *PrivateRoomOptions) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*PrivateRoomOptions) ProtoMessage() {}
+
+func (x *PrivateRoomOptions) ProtoReflect() protoreflect.Message {
+	mi := &file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes[1]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use PrivateRoomOptions.ProtoReflect.Descriptor instead.
+func (*PrivateRoomOptions) Descriptor() ([]byte, []int) {
+	return file_runecraftstudios_pastello_game_session_v1_session_proto_rawDescGZIP(), []int{1}
+}
+
+func (x *PrivateRoomOptions) GetPassword() string {
+	if x != nil {
+		return x.Password
+	}
+	return ""
+}
+
 type CreateGameSessionRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	GameType  v1.GameType    `protobuf:"varint,1,opt,name=game_type,json=gameType,proto3,enum=runecraftstudios.pastello.game.types.v1.GameType" json:"game_type,omitempty"`
-	PlayerIds []*v1.PlayerId `protobuf:"bytes,2,rep,name=player_ids,json=playerIds,proto3" json:"player_ids,omitempty"`
+	GameType    v1.GameType         `protobuf:"varint,1,opt,name=game_type,json=gameType,proto3,enum=runecraftstudios.pastello.game.types.v1.GameType" json:"game_type,omitempty"`
+	PlayerIds   []*v1.PlayerId      `protobuf:"bytes,2,rep,name=player_ids,json=playerIds,proto3" json:"player_ids,omitempty"`
+	PrivateRoom *PrivateRoomOptions `protobuf:"bytes,3,opt,name=private_room,json=privateRoom,proto3" json:"private_room,omitempty"`
 	// Types that are assignable to Config:
 	//
 	//	*CreateGameSessionRequest_Trivia
@@ -129,7 +213,7 @@ type CreateGameSessionRequest struct {
 func (x *CreateGameSessionRequest) Reset() {
 	*x = CreateGameSessionRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes[1]
+		mi := &file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes[2]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -142,7 +226,7 @@ func (x *CreateGameSessionRequest) String() string {
 func (*CreateGameSessionRequest) ProtoMessage() {}
 
 func (x *CreateGameSessionRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes[1]
+	mi := &file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes[2]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -155,7 +239,7 @@ func (x *CreateGameSessionRequest) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use CreateGameSessionRequest.ProtoReflect.Descriptor instead.
 func (*CreateGameSessionRequest) Descriptor() ([]byte, []int) {
-	return file_runecraftstudios_pastello_game_session_v1_session_proto_rawDescGZIP(), []int{1}
+	return file_runecraftstudios_pastello_game_session_v1_session_proto_rawDescGZIP(), []int{2}
 }
 
 func (x *CreateGameSessionRequest) GetGameType() v1.GameType {
@@ -172,6 +256,13 @@ func (x *CreateGameSessionRequest) GetPlayerIds() []*v1.PlayerId {
 	return nil
 }
 
+func (x *CreateGameSessionRequest) GetPrivateRoom() *PrivateRoomOptions {
+	if x != nil {
+		return x.PrivateRoom
+	}
+	return nil
+}
+
 func (m *CreateGameSessionRequest) GetConfig() isCreateGameSessionRequest_Config {
 	if m != nil {
 		return m.Config
@@ -233,7 +324,7 @@ type CreateGameSessionResponse struct {
 func (x *CreateGameSessionResponse) Reset() {
 	*x = CreateGameSessionResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes[2]
+		mi := &file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes[3]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -246,7 +337,7 @@ func (x *CreateGameSessionResponse) String() string {
 func (*CreateGameSessionResponse) ProtoMessage() {}
 
 func (x *CreateGameSessionResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes[2]
+	mi := &file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes[3]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -259,7 +350,7 @@ func (x *CreateGameSessionResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use CreateGameSessionResponse.ProtoReflect.Descriptor instead.
 func (*CreateGameSessionResponse) Descriptor() ([]byte, []int) {
-	return file_runecraftstudios_pastello_game_session_v1_session_proto_rawDescGZIP(), []int{2}
+	return file_runecraftstudios_pastello_game_session_v1_session_proto_rawDescGZIP(), []int{3}
 }
 
 func (x *CreateGameSessionResponse) GetSession() *GameSession {
@@ -269,6 +360,100 @@ func (x *CreateGameSessionResponse) GetSession() *GameSession {
 	return nil
 }
 
+type GetGameSessionRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Id *v1.GameSessionId `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+}
+
+func (x *GetGameSessionRequest) Reset() {
+	*x = GetGameSessionRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes[4]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetGameSessionRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetGameSessionRequest) ProtoMessage() {}
+
+func (x *GetGameSessionRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes[4]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetGameSessionRequest.ProtoReflect.Descriptor instead.
+func (*GetGameSessionRequest) Descriptor() ([]byte, []int) {
+	return file_runecraftstudios_pastello_game_session_v1_session_proto_rawDescGZIP(), []int{4}
+}
+
+func (x *GetGameSessionRequest) GetId() *v1.GameSessionId {
+	if x != nil {
+		return x.Id
+	}
+	return nil
+}
+
+type GetGameSessionResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Session *GameSession `protobuf:"bytes,1,opt,name=session,proto3" json:"session,omitempty"`
+}
+
+func (x *GetGameSessionResponse) Reset() {
+	*x = GetGameSessionResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes[5]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetGameSessionResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetGameSessionResponse) ProtoMessage() {}
+
+func (x *GetGameSessionResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes[5]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetGameSessionResponse.ProtoReflect.Descriptor instead.
+func (*GetGameSessionResponse) Descriptor() ([]byte, []int) {
+	return file_runecraftstudios_pastello_game_session_v1_session_proto_rawDescGZIP(), []int{5}
+}
+
+func (x *GetGameSessionResponse) GetSession() *GameSession {
+	if x != nil {
+		return x.Session
+	}
+	return nil
+}
+
 var File_runecraftstudios_pastello_game_session_v1_session_proto protoreflect.FileDescriptor
 
 var file_runecraftstudios_pastello_game_session_v1_session_proto_rawDesc = []byte{
@@ -290,7 +475,7 @@ var file_runecraftstudios_pastello_game_session_v1_session_proto_rawDesc = []byt
 	0x1a, 0x34, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69,
 	0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x67, 0x61, 0x6d, 0x65,
 	0x2f, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x2f, 0x76, 0x31, 0x2f, 0x72, 0x75, 0x6c, 0x65, 0x73,
-	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0xa1, 0x03, 0x0a, 0x0b, 0x47, 0x61, 0x6d, 0x65, 0x53,
+	0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x86, 0x05, 0x0a, 0x0b, 0x47, 0x61, 0x6d, 0x65, 0x53,
 	0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x46, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
 	0x28, 0x0b, 0x32, 0x36, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74,
 	0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67,
@@ -316,63 +501,100 @@ var file_runecraftstudios_pastello_game_session_v1_session_proto_rawDesc = []byt
 	0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70,
 	0x52, 0x09, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x41, 0x74, 0x12, 0x1d, 0x0a, 0x0a, 0x72,
 	0x75, 0x6c, 0x65, 0x73, 0x65, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x52,
-	0x09, 0x72, 0x75, 0x6c, 0x65, 0x73, 0x65, 0x74, 0x49, 0x64, 0x22, 0x89, 0x03, 0x0a, 0x18, 0x43,
-	0x72, 0x65, 0x61, 0x74, 0x65, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e,
-	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x4e, 0x0a, 0x09, 0x67, 0x61, 0x6d, 0x65, 0x5f,
-	0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e,
-	0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61,
-	0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65,
-	0x73, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x52, 0x08, 0x67,
-	0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x12, 0x50, 0x0a, 0x0a, 0x70, 0x6c, 0x61, 0x79, 0x65,
-	0x72, 0x5f, 0x69, 0x64, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x31, 0x2e, 0x72, 0x75,
-	0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70,
-	0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70,
-	0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x49, 0x64, 0x52, 0x09,
-	0x70, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x49, 0x64, 0x73, 0x12, 0x4f, 0x0a, 0x06, 0x74, 0x72, 0x69,
-	0x76, 0x69, 0x61, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x35, 0x2e, 0x72, 0x75, 0x6e, 0x65,
-	0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73,
-	0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x72, 0x69, 0x76, 0x69,
-	0x61, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x72, 0x69, 0x76, 0x69, 0x61, 0x52, 0x75, 0x6c, 0x65, 0x73,
-	0x48, 0x00, 0x52, 0x06, 0x74, 0x72, 0x69, 0x76, 0x69, 0x61, 0x12, 0x4f, 0x0a, 0x06, 0x70, 0x75,
-	0x7a, 0x7a, 0x6c, 0x65, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x35, 0x2e, 0x72, 0x75, 0x6e,
+	0x09, 0x72, 0x75, 0x6c, 0x65, 0x73, 0x65, 0x74, 0x49, 0x64, 0x12, 0x56, 0x0a, 0x0d, 0x73, 0x70,
+	0x65, 0x63, 0x74, 0x61, 0x74, 0x6f, 0x72, 0x5f, 0x69, 0x64, 0x73, 0x18, 0x07, 0x20, 0x03, 0x28,
+	0x0b, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75,
+	0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61,
+	0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x6c, 0x61, 0x79,
+	0x65, 0x72, 0x49, 0x64, 0x52, 0x0c, 0x73, 0x70, 0x65, 0x63, 0x74, 0x61, 0x74, 0x6f, 0x72, 0x49,
+	0x64, 0x73, 0x12, 0x1b, 0x0a, 0x09, 0x6a, 0x6f, 0x69, 0x6e, 0x5f, 0x63, 0x6f, 0x64, 0x65, 0x18,
+	0x08, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x6a, 0x6f, 0x69, 0x6e, 0x43, 0x6f, 0x64, 0x65, 0x12,
+	0x4b, 0x0a, 0x14, 0x6a, 0x6f, 0x69, 0x6e, 0x5f, 0x63, 0x6f, 0x64, 0x65, 0x5f, 0x65, 0x78, 0x70,
+	0x69, 0x72, 0x65, 0x73, 0x5f, 0x61, 0x74, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e,
+	0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e,
+	0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x11, 0x6a, 0x6f, 0x69, 0x6e, 0x43,
+	0x6f, 0x64, 0x65, 0x45, 0x78, 0x70, 0x69, 0x72, 0x65, 0x73, 0x41, 0x74, 0x12, 0x21, 0x0a, 0x0c,
+	0x68, 0x61, 0x73, 0x5f, 0x70, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x18, 0x0a, 0x20, 0x01,
+	0x28, 0x08, 0x52, 0x0b, 0x68, 0x61, 0x73, 0x50, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72, 0x64, 0x22,
+	0x30, 0x0a, 0x12, 0x50, 0x72, 0x69, 0x76, 0x61, 0x74, 0x65, 0x52, 0x6f, 0x6f, 0x6d, 0x4f, 0x70,
+	0x74, 0x69, 0x6f, 0x6e, 0x73, 0x12, 0x1a, 0x0a, 0x08, 0x70, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72,
+	0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x70, 0x61, 0x73, 0x73, 0x77, 0x6f, 0x72,
+	0x64, 0x22, 0xeb, 0x03, 0x0a, 0x18, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x47, 0x61, 0x6d, 0x65,
+	0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x4e,
+	0x0a, 0x09, 0x67, 0x61, 0x6d, 0x65, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x0e, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75,
+	0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61,
+	0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65,
+	0x54, 0x79, 0x70, 0x65, 0x52, 0x08, 0x67, 0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x12, 0x50,
+	0x0a, 0x0a, 0x70, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x73, 0x18, 0x02, 0x20, 0x03,
+	0x28, 0x0b, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74,
+	0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67,
+	0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x6c, 0x61,
+	0x79, 0x65, 0x72, 0x49, 0x64, 0x52, 0x09, 0x70, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x49, 0x64, 0x73,
+	0x12, 0x60, 0x0a, 0x0c, 0x70, 0x72, 0x69, 0x76, 0x61, 0x74, 0x65, 0x5f, 0x72, 0x6f, 0x6f, 0x6d,
+	0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x3d, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61,
+	0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c,
+	0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2e,
+	0x76, 0x31, 0x2e, 0x50, 0x72, 0x69, 0x76, 0x61, 0x74, 0x65, 0x52, 0x6f, 0x6f, 0x6d, 0x4f, 0x70,
+	0x74, 0x69, 0x6f, 0x6e, 0x73, 0x52, 0x0b, 0x70, 0x72, 0x69, 0x76, 0x61, 0x74, 0x65, 0x52, 0x6f,
+	0x6f, 0x6d, 0x12, 0x4f, 0x0a, 0x06, 0x74, 0x72, 0x69, 0x76, 0x69, 0x61, 0x18, 0x0a, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x35, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74,
+	0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67,
+	0x61, 0x6d, 0x65, 0x2e, 0x74, 0x72, 0x69, 0x76, 0x69, 0x61, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x72,
+	0x69, 0x76, 0x69, 0x61, 0x52, 0x75, 0x6c, 0x65, 0x73, 0x48, 0x00, 0x52, 0x06, 0x74, 0x72, 0x69,
+	0x76, 0x69, 0x61, 0x12, 0x4f, 0x0a, 0x06, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x18, 0x0b, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x35, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73,
+	0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e,
+	0x67, 0x61, 0x6d, 0x65, 0x2e, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x50,
+	0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x52, 0x75, 0x6c, 0x65, 0x73, 0x48, 0x00, 0x52, 0x06, 0x70, 0x75,
+	0x7a, 0x7a, 0x6c, 0x65, 0x12, 0x1f, 0x0a, 0x0a, 0x72, 0x75, 0x6c, 0x65, 0x73, 0x65, 0x74, 0x5f,
+	0x69, 0x64, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x09, 0x72, 0x75, 0x6c, 0x65,
+	0x73, 0x65, 0x74, 0x49, 0x64, 0x42, 0x08, 0x0a, 0x06, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x22,
+	0x6d, 0x0a, 0x19, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73,
+	0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x50, 0x0a, 0x07,
+	0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x36, 0x2e,
+	0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73,
+	0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x73,
+	0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65,
+	0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x07, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0x5f,
+	0x0a, 0x15, 0x47, 0x65, 0x74, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x46, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x36, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73,
+	0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e,
+	0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61,
+	0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x52, 0x02, 0x69, 0x64, 0x22,
+	0x6a, 0x0a, 0x16, 0x47, 0x65, 0x74, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f,
+	0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x50, 0x0a, 0x07, 0x73, 0x65, 0x73,
+	0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x36, 0x2e, 0x72, 0x75, 0x6e,
 	0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61,
-	0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x70, 0x75, 0x7a, 0x7a,
-	0x6c, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x52, 0x75, 0x6c, 0x65,
-	0x73, 0x48, 0x00, 0x52, 0x06, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x12, 0x1f, 0x0a, 0x0a, 0x72,
-	0x75, 0x6c, 0x65, 0x73, 0x65, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x09, 0x48,
-	0x00, 0x52, 0x09, 0x72, 0x75, 0x6c, 0x65, 0x73, 0x65, 0x74, 0x49, 0x64, 0x42, 0x08, 0x0a, 0x06,
-	0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x22, 0x6d, 0x0a, 0x19, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65,
-	0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f,
-	0x6e, 0x73, 0x65, 0x12, 0x50, 0x0a, 0x07, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01,
-	0x20, 0x01, 0x28, 0x0b, 0x32, 0x36, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74,
-	0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f,
-	0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31,
-	0x2e, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x07, 0x73, 0x65,
-	0x73, 0x73, 0x69, 0x6f, 0x6e, 0x42, 0xfb, 0x02, 0x0a, 0x2d, 0x63, 0x6f, 0x6d, 0x2e, 0x72, 0x75,
-	0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70,
-	0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x73, 0x65, 0x73,
-	0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x42, 0x0c, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e,
-	0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50, 0x01, 0x5a, 0x73, 0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x2e,
-	0x63, 0x6f, 0x6d, 0x2f, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x2d, 0x73, 0x74,
-	0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x70,
-	0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x73, 0x2f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74,
-	0x73, 0x2f, 0x67, 0x65, 0x6e, 0x2f, 0x67, 0x6f, 0x2f, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61,
-	0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c,
-	0x6c, 0x6f, 0x2f, 0x67, 0x61, 0x6d, 0x65, 0x2f, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2f,
-	0x76, 0x31, 0x3b, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x76, 0x31, 0xa2, 0x02, 0x04, 0x52,
-	0x50, 0x47, 0x53, 0xaa, 0x02, 0x29, 0x52, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73,
-	0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x50, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e,
-	0x47, 0x61, 0x6d, 0x65, 0x2e, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x56, 0x31, 0xca,
-	0x02, 0x29, 0x52, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69,
-	0x6f, 0x73, 0x5c, 0x50, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x5c, 0x47, 0x61, 0x6d, 0x65,
-	0x5c, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x5c, 0x56, 0x31, 0xe2, 0x02, 0x35, 0x52, 0x75,
-	0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x5c, 0x50,
-	0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x5c, 0x47, 0x61, 0x6d, 0x65, 0x5c, 0x53, 0x65, 0x73,
-	0x73, 0x69, 0x6f, 0x6e, 0x5c, 0x56, 0x31, 0x5c, 0x47, 0x50, 0x42, 0x4d, 0x65, 0x74, 0x61, 0x64,
-	0x61, 0x74, 0x61, 0xea, 0x02, 0x2d, 0x52, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73,
-	0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x3a, 0x3a, 0x50, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f,
-	0x3a, 0x3a, 0x47, 0x61, 0x6d, 0x65, 0x3a, 0x3a, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x3a,
-	0x3a, 0x56, 0x31, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+	0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x73, 0x65, 0x73, 0x73,
+	0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69,
+	0x6f, 0x6e, 0x52, 0x07, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x42, 0xfb, 0x02, 0x0a, 0x2d,
+	0x63, 0x6f, 0x6d, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75,
+	0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61,
+	0x6d, 0x65, 0x2e, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x42, 0x0c, 0x53,
+	0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50, 0x01, 0x5a, 0x73, 0x67,
+	0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72,
+	0x61, 0x66, 0x74, 0x2d, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74,
+	0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x73, 0x2f, 0x63, 0x6f,
+	0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x73, 0x2f, 0x67, 0x65, 0x6e, 0x2f, 0x67, 0x6f, 0x2f, 0x72,
+	0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f,
+	0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x67, 0x61, 0x6d, 0x65, 0x2f, 0x73, 0x65,
+	0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2f, 0x76, 0x31, 0x3b, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e,
+	0x76, 0x31, 0xa2, 0x02, 0x04, 0x52, 0x50, 0x47, 0x53, 0xaa, 0x02, 0x29, 0x52, 0x75, 0x6e, 0x65,
+	0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x50, 0x61, 0x73,
+	0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x2e, 0x53, 0x65, 0x73, 0x73, 0x69,
+	0x6f, 0x6e, 0x2e, 0x56, 0x31, 0xca, 0x02, 0x29, 0x52, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66,
+	0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x5c, 0x50, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c,
+	0x6f, 0x5c, 0x47, 0x61, 0x6d, 0x65, 0x5c, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x5c, 0x56,
+	0x31, 0xe2, 0x02, 0x35, 0x52, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75,
+	0x64, 0x69, 0x6f, 0x73, 0x5c, 0x50, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x5c, 0x47, 0x61,
+	0x6d, 0x65, 0x5c, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x5c, 0x56, 0x31, 0x5c, 0x47, 0x50,
+	0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x2d, 0x52, 0x75, 0x6e, 0x65,
+	0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x3a, 0x3a, 0x50, 0x61,
+	0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x3a, 0x3a, 0x47, 0x61, 0x6d, 0x65, 0x3a, 0x3a, 0x53, 0x65,
+	0x73, 0x73, 0x69, 0x6f, 0x6e, 0x3a, 0x3a, 0x56, 0x31, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+	0x33,
 }
 
 var (
@@ -387,35 +609,43 @@ func file_runecraftstudios_pastello_game_session_v1_session_proto_rawDescGZIP()
 	return file_runecraftstudios_pastello_game_session_v1_session_proto_rawDescData
 }
 
-var file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes = make([]protoimpl.MessageInfo, 3)
+var file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes = make([]protoimpl.MessageInfo, 6)
 var file_runecraftstudios_pastello_game_session_v1_session_proto_goTypes = []interface{}{
 	(*GameSession)(nil),               // 0: runecraftstudios.pastello.game.session.v1.GameSession
-	(*CreateGameSessionRequest)(nil),  // 1: runecraftstudios.pastello.game.session.v1.CreateGameSessionRequest
-	(*CreateGameSessionResponse)(nil), // 2: runecraftstudios.pastello.game.session.v1.CreateGameSessionResponse
-	(*v1.GameSessionId)(nil),          // 3: runecraftstudios.pastello.game.types.v1.GameSessionId
-	(v1.GameType)(0),                  // 4: runecraftstudios.pastello.game.types.v1.GameType
-	(*v1.PlayerId)(nil),               // 5: runecraftstudios.pastello.game.types.v1.PlayerId
-	(v1.SessionStatus)(0),             // 6: runecraftstudios.pastello.game.types.v1.SessionStatus
-	(*timestamppb.Timestamp)(nil),     // 7: google.protobuf.Timestamp
-	(*v11.TriviaRules)(nil),           // 8: runecraftstudios.pastello.game.trivia.v1.TriviaRules
-	(*v12.PuzzleRules)(nil),           // 9: runecraftstudios.pastello.game.puzzle.v1.PuzzleRules
+	(*PrivateRoomOptions)(nil),        // 1: runecraftstudios.pastello.game.session.v1.PrivateRoomOptions
+	(*CreateGameSessionRequest)(nil),  // 2: runecraftstudios.pastello.game.session.v1.CreateGameSessionRequest
+	(*CreateGameSessionResponse)(nil), // 3: runecraftstudios.pastello.game.session.v1.CreateGameSessionResponse
+	(*GetGameSessionRequest)(nil),     // 4: runecraftstudios.pastello.game.session.v1.GetGameSessionRequest
+	(*GetGameSessionResponse)(nil),    // 5: runecraftstudios.pastello.game.session.v1.GetGameSessionResponse
+	(*v1.GameSessionId)(nil),          // 6: runecraftstudios.pastello.game.types.v1.GameSessionId
+	(v1.GameType)(0),                  // 7: runecraftstudios.pastello.game.types.v1.GameType
+	(*v1.PlayerId)(nil),               // 8: runecraftstudios.pastello.game.types.v1.PlayerId
+	(v1.SessionStatus)(0),             // 9: runecraftstudios.pastello.game.types.v1.SessionStatus
+	(*timestamppb.Timestamp)(nil),     // 10: google.protobuf.Timestamp
+	(*v11.TriviaRules)(nil),           // 11: runecraftstudios.pastello.game.trivia.v1.TriviaRules
+	(*v12.PuzzleRules)(nil),           // 12: runecraftstudios.pastello.game.puzzle.v1.PuzzleRules
 }
 var file_runecraftstudios_pastello_game_session_v1_session_proto_depIdxs = []int32{
-	3,  // 0: runecraftstudios.pastello.game.session.v1.GameSession.id:type_name -> runecraftstudios.pastello.game.types.v1.GameSessionId
-	4,  // 1: runecraftstudios.pastello.game.session.v1.GameSession.game_type:type_name -> runecraftstudios.pastello.game.types.v1.GameType
-	5,  // 2: runecraftstudios.pastello.game.session.v1.GameSession.player_ids:type_name -> runecraftstudios.pastello.game.types.v1.PlayerId
-	6,  // 3: runecraftstudios.pastello.game.session.v1.GameSession.status:type_name -> runecraftstudios.pastello.game.types.v1.SessionStatus
-	7,  // 4: runecraftstudios.pastello.game.session.v1.GameSession.created_at:type_name -> google.protobuf.Timestamp
-	4,  // 5: runecraftstudios.pastello.game.session.v1.CreateGameSessionRequest.game_type:type_name -> runecraftstudios.pastello.game.types.v1.GameType
-	5,  // 6: runecraftstudios.pastello.game.session.v1.CreateGameSessionRequest.player_ids:type_name -> runecraftstudios.pastello.game.types.v1.PlayerId
-	8,  // 7: runecraftstudios.pastello.game.session.v1.CreateGameSessionRequest.trivia:type_name -> runecraftstudios.pastello.game.trivia.v1.TriviaRules
-	9,  // 8: runecraftstudios.pastello.game.session.v1.CreateGameSessionRequest.puzzle:type_name -> runecraftstudios.pastello.game.puzzle.v1.PuzzleRules
-	0,  // 9: runecraftstudios.pastello.game.session.v1.CreateGameSessionResponse.session:type_name -> runecraftstudios.pastello.game.session.v1.GameSession
-	10, // [10:10] is the sub-list for method output_type
-	10, // [10:10] is the sub-list for method input_type
-	10, // [10:10] is the sub-list for extension type_name
-	10, // [10:10] is the sub-list for extension extendee
-	0,  // [0:10] is the sub-list for field type_name
+	6,  // 0: runecraftstudios.pastello.game.session.v1.GameSession.id:type_name -> runecraftstudios.pastello.game.types.v1.GameSessionId
+	7,  // 1: runecraftstudios.pastello.game.session.v1.GameSession.game_type:type_name -> runecraftstudios.pastello.game.types.v1.GameType
+	8,  // 2: runecraftstudios.pastello.game.session.v1.GameSession.player_ids:type_name -> runecraftstudios.pastello.game.types.v1.PlayerId
+	9,  // 3: runecraftstudios.pastello.game.session.v1.GameSession.status:type_name -> runecraftstudios.pastello.game.types.v1.SessionStatus
+	10, // 4: runecraftstudios.pastello.game.session.v1.GameSession.created_at:type_name -> google.protobuf.Timestamp
+	8,  // 5: runecraftstudios.pastello.game.session.v1.GameSession.spectator_ids:type_name -> runecraftstudios.pastello.game.types.v1.PlayerId
+	10, // 6: runecraftstudios.pastello.game.session.v1.GameSession.join_code_expires_at:type_name -> google.protobuf.Timestamp
+	7,  // 7: runecraftstudios.pastello.game.session.v1.CreateGameSessionRequest.game_type:type_name -> runecraftstudios.pastello.game.types.v1.GameType
+	8,  // 8: runecraftstudios.pastello.game.session.v1.CreateGameSessionRequest.player_ids:type_name -> runecraftstudios.pastello.game.types.v1.PlayerId
+	1,  // 9: runecraftstudios.pastello.game.session.v1.CreateGameSessionRequest.private_room:type_name -> runecraftstudios.pastello.game.session.v1.PrivateRoomOptions
+	11, // 10: runecraftstudios.pastello.game.session.v1.CreateGameSessionRequest.trivia:type_name -> runecraftstudios.pastello.game.trivia.v1.TriviaRules
+	12, // 11: runecraftstudios.pastello.game.session.v1.CreateGameSessionRequest.puzzle:type_name -> runecraftstudios.pastello.game.puzzle.v1.PuzzleRules
+	0,  // 12: runecraftstudios.pastello.game.session.v1.CreateGameSessionResponse.session:type_name -> runecraftstudios.pastello.game.session.v1.GameSession
+	6,  // 13: runecraftstudios.pastello.game.session.v1.GetGameSessionRequest.id:type_name -> runecraftstudios.pastello.game.types.v1.GameSessionId
+	0,  // 14: runecraftstudios.pastello.game.session.v1.GetGameSessionResponse.session:type_name -> runecraftstudios.pastello.game.session.v1.GameSession
+	15, // [15:15] is the sub-list for method output_type
+	15, // [15:15] is the sub-list for method input_type
+	15, // [15:15] is the sub-list for extension type_name
+	15, // [15:15] is the sub-list for extension extendee
+	0,  // [0:15] is the sub-list for field type_name
 }
 
 func init() { file_runecraftstudios_pastello_game_session_v1_session_proto_init() }
@@ -437,7 +667,7 @@ func file_runecraftstudios_pastello_game_session_v1_session_proto_init() {
 			}
 		}
 		file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes[1].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*CreateGameSessionRequest); i {
+			switch v := v.(*PrivateRoomOptions); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -449,6 +679,18 @@ func file_runecraftstudios_pastello_game_session_v1_session_proto_init() {
 			}
 		}
 		file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes[2].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreateGameSessionRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes[3].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*CreateGameSessionResponse); i {
 			case 0:
 				return &v.state
@@ -460,8 +702,32 @@ func file_runecraftstudios_pastello_game_session_v1_session_proto_init() {
 				return nil
 			}
 		}
+		file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes[4].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetGameSessionRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes[5].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetGameSessionResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
 	}
-	file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes[1].OneofWrappers = []interface{}{
+	file_runecraftstudios_pastello_game_session_v1_session_proto_msgTypes[2].OneofWrappers = []interface{}{
 		(*CreateGameSessionRequest_Trivia)(nil),
 		(*CreateGameSessionRequest_Puzzle)(nil),
 		(*CreateGameSessionRequest_RulesetId)(nil),
@@ -472,7 +738,7 @@ func file_runecraftstudios_pastello_game_session_v1_session_proto_init() {
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: file_runecraftstudios_pastello_game_session_v1_session_proto_rawDesc,
 			NumEnums:      0,
-			NumMessages:   3,
+			NumMessages:   6,
 			NumExtensions: 0,
 			NumServices:   0,
 		},
@@ -227,7 +227,7 @@ type Audit struct {
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	DummySlot int32                  `protobuf:"varint,1,opt,name=dummy_slot,json=dummySlot,proto3" json:"dummy_slot,omitempty"` //
+	DummySlot int32                  `protobuf:"varint,1,opt,name=dummy_slot,json=dummySlot,proto3" json:"dummy_slot,omitempty"`
 	CreatedAt *timestamppb.Timestamp `protobuf:"bytes,2,opt,name=created_at,json=createdAt,proto3" json:"created_at,omitempty"`
 	UpdatedAt *timestamppb.Timestamp `protobuf:"bytes,3,opt,name=updated_at,json=updatedAt,proto3" json:"updated_at,omitempty"`
 }
@@ -12,6 +12,7 @@ import (
 	v1 "github.com/runecraft-studios/pastello/packages/contracts/gen/go/runecraftstudios/pastello/game/types/v1"
 	protoreflect "google.golang.org/protobuf/reflect/protoreflect"
 	protoimpl "google.golang.org/protobuf/runtime/protoimpl"
+	timestamppb "google.golang.org/protobuf/types/known/timestamppb"
 	reflect "reflect"
 	sync "sync"
 )
@@ -23,6 +24,65 @@ const (
 	_ = protoimpl.EnforceVersion(protoimpl.MaxVersion - 20)
 )
 
+type LeaderboardPeriod int32
+
+const (
+	// Treated as all time.
+	LeaderboardPeriod_LEADERBOARD_PERIOD_UNSPECIFIED LeaderboardPeriod = 0
+	LeaderboardPeriod_LEADERBOARD_PERIOD_ALL_TIME    LeaderboardPeriod = 1
+	// Current ISO week, from Monday 00:00 UTC.
+	LeaderboardPeriod_LEADERBOARD_PERIOD_WEEKLY LeaderboardPeriod = 2
+	// Current day in UTC.
+	LeaderboardPeriod_LEADERBOARD_PERIOD_DAILY LeaderboardPeriod = 3
+	// Today's daily challenge: one attempt per player on the same seeded game.
+	LeaderboardPeriod_LEADERBOARD_PERIOD_DAILY_CHALLENGE LeaderboardPeriod = 4
+)
+
+// Enum value maps for LeaderboardPeriod.
+var (
+	LeaderboardPeriod_name = map[int32]string{
+		0: "LEADERBOARD_PERIOD_UNSPECIFIED",
+		1: "LEADERBOARD_PERIOD_ALL_TIME",
+		2: "LEADERBOARD_PERIOD_WEEKLY",
+		3: "LEADERBOARD_PERIOD_DAILY",
+		4: "LEADERBOARD_PERIOD_DAILY_CHALLENGE",
+	}
+	LeaderboardPeriod_value = map[string]int32{
+		"LEADERBOARD_PERIOD_UNSPECIFIED":     0,
+		"LEADERBOARD_PERIOD_ALL_TIME":        1,
+		"LEADERBOARD_PERIOD_WEEKLY":          2,
+		"LEADERBOARD_PERIOD_DAILY":           3,
+		"LEADERBOARD_PERIOD_DAILY_CHALLENGE": 4,
+	}
+)
+
+func (x LeaderboardPeriod) Enum() *LeaderboardPeriod {
+	p := new(LeaderboardPeriod)
+	*p = x
+	return p
+}
+
+func (x LeaderboardPeriod) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (LeaderboardPeriod) Descriptor() protoreflect.EnumDescriptor {
+	return file_runecraftstudios_pastello_web_game_v1_envelope_proto_enumTypes[0].Descriptor()
+}
+
+func (LeaderboardPeriod) Type() protoreflect.EnumType {
+	return &file_runecraftstudios_pastello_web_game_v1_envelope_proto_enumTypes[0]
+}
+
+func (x LeaderboardPeriod) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use LeaderboardPeriod.Descriptor instead.
+func (LeaderboardPeriod) EnumDescriptor() ([]byte, []int) {
+	return file_runecraftstudios_pastello_web_game_v1_envelope_proto_rawDescGZIP(), []int{0}
+}
+
 // ClientEnvelope is the top-level message from the client over WebSocket.
 // The Rust code `ws/mod.rs` expects this structure.
 type ClientEnvelope struct {
@@ -34,6 +94,9 @@ type ClientEnvelope struct {
 	//
 	//	*ClientEnvelope_StartGame
 	//	*ClientEnvelope_GameCommand
+	//	*ClientEnvelope_JoinQueue
+	//	*ClientEnvelope_LeaveQueue
+	//	*ClientEnvelope_GetLeaderboard
 	Message isClientEnvelope_Message `protobuf_oneof:"message"`
 }
 
@@ -90,6 +153,27 @@ func (x *ClientEnvelope) GetGameCommand() *GameCommandEnvelope {
 	return nil
 }
 
+func (x *ClientEnvelope) GetJoinQueue() *JoinQueueCommand {
+	if x, ok := x.GetMessage().(*ClientEnvelope_JoinQueue); ok {
+		return x.JoinQueue
+	}
+	return nil
+}
+
+func (x *ClientEnvelope) GetLeaveQueue() *LeaveQueueCommand {
+	if x, ok := x.GetMessage().(*ClientEnvelope_LeaveQueue); ok {
+		return x.LeaveQueue
+	}
+	return nil
+}
+
+func (x *ClientEnvelope) GetGetLeaderboard() *GetLeaderboardQuery {
+	if x, ok := x.GetMessage().(*ClientEnvelope_GetLeaderboard); ok {
+		return x.GetLeaderboard
+	}
+	return nil
+}
+
 type isClientEnvelope_Message interface {
 	isClientEnvelope_Message()
 }
@@ -102,10 +186,28 @@ type ClientEnvelope_GameCommand struct {
 	GameCommand *GameCommandEnvelope `protobuf:"bytes,2,opt,name=game_command,json=gameCommand,proto3,oneof"`
 }
 
+type ClientEnvelope_JoinQueue struct {
+	JoinQueue *JoinQueueCommand `protobuf:"bytes,3,opt,name=join_queue,json=joinQueue,proto3,oneof"`
+}
+
+type ClientEnvelope_LeaveQueue struct {
+	LeaveQueue *LeaveQueueCommand `protobuf:"bytes,4,opt,name=leave_queue,json=leaveQueue,proto3,oneof"`
+}
+
+type ClientEnvelope_GetLeaderboard struct {
+	GetLeaderboard *GetLeaderboardQuery `protobuf:"bytes,5,opt,name=get_leaderboard,json=getLeaderboard,proto3,oneof"`
+}
+
 func (*ClientEnvelope_StartGame) isClientEnvelope_Message() {}
 
 func (*ClientEnvelope_GameCommand) isClientEnvelope_Message() {}
 
+func (*ClientEnvelope_JoinQueue) isClientEnvelope_Message() {}
+
+func (*ClientEnvelope_LeaveQueue) isClientEnvelope_Message() {}
+
+func (*ClientEnvelope_GetLeaderboard) isClientEnvelope_Message() {}
+
 // Sent by the client to initiate a game session.
 // The Rust code `ws/mod.rs` expects this to have a `game_type` field.
 type StartGameCommand struct {
@@ -155,6 +257,175 @@ func (x *StartGameCommand) GetGameType() v1.GameType {
 	return v1.GameType(0)
 }
 
+// Enters the quick-play queue for a game type. Joining again replaces the earlier entry.
+type JoinQueueCommand struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	GameType v1.GameType `protobuf:"varint,1,opt,name=game_type,json=gameType,proto3,enum=runecraftstudios.pastello.game.types.v1.GameType" json:"game_type,omitempty"`
+	// Ignored: players are matched on the skill rating stored on their profile.
+	Rating *float64 `protobuf:"fixed64,2,opt,name=rating,proto3,oneof" json:"rating,omitempty"`
+}
+
+func (x *JoinQueueCommand) Reset() {
+	*x = JoinQueueCommand{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[2]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *JoinQueueCommand) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*JoinQueueCommand) ProtoMessage() {}
+
+func (x *JoinQueueCommand) ProtoReflect() protoreflect.Message {
+	mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[2]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use JoinQueueCommand.ProtoReflect.Descriptor instead.
+func (*JoinQueueCommand) Descriptor() ([]byte, []int) {
+	return file_runecraftstudios_pastello_web_game_v1_envelope_proto_rawDescGZIP(), []int{2}
+}
+
+func (x *JoinQueueCommand) GetGameType() v1.GameType {
+	if x != nil {
+		return x.GameType
+	}
+	return v1.GameType(0)
+}
+
+func (x *JoinQueueCommand) GetRating() float64 {
+	if x != nil && x.Rating != nil {
+		return *x.Rating
+	}
+	return 0
+}
+
+type LeaveQueueCommand struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *LeaveQueueCommand) Reset() {
+	*x = LeaveQueueCommand{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[3]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *LeaveQueueCommand) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*LeaveQueueCommand) ProtoMessage() {}
+
+func (x *LeaveQueueCommand) ProtoReflect() protoreflect.Message {
+	mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[3]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use LeaveQueueCommand.ProtoReflect.Descriptor instead.
+func (*LeaveQueueCommand) Descriptor() ([]byte, []int) {
+	return file_runecraftstudios_pastello_web_game_v1_envelope_proto_rawDescGZIP(), []int{3}
+}
+
+// Reads a leaderboard; answered with a Leaderboard message.
+type GetLeaderboardQuery struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	GameType v1.GameType `protobuf:"varint,1,opt,name=game_type,json=gameType,proto3,enum=runecraftstudios.pastello.game.types.v1.GameType" json:"game_type,omitempty"`
+	// Only sessions played with this ruleset; empty ranks every session of the game type.
+	RulesetId string            `protobuf:"bytes,2,opt,name=ruleset_id,json=rulesetId,proto3" json:"ruleset_id,omitempty"`
+	Period    LeaderboardPeriod `protobuf:"varint,3,opt,name=period,proto3,enum=runecraftstudios.pastello.web.game.v1.LeaderboardPeriod" json:"period,omitempty"`
+	// Entries to return; 0 uses the server default.
+	Limit uint32 `protobuf:"varint,4,opt,name=limit,proto3" json:"limit,omitempty"`
+}
+
+func (x *GetLeaderboardQuery) Reset() {
+	*x = GetLeaderboardQuery{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[4]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GetLeaderboardQuery) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetLeaderboardQuery) ProtoMessage() {}
+
+func (x *GetLeaderboardQuery) ProtoReflect() protoreflect.Message {
+	mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[4]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetLeaderboardQuery.ProtoReflect.Descriptor instead.
+func (*GetLeaderboardQuery) Descriptor() ([]byte, []int) {
+	return file_runecraftstudios_pastello_web_game_v1_envelope_proto_rawDescGZIP(), []int{4}
+}
+
+func (x *GetLeaderboardQuery) GetGameType() v1.GameType {
+	if x != nil {
+		return x.GameType
+	}
+	return v1.GameType(0)
+}
+
+func (x *GetLeaderboardQuery) GetRulesetId() string {
+	if x != nil {
+		return x.RulesetId
+	}
+	return ""
+}
+
+func (x *GetLeaderboardQuery) GetPeriod() LeaderboardPeriod {
+	if x != nil {
+		return x.Period
+	}
+	return LeaderboardPeriod_LEADERBOARD_PERIOD_UNSPECIFIED
+}
+
+func (x *GetLeaderboardQuery) GetLimit() uint32 {
+	if x != nil {
+		return x.Limit
+	}
+	return 0
+}
+
 // GameCommandEnvelope wraps all in-game actions.
 // The Rust code `ws/mod.rs` expects this structure.
 type GameCommandEnvelope struct {
@@ -174,7 +445,7 @@ type GameCommandEnvelope struct {
 func (x *GameCommandEnvelope) Reset() {
 	*x = GameCommandEnvelope{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[2]
+		mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[5]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -187,7 +458,7 @@ func (x *GameCommandEnvelope) String() string {
 func (*GameCommandEnvelope) ProtoMessage() {}
 
 func (x *GameCommandEnvelope) ProtoReflect() protoreflect.Message {
-	mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[2]
+	mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[5]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -200,7 +471,7 @@ func (x *GameCommandEnvelope) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use GameCommandEnvelope.ProtoReflect.Descriptor instead.
 func (*GameCommandEnvelope) Descriptor() ([]byte, []int) {
-	return file_runecraftstudios_pastello_web_game_v1_envelope_proto_rawDescGZIP(), []int{2}
+	return file_runecraftstudios_pastello_web_game_v1_envelope_proto_rawDescGZIP(), []int{5}
 }
 
 func (m *GameCommandEnvelope) GetCommand() isGameCommandEnvelope_Command {
@@ -279,13 +550,17 @@ type ServerEnvelope struct {
 	//	*ServerEnvelope_GameState
 	//	*ServerEnvelope_Error
 	//	*ServerEnvelope_GameEvent
+	//	*ServerEnvelope_QueueStatus
+	//	*ServerEnvelope_MatchFound
+	//	*ServerEnvelope_Leaderboard
+	//	*ServerEnvelope_AchievementUnlocked
 	Message isServerEnvelope_Message `protobuf_oneof:"message"`
 }
 
 func (x *ServerEnvelope) Reset() {
 	*x = ServerEnvelope{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[3]
+		mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[6]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -298,7 +573,7 @@ func (x *ServerEnvelope) String() string {
 func (*ServerEnvelope) ProtoMessage() {}
 
 func (x *ServerEnvelope) ProtoReflect() protoreflect.Message {
-	mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[3]
+	mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[6]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -311,7 +586,7 @@ func (x *ServerEnvelope) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use ServerEnvelope.ProtoReflect.Descriptor instead.
 func (*ServerEnvelope) Descriptor() ([]byte, []int) {
-	return file_runecraftstudios_pastello_web_game_v1_envelope_proto_rawDescGZIP(), []int{3}
+	return file_runecraftstudios_pastello_web_game_v1_envelope_proto_rawDescGZIP(), []int{6}
 }
 
 func (m *ServerEnvelope) GetMessage() isServerEnvelope_Message {
@@ -349,6 +624,34 @@ func (x *ServerEnvelope) GetGameEvent() *GameEventEnvelope {
 	return nil
 }
 
+func (x *ServerEnvelope) GetQueueStatus() *QueueStatus {
+	if x, ok := x.GetMessage().(*ServerEnvelope_QueueStatus); ok {
+		return x.QueueStatus
+	}
+	return nil
+}
+
+func (x *ServerEnvelope) GetMatchFound() *MatchFound {
+	if x, ok := x.GetMessage().(*ServerEnvelope_MatchFound); ok {
+		return x.MatchFound
+	}
+	return nil
+}
+
+func (x *ServerEnvelope) GetLeaderboard() *Leaderboard {
+	if x, ok := x.GetMessage().(*ServerEnvelope_Leaderboard); ok {
+		return x.Leaderboard
+	}
+	return nil
+}
+
+func (x *ServerEnvelope) GetAchievementUnlocked() *AchievementUnlocked {
+	if x, ok := x.GetMessage().(*ServerEnvelope_AchievementUnlocked); ok {
+		return x.AchievementUnlocked
+	}
+	return nil
+}
+
 type isServerEnvelope_Message interface {
 	isServerEnvelope_Message()
 }
@@ -373,6 +676,26 @@ type ServerEnvelope_GameEvent struct {
 	GameEvent *GameEventEnvelope `protobuf:"bytes,4,opt,name=game_event,json=gameEvent,proto3,oneof"`
 }
 
+type ServerEnvelope_QueueStatus struct {
+	// Matchmaking progress for a queued player.
+	QueueStatus *QueueStatus `protobuf:"bytes,5,opt,name=queue_status,json=queueStatus,proto3,oneof"`
+}
+
+type ServerEnvelope_MatchFound struct {
+	// The player was placed in a session; attach to it to play.
+	MatchFound *MatchFound `protobuf:"bytes,6,opt,name=match_found,json=matchFound,proto3,oneof"`
+}
+
+type ServerEnvelope_Leaderboard struct {
+	// Reply to GetLeaderboardQuery.
+	Leaderboard *Leaderboard `protobuf:"bytes,7,opt,name=leaderboard,proto3,oneof"`
+}
+
+type ServerEnvelope_AchievementUnlocked struct {
+	// The player earned an achievement.
+	AchievementUnlocked *AchievementUnlocked `protobuf:"bytes,8,opt,name=achievement_unlocked,json=achievementUnlocked,proto3,oneof"`
+}
+
 func (*ServerEnvelope_AuthStatus) isServerEnvelope_Message() {}
 
 func (*ServerEnvelope_GameState) isServerEnvelope_Message() {}
@@ -381,36 +704,43 @@ func (*ServerEnvelope_Error) isServerEnvelope_Message() {}
 
 func (*ServerEnvelope_GameEvent) isServerEnvelope_Message() {}
 
-// GameEventEnvelope wraps all game-specific events.
-type GameEventEnvelope struct {
+func (*ServerEnvelope_QueueStatus) isServerEnvelope_Message() {}
+
+func (*ServerEnvelope_MatchFound) isServerEnvelope_Message() {}
+
+func (*ServerEnvelope_Leaderboard) isServerEnvelope_Message() {}
+
+func (*ServerEnvelope_AchievementUnlocked) isServerEnvelope_Message() {}
+
+type QueueStatus struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	// Types that are assignable to Event:
-	//
-	//	*GameEventEnvelope_PuzzleEvent
-	//	*GameEventEnvelope_TriviaEvent
-	Event isGameEventEnvelope_Event `protobuf_oneof:"event"`
+	GameType v1.GameType `protobuf:"varint,1,opt,name=game_type,json=gameType,proto3,enum=runecraftstudios.pastello.game.types.v1.GameType" json:"game_type,omitempty"`
+	// 1-based place in the queue; 0 once the player has left it.
+	Position      uint32 `protobuf:"varint,2,opt,name=position,proto3" json:"position,omitempty"`
+	Queued        uint32 `protobuf:"varint,3,opt,name=queued,proto3" json:"queued,omitempty"`
+	WaitedSeconds uint32 `protobuf:"varint,4,opt,name=waited_seconds,json=waitedSeconds,proto3" json:"waited_seconds,omitempty"`
 }
 
-func (x *GameEventEnvelope) Reset() {
-	*x = GameEventEnvelope{}
+func (x *QueueStatus) Reset() {
+	*x = QueueStatus{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[4]
+		mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[7]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *GameEventEnvelope) String() string {
+func (x *QueueStatus) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GameEventEnvelope) ProtoMessage() {}
+func (*QueueStatus) ProtoMessage() {}
 
-func (x *GameEventEnvelope) ProtoReflect() protoreflect.Message {
-	mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[4]
+func (x *QueueStatus) ProtoReflect() protoreflect.Message {
+	mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[7]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -421,49 +751,452 @@ func (x *GameEventEnvelope) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GameEventEnvelope.ProtoReflect.Descriptor instead.
-func (*GameEventEnvelope) Descriptor() ([]byte, []int) {
-	return file_runecraftstudios_pastello_web_game_v1_envelope_proto_rawDescGZIP(), []int{4}
+// Deprecated: Use QueueStatus.ProtoReflect.Descriptor instead.
+func (*QueueStatus) Descriptor() ([]byte, []int) {
+	return file_runecraftstudios_pastello_web_game_v1_envelope_proto_rawDescGZIP(), []int{7}
 }
 
-func (m *GameEventEnvelope) GetEvent() isGameEventEnvelope_Event {
-	if m != nil {
-		return m.Event
+func (x *QueueStatus) GetGameType() v1.GameType {
+	if x != nil {
+		return x.GameType
 	}
-	return nil
+	return v1.GameType(0)
 }
 
-func (x *GameEventEnvelope) GetPuzzleEvent() string {
-	if x, ok := x.GetEvent().(*GameEventEnvelope_PuzzleEvent); ok {
-		return x.PuzzleEvent
+func (x *QueueStatus) GetPosition() uint32 {
+	if x != nil {
+		return x.Position
 	}
-	return ""
+	return 0
 }
 
-func (x *GameEventEnvelope) GetTriviaEvent() string {
-	if x, ok := x.GetEvent().(*GameEventEnvelope_TriviaEvent); ok {
-		return x.TriviaEvent
+func (x *QueueStatus) GetQueued() uint32 {
+	if x != nil {
+		return x.Queued
 	}
-	return ""
+	return 0
 }
 
-type isGameEventEnvelope_Event interface {
-	isGameEventEnvelope_Event()
+func (x *QueueStatus) GetWaitedSeconds() uint32 {
+	if x != nil {
+		return x.WaitedSeconds
+	}
+	return 0
 }
 
-type GameEventEnvelope_PuzzleEvent struct {
-	// Placeholder for puzzle events
-	PuzzleEvent string `protobuf:"bytes,1,opt,name=puzzle_event,json=puzzleEvent,proto3,oneof"`
+type MatchFound struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	SessionId *v1.GameSessionId `protobuf:"bytes,1,opt,name=session_id,json=sessionId,proto3" json:"session_id,omitempty"`
+	GameType  v1.GameType       `protobuf:"varint,2,opt,name=game_type,json=gameType,proto3,enum=runecraftstudios.pastello.game.types.v1.GameType" json:"game_type,omitempty"`
+	PlayerIds []*v1.PlayerId    `protobuf:"bytes,3,rep,name=player_ids,json=playerIds,proto3" json:"player_ids,omitempty"`
 }
 
-type GameEventEnvelope_TriviaEvent struct {
-	// Placeholder for trivia events
-	TriviaEvent string `protobuf:"bytes,2,opt,name=trivia_event,json=triviaEvent,proto3,oneof"`
+func (x *MatchFound) Reset() {
+	*x = MatchFound{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[8]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
 }
 
-func (*GameEventEnvelope_PuzzleEvent) isGameEventEnvelope_Event() {}
+func (x *MatchFound) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
 
-func (*GameEventEnvelope_TriviaEvent) isGameEventEnvelope_Event() {}
+func (*MatchFound) ProtoMessage() {}
+
+func (x *MatchFound) ProtoReflect() protoreflect.Message {
+	mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[8]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use MatchFound.ProtoReflect.Descriptor instead.
+func (*MatchFound) Descriptor() ([]byte, []int) {
+	return file_runecraftstudios_pastello_web_game_v1_envelope_proto_rawDescGZIP(), []int{8}
+}
+
+func (x *MatchFound) GetSessionId() *v1.GameSessionId {
+	if x != nil {
+		return x.SessionId
+	}
+	return nil
+}
+
+func (x *MatchFound) GetGameType() v1.GameType {
+	if x != nil {
+		return x.GameType
+	}
+	return v1.GameType(0)
+}
+
+func (x *MatchFound) GetPlayerIds() []*v1.PlayerId {
+	if x != nil {
+		return x.PlayerIds
+	}
+	return nil
+}
+
+type Leaderboard struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	GameType  v1.GameType       `protobuf:"varint,1,opt,name=game_type,json=gameType,proto3,enum=runecraftstudios.pastello.game.types.v1.GameType" json:"game_type,omitempty"`
+	RulesetId string            `protobuf:"bytes,2,opt,name=ruleset_id,json=rulesetId,proto3" json:"ruleset_id,omitempty"`
+	Period    LeaderboardPeriod `protobuf:"varint,3,opt,name=period,proto3,enum=runecraftstudios.pastello.web.game.v1.LeaderboardPeriod" json:"period,omitempty"`
+	// Window the board covers, e.g. "2024-W07" or "2024-02-14"; "all" for all time.
+	Window string `protobuf:"bytes,4,opt,name=window,proto3" json:"window,omitempty"`
+	// Players ranked on the board.
+	Total   uint32              `protobuf:"varint,5,opt,name=total,proto3" json:"total,omitempty"`
+	Entries []*LeaderboardEntry `protobuf:"bytes,6,rep,name=entries,proto3" json:"entries,omitempty"`
+	// The caller's own standing; unset if they have no result on this board.
+	Me *LeaderboardEntry `protobuf:"bytes,7,opt,name=me,proto3" json:"me,omitempty"`
+}
+
+func (x *Leaderboard) Reset() {
+	*x = Leaderboard{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[9]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *Leaderboard) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*Leaderboard) ProtoMessage() {}
+
+func (x *Leaderboard) ProtoReflect() protoreflect.Message {
+	mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[9]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use Leaderboard.ProtoReflect.Descriptor instead.
+func (*Leaderboard) Descriptor() ([]byte, []int) {
+	return file_runecraftstudios_pastello_web_game_v1_envelope_proto_rawDescGZIP(), []int{9}
+}
+
+func (x *Leaderboard) GetGameType() v1.GameType {
+	if x != nil {
+		return x.GameType
+	}
+	return v1.GameType(0)
+}
+
+func (x *Leaderboard) GetRulesetId() string {
+	if x != nil {
+		return x.RulesetId
+	}
+	return ""
+}
+
+func (x *Leaderboard) GetPeriod() LeaderboardPeriod {
+	if x != nil {
+		return x.Period
+	}
+	return LeaderboardPeriod_LEADERBOARD_PERIOD_UNSPECIFIED
+}
+
+func (x *Leaderboard) GetWindow() string {
+	if x != nil {
+		return x.Window
+	}
+	return ""
+}
+
+func (x *Leaderboard) GetTotal() uint32 {
+	if x != nil {
+		return x.Total
+	}
+	return 0
+}
+
+func (x *Leaderboard) GetEntries() []*LeaderboardEntry {
+	if x != nil {
+		return x.Entries
+	}
+	return nil
+}
+
+func (x *Leaderboard) GetMe() *LeaderboardEntry {
+	if x != nil {
+		return x.Me
+	}
+	return nil
+}
+
+type LeaderboardEntry struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Rank     uint32       `protobuf:"varint,1,opt,name=rank,proto3" json:"rank,omitempty"`
+	PlayerId *v1.PlayerId `protobuf:"bytes,2,opt,name=player_id,json=playerId,proto3" json:"player_id,omitempty"`
+	// Final score for trivia, completion time in milliseconds for puzzles.
+	Value      int64                  `protobuf:"varint,3,opt,name=value,proto3" json:"value,omitempty"`
+	AchievedAt *timestamppb.Timestamp `protobuf:"bytes,4,opt,name=achieved_at,json=achievedAt,proto3" json:"achieved_at,omitempty"`
+	SessionId  *v1.GameSessionId      `protobuf:"bytes,5,opt,name=session_id,json=sessionId,proto3" json:"session_id,omitempty"`
+}
+
+func (x *LeaderboardEntry) Reset() {
+	*x = LeaderboardEntry{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[10]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *LeaderboardEntry) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*LeaderboardEntry) ProtoMessage() {}
+
+func (x *LeaderboardEntry) ProtoReflect() protoreflect.Message {
+	mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[10]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use LeaderboardEntry.ProtoReflect.Descriptor instead.
+func (*LeaderboardEntry) Descriptor() ([]byte, []int) {
+	return file_runecraftstudios_pastello_web_game_v1_envelope_proto_rawDescGZIP(), []int{10}
+}
+
+func (x *LeaderboardEntry) GetRank() uint32 {
+	if x != nil {
+		return x.Rank
+	}
+	return 0
+}
+
+func (x *LeaderboardEntry) GetPlayerId() *v1.PlayerId {
+	if x != nil {
+		return x.PlayerId
+	}
+	return nil
+}
+
+func (x *LeaderboardEntry) GetValue() int64 {
+	if x != nil {
+		return x.Value
+	}
+	return 0
+}
+
+func (x *LeaderboardEntry) GetAchievedAt() *timestamppb.Timestamp {
+	if x != nil {
+		return x.AchievedAt
+	}
+	return nil
+}
+
+func (x *LeaderboardEntry) GetSessionId() *v1.GameSessionId {
+	if x != nil {
+		return x.SessionId
+	}
+	return nil
+}
+
+type AchievementUnlocked struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	AchievementId string `protobuf:"bytes,1,opt,name=achievement_id,json=achievementId,proto3" json:"achievement_id,omitempty"`
+	Name          string `protobuf:"bytes,2,opt,name=name,proto3" json:"name,omitempty"`
+	Description   string `protobuf:"bytes,3,opt,name=description,proto3" json:"description,omitempty"`
+	// Empty if the achievement has no badge image.
+	BadgeUrl   string                 `protobuf:"bytes,4,opt,name=badge_url,json=badgeUrl,proto3" json:"badge_url,omitempty"`
+	UnlockedAt *timestamppb.Timestamp `protobuf:"bytes,5,opt,name=unlocked_at,json=unlockedAt,proto3" json:"unlocked_at,omitempty"`
+	// Session the achievement was earned in.
+	SessionId *v1.GameSessionId `protobuf:"bytes,6,opt,name=session_id,json=sessionId,proto3" json:"session_id,omitempty"`
+}
+
+func (x *AchievementUnlocked) Reset() {
+	*x = AchievementUnlocked{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[11]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AchievementUnlocked) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AchievementUnlocked) ProtoMessage() {}
+
+func (x *AchievementUnlocked) ProtoReflect() protoreflect.Message {
+	mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[11]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AchievementUnlocked.ProtoReflect.Descriptor instead.
+func (*AchievementUnlocked) Descriptor() ([]byte, []int) {
+	return file_runecraftstudios_pastello_web_game_v1_envelope_proto_rawDescGZIP(), []int{11}
+}
+
+func (x *AchievementUnlocked) GetAchievementId() string {
+	if x != nil {
+		return x.AchievementId
+	}
+	return ""
+}
+
+func (x *AchievementUnlocked) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *AchievementUnlocked) GetDescription() string {
+	if x != nil {
+		return x.Description
+	}
+	return ""
+}
+
+func (x *AchievementUnlocked) GetBadgeUrl() string {
+	if x != nil {
+		return x.BadgeUrl
+	}
+	return ""
+}
+
+func (x *AchievementUnlocked) GetUnlockedAt() *timestamppb.Timestamp {
+	if x != nil {
+		return x.UnlockedAt
+	}
+	return nil
+}
+
+func (x *AchievementUnlocked) GetSessionId() *v1.GameSessionId {
+	if x != nil {
+		return x.SessionId
+	}
+	return nil
+}
+
+// GameEventEnvelope wraps all game-specific events.
+type GameEventEnvelope struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// Types that are assignable to Event:
+	//
+	//	*GameEventEnvelope_PuzzleEvent
+	//	*GameEventEnvelope_TriviaEvent
+	Event isGameEventEnvelope_Event `protobuf_oneof:"event"`
+}
+
+func (x *GameEventEnvelope) Reset() {
+	*x = GameEventEnvelope{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[12]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *GameEventEnvelope) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GameEventEnvelope) ProtoMessage() {}
+
+func (x *GameEventEnvelope) ProtoReflect() protoreflect.Message {
+	mi := &file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[12]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GameEventEnvelope.ProtoReflect.Descriptor instead.
+func (*GameEventEnvelope) Descriptor() ([]byte, []int) {
+	return file_runecraftstudios_pastello_web_game_v1_envelope_proto_rawDescGZIP(), []int{12}
+}
+
+func (m *GameEventEnvelope) GetEvent() isGameEventEnvelope_Event {
+	if m != nil {
+		return m.Event
+	}
+	return nil
+}
+
+func (x *GameEventEnvelope) GetPuzzleEvent() string {
+	if x, ok := x.GetEvent().(*GameEventEnvelope_PuzzleEvent); ok {
+		return x.PuzzleEvent
+	}
+	return ""
+}
+
+func (x *GameEventEnvelope) GetTriviaEvent() string {
+	if x, ok := x.GetEvent().(*GameEventEnvelope_TriviaEvent); ok {
+		return x.TriviaEvent
+	}
+	return ""
+}
+
+type isGameEventEnvelope_Event interface {
+	isGameEventEnvelope_Event()
+}
+
+type GameEventEnvelope_PuzzleEvent struct {
+	// Placeholder for puzzle events
+	PuzzleEvent string `protobuf:"bytes,1,opt,name=puzzle_event,json=puzzleEvent,proto3,oneof"`
+}
+
+type GameEventEnvelope_TriviaEvent struct {
+	// Placeholder for trivia events
+	TriviaEvent string `protobuf:"bytes,2,opt,name=trivia_event,json=triviaEvent,proto3,oneof"`
+}
+
+func (*GameEventEnvelope_PuzzleEvent) isGameEventEnvelope_Event() {}
+
+func (*GameEventEnvelope_TriviaEvent) isGameEventEnvelope_Event() {}
 
 var File_runecraftstudios_pastello_web_game_v1_envelope_proto protoreflect.FileDescriptor
 
@@ -484,27 +1217,72 @@ var file_runecraftstudios_pastello_web_game_v1_envelope_proto_rawDesc = []byte{
 	0x33, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f,
 	0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x67, 0x61, 0x6d, 0x65, 0x2f,
 	0x74, 0x79, 0x70, 0x65, 0x73, 0x2f, 0x76, 0x31, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x70,
-	0x72, 0x6f, 0x74, 0x6f, 0x22, 0xd6, 0x01, 0x0a, 0x0e, 0x43, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x45,
-	0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x12, 0x58, 0x0a, 0x0a, 0x73, 0x74, 0x61, 0x72, 0x74,
-	0x5f, 0x67, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x37, 0x2e, 0x72, 0x75,
-	0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70,
-	0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65,
-	0x2e, 0x76, 0x31, 0x2e, 0x53, 0x74, 0x61, 0x72, 0x74, 0x47, 0x61, 0x6d, 0x65, 0x43, 0x6f, 0x6d,
-	0x6d, 0x61, 0x6e, 0x64, 0x48, 0x00, 0x52, 0x09, 0x73, 0x74, 0x61, 0x72, 0x74, 0x47, 0x61, 0x6d,
-	0x65, 0x12, 0x5f, 0x0a, 0x0c, 0x67, 0x61, 0x6d, 0x65, 0x5f, 0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
-	0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x3a, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72,
+	0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x1f, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2f, 0x70, 0x72, 0x6f,
+	0x74, 0x6f, 0x62, 0x75, 0x66, 0x2f, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0xf4, 0x03, 0x0a, 0x0e, 0x43, 0x6c, 0x69, 0x65, 0x6e, 0x74,
+	0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x12, 0x58, 0x0a, 0x0a, 0x73, 0x74, 0x61, 0x72,
+	0x74, 0x5f, 0x67, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x37, 0x2e, 0x72,
+	0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e,
+	0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d,
+	0x65, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x74, 0x61, 0x72, 0x74, 0x47, 0x61, 0x6d, 0x65, 0x43, 0x6f,
+	0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x48, 0x00, 0x52, 0x09, 0x73, 0x74, 0x61, 0x72, 0x74, 0x47, 0x61,
+	0x6d, 0x65, 0x12, 0x5f, 0x0a, 0x0c, 0x67, 0x61, 0x6d, 0x65, 0x5f, 0x63, 0x6f, 0x6d, 0x6d, 0x61,
+	0x6e, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x3a, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63,
+	0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74,
+	0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31,
+	0x2e, 0x47, 0x61, 0x6d, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x45, 0x6e, 0x76, 0x65,
+	0x6c, 0x6f, 0x70, 0x65, 0x48, 0x00, 0x52, 0x0b, 0x67, 0x61, 0x6d, 0x65, 0x43, 0x6f, 0x6d, 0x6d,
+	0x61, 0x6e, 0x64, 0x12, 0x58, 0x0a, 0x0a, 0x6a, 0x6f, 0x69, 0x6e, 0x5f, 0x71, 0x75, 0x65, 0x75,
+	0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x37, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72,
 	0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65,
 	0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31, 0x2e,
-	0x47, 0x61, 0x6d, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x45, 0x6e, 0x76, 0x65, 0x6c,
-	0x6f, 0x70, 0x65, 0x48, 0x00, 0x52, 0x0b, 0x67, 0x61, 0x6d, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61,
-	0x6e, 0x64, 0x42, 0x09, 0x0a, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x22, 0x62, 0x0a,
-	0x10, 0x53, 0x74, 0x61, 0x72, 0x74, 0x47, 0x61, 0x6d, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
-	0x64, 0x12, 0x4e, 0x0a, 0x09, 0x67, 0x61, 0x6d, 0x65, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01,
-	0x20, 0x01, 0x28, 0x0e, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74,
-	0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f,
-	0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x47,
-	0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x52, 0x08, 0x67, 0x61, 0x6d, 0x65, 0x54, 0x79, 0x70,
-	0x65, 0x22, 0xa3, 0x03, 0x0a, 0x13, 0x47, 0x61, 0x6d, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
+	0x4a, 0x6f, 0x69, 0x6e, 0x51, 0x75, 0x65, 0x75, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64,
+	0x48, 0x00, 0x52, 0x09, 0x6a, 0x6f, 0x69, 0x6e, 0x51, 0x75, 0x65, 0x75, 0x65, 0x12, 0x5b, 0x0a,
+	0x0b, 0x6c, 0x65, 0x61, 0x76, 0x65, 0x5f, 0x71, 0x75, 0x65, 0x75, 0x65, 0x18, 0x04, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x38, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74,
+	0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77,
+	0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x65, 0x61, 0x76, 0x65,
+	0x51, 0x75, 0x65, 0x75, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x48, 0x00, 0x52, 0x0a,
+	0x6c, 0x65, 0x61, 0x76, 0x65, 0x51, 0x75, 0x65, 0x75, 0x65, 0x12, 0x65, 0x0a, 0x0f, 0x67, 0x65,
+	0x74, 0x5f, 0x6c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x18, 0x05, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x3a, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73,
+	0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e,
+	0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x4c,
+	0x65, 0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x51, 0x75, 0x65, 0x72, 0x79, 0x48,
+	0x00, 0x52, 0x0e, 0x67, 0x65, 0x74, 0x4c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72,
+	0x64, 0x42, 0x09, 0x0a, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x22, 0x62, 0x0a, 0x10,
+	0x53, 0x74, 0x61, 0x72, 0x74, 0x47, 0x61, 0x6d, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64,
+	0x12, 0x4e, 0x0a, 0x09, 0x67, 0x61, 0x6d, 0x65, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x0e, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73,
+	0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e,
+	0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61,
+	0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x52, 0x08, 0x67, 0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65,
+	0x22, 0x8a, 0x01, 0x0a, 0x10, 0x4a, 0x6f, 0x69, 0x6e, 0x51, 0x75, 0x65, 0x75, 0x65, 0x43, 0x6f,
+	0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x12, 0x4e, 0x0a, 0x09, 0x67, 0x61, 0x6d, 0x65, 0x5f, 0x74, 0x79,
+	0x70, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63,
+	0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74,
+	0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e,
+	0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x52, 0x08, 0x67, 0x61, 0x6d,
+	0x65, 0x54, 0x79, 0x70, 0x65, 0x12, 0x1b, 0x0a, 0x06, 0x72, 0x61, 0x74, 0x69, 0x6e, 0x67, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x01, 0x48, 0x00, 0x52, 0x06, 0x72, 0x61, 0x74, 0x69, 0x6e, 0x67, 0x88,
+	0x01, 0x01, 0x42, 0x09, 0x0a, 0x07, 0x5f, 0x72, 0x61, 0x74, 0x69, 0x6e, 0x67, 0x22, 0x13, 0x0a,
+	0x11, 0x4c, 0x65, 0x61, 0x76, 0x65, 0x51, 0x75, 0x65, 0x75, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61,
+	0x6e, 0x64, 0x22, 0xec, 0x01, 0x0a, 0x13, 0x47, 0x65, 0x74, 0x4c, 0x65, 0x61, 0x64, 0x65, 0x72,
+	0x62, 0x6f, 0x61, 0x72, 0x64, 0x51, 0x75, 0x65, 0x72, 0x79, 0x12, 0x4e, 0x0a, 0x09, 0x67, 0x61,
+	0x6d, 0x65, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x31, 0x2e,
+	0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73,
+	0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74,
+	0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65,
+	0x52, 0x08, 0x67, 0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x72, 0x75,
+	0x6c, 0x65, 0x73, 0x65, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09,
+	0x72, 0x75, 0x6c, 0x65, 0x73, 0x65, 0x74, 0x49, 0x64, 0x12, 0x50, 0x0a, 0x06, 0x70, 0x65, 0x72,
+	0x69, 0x6f, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x38, 0x2e, 0x72, 0x75, 0x6e, 0x65,
+	0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73,
+	0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76,
+	0x31, 0x2e, 0x4c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x50, 0x65, 0x72,
+	0x69, 0x6f, 0x64, 0x52, 0x06, 0x70, 0x65, 0x72, 0x69, 0x6f, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x6c,
+	0x69, 0x6d, 0x69, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x05, 0x6c, 0x69, 0x6d, 0x69,
+	0x74, 0x22, 0xa3, 0x03, 0x0a, 0x13, 0x47, 0x61, 0x6d, 0x65, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e,
 	0x64, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x12, 0x5d, 0x0a, 0x0b, 0x70, 0x75, 0x7a,
 	0x7a, 0x6c, 0x65, 0x5f, 0x6d, 0x6f, 0x76, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x3a,
 	0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f,
@@ -530,7 +1308,7 @@ var file_runecraftstudios_pastello_web_game_v1_envelope_proto_rawDesc = []byte{
 	0x6d, 0x65, 0x2e, 0x74, 0x72, 0x69, 0x76, 0x69, 0x61, 0x2e, 0x76, 0x31, 0x2e, 0x52, 0x65, 0x76,
 	0x65, 0x61, 0x6c, 0x48, 0x69, 0x6e, 0x74, 0x43, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x48, 0x00,
 	0x52, 0x0a, 0x74, 0x72, 0x69, 0x76, 0x69, 0x61, 0x48, 0x69, 0x6e, 0x74, 0x42, 0x09, 0x0a, 0x07,
-	0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x22, 0xd2, 0x01, 0x0a, 0x0e, 0x53, 0x65, 0x72, 0x76,
+	0x63, 0x6f, 0x6d, 0x6d, 0x61, 0x6e, 0x64, 0x22, 0xca, 0x04, 0x0a, 0x0e, 0x53, 0x65, 0x72, 0x76,
 	0x65, 0x72, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x12, 0x21, 0x0a, 0x0b, 0x61, 0x75,
 	0x74, 0x68, 0x5f, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x48,
 	0x00, 0x52, 0x0a, 0x61, 0x75, 0x74, 0x68, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x1f, 0x0a,
@@ -543,36 +1321,162 @@ var file_runecraftstudios_pastello_web_game_v1_envelope_proto_rawDesc = []byte{
 	0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e,
 	0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x45, 0x6e, 0x76, 0x65,
 	0x6c, 0x6f, 0x70, 0x65, 0x48, 0x00, 0x52, 0x09, 0x67, 0x61, 0x6d, 0x65, 0x45, 0x76, 0x65, 0x6e,
-	0x74, 0x42, 0x09, 0x0a, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x22, 0x66, 0x0a, 0x11,
-	0x47, 0x61, 0x6d, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70,
-	0x65, 0x12, 0x23, 0x0a, 0x0c, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x5f, 0x65, 0x76, 0x65, 0x6e,
-	0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x0b, 0x70, 0x75, 0x7a, 0x7a, 0x6c,
-	0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x23, 0x0a, 0x0c, 0x74, 0x72, 0x69, 0x76, 0x69, 0x61,
-	0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x0b,
-	0x74, 0x72, 0x69, 0x76, 0x69, 0x61, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x42, 0x07, 0x0a, 0x05, 0x65,
-	0x76, 0x65, 0x6e, 0x74, 0x42, 0xe1, 0x02, 0x0a, 0x29, 0x63, 0x6f, 0x6d, 0x2e, 0x72, 0x75, 0x6e,
+	0x74, 0x12, 0x57, 0x0a, 0x0c, 0x71, 0x75, 0x65, 0x75, 0x65, 0x5f, 0x73, 0x74, 0x61, 0x74, 0x75,
+	0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x32, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72,
+	0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65,
+	0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31, 0x2e,
+	0x51, 0x75, 0x65, 0x75, 0x65, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x48, 0x00, 0x52, 0x0b, 0x71,
+	0x75, 0x65, 0x75, 0x65, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x54, 0x0a, 0x0b, 0x6d, 0x61,
+	0x74, 0x63, 0x68, 0x5f, 0x66, 0x6f, 0x75, 0x6e, 0x64, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x31, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69,
+	0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e,
+	0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x4d, 0x61, 0x74, 0x63, 0x68, 0x46, 0x6f, 0x75,
+	0x6e, 0x64, 0x48, 0x00, 0x52, 0x0a, 0x6d, 0x61, 0x74, 0x63, 0x68, 0x46, 0x6f, 0x75, 0x6e, 0x64,
+	0x12, 0x56, 0x0a, 0x0b, 0x6c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x18,
+	0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x32, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66,
+	0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c,
+	0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x65,
+	0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x48, 0x00, 0x52, 0x0b, 0x6c, 0x65, 0x61,
+	0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x12, 0x6f, 0x0a, 0x14, 0x61, 0x63, 0x68, 0x69,
+	0x65, 0x76, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x5f, 0x75, 0x6e, 0x6c, 0x6f, 0x63, 0x6b, 0x65, 0x64,
+	0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x3a, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61,
+	0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c,
+	0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x41,
+	0x63, 0x68, 0x69, 0x65, 0x76, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x55, 0x6e, 0x6c, 0x6f, 0x63, 0x6b,
+	0x65, 0x64, 0x48, 0x00, 0x52, 0x13, 0x61, 0x63, 0x68, 0x69, 0x65, 0x76, 0x65, 0x6d, 0x65, 0x6e,
+	0x74, 0x55, 0x6e, 0x6c, 0x6f, 0x63, 0x6b, 0x65, 0x64, 0x42, 0x09, 0x0a, 0x07, 0x6d, 0x65, 0x73,
+	0x73, 0x61, 0x67, 0x65, 0x22, 0xb8, 0x01, 0x0a, 0x0b, 0x51, 0x75, 0x65, 0x75, 0x65, 0x53, 0x74,
+	0x61, 0x74, 0x75, 0x73, 0x12, 0x4e, 0x0a, 0x09, 0x67, 0x61, 0x6d, 0x65, 0x5f, 0x74, 0x79, 0x70,
+	0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72,
+	0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65,
+	0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76,
+	0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x52, 0x08, 0x67, 0x61, 0x6d, 0x65,
+	0x54, 0x79, 0x70, 0x65, 0x12, 0x1a, 0x0a, 0x08, 0x70, 0x6f, 0x73, 0x69, 0x74, 0x69, 0x6f, 0x6e,
+	0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x08, 0x70, 0x6f, 0x73, 0x69, 0x74, 0x69, 0x6f, 0x6e,
+	0x12, 0x16, 0x0a, 0x06, 0x71, 0x75, 0x65, 0x75, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d,
+	0x52, 0x06, 0x71, 0x75, 0x65, 0x75, 0x65, 0x64, 0x12, 0x25, 0x0a, 0x0e, 0x77, 0x61, 0x69, 0x74,
+	0x65, 0x64, 0x5f, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0d,
+	0x52, 0x0d, 0x77, 0x61, 0x69, 0x74, 0x65, 0x64, 0x53, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x22,
+	0x85, 0x02, 0x0a, 0x0a, 0x4d, 0x61, 0x74, 0x63, 0x68, 0x46, 0x6f, 0x75, 0x6e, 0x64, 0x12, 0x55,
+	0x0a, 0x0a, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x36, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74,
+	0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67,
+	0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d,
+	0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x52, 0x09, 0x73, 0x65, 0x73, 0x73,
+	0x69, 0x6f, 0x6e, 0x49, 0x64, 0x12, 0x4e, 0x0a, 0x09, 0x67, 0x61, 0x6d, 0x65, 0x5f, 0x74, 0x79,
+	0x70, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63,
+	0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74,
+	0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e,
+	0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x52, 0x08, 0x67, 0x61, 0x6d,
+	0x65, 0x54, 0x79, 0x70, 0x65, 0x12, 0x50, 0x0a, 0x0a, 0x70, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x5f,
+	0x69, 0x64, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e, 0x65,
+	0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73,
+	0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73,
+	0x2e, 0x76, 0x31, 0x2e, 0x50, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x49, 0x64, 0x52, 0x09, 0x70, 0x6c,
+	0x61, 0x79, 0x65, 0x72, 0x49, 0x64, 0x73, 0x22, 0x98, 0x03, 0x0a, 0x0b, 0x4c, 0x65, 0x61, 0x64,
+	0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x12, 0x4e, 0x0a, 0x09, 0x67, 0x61, 0x6d, 0x65, 0x5f,
+	0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x31, 0x2e, 0x72, 0x75, 0x6e,
 	0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61,
-	0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e,
-	0x76, 0x31, 0x42, 0x0d, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x50, 0x72, 0x6f, 0x74,
-	0x6f, 0x50, 0x01, 0x5a, 0x6c, 0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f,
-	0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x2d, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f,
-	0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x70, 0x61, 0x63, 0x6b, 0x61,
-	0x67, 0x65, 0x73, 0x2f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x73, 0x2f, 0x67, 0x65,
-	0x6e, 0x2f, 0x67, 0x6f, 0x2f, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74,
-	0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x77,
-	0x65, 0x62, 0x2f, 0x67, 0x61, 0x6d, 0x65, 0x2f, 0x76, 0x31, 0x3b, 0x67, 0x61, 0x6d, 0x65, 0x76,
-	0x31, 0xa2, 0x02, 0x04, 0x52, 0x50, 0x57, 0x47, 0xaa, 0x02, 0x25, 0x52, 0x75, 0x6e, 0x65, 0x63,
-	0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x50, 0x61, 0x73, 0x74,
-	0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x57, 0x65, 0x62, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x2e, 0x56, 0x31,
-	0xca, 0x02, 0x25, 0x52, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64,
-	0x69, 0x6f, 0x73, 0x5c, 0x50, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x5c, 0x57, 0x65, 0x62,
-	0x5c, 0x47, 0x61, 0x6d, 0x65, 0x5c, 0x56, 0x31, 0xe2, 0x02, 0x31, 0x52, 0x75, 0x6e, 0x65, 0x63,
-	0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x5c, 0x50, 0x61, 0x73, 0x74,
-	0x65, 0x6c, 0x6c, 0x6f, 0x5c, 0x57, 0x65, 0x62, 0x5c, 0x47, 0x61, 0x6d, 0x65, 0x5c, 0x56, 0x31,
-	0x5c, 0x47, 0x50, 0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x29, 0x52,
-	0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x3a,
-	0x3a, 0x50, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x3a, 0x3a, 0x57, 0x65, 0x62, 0x3a, 0x3a,
-	0x47, 0x61, 0x6d, 0x65, 0x3a, 0x3a, 0x56, 0x31, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+	0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65,
+	0x73, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x52, 0x08, 0x67,
+	0x61, 0x6d, 0x65, 0x54, 0x79, 0x70, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x72, 0x75, 0x6c, 0x65, 0x73,
+	0x65, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x72, 0x75, 0x6c,
+	0x65, 0x73, 0x65, 0x74, 0x49, 0x64, 0x12, 0x50, 0x0a, 0x06, 0x70, 0x65, 0x72, 0x69, 0x6f, 0x64,
+	0x18, 0x03, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x38, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61,
+	0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c,
+	0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x4c,
+	0x65, 0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x50, 0x65, 0x72, 0x69, 0x6f, 0x64,
+	0x52, 0x06, 0x70, 0x65, 0x72, 0x69, 0x6f, 0x64, 0x12, 0x16, 0x0a, 0x06, 0x77, 0x69, 0x6e, 0x64,
+	0x6f, 0x77, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x77, 0x69, 0x6e, 0x64, 0x6f, 0x77,
+	0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0d, 0x52,
+	0x05, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x12, 0x51, 0x0a, 0x07, 0x65, 0x6e, 0x74, 0x72, 0x69, 0x65,
+	0x73, 0x18, 0x06, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x37, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72,
+	0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65,
+	0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31, 0x2e,
+	0x4c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x45, 0x6e, 0x74, 0x72, 0x79,
+	0x52, 0x07, 0x65, 0x6e, 0x74, 0x72, 0x69, 0x65, 0x73, 0x12, 0x47, 0x0a, 0x02, 0x6d, 0x65, 0x18,
+	0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x37, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66,
+	0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c,
+	0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x65,
+	0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61, 0x72, 0x64, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x02,
+	0x6d, 0x65, 0x22, 0xa0, 0x02, 0x0a, 0x10, 0x4c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x62, 0x6f, 0x61,
+	0x72, 0x64, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x12, 0x0a, 0x04, 0x72, 0x61, 0x6e, 0x6b, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x04, 0x72, 0x61, 0x6e, 0x6b, 0x12, 0x4e, 0x0a, 0x09, 0x70,
+	0x6c, 0x61, 0x79, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x31,
+	0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f,
+	0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e,
+	0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x49,
+	0x64, 0x52, 0x08, 0x70, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x49, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x76,
+	0x61, 0x6c, 0x75, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x03, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75,
+	0x65, 0x12, 0x3b, 0x0a, 0x0b, 0x61, 0x63, 0x68, 0x69, 0x65, 0x76, 0x65, 0x64, 0x5f, 0x61, 0x74,
+	0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61,
+	0x6d, 0x70, 0x52, 0x0a, 0x61, 0x63, 0x68, 0x69, 0x65, 0x76, 0x65, 0x64, 0x41, 0x74, 0x12, 0x55,
+	0x0a, 0x0a, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x05, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x36, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74,
+	0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67,
+	0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d,
+	0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x52, 0x09, 0x73, 0x65, 0x73, 0x73,
+	0x69, 0x6f, 0x6e, 0x49, 0x64, 0x22, 0xa3, 0x02, 0x0a, 0x13, 0x41, 0x63, 0x68, 0x69, 0x65, 0x76,
+	0x65, 0x6d, 0x65, 0x6e, 0x74, 0x55, 0x6e, 0x6c, 0x6f, 0x63, 0x6b, 0x65, 0x64, 0x12, 0x25, 0x0a,
+	0x0e, 0x61, 0x63, 0x68, 0x69, 0x65, 0x76, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x5f, 0x69, 0x64, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0d, 0x61, 0x63, 0x68, 0x69, 0x65, 0x76, 0x65, 0x6d, 0x65,
+	0x6e, 0x74, 0x49, 0x64, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01,
+	0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x12, 0x20, 0x0a, 0x0b, 0x64, 0x65, 0x73, 0x63,
+	0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x64,
+	0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1b, 0x0a, 0x09, 0x62, 0x61,
+	0x64, 0x67, 0x65, 0x5f, 0x75, 0x72, 0x6c, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x62,
+	0x61, 0x64, 0x67, 0x65, 0x55, 0x72, 0x6c, 0x12, 0x3b, 0x0a, 0x0b, 0x75, 0x6e, 0x6c, 0x6f, 0x63,
+	0x6b, 0x65, 0x64, 0x5f, 0x61, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67,
+	0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54,
+	0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x0a, 0x75, 0x6e, 0x6c, 0x6f, 0x63, 0x6b,
+	0x65, 0x64, 0x41, 0x74, 0x12, 0x55, 0x0a, 0x0a, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x5f,
+	0x69, 0x64, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x36, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63,
+	0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74,
+	0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e,
+	0x76, 0x31, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64,
+	0x52, 0x09, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x49, 0x64, 0x22, 0x66, 0x0a, 0x11, 0x47,
+	0x61, 0x6d, 0x65, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65,
+	0x12, 0x23, 0x0a, 0x0c, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65, 0x5f, 0x65, 0x76, 0x65, 0x6e, 0x74,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x0b, 0x70, 0x75, 0x7a, 0x7a, 0x6c, 0x65,
+	0x45, 0x76, 0x65, 0x6e, 0x74, 0x12, 0x23, 0x0a, 0x0c, 0x74, 0x72, 0x69, 0x76, 0x69, 0x61, 0x5f,
+	0x65, 0x76, 0x65, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x0b, 0x74,
+	0x72, 0x69, 0x76, 0x69, 0x61, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x42, 0x07, 0x0a, 0x05, 0x65, 0x76,
+	0x65, 0x6e, 0x74, 0x2a, 0xbd, 0x01, 0x0a, 0x11, 0x4c, 0x65, 0x61, 0x64, 0x65, 0x72, 0x62, 0x6f,
+	0x61, 0x72, 0x64, 0x50, 0x65, 0x72, 0x69, 0x6f, 0x64, 0x12, 0x22, 0x0a, 0x1e, 0x4c, 0x45, 0x41,
+	0x44, 0x45, 0x52, 0x42, 0x4f, 0x41, 0x52, 0x44, 0x5f, 0x50, 0x45, 0x52, 0x49, 0x4f, 0x44, 0x5f,
+	0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x1f, 0x0a,
+	0x1b, 0x4c, 0x45, 0x41, 0x44, 0x45, 0x52, 0x42, 0x4f, 0x41, 0x52, 0x44, 0x5f, 0x50, 0x45, 0x52,
+	0x49, 0x4f, 0x44, 0x5f, 0x41, 0x4c, 0x4c, 0x5f, 0x54, 0x49, 0x4d, 0x45, 0x10, 0x01, 0x12, 0x1d,
+	0x0a, 0x19, 0x4c, 0x45, 0x41, 0x44, 0x45, 0x52, 0x42, 0x4f, 0x41, 0x52, 0x44, 0x5f, 0x50, 0x45,
+	0x52, 0x49, 0x4f, 0x44, 0x5f, 0x57, 0x45, 0x45, 0x4b, 0x4c, 0x59, 0x10, 0x02, 0x12, 0x1c, 0x0a,
+	0x18, 0x4c, 0x45, 0x41, 0x44, 0x45, 0x52, 0x42, 0x4f, 0x41, 0x52, 0x44, 0x5f, 0x50, 0x45, 0x52,
+	0x49, 0x4f, 0x44, 0x5f, 0x44, 0x41, 0x49, 0x4c, 0x59, 0x10, 0x03, 0x12, 0x26, 0x0a, 0x22, 0x4c,
+	0x45, 0x41, 0x44, 0x45, 0x52, 0x42, 0x4f, 0x41, 0x52, 0x44, 0x5f, 0x50, 0x45, 0x52, 0x49, 0x4f,
+	0x44, 0x5f, 0x44, 0x41, 0x49, 0x4c, 0x59, 0x5f, 0x43, 0x48, 0x41, 0x4c, 0x4c, 0x45, 0x4e, 0x47,
+	0x45, 0x10, 0x04, 0x42, 0xe1, 0x02, 0x0a, 0x29, 0x63, 0x6f, 0x6d, 0x2e, 0x72, 0x75, 0x6e, 0x65,
+	0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73,
+	0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76,
+	0x31, 0x42, 0x0d, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x50, 0x72, 0x6f, 0x74, 0x6f,
+	0x50, 0x01, 0x5a, 0x6c, 0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f, 0x72,
+	0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x2d, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73,
+	0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67,
+	0x65, 0x73, 0x2f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x73, 0x2f, 0x67, 0x65, 0x6e,
+	0x2f, 0x67, 0x6f, 0x2f, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75,
+	0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x77, 0x65,
+	0x62, 0x2f, 0x67, 0x61, 0x6d, 0x65, 0x2f, 0x76, 0x31, 0x3b, 0x67, 0x61, 0x6d, 0x65, 0x76, 0x31,
+	0xa2, 0x02, 0x04, 0x52, 0x50, 0x57, 0x47, 0xaa, 0x02, 0x25, 0x52, 0x75, 0x6e, 0x65, 0x63, 0x72,
+	0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x50, 0x61, 0x73, 0x74, 0x65,
+	0x6c, 0x6c, 0x6f, 0x2e, 0x57, 0x65, 0x62, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x2e, 0x56, 0x31, 0xca,
+	0x02, 0x25, 0x52, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69,
+	0x6f, 0x73, 0x5c, 0x50, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x5c, 0x57, 0x65, 0x62, 0x5c,
+	0x47, 0x61, 0x6d, 0x65, 0x5c, 0x56, 0x31, 0xe2, 0x02, 0x31, 0x52, 0x75, 0x6e, 0x65, 0x63, 0x72,
+	0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x5c, 0x50, 0x61, 0x73, 0x74, 0x65,
+	0x6c, 0x6c, 0x6f, 0x5c, 0x57, 0x65, 0x62, 0x5c, 0x47, 0x61, 0x6d, 0x65, 0x5c, 0x56, 0x31, 0x5c,
+	0x47, 0x50, 0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x29, 0x52, 0x75,
+	0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x3a, 0x3a,
+	0x50, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x3a, 0x3a, 0x57, 0x65, 0x62, 0x3a, 0x3a, 0x47,
+	0x61, 0x6d, 0x65, 0x3a, 0x3a, 0x56, 0x31, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
 }
 
 var (
@@ -587,33 +1491,69 @@ func file_runecraftstudios_pastello_web_game_v1_envelope_proto_rawDescGZIP() []b
 	return file_runecraftstudios_pastello_web_game_v1_envelope_proto_rawDescData
 }
 
-var file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes = make([]protoimpl.MessageInfo, 5)
+var file_runecraftstudios_pastello_web_game_v1_envelope_proto_enumTypes = make([]protoimpl.EnumInfo, 1)
+var file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes = make([]protoimpl.MessageInfo, 13)
 var file_runecraftstudios_pastello_web_game_v1_envelope_proto_goTypes = []interface{}{
-	(*ClientEnvelope)(nil),          // 0: runecraftstudios.pastello.web.game.v1.ClientEnvelope
-	(*StartGameCommand)(nil),        // 1: runecraftstudios.pastello.web.game.v1.StartGameCommand
-	(*GameCommandEnvelope)(nil),     // 2: runecraftstudios.pastello.web.game.v1.GameCommandEnvelope
-	(*ServerEnvelope)(nil),          // 3: runecraftstudios.pastello.web.game.v1.ServerEnvelope
-	(*GameEventEnvelope)(nil),       // 4: runecraftstudios.pastello.web.game.v1.GameEventEnvelope
-	(v1.GameType)(0),                // 5: runecraftstudios.pastello.game.types.v1.GameType
-	(*v11.MovePieceCommand)(nil),    // 6: runecraftstudios.pastello.game.puzzle.v1.MovePieceCommand
-	(*v11.UndoMoveCommand)(nil),     // 7: runecraftstudios.pastello.game.puzzle.v1.UndoMoveCommand
-	(*v12.SubmitAnswerCommand)(nil), // 8: runecraftstudios.pastello.game.trivia.v1.SubmitAnswerCommand
-	(*v12.RevealHintCommand)(nil),   // 9: runecraftstudios.pastello.game.trivia.v1.RevealHintCommand
+	(LeaderboardPeriod)(0),          // 0: runecraftstudios.pastello.web.game.v1.LeaderboardPeriod
+	(*ClientEnvelope)(nil),          // 1: runecraftstudios.pastello.web.game.v1.ClientEnvelope
+	(*StartGameCommand)(nil),        // 2: runecraftstudios.pastello.web.game.v1.StartGameCommand
+	(*JoinQueueCommand)(nil),        // 3: runecraftstudios.pastello.web.game.v1.JoinQueueCommand
+	(*LeaveQueueCommand)(nil),       // 4: runecraftstudios.pastello.web.game.v1.LeaveQueueCommand
+	(*GetLeaderboardQuery)(nil),     // 5: runecraftstudios.pastello.web.game.v1.GetLeaderboardQuery
+	(*GameCommandEnvelope)(nil),     // 6: runecraftstudios.pastello.web.game.v1.GameCommandEnvelope
+	(*ServerEnvelope)(nil),          // 7: runecraftstudios.pastello.web.game.v1.ServerEnvelope
+	(*QueueStatus)(nil),             // 8: runecraftstudios.pastello.web.game.v1.QueueStatus
+	(*MatchFound)(nil),              // 9: runecraftstudios.pastello.web.game.v1.MatchFound
+	(*Leaderboard)(nil),             // 10: runecraftstudios.pastello.web.game.v1.Leaderboard
+	(*LeaderboardEntry)(nil),        // 11: runecraftstudios.pastello.web.game.v1.LeaderboardEntry
+	(*AchievementUnlocked)(nil),     // 12: runecraftstudios.pastello.web.game.v1.AchievementUnlocked
+	(*GameEventEnvelope)(nil),       // 13: runecraftstudios.pastello.web.game.v1.GameEventEnvelope
+	(v1.GameType)(0),                // 14: runecraftstudios.pastello.game.types.v1.GameType
+	(*v11.MovePieceCommand)(nil),    // 15: runecraftstudios.pastello.game.puzzle.v1.MovePieceCommand
+	(*v11.UndoMoveCommand)(nil),     // 16: runecraftstudios.pastello.game.puzzle.v1.UndoMoveCommand
+	(*v12.SubmitAnswerCommand)(nil), // 17: runecraftstudios.pastello.game.trivia.v1.SubmitAnswerCommand
+	(*v12.RevealHintCommand)(nil),   // 18: runecraftstudios.pastello.game.trivia.v1.RevealHintCommand
+	(*v1.GameSessionId)(nil),        // 19: runecraftstudios.pastello.game.types.v1.GameSessionId
+	(*v1.PlayerId)(nil),             // 20: runecraftstudios.pastello.game.types.v1.PlayerId
+	(*timestamppb.Timestamp)(nil),   // 21: google.protobuf.Timestamp
 }
 var file_runecraftstudios_pastello_web_game_v1_envelope_proto_depIdxs = []int32{
-	1, // 0: runecraftstudios.pastello.web.game.v1.ClientEnvelope.start_game:type_name -> runecraftstudios.pastello.web.game.v1.StartGameCommand
-	2, // 1: runecraftstudios.pastello.web.game.v1.ClientEnvelope.game_command:type_name -> runecraftstudios.pastello.web.game.v1.GameCommandEnvelope
-	5, // 2: runecraftstudios.pastello.web.game.v1.StartGameCommand.game_type:type_name -> runecraftstudios.pastello.game.types.v1.GameType
-	6, // 3: runecraftstudios.pastello.web.game.v1.GameCommandEnvelope.puzzle_move:type_name -> runecraftstudios.pastello.game.puzzle.v1.MovePieceCommand
-	7, // 4: runecraftstudios.pastello.web.game.v1.GameCommandEnvelope.puzzle_undo:type_name -> runecraftstudios.pastello.game.puzzle.v1.UndoMoveCommand
-	8, // 5: runecraftstudios.pastello.web.game.v1.GameCommandEnvelope.trivia_submit:type_name -> runecraftstudios.pastello.game.trivia.v1.SubmitAnswerCommand
-	9, // 6: runecraftstudios.pastello.web.game.v1.GameCommandEnvelope.trivia_hint:type_name -> runecraftstudios.pastello.game.trivia.v1.RevealHintCommand
-	4, // 7: runecraftstudios.pastello.web.game.v1.ServerEnvelope.game_event:type_name -> runecraftstudios.pastello.web.game.v1.GameEventEnvelope
-	8, // [8:8] is the sub-list for method output_type
-	8, // [8:8] is the sub-list for method input_type
-	8, // [8:8] is the sub-list for extension type_name
-	8, // [8:8] is the sub-list for extension extendee
-	0, // [0:8] is the sub-list for field type_name
+	2,  // 0: runecraftstudios.pastello.web.game.v1.ClientEnvelope.start_game:type_name -> runecraftstudios.pastello.web.game.v1.StartGameCommand
+	6,  // 1: runecraftstudios.pastello.web.game.v1.ClientEnvelope.game_command:type_name -> runecraftstudios.pastello.web.game.v1.GameCommandEnvelope
+	3,  // 2: runecraftstudios.pastello.web.game.v1.ClientEnvelope.join_queue:type_name -> runecraftstudios.pastello.web.game.v1.JoinQueueCommand
+	4,  // 3: runecraftstudios.pastello.web.game.v1.ClientEnvelope.leave_queue:type_name -> runecraftstudios.pastello.web.game.v1.LeaveQueueCommand
+	5,  // 4: runecraftstudios.pastello.web.game.v1.ClientEnvelope.get_leaderboard:type_name -> runecraftstudios.pastello.web.game.v1.GetLeaderboardQuery
+	14, // 5: runecraftstudios.pastello.web.game.v1.StartGameCommand.game_type:type_name -> runecraftstudios.pastello.game.types.v1.GameType
+	14, // 6: runecraftstudios.pastello.web.game.v1.JoinQueueCommand.game_type:type_name -> runecraftstudios.pastello.game.types.v1.GameType
+	14, // 7: runecraftstudios.pastello.web.game.v1.GetLeaderboardQuery.game_type:type_name -> runecraftstudios.pastello.game.types.v1.GameType
+	0,  // 8: runecraftstudios.pastello.web.game.v1.GetLeaderboardQuery.period:type_name -> runecraftstudios.pastello.web.game.v1.LeaderboardPeriod
+	15, // 9: runecraftstudios.pastello.web.game.v1.GameCommandEnvelope.puzzle_move:type_name -> runecraftstudios.pastello.game.puzzle.v1.MovePieceCommand
+	16, // 10: runecraftstudios.pastello.web.game.v1.GameCommandEnvelope.puzzle_undo:type_name -> runecraftstudios.pastello.game.puzzle.v1.UndoMoveCommand
+	17, // 11: runecraftstudios.pastello.web.game.v1.GameCommandEnvelope.trivia_submit:type_name -> runecraftstudios.pastello.game.trivia.v1.SubmitAnswerCommand
+	18, // 12: runecraftstudios.pastello.web.game.v1.GameCommandEnvelope.trivia_hint:type_name -> runecraftstudios.pastello.game.trivia.v1.RevealHintCommand
+	13, // 13: runecraftstudios.pastello.web.game.v1.ServerEnvelope.game_event:type_name -> runecraftstudios.pastello.web.game.v1.GameEventEnvelope
+	8,  // 14: runecraftstudios.pastello.web.game.v1.ServerEnvelope.queue_status:type_name -> runecraftstudios.pastello.web.game.v1.QueueStatus
+	9,  // 15: runecraftstudios.pastello.web.game.v1.ServerEnvelope.match_found:type_name -> runecraftstudios.pastello.web.game.v1.MatchFound
+	10, // 16: runecraftstudios.pastello.web.game.v1.ServerEnvelope.leaderboard:type_name -> runecraftstudios.pastello.web.game.v1.Leaderboard
+	12, // 17: runecraftstudios.pastello.web.game.v1.ServerEnvelope.achievement_unlocked:type_name -> runecraftstudios.pastello.web.game.v1.AchievementUnlocked
+	14, // 18: runecraftstudios.pastello.web.game.v1.QueueStatus.game_type:type_name -> runecraftstudios.pastello.game.types.v1.GameType
+	19, // 19: runecraftstudios.pastello.web.game.v1.MatchFound.session_id:type_name -> runecraftstudios.pastello.game.types.v1.GameSessionId
+	14, // 20: runecraftstudios.pastello.web.game.v1.MatchFound.game_type:type_name -> runecraftstudios.pastello.game.types.v1.GameType
+	20, // 21: runecraftstudios.pastello.web.game.v1.MatchFound.player_ids:type_name -> runecraftstudios.pastello.game.types.v1.PlayerId
+	14, // 22: runecraftstudios.pastello.web.game.v1.Leaderboard.game_type:type_name -> runecraftstudios.pastello.game.types.v1.GameType
+	0,  // 23: runecraftstudios.pastello.web.game.v1.Leaderboard.period:type_name -> runecraftstudios.pastello.web.game.v1.LeaderboardPeriod
+	11, // 24: runecraftstudios.pastello.web.game.v1.Leaderboard.entries:type_name -> runecraftstudios.pastello.web.game.v1.LeaderboardEntry
+	11, // 25: runecraftstudios.pastello.web.game.v1.Leaderboard.me:type_name -> runecraftstudios.pastello.web.game.v1.LeaderboardEntry
+	20, // 26: runecraftstudios.pastello.web.game.v1.LeaderboardEntry.player_id:type_name -> runecraftstudios.pastello.game.types.v1.PlayerId
+	21, // 27: runecraftstudios.pastello.web.game.v1.LeaderboardEntry.achieved_at:type_name -> google.protobuf.Timestamp
+	19, // 28: runecraftstudios.pastello.web.game.v1.LeaderboardEntry.session_id:type_name -> runecraftstudios.pastello.game.types.v1.GameSessionId
+	21, // 29: runecraftstudios.pastello.web.game.v1.AchievementUnlocked.unlocked_at:type_name -> google.protobuf.Timestamp
+	19, // 30: runecraftstudios.pastello.web.game.v1.AchievementUnlocked.session_id:type_name -> runecraftstudios.pastello.game.types.v1.GameSessionId
+	31, // [31:31] is the sub-list for method output_type
+	31, // [31:31] is the sub-list for method input_type
+	31, // [31:31] is the sub-list for extension type_name
+	31, // [31:31] is the sub-list for extension extendee
+	0,  // [0:31] is the sub-list for field type_name
 }
 
 func init() { file_runecraftstudios_pastello_web_game_v1_envelope_proto_init() }
@@ -647,7 +1587,7 @@ func file_runecraftstudios_pastello_web_game_v1_envelope_proto_init() {
 			}
 		}
 		file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[2].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*GameCommandEnvelope); i {
+			switch v := v.(*JoinQueueCommand); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -659,7 +1599,7 @@ func file_runecraftstudios_pastello_web_game_v1_envelope_proto_init() {
 			}
 		}
 		file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[3].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*ServerEnvelope); i {
+			switch v := v.(*LeaveQueueCommand); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -671,6 +1611,102 @@ func file_runecraftstudios_pastello_web_game_v1_envelope_proto_init() {
 			}
 		}
 		file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[4].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GetLeaderboardQuery); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[5].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*GameCommandEnvelope); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[6].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ServerEnvelope); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[7].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueueStatus); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[8].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*MatchFound); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[9].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Leaderboard); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[10].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*LeaderboardEntry); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[11].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AchievementUnlocked); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[12].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*GameEventEnvelope); i {
 			case 0:
 				return &v.state
@@ -686,20 +1722,28 @@ func file_runecraftstudios_pastello_web_game_v1_envelope_proto_init() {
 	file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[0].OneofWrappers = []interface{}{
 		(*ClientEnvelope_StartGame)(nil),
 		(*ClientEnvelope_GameCommand)(nil),
+		(*ClientEnvelope_JoinQueue)(nil),
+		(*ClientEnvelope_LeaveQueue)(nil),
+		(*ClientEnvelope_GetLeaderboard)(nil),
 	}
-	file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[2].OneofWrappers = []interface{}{
+	file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[2].OneofWrappers = []interface{}{}
+	file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[5].OneofWrappers = []interface{}{
 		(*GameCommandEnvelope_PuzzleMove)(nil),
 		(*GameCommandEnvelope_PuzzleUndo)(nil),
 		(*GameCommandEnvelope_TriviaSubmit)(nil),
 		(*GameCommandEnvelope_TriviaHint)(nil),
 	}
-	file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[3].OneofWrappers = []interface{}{
+	file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[6].OneofWrappers = []interface{}{
 		(*ServerEnvelope_AuthStatus)(nil),
 		(*ServerEnvelope_GameState)(nil),
 		(*ServerEnvelope_Error)(nil),
 		(*ServerEnvelope_GameEvent)(nil),
+		(*ServerEnvelope_QueueStatus)(nil),
+		(*ServerEnvelope_MatchFound)(nil),
+		(*ServerEnvelope_Leaderboard)(nil),
+		(*ServerEnvelope_AchievementUnlocked)(nil),
 	}
-	file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[4].OneofWrappers = []interface{}{
+	file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes[12].OneofWrappers = []interface{}{
 		(*GameEventEnvelope_PuzzleEvent)(nil),
 		(*GameEventEnvelope_TriviaEvent)(nil),
 	}
@@ -708,13 +1752,14 @@ func file_runecraftstudios_pastello_web_game_v1_envelope_proto_init() {
 		File: protoimpl.DescBuilder{
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: file_runecraftstudios_pastello_web_game_v1_envelope_proto_rawDesc,
-			NumEnums:      0,
-			NumMessages:   5,
+			NumEnums:      1,
+			NumMessages:   13,
 			NumExtensions: 0,
 			NumServices:   0,
 		},
 		GoTypes:           file_runecraftstudios_pastello_web_game_v1_envelope_proto_goTypes,
 		DependencyIndexes: file_runecraftstudios_pastello_web_game_v1_envelope_proto_depIdxs,
+		EnumInfos:         file_runecraftstudios_pastello_web_game_v1_envelope_proto_enumTypes,
 		MessageInfos:      file_runecraftstudios_pastello_web_game_v1_envelope_proto_msgTypes,
 	}.Build()
 	File_runecraftstudios_pastello_web_game_v1_envelope_proto = out.File
@@ -0,0 +1,137 @@
+// Code generated by protoc-gen-go. DO NOT EDIT.
+// versions:
+// 	protoc-gen-go v1.34.1
+// 	protoc        (unknown)
+// source: runecraftstudios/pastello/web/game/v1/service.proto
+
+package gamev1
+
+import (
+	v1 "github.com/runecraft-studios/pastello/packages/contracts/gen/go/runecraftstudios/pastello/game/session/v1"
+	protoreflect "google.golang.org/protobuf/reflect/protoreflect"
+	protoimpl "google.golang.org/protobuf/runtime/protoimpl"
+	reflect "reflect"
+)
+
+const (
+	// Verify that this generated code is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(20 - protoimpl.MinVersion)
+	// Verify that runtime/protoimpl is sufficiently up-to-date.
+	_ = protoimpl.EnforceVersion(protoimpl.MaxVersion - 20)
+)
+
+var File_runecraftstudios_pastello_web_game_v1_service_proto protoreflect.FileDescriptor
+
+var file_runecraftstudios_pastello_web_game_v1_service_proto_rawDesc = []byte{
+	0x0a, 0x33, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69,
+	0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x77, 0x65, 0x62, 0x2f,
+	0x67, 0x61, 0x6d, 0x65, 0x2f, 0x76, 0x31, 0x2f, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x25, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74,
+	0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f,
+	0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31, 0x1a, 0x37, 0x72, 0x75,
+	0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70,
+	0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x67, 0x61, 0x6d, 0x65, 0x2f, 0x73, 0x65, 0x73,
+	0x73, 0x69, 0x6f, 0x6e, 0x2f, 0x76, 0x31, 0x2f, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2e,
+	0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x34, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74,
+	0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f,
+	0x2f, 0x77, 0x65, 0x62, 0x2f, 0x67, 0x61, 0x6d, 0x65, 0x2f, 0x76, 0x31, 0x2f, 0x65, 0x6e, 0x76,
+	0x65, 0x6c, 0x6f, 0x70, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x32, 0xc4, 0x03, 0x0a, 0x0b,
+	0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x9e, 0x01, 0x0a, 0x11,
+	0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f,
+	0x6e, 0x12, 0x43, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75,
+	0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61,
+	0x6d, 0x65, 0x2e, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x72,
+	0x65, 0x61, 0x74, 0x65, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x44, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61,
+	0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c,
+	0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2e,
+	0x76, 0x31, 0x2e, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73,
+	0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x95, 0x01, 0x0a,
+	0x0e, 0x47, 0x65, 0x74, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x12,
+	0x40, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69,
+	0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61, 0x6d, 0x65,
+	0x2e, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x74, 0x47,
+	0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x1a, 0x41, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75,
+	0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x67, 0x61,
+	0x6d, 0x65, 0x2e, 0x73, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65,
+	0x74, 0x47, 0x61, 0x6d, 0x65, 0x53, 0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x70,
+	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x7c, 0x0a, 0x08, 0x50, 0x6c, 0x61, 0x79, 0x47, 0x61, 0x6d, 0x65,
+	0x12, 0x35, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64,
+	0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62,
+	0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x45,
+	0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x1a, 0x35, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72,
+	0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74, 0x65,
+	0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31, 0x2e,
+	0x53, 0x65, 0x72, 0x76, 0x65, 0x72, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x28, 0x01,
+	0x30, 0x01, 0x42, 0xe0, 0x02, 0x0a, 0x29, 0x63, 0x6f, 0x6d, 0x2e, 0x72, 0x75, 0x6e, 0x65, 0x63,
+	0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x70, 0x61, 0x73, 0x74,
+	0x65, 0x6c, 0x6c, 0x6f, 0x2e, 0x77, 0x65, 0x62, 0x2e, 0x67, 0x61, 0x6d, 0x65, 0x2e, 0x76, 0x31,
+	0x42, 0x0c, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50, 0x01,
+	0x5a, 0x6c, 0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f, 0x72, 0x75, 0x6e,
+	0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x2d, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2f, 0x70,
+	0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x73,
+	0x2f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x73, 0x2f, 0x67, 0x65, 0x6e, 0x2f, 0x67,
+	0x6f, 0x2f, 0x72, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69,
+	0x6f, 0x73, 0x2f, 0x70, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x2f, 0x77, 0x65, 0x62, 0x2f,
+	0x67, 0x61, 0x6d, 0x65, 0x2f, 0x76, 0x31, 0x3b, 0x67, 0x61, 0x6d, 0x65, 0x76, 0x31, 0xa2, 0x02,
+	0x04, 0x52, 0x50, 0x57, 0x47, 0xaa, 0x02, 0x25, 0x52, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66,
+	0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x2e, 0x50, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c,
+	0x6f, 0x2e, 0x57, 0x65, 0x62, 0x2e, 0x47, 0x61, 0x6d, 0x65, 0x2e, 0x56, 0x31, 0xca, 0x02, 0x25,
+	0x52, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73,
+	0x5c, 0x50, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x5c, 0x57, 0x65, 0x62, 0x5c, 0x47, 0x61,
+	0x6d, 0x65, 0x5c, 0x56, 0x31, 0xe2, 0x02, 0x31, 0x52, 0x75, 0x6e, 0x65, 0x63, 0x72, 0x61, 0x66,
+	0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x5c, 0x50, 0x61, 0x73, 0x74, 0x65, 0x6c, 0x6c,
+	0x6f, 0x5c, 0x57, 0x65, 0x62, 0x5c, 0x47, 0x61, 0x6d, 0x65, 0x5c, 0x56, 0x31, 0x5c, 0x47, 0x50,
+	0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x29, 0x52, 0x75, 0x6e, 0x65,
+	0x63, 0x72, 0x61, 0x66, 0x74, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x73, 0x3a, 0x3a, 0x50, 0x61,
+	0x73, 0x74, 0x65, 0x6c, 0x6c, 0x6f, 0x3a, 0x3a, 0x57, 0x65, 0x62, 0x3a, 0x3a, 0x47, 0x61, 0x6d,
+	0x65, 0x3a, 0x3a, 0x56, 0x31, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+}
+
+var file_runecraftstudios_pastello_web_game_v1_service_proto_goTypes = []interface{}{
+	(*v1.CreateGameSessionRequest)(nil),  // 0: runecraftstudios.pastello.game.session.v1.CreateGameSessionRequest
+	(*v1.GetGameSessionRequest)(nil),     // 1: runecraftstudios.pastello.game.session.v1.GetGameSessionRequest
+	(*ClientEnvelope)(nil),               // 2: runecraftstudios.pastello.web.game.v1.ClientEnvelope
+	(*v1.CreateGameSessionResponse)(nil), // 3: runecraftstudios.pastello.game.session.v1.CreateGameSessionResponse
+	(*v1.GetGameSessionResponse)(nil),    // 4: runecraftstudios.pastello.game.session.v1.GetGameSessionResponse
+	(*ServerEnvelope)(nil),               // 5: runecraftstudios.pastello.web.game.v1.ServerEnvelope
+}
+var file_runecraftstudios_pastello_web_game_v1_service_proto_depIdxs = []int32{
+	0, // 0: runecraftstudios.pastello.web.game.v1.GameService.CreateGameSession:input_type -> runecraftstudios.pastello.game.session.v1.CreateGameSessionRequest
+	1, // 1: runecraftstudios.pastello.web.game.v1.GameService.GetGameSession:input_type -> runecraftstudios.pastello.game.session.v1.GetGameSessionRequest
+	2, // 2: runecraftstudios.pastello.web.game.v1.GameService.PlayGame:input_type -> runecraftstudios.pastello.web.game.v1.ClientEnvelope
+	3, // 3: runecraftstudios.pastello.web.game.v1.GameService.CreateGameSession:output_type -> runecraftstudios.pastello.game.session.v1.CreateGameSessionResponse
+	4, // 4: runecraftstudios.pastello.web.game.v1.GameService.GetGameSession:output_type -> runecraftstudios.pastello.game.session.v1.GetGameSessionResponse
+	5, // 5: runecraftstudios.pastello.web.game.v1.GameService.PlayGame:output_type -> runecraftstudios.pastello.web.game.v1.ServerEnvelope
+	3, // [3:6] is the sub-list for method output_type
+	0, // [0:3] is the sub-list for method input_type
+	0, // [0:0] is the sub-list for extension type_name
+	0, // [0:0] is the sub-list for extension extendee
+	0, // [0:0] is the sub-list for field type_name
+}
+
+func init() { file_runecraftstudios_pastello_web_game_v1_service_proto_init() }
+func file_runecraftstudios_pastello_web_game_v1_service_proto_init() {
+	if File_runecraftstudios_pastello_web_game_v1_service_proto != nil {
+		return
+	}
+	file_runecraftstudios_pastello_web_game_v1_envelope_proto_init()
+	type x struct{}
+	out := protoimpl.TypeBuilder{
+		File: protoimpl.DescBuilder{
+			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
+			RawDescriptor: file_runecraftstudios_pastello_web_game_v1_service_proto_rawDesc,
+			NumEnums:      0,
+			NumMessages:   0,
+			NumExtensions: 0,
+			NumServices:   1,
+		},
+		GoTypes:           file_runecraftstudios_pastello_web_game_v1_service_proto_goTypes,
+		DependencyIndexes: file_runecraftstudios_pastello_web_game_v1_service_proto_depIdxs,
+	}.Build()
+	File_runecraftstudios_pastello_web_game_v1_service_proto = out.File
+	file_runecraftstudios_pastello_web_game_v1_service_proto_rawDesc = nil
+	file_runecraftstudios_pastello_web_game_v1_service_proto_goTypes = nil
+	file_runecraftstudios_pastello_web_game_v1_service_proto_depIdxs = nil
+}
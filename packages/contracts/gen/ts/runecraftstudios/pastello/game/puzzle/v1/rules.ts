@@ -48,15 +48,57 @@ export function puzzleDifficultyToJSON(object: PuzzleDifficulty): string {
   }
 }
 
+export enum PuzzleMode {
+  PUZZLE_MODE_UNSPECIFIED = 0,
+  /** Players share one board and take turns. */
+  PUZZLE_MODE_TURN_BASED = 1,
+  /** Every player solves their own identically seeded board. */
+  PUZZLE_MODE_RACE = 2,
+  UNRECOGNIZED = -1,
+}
+
+export function puzzleModeFromJSON(object: any): PuzzleMode {
+  switch (object) {
+    case 0:
+    case "PUZZLE_MODE_UNSPECIFIED":
+      return PuzzleMode.PUZZLE_MODE_UNSPECIFIED;
+    case 1:
+    case "PUZZLE_MODE_TURN_BASED":
+      return PuzzleMode.PUZZLE_MODE_TURN_BASED;
+    case 2:
+    case "PUZZLE_MODE_RACE":
+      return PuzzleMode.PUZZLE_MODE_RACE;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return PuzzleMode.UNRECOGNIZED;
+  }
+}
+
+export function puzzleModeToJSON(object: PuzzleMode): string {
+  switch (object) {
+    case PuzzleMode.PUZZLE_MODE_UNSPECIFIED:
+      return "PUZZLE_MODE_UNSPECIFIED";
+    case PuzzleMode.PUZZLE_MODE_TURN_BASED:
+      return "PUZZLE_MODE_TURN_BASED";
+    case PuzzleMode.PUZZLE_MODE_RACE:
+      return "PUZZLE_MODE_RACE";
+    case PuzzleMode.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
 export interface PuzzleRules {
   difficulty: PuzzleDifficulty;
   allowHints: boolean;
   timeLimitSeconds: number;
   maxPlayers: number;
+  mode: PuzzleMode;
 }
 
 function createBasePuzzleRules(): PuzzleRules {
-  return { difficulty: 0, allowHints: false, timeLimitSeconds: 0, maxPlayers: 0 };
+  return { difficulty: 0, allowHints: false, timeLimitSeconds: 0, maxPlayers: 0, mode: 0 };
 }
 
 export const PuzzleRules = {
@@ -73,6 +115,9 @@ export const PuzzleRules = {
     if (message.maxPlayers !== 0) {
       writer.uint32(32).uint32(message.maxPlayers);
     }
+    if (message.mode !== 0) {
+      writer.uint32(40).int32(message.mode);
+    }
     return writer;
   },
 
@@ -111,6 +156,13 @@ export const PuzzleRules = {
 
           message.maxPlayers = reader.uint32();
           continue;
+        case 5:
+          if (tag !== 40) {
+            break;
+          }
+
+          message.mode = reader.int32() as any;
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -126,6 +178,7 @@ export const PuzzleRules = {
       allowHints: isSet(object.allowHints) ? globalThis.Boolean(object.allowHints) : false,
       timeLimitSeconds: isSet(object.timeLimitSeconds) ? globalThis.Number(object.timeLimitSeconds) : 0,
       maxPlayers: isSet(object.maxPlayers) ? globalThis.Number(object.maxPlayers) : 0,
+      mode: isSet(object.mode) ? puzzleModeFromJSON(object.mode) : 0,
     };
   },
 
@@ -143,6 +196,9 @@ export const PuzzleRules = {
     if (message.maxPlayers !== 0) {
       obj.maxPlayers = Math.round(message.maxPlayers);
     }
+    if (message.mode !== 0) {
+      obj.mode = puzzleModeToJSON(message.mode);
+    }
     return obj;
   },
 
@@ -155,6 +211,7 @@ export const PuzzleRules = {
     message.allowHints = object.allowHints ?? false;
     message.timeLimitSeconds = object.timeLimitSeconds ?? 0;
     message.maxPlayers = object.maxPlayers ?? 0;
+    message.mode = object.mode ?? 0;
     return message;
   },
 };
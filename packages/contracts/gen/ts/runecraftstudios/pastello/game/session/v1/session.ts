@@ -26,11 +26,24 @@ export interface GameSession {
     | undefined;
   /** optional external reference */
   rulesetId: string;
+  /** Read-only participants; not counted toward max_players. */
+  spectatorIds: PlayerId[];
+  /** Private rooms only, and only sent to the host: the code players join with, and when it stops working. */
+  joinCode: string;
+  joinCodeExpiresAt?: Date | undefined;
+  hasPassword: boolean;
+}
+
+/** Makes the new session a private room that players join by code. */
+export interface PrivateRoomOptions {
+  /** Optional; players must give it along with the code. */
+  password: string;
 }
 
 export interface CreateGameSessionRequest {
   gameType: GameType;
   playerIds: PlayerId[];
+  privateRoom?: PrivateRoomOptions | undefined;
   config?: { $case: "trivia"; trivia: TriviaRules } | { $case: "puzzle"; puzzle: PuzzleRules } | {
     $case: "rulesetId";
     rulesetId: string;
@@ -41,8 +54,27 @@ export interface CreateGameSessionResponse {
   session?: GameSession | undefined;
 }
 
+export interface GetGameSessionRequest {
+  id?: GameSessionId | undefined;
+}
+
+export interface GetGameSessionResponse {
+  session?: GameSession | undefined;
+}
+
 function createBaseGameSession(): GameSession {
-  return { id: undefined, gameType: 0, playerIds: [], status: 0, createdAt: undefined, rulesetId: "" };
+  return {
+    id: undefined,
+    gameType: 0,
+    playerIds: [],
+    status: 0,
+    createdAt: undefined,
+    rulesetId: "",
+    spectatorIds: [],
+    joinCode: "",
+    joinCodeExpiresAt: undefined,
+    hasPassword: false,
+  };
 }
 
 export const GameSession = {
@@ -65,6 +97,18 @@ export const GameSession = {
     if (message.rulesetId !== "") {
       writer.uint32(50).string(message.rulesetId);
     }
+    for (const v of message.spectatorIds) {
+      PlayerId.encode(v!, writer.uint32(58).fork()).ldelim();
+    }
+    if (message.joinCode !== "") {
+      writer.uint32(66).string(message.joinCode);
+    }
+    if (message.joinCodeExpiresAt !== undefined) {
+      Timestamp.encode(toTimestamp(message.joinCodeExpiresAt), writer.uint32(74).fork()).ldelim();
+    }
+    if (message.hasPassword === true) {
+      writer.uint32(80).bool(message.hasPassword);
+    }
     return writer;
   },
 
@@ -117,6 +161,34 @@ export const GameSession = {
 
           message.rulesetId = reader.string();
           continue;
+        case 7:
+          if (tag !== 58) {
+            break;
+          }
+
+          message.spectatorIds.push(PlayerId.decode(reader, reader.uint32()));
+          continue;
+        case 8:
+          if (tag !== 66) {
+            break;
+          }
+
+          message.joinCode = reader.string();
+          continue;
+        case 9:
+          if (tag !== 74) {
+            break;
+          }
+
+          message.joinCodeExpiresAt = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 10:
+          if (tag !== 80) {
+            break;
+          }
+
+          message.hasPassword = reader.bool();
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -136,6 +208,12 @@ export const GameSession = {
       status: isSet(object.status) ? sessionStatusFromJSON(object.status) : 0,
       createdAt: isSet(object.createdAt) ? fromJsonTimestamp(object.createdAt) : undefined,
       rulesetId: isSet(object.rulesetId) ? globalThis.String(object.rulesetId) : "",
+      spectatorIds: globalThis.Array.isArray(object?.spectatorIds)
+        ? object.spectatorIds.map((e: any) => PlayerId.fromJSON(e))
+        : [],
+      joinCode: isSet(object.joinCode) ? globalThis.String(object.joinCode) : "",
+      joinCodeExpiresAt: isSet(object.joinCodeExpiresAt) ? fromJsonTimestamp(object.joinCodeExpiresAt) : undefined,
+      hasPassword: isSet(object.hasPassword) ? globalThis.Boolean(object.hasPassword) : false,
     };
   },
 
@@ -159,6 +237,18 @@ export const GameSession = {
     if (message.rulesetId !== "") {
       obj.rulesetId = message.rulesetId;
     }
+    if (message.spectatorIds?.length) {
+      obj.spectatorIds = message.spectatorIds.map((e) => PlayerId.toJSON(e));
+    }
+    if (message.joinCode !== "") {
+      obj.joinCode = message.joinCode;
+    }
+    if (message.joinCodeExpiresAt !== undefined) {
+      obj.joinCodeExpiresAt = message.joinCodeExpiresAt.toISOString();
+    }
+    if (message.hasPassword === true) {
+      obj.hasPassword = message.hasPassword;
+    }
     return obj;
   },
 
@@ -173,12 +263,73 @@ export const GameSession = {
     message.status = object.status ?? 0;
     message.createdAt = object.createdAt ?? undefined;
     message.rulesetId = object.rulesetId ?? "";
+    message.spectatorIds = object.spectatorIds?.map((e) => PlayerId.fromPartial(e)) || [];
+    message.joinCode = object.joinCode ?? "";
+    message.joinCodeExpiresAt = object.joinCodeExpiresAt ?? undefined;
+    message.hasPassword = object.hasPassword ?? false;
+    return message;
+  },
+};
+
+function createBasePrivateRoomOptions(): PrivateRoomOptions {
+  return { password: "" };
+}
+
+export const PrivateRoomOptions = {
+  encode(message: PrivateRoomOptions, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.password !== "") {
+      writer.uint32(10).string(message.password);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): PrivateRoomOptions {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBasePrivateRoomOptions();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.password = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): PrivateRoomOptions {
+    return { password: isSet(object.password) ? globalThis.String(object.password) : "" };
+  },
+
+  toJSON(message: PrivateRoomOptions): unknown {
+    const obj: any = {};
+    if (message.password !== "") {
+      obj.password = message.password;
+    }
+    return obj;
+  },
+
+  create(base?: DeepPartial<PrivateRoomOptions>): PrivateRoomOptions {
+    return PrivateRoomOptions.fromPartial(base ?? {});
+  },
+  fromPartial(object: DeepPartial<PrivateRoomOptions>): PrivateRoomOptions {
+    const message = createBasePrivateRoomOptions();
+    message.password = object.password ?? "";
     return message;
   },
 };
 
 function createBaseCreateGameSessionRequest(): CreateGameSessionRequest {
-  return { gameType: 0, playerIds: [], config: undefined };
+  return { gameType: 0, playerIds: [], privateRoom: undefined, config: undefined };
 }
 
 export const CreateGameSessionRequest = {
@@ -189,6 +340,9 @@ export const CreateGameSessionRequest = {
     for (const v of message.playerIds) {
       PlayerId.encode(v!, writer.uint32(18).fork()).ldelim();
     }
+    if (message.privateRoom !== undefined) {
+      PrivateRoomOptions.encode(message.privateRoom, writer.uint32(26).fork()).ldelim();
+    }
     switch (message.config?.$case) {
       case "trivia":
         TriviaRules.encode(message.config.trivia, writer.uint32(82).fork()).ldelim();
@@ -224,6 +378,13 @@ export const CreateGameSessionRequest = {
 
           message.playerIds.push(PlayerId.decode(reader, reader.uint32()));
           continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.privateRoom = PrivateRoomOptions.decode(reader, reader.uint32());
+          continue;
         case 10:
           if (tag !== 82) {
             break;
@@ -260,6 +421,7 @@ export const CreateGameSessionRequest = {
       playerIds: globalThis.Array.isArray(object?.playerIds)
         ? object.playerIds.map((e: any) => PlayerId.fromJSON(e))
         : [],
+      privateRoom: isSet(object.privateRoom) ? PrivateRoomOptions.fromJSON(object.privateRoom) : undefined,
       config: isSet(object.trivia)
         ? { $case: "trivia", trivia: TriviaRules.fromJSON(object.trivia) }
         : isSet(object.puzzle)
@@ -278,6 +440,9 @@ export const CreateGameSessionRequest = {
     if (message.playerIds?.length) {
       obj.playerIds = message.playerIds.map((e) => PlayerId.toJSON(e));
     }
+    if (message.privateRoom !== undefined) {
+      obj.privateRoom = PrivateRoomOptions.toJSON(message.privateRoom);
+    }
     if (message.config?.$case === "trivia") {
       obj.trivia = TriviaRules.toJSON(message.config.trivia);
     }
@@ -297,6 +462,9 @@ export const CreateGameSessionRequest = {
     const message = createBaseCreateGameSessionRequest();
     message.gameType = object.gameType ?? 0;
     message.playerIds = object.playerIds?.map((e) => PlayerId.fromPartial(e)) || [];
+    message.privateRoom = (object.privateRoom !== undefined && object.privateRoom !== null)
+      ? PrivateRoomOptions.fromPartial(object.privateRoom)
+      : undefined;
     if (object.config?.$case === "trivia" && object.config?.trivia !== undefined && object.config?.trivia !== null) {
       message.config = { $case: "trivia", trivia: TriviaRules.fromPartial(object.config.trivia) };
     }
@@ -373,6 +541,122 @@ export const CreateGameSessionResponse = {
   },
 };
 
+function createBaseGetGameSessionRequest(): GetGameSessionRequest {
+  return { id: undefined };
+}
+
+export const GetGameSessionRequest = {
+  encode(message: GetGameSessionRequest, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.id !== undefined) {
+      GameSessionId.encode(message.id, writer.uint32(10).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): GetGameSessionRequest {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseGetGameSessionRequest();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.id = GameSessionId.decode(reader, reader.uint32());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): GetGameSessionRequest {
+    return { id: isSet(object.id) ? GameSessionId.fromJSON(object.id) : undefined };
+  },
+
+  toJSON(message: GetGameSessionRequest): unknown {
+    const obj: any = {};
+    if (message.id !== undefined) {
+      obj.id = GameSessionId.toJSON(message.id);
+    }
+    return obj;
+  },
+
+  create(base?: DeepPartial<GetGameSessionRequest>): GetGameSessionRequest {
+    return GetGameSessionRequest.fromPartial(base ?? {});
+  },
+  fromPartial(object: DeepPartial<GetGameSessionRequest>): GetGameSessionRequest {
+    const message = createBaseGetGameSessionRequest();
+    message.id = (object.id !== undefined && object.id !== null) ? GameSessionId.fromPartial(object.id) : undefined;
+    return message;
+  },
+};
+
+function createBaseGetGameSessionResponse(): GetGameSessionResponse {
+  return { session: undefined };
+}
+
+export const GetGameSessionResponse = {
+  encode(message: GetGameSessionResponse, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.session !== undefined) {
+      GameSession.encode(message.session, writer.uint32(10).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): GetGameSessionResponse {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseGetGameSessionResponse();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.session = GameSession.decode(reader, reader.uint32());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): GetGameSessionResponse {
+    return { session: isSet(object.session) ? GameSession.fromJSON(object.session) : undefined };
+  },
+
+  toJSON(message: GetGameSessionResponse): unknown {
+    const obj: any = {};
+    if (message.session !== undefined) {
+      obj.session = GameSession.toJSON(message.session);
+    }
+    return obj;
+  },
+
+  create(base?: DeepPartial<GetGameSessionResponse>): GetGameSessionResponse {
+    return GetGameSessionResponse.fromPartial(base ?? {});
+  },
+  fromPartial(object: DeepPartial<GetGameSessionResponse>): GetGameSessionResponse {
+    const message = createBaseGetGameSessionResponse();
+    message.session = (object.session !== undefined && object.session !== null)
+      ? GameSession.fromPartial(object.session)
+      : undefined;
+    return message;
+  },
+};
+
 type Builtin = Date | Function | Uint8Array | string | number | boolean | undefined;
 
 export type DeepPartial<T> = T extends Builtin ? T
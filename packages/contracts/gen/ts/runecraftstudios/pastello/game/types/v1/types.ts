@@ -106,7 +106,6 @@ export interface PlayerId {
 
 /** Optional audit helper (use when you need it) */
 export interface Audit {
-  /**  */
   dummySlot: number;
   createdAt?: Date | undefined;
   updatedAt?: Date | undefined;
@@ -6,24 +6,46 @@ export const protobufPackage = "runecraftstudios.pastello.web.auth.v1";
 
 export interface StartUserSessionCommand {
   clientVersion: string;
+  /** Auth token; replaces the bare token text frame of the legacy handshake. */
+  token: string;
 }
 
 export interface SessionStartedEvent {
   sessionId: string;
   userId: string;
-  createdAt?: Date | undefined;
+  createdAt?:
+    | Date
+    | undefined;
+  /** Envelope protocol version the server speaks. */
+  protocolVersion: string;
+  /** Optional features this server supports, e.g. "pastello.json.v1". */
+  capabilities: string[];
+}
+
+/**
+ * Sent instead of SessionStartedEvent when the client version is outside the supported range.
+ * The server closes the connection afterwards.
+ */
+export interface UpgradeRequired {
+  clientVersion: string;
+  minClientVersion: string;
+  /** Empty when there is no upper bound. */
+  maxClientVersion: string;
+  protocolVersion: string;
+  message: string;
 }
 
 export interface Envelope {
   correlationId: string;
-  msg?: { $case: "startUserSessionCommand"; startUserSessionCommand: StartUserSessionCommand } | {
-    $case: "sessionStartedEvent";
-    sessionStartedEvent: SessionStartedEvent;
-  } | undefined;
+  msg?:
+    | { $case: "startUserSessionCommand"; startUserSessionCommand: StartUserSessionCommand }
+    | { $case: "sessionStartedEvent"; sessionStartedEvent: SessionStartedEvent }
+    | { $case: "upgradeRequired"; upgradeRequired: UpgradeRequired }
+    | undefined;
 }
 
 function createBaseStartUserSessionCommand(): StartUserSessionCommand {
-  return { clientVersion: "" };
+  return { clientVersion: "", token: "" };
 }
 
 export const StartUserSessionCommand = {
@@ -31,6 +53,9 @@ export const StartUserSessionCommand = {
     if (message.clientVersion !== "") {
       writer.uint32(10).string(message.clientVersion);
     }
+    if (message.token !== "") {
+      writer.uint32(18).string(message.token);
+    }
     return writer;
   },
 
@@ -48,6 +73,13 @@ export const StartUserSessionCommand = {
 
           message.clientVersion = reader.string();
           continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.token = reader.string();
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -58,7 +90,10 @@ export const StartUserSessionCommand = {
   },
 
   fromJSON(object: any): StartUserSessionCommand {
-    return { clientVersion: isSet(object.clientVersion) ? globalThis.String(object.clientVersion) : "" };
+    return {
+      clientVersion: isSet(object.clientVersion) ? globalThis.String(object.clientVersion) : "",
+      token: isSet(object.token) ? globalThis.String(object.token) : "",
+    };
   },
 
   toJSON(message: StartUserSessionCommand): unknown {
@@ -66,6 +101,9 @@ export const StartUserSessionCommand = {
     if (message.clientVersion !== "") {
       obj.clientVersion = message.clientVersion;
     }
+    if (message.token !== "") {
+      obj.token = message.token;
+    }
     return obj;
   },
 
@@ -75,12 +113,13 @@ export const StartUserSessionCommand = {
   fromPartial(object: DeepPartial<StartUserSessionCommand>): StartUserSessionCommand {
     const message = createBaseStartUserSessionCommand();
     message.clientVersion = object.clientVersion ?? "";
+    message.token = object.token ?? "";
     return message;
   },
 };
 
 function createBaseSessionStartedEvent(): SessionStartedEvent {
-  return { sessionId: "", userId: "", createdAt: undefined };
+  return { sessionId: "", userId: "", createdAt: undefined, protocolVersion: "", capabilities: [] };
 }
 
 export const SessionStartedEvent = {
@@ -94,6 +133,12 @@ export const SessionStartedEvent = {
     if (message.createdAt !== undefined) {
       Timestamp.encode(toTimestamp(message.createdAt), writer.uint32(26).fork()).ldelim();
     }
+    if (message.protocolVersion !== "") {
+      writer.uint32(34).string(message.protocolVersion);
+    }
+    for (const v of message.capabilities) {
+      writer.uint32(42).string(v!);
+    }
     return writer;
   },
 
@@ -125,6 +170,20 @@ export const SessionStartedEvent = {
 
           message.createdAt = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
           continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.protocolVersion = reader.string();
+          continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          message.capabilities.push(reader.string());
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -139,6 +198,10 @@ export const SessionStartedEvent = {
       sessionId: isSet(object.sessionId) ? globalThis.String(object.sessionId) : "",
       userId: isSet(object.userId) ? globalThis.String(object.userId) : "",
       createdAt: isSet(object.createdAt) ? fromJsonTimestamp(object.createdAt) : undefined,
+      protocolVersion: isSet(object.protocolVersion) ? globalThis.String(object.protocolVersion) : "",
+      capabilities: globalThis.Array.isArray(object?.capabilities)
+        ? object.capabilities.map((e: any) => globalThis.String(e))
+        : [],
     };
   },
 
@@ -153,6 +216,12 @@ export const SessionStartedEvent = {
     if (message.createdAt !== undefined) {
       obj.createdAt = message.createdAt.toISOString();
     }
+    if (message.protocolVersion !== "") {
+      obj.protocolVersion = message.protocolVersion;
+    }
+    if (message.capabilities?.length) {
+      obj.capabilities = message.capabilities;
+    }
     return obj;
   },
 
@@ -164,6 +233,127 @@ export const SessionStartedEvent = {
     message.sessionId = object.sessionId ?? "";
     message.userId = object.userId ?? "";
     message.createdAt = object.createdAt ?? undefined;
+    message.protocolVersion = object.protocolVersion ?? "";
+    message.capabilities = object.capabilities?.map((e) => e) || [];
+    return message;
+  },
+};
+
+function createBaseUpgradeRequired(): UpgradeRequired {
+  return { clientVersion: "", minClientVersion: "", maxClientVersion: "", protocolVersion: "", message: "" };
+}
+
+export const UpgradeRequired = {
+  encode(message: UpgradeRequired, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.clientVersion !== "") {
+      writer.uint32(10).string(message.clientVersion);
+    }
+    if (message.minClientVersion !== "") {
+      writer.uint32(18).string(message.minClientVersion);
+    }
+    if (message.maxClientVersion !== "") {
+      writer.uint32(26).string(message.maxClientVersion);
+    }
+    if (message.protocolVersion !== "") {
+      writer.uint32(34).string(message.protocolVersion);
+    }
+    if (message.message !== "") {
+      writer.uint32(42).string(message.message);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): UpgradeRequired {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseUpgradeRequired();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.clientVersion = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.minClientVersion = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.maxClientVersion = reader.string();
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.protocolVersion = reader.string();
+          continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          message.message = reader.string();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): UpgradeRequired {
+    return {
+      clientVersion: isSet(object.clientVersion) ? globalThis.String(object.clientVersion) : "",
+      minClientVersion: isSet(object.minClientVersion) ? globalThis.String(object.minClientVersion) : "",
+      maxClientVersion: isSet(object.maxClientVersion) ? globalThis.String(object.maxClientVersion) : "",
+      protocolVersion: isSet(object.protocolVersion) ? globalThis.String(object.protocolVersion) : "",
+      message: isSet(object.message) ? globalThis.String(object.message) : "",
+    };
+  },
+
+  toJSON(message: UpgradeRequired): unknown {
+    const obj: any = {};
+    if (message.clientVersion !== "") {
+      obj.clientVersion = message.clientVersion;
+    }
+    if (message.minClientVersion !== "") {
+      obj.minClientVersion = message.minClientVersion;
+    }
+    if (message.maxClientVersion !== "") {
+      obj.maxClientVersion = message.maxClientVersion;
+    }
+    if (message.protocolVersion !== "") {
+      obj.protocolVersion = message.protocolVersion;
+    }
+    if (message.message !== "") {
+      obj.message = message.message;
+    }
+    return obj;
+  },
+
+  create(base?: DeepPartial<UpgradeRequired>): UpgradeRequired {
+    return UpgradeRequired.fromPartial(base ?? {});
+  },
+  fromPartial(object: DeepPartial<UpgradeRequired>): UpgradeRequired {
+    const message = createBaseUpgradeRequired();
+    message.clientVersion = object.clientVersion ?? "";
+    message.minClientVersion = object.minClientVersion ?? "";
+    message.maxClientVersion = object.maxClientVersion ?? "";
+    message.protocolVersion = object.protocolVersion ?? "";
+    message.message = object.message ?? "";
     return message;
   },
 };
@@ -184,6 +374,9 @@ export const Envelope = {
       case "sessionStartedEvent":
         SessionStartedEvent.encode(message.msg.sessionStartedEvent, writer.uint32(18).fork()).ldelim();
         break;
+      case "upgradeRequired":
+        UpgradeRequired.encode(message.msg.upgradeRequired, writer.uint32(26).fork()).ldelim();
+        break;
     }
     return writer;
   },
@@ -222,6 +415,13 @@ export const Envelope = {
             sessionStartedEvent: SessionStartedEvent.decode(reader, reader.uint32()),
           };
           continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.msg = { $case: "upgradeRequired", upgradeRequired: UpgradeRequired.decode(reader, reader.uint32()) };
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -244,6 +444,8 @@ export const Envelope = {
           $case: "sessionStartedEvent",
           sessionStartedEvent: SessionStartedEvent.fromJSON(object.sessionStartedEvent),
         }
+        : isSet(object.upgradeRequired)
+        ? { $case: "upgradeRequired", upgradeRequired: UpgradeRequired.fromJSON(object.upgradeRequired) }
         : undefined,
     };
   },
@@ -259,6 +461,9 @@ export const Envelope = {
     if (message.msg?.$case === "sessionStartedEvent") {
       obj.sessionStartedEvent = SessionStartedEvent.toJSON(message.msg.sessionStartedEvent);
     }
+    if (message.msg?.$case === "upgradeRequired") {
+      obj.upgradeRequired = UpgradeRequired.toJSON(message.msg.upgradeRequired);
+    }
     return obj;
   },
 
@@ -288,6 +493,16 @@ export const Envelope = {
         sessionStartedEvent: SessionStartedEvent.fromPartial(object.msg.sessionStartedEvent),
       };
     }
+    if (
+      object.msg?.$case === "upgradeRequired" &&
+      object.msg?.upgradeRequired !== undefined &&
+      object.msg?.upgradeRequired !== null
+    ) {
+      message.msg = {
+        $case: "upgradeRequired",
+        upgradeRequired: UpgradeRequired.fromPartial(object.msg.upgradeRequired),
+      };
+    }
     return message;
   },
 };
@@ -1,20 +1,80 @@
 /* eslint-disable */
+import Long from "long";
 import _m0 from "protobufjs/minimal";
+import { Timestamp } from "../../../../../google/protobuf/timestamp";
 import { MovePieceCommand, UndoMoveCommand } from "../../../game/puzzle/v1/commands";
 import { RevealHintCommand, SubmitAnswerCommand } from "../../../game/trivia/v1/commands";
-import { GameType, gameTypeFromJSON, gameTypeToJSON } from "../../../game/types/v1/types";
+import { GameSessionId, GameType, gameTypeFromJSON, gameTypeToJSON, PlayerId } from "../../../game/types/v1/types";
 
 export const protobufPackage = "runecraftstudios.pastello.web.game.v1";
 
+export enum LeaderboardPeriod {
+  /** Treated as all time. */
+  LEADERBOARD_PERIOD_UNSPECIFIED = 0,
+  LEADERBOARD_PERIOD_ALL_TIME = 1,
+  /** Current ISO week, from Monday 00:00 UTC. */
+  LEADERBOARD_PERIOD_WEEKLY = 2,
+  /** Current day in UTC. */
+  LEADERBOARD_PERIOD_DAILY = 3,
+  /** Today's daily challenge: one attempt per player on the same seeded game. */
+  LEADERBOARD_PERIOD_DAILY_CHALLENGE = 4,
+  UNRECOGNIZED = -1,
+}
+
+export function leaderboardPeriodFromJSON(object: any): LeaderboardPeriod {
+  switch (object) {
+    case 0:
+    case "LEADERBOARD_PERIOD_UNSPECIFIED":
+      return LeaderboardPeriod.LEADERBOARD_PERIOD_UNSPECIFIED;
+    case 1:
+    case "LEADERBOARD_PERIOD_ALL_TIME":
+      return LeaderboardPeriod.LEADERBOARD_PERIOD_ALL_TIME;
+    case 2:
+    case "LEADERBOARD_PERIOD_WEEKLY":
+      return LeaderboardPeriod.LEADERBOARD_PERIOD_WEEKLY;
+    case 3:
+    case "LEADERBOARD_PERIOD_DAILY":
+      return LeaderboardPeriod.LEADERBOARD_PERIOD_DAILY;
+    case 4:
+    case "LEADERBOARD_PERIOD_DAILY_CHALLENGE":
+      return LeaderboardPeriod.LEADERBOARD_PERIOD_DAILY_CHALLENGE;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return LeaderboardPeriod.UNRECOGNIZED;
+  }
+}
+
+export function leaderboardPeriodToJSON(object: LeaderboardPeriod): string {
+  switch (object) {
+    case LeaderboardPeriod.LEADERBOARD_PERIOD_UNSPECIFIED:
+      return "LEADERBOARD_PERIOD_UNSPECIFIED";
+    case LeaderboardPeriod.LEADERBOARD_PERIOD_ALL_TIME:
+      return "LEADERBOARD_PERIOD_ALL_TIME";
+    case LeaderboardPeriod.LEADERBOARD_PERIOD_WEEKLY:
+      return "LEADERBOARD_PERIOD_WEEKLY";
+    case LeaderboardPeriod.LEADERBOARD_PERIOD_DAILY:
+      return "LEADERBOARD_PERIOD_DAILY";
+    case LeaderboardPeriod.LEADERBOARD_PERIOD_DAILY_CHALLENGE:
+      return "LEADERBOARD_PERIOD_DAILY_CHALLENGE";
+    case LeaderboardPeriod.UNRECOGNIZED:
+    default:
+      return "UNRECOGNIZED";
+  }
+}
+
 /**
  * ClientEnvelope is the top-level message from the client over WebSocket.
  * The Rust code `ws/mod.rs` expects this structure.
  */
 export interface ClientEnvelope {
-  message?: { $case: "startGame"; startGame: StartGameCommand } | {
-    $case: "gameCommand";
-    gameCommand: GameCommandEnvelope;
-  } | undefined;
+  message?:
+    | { $case: "startGame"; startGame: StartGameCommand }
+    | { $case: "gameCommand"; gameCommand: GameCommandEnvelope }
+    | { $case: "joinQueue"; joinQueue: JoinQueueCommand }
+    | { $case: "leaveQueue"; leaveQueue: LeaveQueueCommand }
+    | { $case: "getLeaderboard"; getLeaderboard: GetLeaderboardQuery }
+    | undefined;
 }
 
 /**
@@ -25,6 +85,26 @@ export interface StartGameCommand {
   gameType: GameType;
 }
 
+/** Enters the quick-play queue for a game type. Joining again replaces the earlier entry. */
+export interface JoinQueueCommand {
+  gameType: GameType;
+  /** Ignored: players are matched on the skill rating stored on their profile. */
+  rating?: number | undefined;
+}
+
+export interface LeaveQueueCommand {
+}
+
+/** Reads a leaderboard; answered with a Leaderboard message. */
+export interface GetLeaderboardQuery {
+  gameType: GameType;
+  /** Only sessions played with this ruleset; empty ranks every session of the game type. */
+  rulesetId: string;
+  period: LeaderboardPeriod;
+  /** Entries to return; 0 uses the server default. */
+  limit: number;
+}
+
 /**
  * GameCommandEnvelope wraps all in-game actions.
  * The Rust code `ws/mod.rs` expects this structure.
@@ -48,9 +128,64 @@ export interface ServerEnvelope {
     | { $case: "gameState"; gameState: string }
     | { $case: "error"; error: string }
     | { $case: "gameEvent"; gameEvent: GameEventEnvelope }
+    | { $case: "queueStatus"; queueStatus: QueueStatus }
+    | { $case: "matchFound"; matchFound: MatchFound }
+    | { $case: "leaderboard"; leaderboard: Leaderboard }
+    | { $case: "achievementUnlocked"; achievementUnlocked: AchievementUnlocked }
     | undefined;
 }
 
+export interface QueueStatus {
+  gameType: GameType;
+  /** 1-based place in the queue; 0 once the player has left it. */
+  position: number;
+  queued: number;
+  waitedSeconds: number;
+}
+
+export interface MatchFound {
+  sessionId?: GameSessionId | undefined;
+  gameType: GameType;
+  playerIds: PlayerId[];
+}
+
+export interface Leaderboard {
+  gameType: GameType;
+  rulesetId: string;
+  period: LeaderboardPeriod;
+  /** Window the board covers, e.g. "2024-W07" or "2024-02-14"; "all" for all time. */
+  window: string;
+  /** Players ranked on the board. */
+  total: number;
+  entries: LeaderboardEntry[];
+  /** The caller's own standing; unset if they have no result on this board. */
+  me?: LeaderboardEntry | undefined;
+}
+
+export interface LeaderboardEntry {
+  rank: number;
+  playerId?:
+    | PlayerId
+    | undefined;
+  /** Final score for trivia, completion time in milliseconds for puzzles. */
+  value: number;
+  achievedAt?: Date | undefined;
+  sessionId?: GameSessionId | undefined;
+}
+
+export interface AchievementUnlocked {
+  achievementId: string;
+  name: string;
+  description: string;
+  /** Empty if the achievement has no badge image. */
+  badgeUrl: string;
+  unlockedAt?:
+    | Date
+    | undefined;
+  /** Session the achievement was earned in. */
+  sessionId?: GameSessionId | undefined;
+}
+
 /** GameEventEnvelope wraps all game-specific events. */
 export interface GameEventEnvelope {
   event?: { $case: "puzzleEvent"; puzzleEvent: string } | { $case: "triviaEvent"; triviaEvent: string } | undefined;
@@ -69,6 +204,15 @@ export const ClientEnvelope = {
       case "gameCommand":
         GameCommandEnvelope.encode(message.message.gameCommand, writer.uint32(18).fork()).ldelim();
         break;
+      case "joinQueue":
+        JoinQueueCommand.encode(message.message.joinQueue, writer.uint32(26).fork()).ldelim();
+        break;
+      case "leaveQueue":
+        LeaveQueueCommand.encode(message.message.leaveQueue, writer.uint32(34).fork()).ldelim();
+        break;
+      case "getLeaderboard":
+        GetLeaderboardQuery.encode(message.message.getLeaderboard, writer.uint32(42).fork()).ldelim();
+        break;
     }
     return writer;
   },
@@ -94,6 +238,30 @@ export const ClientEnvelope = {
 
           message.message = { $case: "gameCommand", gameCommand: GameCommandEnvelope.decode(reader, reader.uint32()) };
           continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.message = { $case: "joinQueue", joinQueue: JoinQueueCommand.decode(reader, reader.uint32()) };
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.message = { $case: "leaveQueue", leaveQueue: LeaveQueueCommand.decode(reader, reader.uint32()) };
+          continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          message.message = {
+            $case: "getLeaderboard",
+            getLeaderboard: GetLeaderboardQuery.decode(reader, reader.uint32()),
+          };
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -109,6 +277,12 @@ export const ClientEnvelope = {
         ? { $case: "startGame", startGame: StartGameCommand.fromJSON(object.startGame) }
         : isSet(object.gameCommand)
         ? { $case: "gameCommand", gameCommand: GameCommandEnvelope.fromJSON(object.gameCommand) }
+        : isSet(object.joinQueue)
+        ? { $case: "joinQueue", joinQueue: JoinQueueCommand.fromJSON(object.joinQueue) }
+        : isSet(object.leaveQueue)
+        ? { $case: "leaveQueue", leaveQueue: LeaveQueueCommand.fromJSON(object.leaveQueue) }
+        : isSet(object.getLeaderboard)
+        ? { $case: "getLeaderboard", getLeaderboard: GetLeaderboardQuery.fromJSON(object.getLeaderboard) }
         : undefined,
     };
   },
@@ -121,6 +295,15 @@ export const ClientEnvelope = {
     if (message.message?.$case === "gameCommand") {
       obj.gameCommand = GameCommandEnvelope.toJSON(message.message.gameCommand);
     }
+    if (message.message?.$case === "joinQueue") {
+      obj.joinQueue = JoinQueueCommand.toJSON(message.message.joinQueue);
+    }
+    if (message.message?.$case === "leaveQueue") {
+      obj.leaveQueue = LeaveQueueCommand.toJSON(message.message.leaveQueue);
+    }
+    if (message.message?.$case === "getLeaderboard") {
+      obj.getLeaderboard = GetLeaderboardQuery.toJSON(message.message.getLeaderboard);
+    }
     return obj;
   },
 
@@ -146,6 +329,30 @@ export const ClientEnvelope = {
         gameCommand: GameCommandEnvelope.fromPartial(object.message.gameCommand),
       };
     }
+    if (
+      object.message?.$case === "joinQueue" &&
+      object.message?.joinQueue !== undefined &&
+      object.message?.joinQueue !== null
+    ) {
+      message.message = { $case: "joinQueue", joinQueue: JoinQueueCommand.fromPartial(object.message.joinQueue) };
+    }
+    if (
+      object.message?.$case === "leaveQueue" &&
+      object.message?.leaveQueue !== undefined &&
+      object.message?.leaveQueue !== null
+    ) {
+      message.message = { $case: "leaveQueue", leaveQueue: LeaveQueueCommand.fromPartial(object.message.leaveQueue) };
+    }
+    if (
+      object.message?.$case === "getLeaderboard" &&
+      object.message?.getLeaderboard !== undefined &&
+      object.message?.getLeaderboard !== null
+    ) {
+      message.message = {
+        $case: "getLeaderboard",
+        getLeaderboard: GetLeaderboardQuery.fromPartial(object.message.getLeaderboard),
+      };
+    }
     return message;
   },
 };
@@ -207,6 +414,227 @@ export const StartGameCommand = {
   },
 };
 
+function createBaseJoinQueueCommand(): JoinQueueCommand {
+  return { gameType: 0, rating: undefined };
+}
+
+export const JoinQueueCommand = {
+  encode(message: JoinQueueCommand, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.gameType !== 0) {
+      writer.uint32(8).int32(message.gameType);
+    }
+    if (message.rating !== undefined) {
+      writer.uint32(17).double(message.rating);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): JoinQueueCommand {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseJoinQueueCommand();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.gameType = reader.int32() as any;
+          continue;
+        case 2:
+          if (tag !== 17) {
+            break;
+          }
+
+          message.rating = reader.double();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): JoinQueueCommand {
+    return {
+      gameType: isSet(object.gameType) ? gameTypeFromJSON(object.gameType) : 0,
+      rating: isSet(object.rating) ? globalThis.Number(object.rating) : undefined,
+    };
+  },
+
+  toJSON(message: JoinQueueCommand): unknown {
+    const obj: any = {};
+    if (message.gameType !== 0) {
+      obj.gameType = gameTypeToJSON(message.gameType);
+    }
+    if (message.rating !== undefined) {
+      obj.rating = message.rating;
+    }
+    return obj;
+  },
+
+  create(base?: DeepPartial<JoinQueueCommand>): JoinQueueCommand {
+    return JoinQueueCommand.fromPartial(base ?? {});
+  },
+  fromPartial(object: DeepPartial<JoinQueueCommand>): JoinQueueCommand {
+    const message = createBaseJoinQueueCommand();
+    message.gameType = object.gameType ?? 0;
+    message.rating = object.rating ?? undefined;
+    return message;
+  },
+};
+
+function createBaseLeaveQueueCommand(): LeaveQueueCommand {
+  return {};
+}
+
+export const LeaveQueueCommand = {
+  encode(_: LeaveQueueCommand, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): LeaveQueueCommand {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseLeaveQueueCommand();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(_: any): LeaveQueueCommand {
+    return {};
+  },
+
+  toJSON(_: LeaveQueueCommand): unknown {
+    const obj: any = {};
+    return obj;
+  },
+
+  create(base?: DeepPartial<LeaveQueueCommand>): LeaveQueueCommand {
+    return LeaveQueueCommand.fromPartial(base ?? {});
+  },
+  fromPartial(_: DeepPartial<LeaveQueueCommand>): LeaveQueueCommand {
+    const message = createBaseLeaveQueueCommand();
+    return message;
+  },
+};
+
+function createBaseGetLeaderboardQuery(): GetLeaderboardQuery {
+  return { gameType: 0, rulesetId: "", period: 0, limit: 0 };
+}
+
+export const GetLeaderboardQuery = {
+  encode(message: GetLeaderboardQuery, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.gameType !== 0) {
+      writer.uint32(8).int32(message.gameType);
+    }
+    if (message.rulesetId !== "") {
+      writer.uint32(18).string(message.rulesetId);
+    }
+    if (message.period !== 0) {
+      writer.uint32(24).int32(message.period);
+    }
+    if (message.limit !== 0) {
+      writer.uint32(32).uint32(message.limit);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): GetLeaderboardQuery {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseGetLeaderboardQuery();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.gameType = reader.int32() as any;
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.rulesetId = reader.string();
+          continue;
+        case 3:
+          if (tag !== 24) {
+            break;
+          }
+
+          message.period = reader.int32() as any;
+          continue;
+        case 4:
+          if (tag !== 32) {
+            break;
+          }
+
+          message.limit = reader.uint32();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): GetLeaderboardQuery {
+    return {
+      gameType: isSet(object.gameType) ? gameTypeFromJSON(object.gameType) : 0,
+      rulesetId: isSet(object.rulesetId) ? globalThis.String(object.rulesetId) : "",
+      period: isSet(object.period) ? leaderboardPeriodFromJSON(object.period) : 0,
+      limit: isSet(object.limit) ? globalThis.Number(object.limit) : 0,
+    };
+  },
+
+  toJSON(message: GetLeaderboardQuery): unknown {
+    const obj: any = {};
+    if (message.gameType !== 0) {
+      obj.gameType = gameTypeToJSON(message.gameType);
+    }
+    if (message.rulesetId !== "") {
+      obj.rulesetId = message.rulesetId;
+    }
+    if (message.period !== 0) {
+      obj.period = leaderboardPeriodToJSON(message.period);
+    }
+    if (message.limit !== 0) {
+      obj.limit = Math.round(message.limit);
+    }
+    return obj;
+  },
+
+  create(base?: DeepPartial<GetLeaderboardQuery>): GetLeaderboardQuery {
+    return GetLeaderboardQuery.fromPartial(base ?? {});
+  },
+  fromPartial(object: DeepPartial<GetLeaderboardQuery>): GetLeaderboardQuery {
+    const message = createBaseGetLeaderboardQuery();
+    message.gameType = object.gameType ?? 0;
+    message.rulesetId = object.rulesetId ?? "";
+    message.period = object.period ?? 0;
+    message.limit = object.limit ?? 0;
+    return message;
+  },
+};
+
 function createBaseGameCommandEnvelope(): GameCommandEnvelope {
   return { command: undefined };
 }
@@ -367,6 +795,18 @@ export const ServerEnvelope = {
       case "gameEvent":
         GameEventEnvelope.encode(message.message.gameEvent, writer.uint32(34).fork()).ldelim();
         break;
+      case "queueStatus":
+        QueueStatus.encode(message.message.queueStatus, writer.uint32(42).fork()).ldelim();
+        break;
+      case "matchFound":
+        MatchFound.encode(message.message.matchFound, writer.uint32(50).fork()).ldelim();
+        break;
+      case "leaderboard":
+        Leaderboard.encode(message.message.leaderboard, writer.uint32(58).fork()).ldelim();
+        break;
+      case "achievementUnlocked":
+        AchievementUnlocked.encode(message.message.achievementUnlocked, writer.uint32(66).fork()).ldelim();
+        break;
     }
     return writer;
   },
@@ -406,6 +846,37 @@ export const ServerEnvelope = {
 
           message.message = { $case: "gameEvent", gameEvent: GameEventEnvelope.decode(reader, reader.uint32()) };
           continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          message.message = { $case: "queueStatus", queueStatus: QueueStatus.decode(reader, reader.uint32()) };
+          continue;
+        case 6:
+          if (tag !== 50) {
+            break;
+          }
+
+          message.message = { $case: "matchFound", matchFound: MatchFound.decode(reader, reader.uint32()) };
+          continue;
+        case 7:
+          if (tag !== 58) {
+            break;
+          }
+
+          message.message = { $case: "leaderboard", leaderboard: Leaderboard.decode(reader, reader.uint32()) };
+          continue;
+        case 8:
+          if (tag !== 66) {
+            break;
+          }
+
+          message.message = {
+            $case: "achievementUnlocked",
+            achievementUnlocked: AchievementUnlocked.decode(reader, reader.uint32()),
+          };
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -425,6 +896,17 @@ export const ServerEnvelope = {
         ? { $case: "error", error: globalThis.String(object.error) }
         : isSet(object.gameEvent)
         ? { $case: "gameEvent", gameEvent: GameEventEnvelope.fromJSON(object.gameEvent) }
+        : isSet(object.queueStatus)
+        ? { $case: "queueStatus", queueStatus: QueueStatus.fromJSON(object.queueStatus) }
+        : isSet(object.matchFound)
+        ? { $case: "matchFound", matchFound: MatchFound.fromJSON(object.matchFound) }
+        : isSet(object.leaderboard)
+        ? { $case: "leaderboard", leaderboard: Leaderboard.fromJSON(object.leaderboard) }
+        : isSet(object.achievementUnlocked)
+        ? {
+          $case: "achievementUnlocked",
+          achievementUnlocked: AchievementUnlocked.fromJSON(object.achievementUnlocked),
+        }
         : undefined,
     };
   },
@@ -443,6 +925,18 @@ export const ServerEnvelope = {
     if (message.message?.$case === "gameEvent") {
       obj.gameEvent = GameEventEnvelope.toJSON(message.message.gameEvent);
     }
+    if (message.message?.$case === "queueStatus") {
+      obj.queueStatus = QueueStatus.toJSON(message.message.queueStatus);
+    }
+    if (message.message?.$case === "matchFound") {
+      obj.matchFound = MatchFound.toJSON(message.message.matchFound);
+    }
+    if (message.message?.$case === "leaderboard") {
+      obj.leaderboard = Leaderboard.toJSON(message.message.leaderboard);
+    }
+    if (message.message?.$case === "achievementUnlocked") {
+      obj.achievementUnlocked = AchievementUnlocked.toJSON(message.message.achievementUnlocked);
+    }
     return obj;
   },
 
@@ -475,11 +969,649 @@ export const ServerEnvelope = {
     ) {
       message.message = { $case: "gameEvent", gameEvent: GameEventEnvelope.fromPartial(object.message.gameEvent) };
     }
+    if (
+      object.message?.$case === "queueStatus" &&
+      object.message?.queueStatus !== undefined &&
+      object.message?.queueStatus !== null
+    ) {
+      message.message = { $case: "queueStatus", queueStatus: QueueStatus.fromPartial(object.message.queueStatus) };
+    }
+    if (
+      object.message?.$case === "matchFound" &&
+      object.message?.matchFound !== undefined &&
+      object.message?.matchFound !== null
+    ) {
+      message.message = { $case: "matchFound", matchFound: MatchFound.fromPartial(object.message.matchFound) };
+    }
+    if (
+      object.message?.$case === "leaderboard" &&
+      object.message?.leaderboard !== undefined &&
+      object.message?.leaderboard !== null
+    ) {
+      message.message = { $case: "leaderboard", leaderboard: Leaderboard.fromPartial(object.message.leaderboard) };
+    }
+    if (
+      object.message?.$case === "achievementUnlocked" &&
+      object.message?.achievementUnlocked !== undefined &&
+      object.message?.achievementUnlocked !== null
+    ) {
+      message.message = {
+        $case: "achievementUnlocked",
+        achievementUnlocked: AchievementUnlocked.fromPartial(object.message.achievementUnlocked),
+      };
+    }
     return message;
   },
 };
 
-function createBaseGameEventEnvelope(): GameEventEnvelope {
+function createBaseQueueStatus(): QueueStatus {
+  return { gameType: 0, position: 0, queued: 0, waitedSeconds: 0 };
+}
+
+export const QueueStatus = {
+  encode(message: QueueStatus, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.gameType !== 0) {
+      writer.uint32(8).int32(message.gameType);
+    }
+    if (message.position !== 0) {
+      writer.uint32(16).uint32(message.position);
+    }
+    if (message.queued !== 0) {
+      writer.uint32(24).uint32(message.queued);
+    }
+    if (message.waitedSeconds !== 0) {
+      writer.uint32(32).uint32(message.waitedSeconds);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): QueueStatus {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseQueueStatus();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.gameType = reader.int32() as any;
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.position = reader.uint32();
+          continue;
+        case 3:
+          if (tag !== 24) {
+            break;
+          }
+
+          message.queued = reader.uint32();
+          continue;
+        case 4:
+          if (tag !== 32) {
+            break;
+          }
+
+          message.waitedSeconds = reader.uint32();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): QueueStatus {
+    return {
+      gameType: isSet(object.gameType) ? gameTypeFromJSON(object.gameType) : 0,
+      position: isSet(object.position) ? globalThis.Number(object.position) : 0,
+      queued: isSet(object.queued) ? globalThis.Number(object.queued) : 0,
+      waitedSeconds: isSet(object.waitedSeconds) ? globalThis.Number(object.waitedSeconds) : 0,
+    };
+  },
+
+  toJSON(message: QueueStatus): unknown {
+    const obj: any = {};
+    if (message.gameType !== 0) {
+      obj.gameType = gameTypeToJSON(message.gameType);
+    }
+    if (message.position !== 0) {
+      obj.position = Math.round(message.position);
+    }
+    if (message.queued !== 0) {
+      obj.queued = Math.round(message.queued);
+    }
+    if (message.waitedSeconds !== 0) {
+      obj.waitedSeconds = Math.round(message.waitedSeconds);
+    }
+    return obj;
+  },
+
+  create(base?: DeepPartial<QueueStatus>): QueueStatus {
+    return QueueStatus.fromPartial(base ?? {});
+  },
+  fromPartial(object: DeepPartial<QueueStatus>): QueueStatus {
+    const message = createBaseQueueStatus();
+    message.gameType = object.gameType ?? 0;
+    message.position = object.position ?? 0;
+    message.queued = object.queued ?? 0;
+    message.waitedSeconds = object.waitedSeconds ?? 0;
+    return message;
+  },
+};
+
+function createBaseMatchFound(): MatchFound {
+  return { sessionId: undefined, gameType: 0, playerIds: [] };
+}
+
+export const MatchFound = {
+  encode(message: MatchFound, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.sessionId !== undefined) {
+      GameSessionId.encode(message.sessionId, writer.uint32(10).fork()).ldelim();
+    }
+    if (message.gameType !== 0) {
+      writer.uint32(16).int32(message.gameType);
+    }
+    for (const v of message.playerIds) {
+      PlayerId.encode(v!, writer.uint32(26).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): MatchFound {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseMatchFound();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.sessionId = GameSessionId.decode(reader, reader.uint32());
+          continue;
+        case 2:
+          if (tag !== 16) {
+            break;
+          }
+
+          message.gameType = reader.int32() as any;
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.playerIds.push(PlayerId.decode(reader, reader.uint32()));
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): MatchFound {
+    return {
+      sessionId: isSet(object.sessionId) ? GameSessionId.fromJSON(object.sessionId) : undefined,
+      gameType: isSet(object.gameType) ? gameTypeFromJSON(object.gameType) : 0,
+      playerIds: globalThis.Array.isArray(object?.playerIds)
+        ? object.playerIds.map((e: any) => PlayerId.fromJSON(e))
+        : [],
+    };
+  },
+
+  toJSON(message: MatchFound): unknown {
+    const obj: any = {};
+    if (message.sessionId !== undefined) {
+      obj.sessionId = GameSessionId.toJSON(message.sessionId);
+    }
+    if (message.gameType !== 0) {
+      obj.gameType = gameTypeToJSON(message.gameType);
+    }
+    if (message.playerIds?.length) {
+      obj.playerIds = message.playerIds.map((e) => PlayerId.toJSON(e));
+    }
+    return obj;
+  },
+
+  create(base?: DeepPartial<MatchFound>): MatchFound {
+    return MatchFound.fromPartial(base ?? {});
+  },
+  fromPartial(object: DeepPartial<MatchFound>): MatchFound {
+    const message = createBaseMatchFound();
+    message.sessionId = (object.sessionId !== undefined && object.sessionId !== null)
+      ? GameSessionId.fromPartial(object.sessionId)
+      : undefined;
+    message.gameType = object.gameType ?? 0;
+    message.playerIds = object.playerIds?.map((e) => PlayerId.fromPartial(e)) || [];
+    return message;
+  },
+};
+
+function createBaseLeaderboard(): Leaderboard {
+  return { gameType: 0, rulesetId: "", period: 0, window: "", total: 0, entries: [], me: undefined };
+}
+
+export const Leaderboard = {
+  encode(message: Leaderboard, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.gameType !== 0) {
+      writer.uint32(8).int32(message.gameType);
+    }
+    if (message.rulesetId !== "") {
+      writer.uint32(18).string(message.rulesetId);
+    }
+    if (message.period !== 0) {
+      writer.uint32(24).int32(message.period);
+    }
+    if (message.window !== "") {
+      writer.uint32(34).string(message.window);
+    }
+    if (message.total !== 0) {
+      writer.uint32(40).uint32(message.total);
+    }
+    for (const v of message.entries) {
+      LeaderboardEntry.encode(v!, writer.uint32(50).fork()).ldelim();
+    }
+    if (message.me !== undefined) {
+      LeaderboardEntry.encode(message.me, writer.uint32(58).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): Leaderboard {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseLeaderboard();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.gameType = reader.int32() as any;
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.rulesetId = reader.string();
+          continue;
+        case 3:
+          if (tag !== 24) {
+            break;
+          }
+
+          message.period = reader.int32() as any;
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.window = reader.string();
+          continue;
+        case 5:
+          if (tag !== 40) {
+            break;
+          }
+
+          message.total = reader.uint32();
+          continue;
+        case 6:
+          if (tag !== 50) {
+            break;
+          }
+
+          message.entries.push(LeaderboardEntry.decode(reader, reader.uint32()));
+          continue;
+        case 7:
+          if (tag !== 58) {
+            break;
+          }
+
+          message.me = LeaderboardEntry.decode(reader, reader.uint32());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): Leaderboard {
+    return {
+      gameType: isSet(object.gameType) ? gameTypeFromJSON(object.gameType) : 0,
+      rulesetId: isSet(object.rulesetId) ? globalThis.String(object.rulesetId) : "",
+      period: isSet(object.period) ? leaderboardPeriodFromJSON(object.period) : 0,
+      window: isSet(object.window) ? globalThis.String(object.window) : "",
+      total: isSet(object.total) ? globalThis.Number(object.total) : 0,
+      entries: globalThis.Array.isArray(object?.entries)
+        ? object.entries.map((e: any) => LeaderboardEntry.fromJSON(e))
+        : [],
+      me: isSet(object.me) ? LeaderboardEntry.fromJSON(object.me) : undefined,
+    };
+  },
+
+  toJSON(message: Leaderboard): unknown {
+    const obj: any = {};
+    if (message.gameType !== 0) {
+      obj.gameType = gameTypeToJSON(message.gameType);
+    }
+    if (message.rulesetId !== "") {
+      obj.rulesetId = message.rulesetId;
+    }
+    if (message.period !== 0) {
+      obj.period = leaderboardPeriodToJSON(message.period);
+    }
+    if (message.window !== "") {
+      obj.window = message.window;
+    }
+    if (message.total !== 0) {
+      obj.total = Math.round(message.total);
+    }
+    if (message.entries?.length) {
+      obj.entries = message.entries.map((e) => LeaderboardEntry.toJSON(e));
+    }
+    if (message.me !== undefined) {
+      obj.me = LeaderboardEntry.toJSON(message.me);
+    }
+    return obj;
+  },
+
+  create(base?: DeepPartial<Leaderboard>): Leaderboard {
+    return Leaderboard.fromPartial(base ?? {});
+  },
+  fromPartial(object: DeepPartial<Leaderboard>): Leaderboard {
+    const message = createBaseLeaderboard();
+    message.gameType = object.gameType ?? 0;
+    message.rulesetId = object.rulesetId ?? "";
+    message.period = object.period ?? 0;
+    message.window = object.window ?? "";
+    message.total = object.total ?? 0;
+    message.entries = object.entries?.map((e) => LeaderboardEntry.fromPartial(e)) || [];
+    message.me = (object.me !== undefined && object.me !== null) ? LeaderboardEntry.fromPartial(object.me) : undefined;
+    return message;
+  },
+};
+
+function createBaseLeaderboardEntry(): LeaderboardEntry {
+  return { rank: 0, playerId: undefined, value: 0, achievedAt: undefined, sessionId: undefined };
+}
+
+export const LeaderboardEntry = {
+  encode(message: LeaderboardEntry, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.rank !== 0) {
+      writer.uint32(8).uint32(message.rank);
+    }
+    if (message.playerId !== undefined) {
+      PlayerId.encode(message.playerId, writer.uint32(18).fork()).ldelim();
+    }
+    if (message.value !== 0) {
+      writer.uint32(24).int64(message.value);
+    }
+    if (message.achievedAt !== undefined) {
+      Timestamp.encode(toTimestamp(message.achievedAt), writer.uint32(34).fork()).ldelim();
+    }
+    if (message.sessionId !== undefined) {
+      GameSessionId.encode(message.sessionId, writer.uint32(42).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): LeaderboardEntry {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseLeaderboardEntry();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 8) {
+            break;
+          }
+
+          message.rank = reader.uint32();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.playerId = PlayerId.decode(reader, reader.uint32());
+          continue;
+        case 3:
+          if (tag !== 24) {
+            break;
+          }
+
+          message.value = longToNumber(reader.int64() as Long);
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.achievedAt = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          message.sessionId = GameSessionId.decode(reader, reader.uint32());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): LeaderboardEntry {
+    return {
+      rank: isSet(object.rank) ? globalThis.Number(object.rank) : 0,
+      playerId: isSet(object.playerId) ? PlayerId.fromJSON(object.playerId) : undefined,
+      value: isSet(object.value) ? globalThis.Number(object.value) : 0,
+      achievedAt: isSet(object.achievedAt) ? fromJsonTimestamp(object.achievedAt) : undefined,
+      sessionId: isSet(object.sessionId) ? GameSessionId.fromJSON(object.sessionId) : undefined,
+    };
+  },
+
+  toJSON(message: LeaderboardEntry): unknown {
+    const obj: any = {};
+    if (message.rank !== 0) {
+      obj.rank = Math.round(message.rank);
+    }
+    if (message.playerId !== undefined) {
+      obj.playerId = PlayerId.toJSON(message.playerId);
+    }
+    if (message.value !== 0) {
+      obj.value = Math.round(message.value);
+    }
+    if (message.achievedAt !== undefined) {
+      obj.achievedAt = message.achievedAt.toISOString();
+    }
+    if (message.sessionId !== undefined) {
+      obj.sessionId = GameSessionId.toJSON(message.sessionId);
+    }
+    return obj;
+  },
+
+  create(base?: DeepPartial<LeaderboardEntry>): LeaderboardEntry {
+    return LeaderboardEntry.fromPartial(base ?? {});
+  },
+  fromPartial(object: DeepPartial<LeaderboardEntry>): LeaderboardEntry {
+    const message = createBaseLeaderboardEntry();
+    message.rank = object.rank ?? 0;
+    message.playerId = (object.playerId !== undefined && object.playerId !== null)
+      ? PlayerId.fromPartial(object.playerId)
+      : undefined;
+    message.value = object.value ?? 0;
+    message.achievedAt = object.achievedAt ?? undefined;
+    message.sessionId = (object.sessionId !== undefined && object.sessionId !== null)
+      ? GameSessionId.fromPartial(object.sessionId)
+      : undefined;
+    return message;
+  },
+};
+
+function createBaseAchievementUnlocked(): AchievementUnlocked {
+  return { achievementId: "", name: "", description: "", badgeUrl: "", unlockedAt: undefined, sessionId: undefined };
+}
+
+export const AchievementUnlocked = {
+  encode(message: AchievementUnlocked, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.achievementId !== "") {
+      writer.uint32(10).string(message.achievementId);
+    }
+    if (message.name !== "") {
+      writer.uint32(18).string(message.name);
+    }
+    if (message.description !== "") {
+      writer.uint32(26).string(message.description);
+    }
+    if (message.badgeUrl !== "") {
+      writer.uint32(34).string(message.badgeUrl);
+    }
+    if (message.unlockedAt !== undefined) {
+      Timestamp.encode(toTimestamp(message.unlockedAt), writer.uint32(42).fork()).ldelim();
+    }
+    if (message.sessionId !== undefined) {
+      GameSessionId.encode(message.sessionId, writer.uint32(50).fork()).ldelim();
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): AchievementUnlocked {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseAchievementUnlocked();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.achievementId = reader.string();
+          continue;
+        case 2:
+          if (tag !== 18) {
+            break;
+          }
+
+          message.name = reader.string();
+          continue;
+        case 3:
+          if (tag !== 26) {
+            break;
+          }
+
+          message.description = reader.string();
+          continue;
+        case 4:
+          if (tag !== 34) {
+            break;
+          }
+
+          message.badgeUrl = reader.string();
+          continue;
+        case 5:
+          if (tag !== 42) {
+            break;
+          }
+
+          message.unlockedAt = fromTimestamp(Timestamp.decode(reader, reader.uint32()));
+          continue;
+        case 6:
+          if (tag !== 50) {
+            break;
+          }
+
+          message.sessionId = GameSessionId.decode(reader, reader.uint32());
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  fromJSON(object: any): AchievementUnlocked {
+    return {
+      achievementId: isSet(object.achievementId) ? globalThis.String(object.achievementId) : "",
+      name: isSet(object.name) ? globalThis.String(object.name) : "",
+      description: isSet(object.description) ? globalThis.String(object.description) : "",
+      badgeUrl: isSet(object.badgeUrl) ? globalThis.String(object.badgeUrl) : "",
+      unlockedAt: isSet(object.unlockedAt) ? fromJsonTimestamp(object.unlockedAt) : undefined,
+      sessionId: isSet(object.sessionId) ? GameSessionId.fromJSON(object.sessionId) : undefined,
+    };
+  },
+
+  toJSON(message: AchievementUnlocked): unknown {
+    const obj: any = {};
+    if (message.achievementId !== "") {
+      obj.achievementId = message.achievementId;
+    }
+    if (message.name !== "") {
+      obj.name = message.name;
+    }
+    if (message.description !== "") {
+      obj.description = message.description;
+    }
+    if (message.badgeUrl !== "") {
+      obj.badgeUrl = message.badgeUrl;
+    }
+    if (message.unlockedAt !== undefined) {
+      obj.unlockedAt = message.unlockedAt.toISOString();
+    }
+    if (message.sessionId !== undefined) {
+      obj.sessionId = GameSessionId.toJSON(message.sessionId);
+    }
+    return obj;
+  },
+
+  create(base?: DeepPartial<AchievementUnlocked>): AchievementUnlocked {
+    return AchievementUnlocked.fromPartial(base ?? {});
+  },
+  fromPartial(object: DeepPartial<AchievementUnlocked>): AchievementUnlocked {
+    const message = createBaseAchievementUnlocked();
+    message.achievementId = object.achievementId ?? "";
+    message.name = object.name ?? "";
+    message.description = object.description ?? "";
+    message.badgeUrl = object.badgeUrl ?? "";
+    message.unlockedAt = object.unlockedAt ?? undefined;
+    message.sessionId = (object.sessionId !== undefined && object.sessionId !== null)
+      ? GameSessionId.fromPartial(object.sessionId)
+      : undefined;
+    return message;
+  },
+};
+
+function createBaseGameEventEnvelope(): GameEventEnvelope {
   return { event: undefined };
 }
 
@@ -579,6 +1711,40 @@ export type DeepPartial<T> = T extends Builtin ? T
   : T extends {} ? { [K in keyof T]?: DeepPartial<T[K]> }
   : Partial<T>;
 
+function toTimestamp(date: Date): Timestamp {
+  const seconds = Math.trunc(date.getTime() / 1_000);
+  const nanos = (date.getTime() % 1_000) * 1_000_000;
+  return { seconds, nanos };
+}
+
+function fromTimestamp(t: Timestamp): Date {
+  let millis = (t.seconds || 0) * 1_000;
+  millis += (t.nanos || 0) / 1_000_000;
+  return new globalThis.Date(millis);
+}
+
+function fromJsonTimestamp(o: any): Date {
+  if (o instanceof globalThis.Date) {
+    return o;
+  } else if (typeof o === "string") {
+    return new globalThis.Date(o);
+  } else {
+    return fromTimestamp(Timestamp.fromJSON(o));
+  }
+}
+
+function longToNumber(long: Long): number {
+  if (long.gt(globalThis.Number.MAX_SAFE_INTEGER)) {
+    throw new globalThis.Error("Value is larger than Number.MAX_SAFE_INTEGER");
+  }
+  return long.toNumber();
+}
+
+if (_m0.util.Long !== Long) {
+  _m0.util.Long = Long as any;
+  _m0.configure();
+}
+
 function isSet(value: any): boolean {
   return value !== null && value !== undefined;
 }
@@ -0,0 +1,3 @@
+/* eslint-disable */
+
+export const protobufPackage = "runecraftstudios.pastello.web.game.v1";